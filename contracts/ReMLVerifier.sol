@@ -8,11 +8,17 @@ pragma solidity ^0.8.20;
  *      1. Verify STARK proof commitment structures
  *      2. Check if a request has been verified via Re-ML
  *      3. Get information about verified batches
+ *      4. Use up a verified request exactly once
+ *      5. Allocate collision-free request IDs
  *
  * Precompile Addresses:
  * - 0x20: VerifyStarkCommitment (50,000 base gas + 100/byte)
  * - 0x21: IsRequestVerified (10,000 gas)
  * - 0x22: GetBatchInfo (15,000 gas)
+ * - 0x23: ConsumeVerifiedRequest (30,000 gas)
+ * - 0x24: AllocateRequestId (25,000 gas)
+ *
+ * IDs are passed to the precompiles as little-endian u64.
  */
 
 /**
@@ -51,6 +57,32 @@ interface IReMLVerifier {
             uint32 signatureCount,
             uint64 verifiedAtBlock
         );
+
+    /**
+     * @notice Use up a verified request on behalf of the calling contract
+     * @param requestId The request ID to consume (little-endian u64)
+     * @param callTag Tag identifying the consuming call, recorded on-chain
+     * @return consumed True once the request is marked as used
+     *
+     * @dev Same semantics as pallet_reml_verifier::consume_verified_request:
+     * reverts with "ReML: request not verified" or "ReML: request already
+     * consumed", so a request can authorize a single action chain-wide.
+     * Not callable via staticcall.
+     */
+    function consumeVerifiedRequest(
+        uint64 requestId,
+        bytes32 callTag
+    ) external returns (bool consumed);
+
+    /**
+     * @notice Allocate the next Re-ML request ID to the calling contract
+     * @return requestId The allocated request ID (little-endian u64)
+     *
+     * @dev IDs come from pallet_reml_verifier's on-chain counter, so they
+     * never collide with other contracts or users. Proofs are only accepted
+     * for allocated IDs. Not callable via staticcall.
+     */
+    function allocateRequestId() external returns (uint64 requestId);
 }
 
 /**
@@ -63,6 +95,17 @@ library ReMLVerifierLib {
     address constant VERIFY_STARK_COMMITMENT = address(0x20);
     address constant IS_REQUEST_VERIFIED = address(0x21);
     address constant GET_BATCH_INFO = address(0x22);
+    address constant CONSUME_VERIFIED_REQUEST = address(0x23);
+    address constant ALLOCATE_REQUEST_ID = address(0x24);
+
+    /**
+     * @notice Encode an ID as the little-endian u64 the precompiles expect
+     */
+    function toLittleEndian(uint64 id) internal pure returns (bytes8 le) {
+        for (uint256 i = 0; i < 8; i++) {
+            le |= bytes8(bytes1(uint8(id >> (8 * i)))) >> (8 * i);
+        }
+    }
 
     /**
      * @notice Check if a STARK proof commitment is structurally valid
@@ -104,7 +147,7 @@ library ReMLVerifierLib {
     function isRequestVerified(
         uint64 requestId
     ) internal view returns (bool verified) {
-        bytes memory input = abi.encodePacked(requestId);
+        bytes memory input = abi.encodePacked(toLittleEndian(requestId));
 
         (bool success, bytes memory result) = IS_REQUEST_VERIFIED.staticcall(
             input
@@ -125,6 +168,48 @@ library ReMLVerifierLib {
         require(isRequestVerified(requestId), "ReML: Request not verified");
     }
 
+    /**
+     * @notice Use up a verified request, reverting if it is not verified or
+     * was already consumed
+     * @param requestId The request ID to consume
+     * @param callTag Tag identifying the consuming call
+     */
+    function consumeVerifiedRequest(
+        uint64 requestId,
+        bytes32 callTag
+    ) internal {
+        bytes memory input = abi.encodePacked(
+            toLittleEndian(requestId),
+            callTag
+        );
+
+        (bool success, bytes memory result) = CONSUME_VERIFIED_REQUEST.call(
+            input
+        );
+
+        if (!success) {
+            // Bubble up the precompile's revert reason
+            if (result.length > 0) {
+                revert(string(result));
+            }
+            revert("ReML: consume failed");
+        }
+    }
+
+    /**
+     * @notice Allocate a request ID to the calling contract
+     * @return requestId The allocated request ID
+     */
+    function allocateRequestId() internal returns (uint64 requestId) {
+        (bool success, bytes memory result) = ALLOCATE_REQUEST_ID.call("");
+        require(success && result.length >= 8, "ReML: allocation failed");
+
+        // Decode the little-endian u64
+        for (uint256 i = 0; i < 8; i++) {
+            requestId |= uint64(uint8(result[i])) << uint64(8 * i);
+        }
+    }
+
     /**
      * @notice Get batch information for a verified batch
      * @param batchId The batch ID
@@ -145,7 +230,7 @@ library ReMLVerifierLib {
             uint64 verifiedAtBlock
         )
     {
-        bytes memory input = abi.encodePacked(batchId);
+        bytes memory input = abi.encodePacked(toLittleEndian(batchId));
 
         (bool success, bytes memory result) = GET_BATCH_INFO.staticcall(input);
 
@@ -205,6 +290,19 @@ abstract contract QuantumSafeBase {
         _;
     }
 
+    /**
+     * @notice Modifier to use up a verified request for this call
+     * @param requestId The request ID that must be verified and unused
+     * @param callTag Tag identifying the consuming call
+     *
+     * @dev Unlike requiresQuantumProof, the request cannot authorize
+     * anything else afterwards, in this or any other contract.
+     */
+    modifier consumesQuantumProof(uint64 requestId, bytes32 callTag) {
+        ReMLVerifierLib.consumeVerifiedRequest(requestId, callTag);
+        _;
+    }
+
     /**
      * @notice Check if a request has been quantum-verified
      */
@@ -237,7 +335,6 @@ contract QuantumVaultEVM is QuantumSafeBase {
     }
 
     mapping(uint64 => PendingTransfer) public pendingTransfers;
-    uint64 public nextRequestId = 1;
 
     /**
      * @notice Queue a quantum-safe transfer
@@ -245,14 +342,14 @@ contract QuantumVaultEVM is QuantumSafeBase {
      * @param amount Amount to transfer
      * @return requestId The request ID for this transfer
      *
-     * @dev After queueing, the transfer must be verified via Re-ML
-     * before it can be executed.
+     * @dev The request ID is allocated on-chain. After queueing, the
+     * transfer must be verified via Re-ML before it can be executed.
      */
     function queueTransfer(
         address to,
         uint256 amount
     ) external returns (uint64 requestId) {
-        requestId = nextRequestId++;
+        requestId = ReMLVerifierLib.allocateRequestId();
 
         pendingTransfers[requestId] = PendingTransfer({
             from: msg.sender,
@@ -269,11 +366,18 @@ contract QuantumVaultEVM is QuantumSafeBase {
      * @param requestId The request ID of the transfer
      *
      * @dev This can only succeed after the request has been verified
-     * via the Re-ML off-chain prover network.
+     * via the Re-ML off-chain prover network. The request is consumed, so
+     * it cannot authorize anything else.
      */
     function executeTransfer(
         uint64 requestId
-    ) external requiresQuantumProof(requestId) {
+    )
+        external
+        consumesQuantumProof(
+            requestId,
+            keccak256(abi.encode(this.executeTransfer.selector, requestId))
+        )
+    {
         PendingTransfer storage transfer = pendingTransfers[requestId];
 
         require(!transfer.executed, "Already executed");
@@ -7,18 +7,33 @@
 
 #![warn(missing_docs)]
 
+pub mod emission;
+pub mod reml;
+pub mod vault;
+
 use std::sync::Arc;
 
-use jsonrpsee::RpcModule;
+use jsonrpsee::{
+    types::error::{ErrorObject, ErrorObjectOwned},
+    RpcModule,
+};
 use sc_transaction_pool_api::TransactionPool;
 use sp_api::ProvideRuntimeApi;
 use sp_block_builder::BlockBuilder;
 use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
-use tesserax_runtime::{opaque::Block, AccountId, Balance, Nonce};
+use tesserax_runtime::{opaque::Block, AccountId, Balance, BlockNumber, Nonce};
 
 /// Tesserax Chain ID: 13817 (derived from floor(π × e × φ × 10^6) = 13,817,580)
 pub const CHAIN_ID: u64 = 13817;
 
+/// Error code returned by Tesserax RPC methods when a runtime API call fails
+const RUNTIME_ERROR: i32 = 1;
+
+/// Map a runtime API failure to a JSON-RPC error
+pub(crate) fn runtime_error(err: impl std::fmt::Debug) -> ErrorObjectOwned {
+    ErrorObject::owned(RUNTIME_ERROR, "Runtime error", Some(format!("{:?}", err)))
+}
+
 /// Full client dependencies.
 pub struct FullDeps<C, P> {
     /// The client instance to use.
@@ -38,24 +53,37 @@ where
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
     C::Api: BlockBuilder<Block>,
+    C::Api: pallet_emission::runtime_api::EmissionApi<Block, BlockNumber>,
+    C::Api: pallet_quantum_vault::runtime_api::QuantumVaultApi<Block, AccountId, Balance>,
+    C::Api:
+        pallet_reml_verifier::runtime_api::RemlVerifierApi<Block, AccountId, Balance, BlockNumber>,
     P: TransactionPool + 'static,
 {
+    use emission::{Emission, EmissionApiServer};
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
+    use reml::{RemlVerifier, RemlVerifierApiServer};
     use substrate_frame_rpc_system::{System, SystemApiServer};
+    use vault::{QuantumVault, QuantumVaultApiServer};
 
     let mut module = RpcModule::new(());
     let FullDeps { client, pool } = deps;
 
     // Substrate RPC
     module.merge(System::new(client.clone(), pool).into_rpc())?;
-    module.merge(TransactionPayment::new(client).into_rpc())?;
+    module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+
+    // Tesserax RPC
+    module.merge(Emission::new(client.clone()).into_rpc())?;
+    module.merge(QuantumVault::new(client.clone()).into_rpc())?;
+    module.merge(RemlVerifier::new(client).into_rpc())?;
 
     // ═══════════════════════════════════════════════════════════════════════════
     // ETHEREUM RPC ENDPOINTS
     // ═══════════════════════════════════════════════════════════════════════════
     // Full eth_*, net_*, and web3_* methods are provided by Frontier via eth.rs
     // The create_eth() function in eth.rs registers all Ethereum-compatible RPCs
-    // This file only provides basic Substrate RPCs (system, transaction_payment)
+    // This file provides the Substrate RPCs (system, transaction_payment) and the
+    // Tesserax pallet RPCs (emission_*, vault_*, reml_*)
 
     Ok(module)
 }
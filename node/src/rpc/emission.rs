@@ -0,0 +1,245 @@
+//! `emission_*` RPC methods backed by the `EmissionApi` runtime API.
+//!
+//! Lets explorers and treasury models read the emission schedule, reward
+//! quotes and projected emission without embedding the emission table.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use pallet_emission::runtime_api::EmissionApi as EmissionRuntimeApi;
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::traits::Block as BlockT;
+
+use super::runtime_error;
+
+/// Scheduled emission of one era as returned over RPC
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EraSchedule {
+    /// Era index (0-based)
+    pub era: u32,
+    /// Reward minted per block during the era
+    pub reward_per_block: NumberOrHex,
+    /// Reward minted over the whole era
+    pub era_emission: NumberOrHex,
+    /// Scheduled emission from era 0 up to and including this era
+    pub cumulative_emission: NumberOrHex,
+}
+
+/// Remaining emission duration as returned over RPC
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeToEnd<BlockNumber> {
+    /// Last block that receives a scheduled reward
+    pub end_block: BlockNumber,
+    /// Blocks left until `endBlock`
+    pub blocks_remaining: BlockNumber,
+    /// Estimated milliseconds left at the target block time
+    pub ms_remaining: u64,
+}
+
+/// Scheduled vs actually minted emission as returned over RPC
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmissionGap {
+    /// Emission the schedule assigns up to the block
+    pub scheduled: NumberOrHex,
+    /// Emission actually minted
+    pub minted: NumberOrHex,
+    /// Scheduled emission that was never minted
+    pub shortfall: NumberOrHex,
+}
+
+/// Emission RPC methods
+#[rpc(server)]
+pub trait EmissionApi<BlockHash, BlockNumber> {
+    /// Era of the given (or best) block
+    #[method(name = "emission_currentEra")]
+    fn current_era(&self, at: Option<BlockHash>) -> RpcResult<u32>;
+
+    /// Reward minted to the author of `block`
+    #[method(name = "emission_rewardAt")]
+    fn reward_at(&self, block: BlockNumber, at: Option<BlockHash>) -> RpcResult<NumberOrHex>;
+
+    /// Scheduled emission from genesis up to and including `block`
+    #[method(name = "emission_totalEmitted")]
+    fn total_emitted(&self, block: BlockNumber, at: Option<BlockHash>) -> RpcResult<NumberOrHex>;
+
+    /// Per-era schedule for `fromEra..=toEra` (at most 1000 eras per call)
+    #[method(name = "emission_schedule")]
+    fn schedule(
+        &self,
+        from_era: u32,
+        to_era: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<EraSchedule>>;
+
+    /// Blocks and estimated time left until the last scheduled reward
+    #[method(name = "emission_timeToEnd")]
+    fn time_to_end(&self, at: Option<BlockHash>) -> RpcResult<TimeToEnd<BlockNumber>>;
+
+    /// Maximum token supply
+    #[method(name = "emission_maxSupply")]
+    fn max_supply(&self, at: Option<BlockHash>) -> RpcResult<NumberOrHex>;
+
+    /// Amount actually minted so far
+    #[method(name = "emission_totalMinted")]
+    fn total_minted(&self, at: Option<BlockHash>) -> RpcResult<NumberOrHex>;
+
+    /// Amount actually minted during `era`
+    #[method(name = "emission_eraMinted")]
+    fn era_minted(&self, era: u32, at: Option<BlockHash>) -> RpcResult<NumberOrHex>;
+
+    /// Gap between scheduled and actually minted emission
+    #[method(name = "emission_gap")]
+    fn gap(&self, at: Option<BlockHash>) -> RpcResult<EmissionGap>;
+}
+
+/// Implementation of the `emission_*` RPC methods
+pub struct Emission<C, Block> {
+    client: Arc<C>,
+    _marker: PhantomData<Block>,
+}
+
+impl<C, Block> Emission<C, Block> {
+    /// Create a new instance backed by `client`
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block, BlockNumber> EmissionApiServer<<Block as BlockT>::Hash, BlockNumber>
+    for Emission<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: EmissionRuntimeApi<Block, BlockNumber>,
+    BlockNumber:
+        codec::Codec + Clone + Send + Sync + Serialize + serde::de::DeserializeOwned + 'static,
+{
+    fn current_era(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<u32> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client
+            .runtime_api()
+            .current_era(at)
+            .map_err(runtime_error)
+    }
+
+    fn reward_at(
+        &self,
+        block: BlockNumber,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<NumberOrHex> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let reward = self
+            .client
+            .runtime_api()
+            .reward_at(at, block)
+            .map_err(runtime_error)?;
+        Ok(reward.into())
+    }
+
+    fn total_emitted(
+        &self,
+        block: BlockNumber,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<NumberOrHex> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let total = self
+            .client
+            .runtime_api()
+            .total_emitted(at, block)
+            .map_err(runtime_error)?;
+        Ok(total.into())
+    }
+
+    fn schedule(
+        &self,
+        from_era: u32,
+        to_era: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<EraSchedule>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let schedule = self
+            .client
+            .runtime_api()
+            .schedule(at, from_era, to_era)
+            .map_err(runtime_error)?;
+        Ok(schedule
+            .into_iter()
+            .map(|era| EraSchedule {
+                era: era.era,
+                reward_per_block: era.reward_per_block.into(),
+                era_emission: era.era_emission.into(),
+                cumulative_emission: era.cumulative_emission.into(),
+            })
+            .collect())
+    }
+
+    fn time_to_end(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<TimeToEnd<BlockNumber>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let remaining = self
+            .client
+            .runtime_api()
+            .time_to_end(at)
+            .map_err(runtime_error)?;
+        Ok(TimeToEnd {
+            end_block: remaining.end_block,
+            blocks_remaining: remaining.blocks_remaining,
+            ms_remaining: remaining.ms_remaining,
+        })
+    }
+
+    fn max_supply(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<NumberOrHex> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let max_supply = self
+            .client
+            .runtime_api()
+            .max_supply(at)
+            .map_err(runtime_error)?;
+        Ok(max_supply.into())
+    }
+
+    fn total_minted(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<NumberOrHex> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let minted = self
+            .client
+            .runtime_api()
+            .total_minted(at)
+            .map_err(runtime_error)?;
+        Ok(minted.into())
+    }
+
+    fn era_minted(&self, era: u32, at: Option<<Block as BlockT>::Hash>) -> RpcResult<NumberOrHex> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let minted = self
+            .client
+            .runtime_api()
+            .era_minted(at, era)
+            .map_err(runtime_error)?;
+        Ok(minted.into())
+    }
+
+    fn gap(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<EmissionGap> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let gap = self
+            .client
+            .runtime_api()
+            .emission_gap(at)
+            .map_err(runtime_error)?;
+        Ok(EmissionGap {
+            scheduled: gap.scheduled.into(),
+            minted: gap.minted.into(),
+            shortfall: gap.shortfall.into(),
+        })
+    }
+}
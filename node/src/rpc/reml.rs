@@ -0,0 +1,272 @@
+//! `reml_*` RPC methods backed by the `RemlVerifierApi` runtime API.
+//!
+//! Lets reml-prover and dApps confirm that a request was settled on-chain,
+//! with a Merkle inclusion proof against the stored batch `requests_root`.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use pallet_reml_verifier::runtime_api::RemlVerifierApi as RemlVerifierRuntimeApi;
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::H256;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::traits::Block as BlockT;
+
+use super::runtime_error;
+
+/// Request settlement status as returned over RPC
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestStatus<BlockNumber> {
+    /// Batch the request was settled in
+    pub batch_id: u64,
+    /// Block in which the batch proof was accepted
+    pub verified_at: BlockNumber,
+}
+
+/// Verified batch as returned over RPC
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchInfo<AccountId, BlockNumber> {
+    /// Aggregator that submitted the proof
+    pub aggregator: AccountId,
+    /// Block in which the proof was accepted
+    pub verified_at: BlockNumber,
+    /// Number of ML-DSA signatures covered
+    pub signature_count: u32,
+    /// Merkle root of the verified request IDs
+    pub requests_root: H256,
+    /// Replay-protection commitment of the proof
+    pub proof_commitment: H256,
+    /// Guest verification key the proof was checked against
+    pub vkey_hash: H256,
+}
+
+/// Aggregator as returned over RPC
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AggregatorInfo<AccountId, BlockNumber> {
+    /// Aggregator account
+    pub account: AccountId,
+    /// Block the aggregator was registered at
+    pub registered_at: BlockNumber,
+    /// Number of accepted proofs
+    pub proofs_submitted: u64,
+    /// Whether the aggregator may submit proofs
+    pub active: bool,
+    /// Reserved bond left after slashes
+    pub bond: NumberOrHex,
+    /// Block of the last accepted proof (registration block before the first)
+    pub last_active: BlockNumber,
+    /// Total rewards paid for accepted proofs
+    pub rewards_earned: NumberOrHex,
+    /// Block from which the bond can be withdrawn, if unbonding
+    pub unbonding_until: Option<BlockNumber>,
+}
+
+/// Verifier counters as returned over RPC
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemlTotals {
+    /// Number of accepted batch proofs
+    pub proofs_verified: u64,
+    /// Number of ML-DSA signatures covered by accepted proofs
+    pub signatures_verified: u64,
+}
+
+/// One step of a Merkle inclusion path
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MerkleProofNode {
+    /// Sibling hash at this level
+    pub hash: H256,
+    /// Whether the sibling sits to the left of the running hash
+    pub is_left: bool,
+}
+
+/// Merkle inclusion proof as returned over RPC
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestProof {
+    /// Batch the request was settled in
+    pub batch_id: u64,
+    /// Root stored on-chain for the batch
+    pub requests_root: H256,
+    /// Position of the request ID within the batch
+    pub leaf_index: u32,
+    /// Number of request IDs in the batch
+    pub leaf_count: u32,
+    /// Sibling hashes from leaf to root
+    pub path: Vec<MerkleProofNode>,
+}
+
+/// Re-ML verifier RPC methods
+#[rpc(server)]
+pub trait RemlVerifierApi<BlockHash, AccountId, BlockNumber> {
+    /// Settlement status of a request ID, `null` if not verified
+    #[method(name = "reml_requestStatus")]
+    fn request_status(
+        &self,
+        request_id: u64,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<RequestStatus<BlockNumber>>>;
+
+    /// Metadata of a verified batch, `null` if unknown
+    #[method(name = "reml_batch")]
+    fn batch(
+        &self,
+        batch_id: u64,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<BatchInfo<AccountId, BlockNumber>>>;
+
+    /// All registered aggregators
+    #[method(name = "reml_aggregators")]
+    fn aggregators(
+        &self,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<AggregatorInfo<AccountId, BlockNumber>>>;
+
+    /// Total proofs and signatures verified
+    #[method(name = "reml_totals")]
+    fn totals(&self, at: Option<BlockHash>) -> RpcResult<RemlTotals>;
+
+    /// Merkle inclusion proof of a verified request ID, `null` if not verified
+    #[method(name = "reml_requestProof")]
+    fn request_proof(
+        &self,
+        request_id: u64,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<RequestProof>>;
+}
+
+/// Implementation of the `reml_*` RPC methods
+pub struct RemlVerifier<C, Block> {
+    client: Arc<C>,
+    _marker: PhantomData<Block>,
+}
+
+impl<C, Block> RemlVerifier<C, Block> {
+    /// Create a new instance backed by `client`
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block, AccountId, BlockNumber>
+    RemlVerifierApiServer<<Block as BlockT>::Hash, AccountId, BlockNumber>
+    for RemlVerifier<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: RemlVerifierRuntimeApi<Block, AccountId, u128, BlockNumber>,
+    AccountId: codec::Codec + Clone + Send + Sync + Serialize + 'static,
+    BlockNumber: codec::Codec + Clone + Send + Sync + Serialize + 'static,
+{
+    fn request_status(
+        &self,
+        request_id: u64,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<RequestStatus<BlockNumber>>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let status = self
+            .client
+            .runtime_api()
+            .request_status(at, request_id)
+            .map_err(runtime_error)?;
+        Ok(status.map(|s| RequestStatus {
+            batch_id: s.batch_id,
+            verified_at: s.verified_at,
+        }))
+    }
+
+    fn batch(
+        &self,
+        batch_id: u64,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<BatchInfo<AccountId, BlockNumber>>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let batch = self
+            .client
+            .runtime_api()
+            .batch(at, batch_id)
+            .map_err(runtime_error)?;
+        Ok(batch.map(|b| BatchInfo {
+            aggregator: b.aggregator,
+            verified_at: b.verified_at,
+            signature_count: b.signature_count,
+            requests_root: H256::from(b.requests_root),
+            proof_commitment: H256::from(b.proof_commitment),
+            vkey_hash: H256::from(b.vkey_hash),
+        }))
+    }
+
+    fn aggregators(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<AggregatorInfo<AccountId, BlockNumber>>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let aggregators = self
+            .client
+            .runtime_api()
+            .aggregators(at)
+            .map_err(runtime_error)?;
+        Ok(aggregators
+            .into_iter()
+            .map(|(account, info)| AggregatorInfo {
+                account,
+                registered_at: info.registered_at,
+                proofs_submitted: info.proofs_submitted,
+                active: info.active,
+                bond: info.bond.into(),
+                last_active: info.last_active,
+                rewards_earned: info.rewards_earned.into(),
+                unbonding_until: info.unbonding_until,
+            })
+            .collect())
+    }
+
+    fn totals(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<RemlTotals> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let totals = self
+            .client
+            .runtime_api()
+            .totals(at)
+            .map_err(runtime_error)?;
+        Ok(RemlTotals {
+            proofs_verified: totals.proofs_verified,
+            signatures_verified: totals.signatures_verified,
+        })
+    }
+
+    fn request_proof(
+        &self,
+        request_id: u64,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<RequestProof>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let proof = self
+            .client
+            .runtime_api()
+            .request_proof(at, request_id)
+            .map_err(runtime_error)?;
+        Ok(proof.map(|p| RequestProof {
+            batch_id: p.batch_id,
+            requests_root: H256::from(p.requests_root),
+            leaf_index: p.leaf_index,
+            leaf_count: p.leaf_count,
+            path: p
+                .path
+                .into_iter()
+                .map(|node| MerkleProofNode {
+                    hash: H256::from(node.hash),
+                    is_left: node.is_left,
+                })
+                .collect(),
+        }))
+    }
+}
@@ -0,0 +1,220 @@
+//! `vault_*` RPC methods backed by the `QuantumVaultApi` runtime API.
+//!
+//! Lets wallets query vault state and fetch the exact bytes to sign for
+//! `vault_transfer` / `destroy_vault` without decoding raw storage.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{ErrorObject, ErrorObjectOwned},
+};
+use pallet_quantum_vault::runtime_api::QuantumVaultApi as QuantumVaultRuntimeApi;
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{Bytes, H256};
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::traits::Block as BlockT;
+
+use super::runtime_error;
+
+/// Vault status as returned over RPC
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VaultStatus {
+    /// Nonce the next vault signature must commit to
+    pub nonce: u64,
+    /// blake2_256 of the registered Dilithium public key
+    pub public_key_hash: H256,
+}
+
+/// Vault fee quote as returned over RPC
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VaultFeeQuote {
+    /// One-off fee for `create_vault`
+    pub creation_fee: NumberOrHex,
+    /// Premium per `vault_transfer`
+    pub transfer_premium: NumberOrHex,
+}
+
+/// Quantum vault RPC methods
+#[rpc(server)]
+pub trait QuantumVaultApi<BlockHash, AccountId> {
+    /// Vault status of an account, `null` if it is not a vault
+    #[method(name = "vault_status")]
+    fn status(&self, who: AccountId, at: Option<BlockHash>) -> RpcResult<Option<VaultStatus>>;
+
+    /// Current vault nonce, `null` if the account is not a vault
+    #[method(name = "vault_nonce")]
+    fn nonce(&self, who: AccountId, at: Option<BlockHash>) -> RpcResult<Option<u64>>;
+
+    /// blake2_256 hash of the vault public key
+    #[method(name = "vault_keyHash")]
+    fn key_hash(&self, who: AccountId, at: Option<BlockHash>) -> RpcResult<Option<H256>>;
+
+    /// Number of active vaults
+    #[method(name = "vault_totalVaults")]
+    fn total_vaults(&self, at: Option<BlockHash>) -> RpcResult<u32>;
+
+    /// Cumulative fees collected by vault operations
+    #[method(name = "vault_totalFeesCollected")]
+    fn total_fees_collected(&self, at: Option<BlockHash>) -> RpcResult<NumberOrHex>;
+
+    /// Creation fee and transfer premium
+    #[method(name = "vault_feeQuote")]
+    fn fee_quote(&self, at: Option<BlockHash>) -> RpcResult<VaultFeeQuote>;
+
+    /// Exact bytes to Dilithium-sign for `vault_transfer` at the current nonce
+    #[method(name = "vault_transferPayload")]
+    fn transfer_payload(
+        &self,
+        from: AccountId,
+        to: AccountId,
+        amount: NumberOrHex,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<Bytes>>;
+
+    /// Exact bytes to Dilithium-sign for `destroy_vault` at the current nonce
+    #[method(name = "vault_destroyPayload")]
+    fn destroy_payload(&self, who: AccountId, at: Option<BlockHash>) -> RpcResult<Option<Bytes>>;
+}
+
+/// Implementation of the `vault_*` RPC methods
+pub struct QuantumVault<C, Block> {
+    client: Arc<C>,
+    _marker: PhantomData<Block>,
+}
+
+impl<C, Block> QuantumVault<C, Block> {
+    /// Create a new instance backed by `client`
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+fn invalid_amount(amount: NumberOrHex) -> ErrorObjectOwned {
+    ErrorObject::owned(
+        jsonrpsee::types::error::INVALID_PARAMS_CODE,
+        "Amount does not fit into the balance type",
+        Some(format!("{:?}", amount)),
+    )
+}
+
+impl<C, Block, AccountId> QuantumVaultApiServer<<Block as BlockT>::Hash, AccountId>
+    for QuantumVault<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: QuantumVaultRuntimeApi<Block, AccountId, u128>,
+    AccountId: codec::Codec + Clone + Send + Sync + serde::de::DeserializeOwned + 'static,
+{
+    fn status(
+        &self,
+        who: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<VaultStatus>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let status = self
+            .client
+            .runtime_api()
+            .vault_status(at, who)
+            .map_err(runtime_error)?;
+        Ok(status.map(|s| VaultStatus {
+            nonce: s.nonce,
+            public_key_hash: H256::from(s.public_key_hash),
+        }))
+    }
+
+    fn nonce(&self, who: AccountId, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Option<u64>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client
+            .runtime_api()
+            .vault_nonce(at, who)
+            .map_err(runtime_error)
+    }
+
+    fn key_hash(
+        &self,
+        who: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<H256>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let hash = self
+            .client
+            .runtime_api()
+            .vault_key_hash(at, who)
+            .map_err(runtime_error)?;
+        Ok(hash.map(H256::from))
+    }
+
+    fn total_vaults(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<u32> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client
+            .runtime_api()
+            .total_vaults(at)
+            .map_err(runtime_error)
+    }
+
+    fn total_fees_collected(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<NumberOrHex> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let total = self
+            .client
+            .runtime_api()
+            .total_fees_collected(at)
+            .map_err(runtime_error)?;
+        Ok(total.into())
+    }
+
+    fn fee_quote(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<VaultFeeQuote> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let quote = self
+            .client
+            .runtime_api()
+            .fee_quote(at)
+            .map_err(runtime_error)?;
+        Ok(VaultFeeQuote {
+            creation_fee: quote.creation_fee.into(),
+            transfer_premium: quote.transfer_premium.into(),
+        })
+    }
+
+    fn transfer_payload(
+        &self,
+        from: AccountId,
+        to: AccountId,
+        amount: NumberOrHex,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<Bytes>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let value: u128 = amount
+            .clone()
+            .try_into()
+            .map_err(|_| invalid_amount(amount))?;
+        let payload = self
+            .client
+            .runtime_api()
+            .transfer_payload(at, from, to, value)
+            .map_err(runtime_error)?;
+        Ok(payload.map(Bytes))
+    }
+
+    fn destroy_payload(
+        &self,
+        who: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<Bytes>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let payload = self
+            .client
+            .runtime_api()
+            .destroy_payload(at, who)
+            .map_err(runtime_error)?;
+        Ok(payload.map(Bytes))
+    }
+}
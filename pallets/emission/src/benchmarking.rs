@@ -3,12 +3,72 @@
 //! These benchmarks measure the weight of the emission hooks:
 //! - `on_initialize_with_reward`: Block with reward minting
 //! - `on_initialize_no_reward`: Block without reward (emission ended)
+//! - `set_reward_shares`: Governance update of the reward split
+//! - `set_payee`: Authority registers a reward payee
+//! - `scale_remaining_eras`, `propose_schedule`, `upload_schedule_chunk`,
+//!   `enact_schedule`, `reset_schedule`: Governance schedule amendments
+//! - `distribute_fees`: Post-emission payout of an era's fee pool
 
 use super::*;
 
 #[allow(unused)]
 use crate::Pallet as Emission;
 use frame_benchmarking::v2::*;
+use frame_support::{
+    traits::{ConstU32, Get, Hooks, SortedMembers},
+    BoundedVec,
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use sp_runtime::{traits::Hash, FixedPointNumber, FixedU128, Perbill};
+
+type ScheduleChunk = BoundedVec<u128, ConstU32<SCHEDULE_CHUNK_ERAS>>;
+
+/// Replacement for every era after the first (worst case: `MAX_SCHEDULE_CHUNKS` chunks)
+///
+/// Rewards are `T::Schedule` less `discount`, so schedules with different
+/// discounts are distinct and never raise projected emission.
+fn full_schedule<T: Config>(
+    discount: u128,
+) -> (
+    u32,
+    alloc::vec::Vec<ScheduleChunk>,
+    BoundedVec<T::Hash, ConstU32<MAX_SCHEDULE_CHUNKS>>,
+) {
+    let total_eras = T::Schedule::total_eras();
+    let rewards: alloc::vec::Vec<u128> = (1..total_eras)
+        .map(|era| T::Schedule::reward_at(era).saturating_sub(discount))
+        .collect();
+    let chunks: alloc::vec::Vec<ScheduleChunk> = rewards
+        .chunks(SCHEDULE_CHUNK_ERAS as usize)
+        .map(|chunk| chunk.to_vec().try_into().expect("chunk fits"))
+        .collect();
+    let hashes = chunks
+        .iter()
+        .map(T::Hashing::hash_of)
+        .collect::<alloc::vec::Vec<_>>()
+        .try_into()
+        .expect("chunk count fits");
+    (total_eras - 1, chunks, hashes)
+}
+
+/// Propose and upload a full replacement schedule
+fn upload_full_schedule<T: Config>(
+    origin: T::RuntimeOrigin,
+    discount: u128,
+) -> Result<(), BenchmarkError> {
+    let (len, chunks, hashes) = full_schedule::<T>(discount);
+    assert_eq!(hashes.len() as u32, MAX_SCHEDULE_CHUNKS);
+    Emission::<T>::propose_schedule(origin, 1, len, hashes)?;
+    let uploader: T::AccountId = whitelisted_caller();
+    for (index, chunk) in chunks.into_iter().enumerate() {
+        Emission::<T>::upload_schedule_chunk(
+            RawOrigin::Signed(uploader.clone()).into(),
+            index as u32,
+            chunk,
+        )?;
+    }
+    Ok(())
+}
 
 #[benchmarks]
 mod benchmarks {
@@ -16,53 +76,196 @@ mod benchmarks {
 
     /// Benchmark on_initialize when reward is minted
     ///
-    /// This measures the cost of:
-    /// - Era calculation
-    /// - Reward lookup from table
-    /// - Finding block author
-    /// - Minting tokens
-    /// - Emitting event
+    /// Worst case: a full replacement schedule and a reward scale are in
+    /// force, so the reward lookup reads every amendment, and the reward is
+    /// split between author, treasury and pool. Where the runtime finds no
+    /// author outside a real block, the reward is deferred instead.
     #[benchmark]
-    fn on_initialize_with_reward() {
-        // Setup: We're at block 1, era 0, so reward will be minted
-        let block_number = frame_system::Pallet::<T>::block_number();
+    fn on_initialize_with_reward() -> Result<(), BenchmarkError> {
+        let origin =
+            T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        upload_full_schedule::<T>(origin.clone(), 0)?;
+        Emission::<T>::enact_schedule(RawOrigin::Signed(whitelisted_caller()).into())?;
+        Emission::<T>::scale_remaining_eras(origin.clone(), FixedU128::from_rational(9, 10))?;
+        Emission::<T>::set_reward_shares(
+            origin,
+            RewardSplit {
+                treasury: Perbill::from_percent(10),
+                pool: Perbill::from_percent(10),
+            },
+        )?;
+
+        // Second block of era 1, the first amended era
+        let block_number: BlockNumberFor<T> = (T::BlocksPerEra::get() + 2).into();
+        frame_system::Pallet::<T>::set_block_number(block_number);
 
         #[block]
         {
-            // This simulates what on_initialize does internally
-            // We can't directly call on_initialize in benchmark v2,
-            // so we measure the equivalent operations
-            let block_num: u32 = block_number.try_into().unwrap_or(1);
-            let current_era = block_num.saturating_sub(1) / BLOCKS_PER_ERA;
-
-            if (current_era as usize) < TOTAL_ERAS {
-                let reward_per_block = REWARD_SCHEDULE[current_era as usize];
-                if reward_per_block > 0 {
-                    // Simulate the reward lookup (O(1) array access)
-                    let _ = REWARD_SCHEDULE[current_era as usize];
-                }
-            }
+            Emission::<T>::on_initialize(block_number);
         }
+
+        assert!(Emission::<T>::total_minted() > 0 || Emission::<T>::unminted_rewards() > 0);
+        Ok(())
     }
 
     /// Benchmark on_initialize when emission has ended
     ///
-    /// This measures the cost of:
-    /// - Era calculation
-    /// - Checking if beyond schedule
+    /// The bonus was minted, so the block only checks the schedule and
+    /// counts its author towards the fee pool.
     #[benchmark]
     fn on_initialize_no_reward() {
-        // Setup: We're way past the emission schedule
-        // Era 8000 is beyond TOTAL_ERAS (7300)
-        let beyond_schedule_era: u32 = 8000;
+        BonusMinted::<T>::put(true);
+        let block_number = Emission::<T>::emission_end_block() + 2u32.into();
+        frame_system::Pallet::<T>::set_block_number(block_number);
 
         #[block]
         {
-            // Simulate checking if emission ended
-            if (beyond_schedule_era as usize) >= TOTAL_ERAS {
-                // Emission ended, no reward
-            }
+            Emission::<T>::on_initialize(block_number);
         }
+
+        assert_eq!(Emission::<T>::total_minted(), 0);
+    }
+
+    /// Benchmark updating the reward split
+    #[benchmark]
+    fn set_reward_shares() -> Result<(), BenchmarkError> {
+        let origin =
+            T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let shares = RewardSplit {
+            treasury: Perbill::from_percent(10),
+            pool: Perbill::from_percent(10),
+        };
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, shares);
+
+        assert_eq!(RewardShares::<T>::get(), shares);
+        Ok(())
+    }
+
+    /// Benchmark registering a reward payee
+    #[benchmark]
+    fn set_payee() {
+        let authority: T::AccountId = whitelisted_caller();
+        let payee: T::AccountId = account("payee", 0, 0);
+        T::Authorities::add(&authority);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(authority.clone()), payee.clone());
+
+        assert_eq!(Payees::<T>::get(&authority), Some(payee));
+    }
+
+    /// Benchmark scaling the remaining eras
+    ///
+    /// Both supply projections read every chunk of a full active schedule.
+    #[benchmark]
+    fn scale_remaining_eras() -> Result<(), BenchmarkError> {
+        let origin =
+            T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        upload_full_schedule::<T>(origin.clone(), 0)?;
+        Emission::<T>::enact_schedule(RawOrigin::Signed(whitelisted_caller()).into())?;
+        let factor = FixedU128::from_rational(9, 10);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, factor);
+
+        assert_eq!(
+            RewardScale::<T>::get().map(|scale| scale.factor),
+            Some(factor)
+        );
+        Ok(())
+    }
+
+    /// Benchmark committing to a replacement schedule
+    #[benchmark]
+    fn propose_schedule() -> Result<(), BenchmarkError> {
+        let origin =
+            T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let (len, _, hashes) = full_schedule::<T>(0);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, 1, len, hashes);
+
+        assert!(ProposedSchedule::<T>::get().is_some());
+        Ok(())
+    }
+
+    /// Benchmark uploading a full-size schedule chunk
+    #[benchmark]
+    fn upload_schedule_chunk() -> Result<(), BenchmarkError> {
+        let origin =
+            T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let (len, mut chunks, hashes) = full_schedule::<T>(0);
+        Emission::<T>::propose_schedule(origin, 1, len, hashes)?;
+        let chunk = chunks.swap_remove(0);
+        let caller: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), 0, chunk);
+
+        assert_eq!(ProposedSchedule::<T>::get().map(|p| p.uploaded), Some(1));
+        Ok(())
+    }
+
+    /// Benchmark enacting a full replacement schedule over a full active one
+    ///
+    /// The current projection reads every chunk of the active schedule, the
+    /// new one every chunk of the proposal, and the active chunks are removed.
+    #[benchmark]
+    fn enact_schedule() -> Result<(), BenchmarkError> {
+        let origin =
+            T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let caller: T::AccountId = whitelisted_caller();
+        upload_full_schedule::<T>(origin.clone(), 0)?;
+        Emission::<T>::enact_schedule(RawOrigin::Signed(caller.clone()).into())?;
+        upload_full_schedule::<T>(origin, 1)?;
+        let previous = ActiveSchedule::<T>::get().map(|active| active.hash);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller));
+
+        assert!(ActiveSchedule::<T>::get().is_some());
+        assert_ne!(
+            ActiveSchedule::<T>::get().map(|active| active.hash),
+            previous
+        );
+        Ok(())
+    }
+
+    /// Benchmark dropping an active full replacement schedule
+    #[benchmark]
+    fn reset_schedule() -> Result<(), BenchmarkError> {
+        let origin =
+            T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        upload_full_schedule::<T>(origin.clone(), 0)?;
+        Emission::<T>::enact_schedule(RawOrigin::Signed(whitelisted_caller()).into())?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin);
+
+        assert!(ActiveSchedule::<T>::get().is_none());
+        Ok(())
+    }
+
+    /// Benchmark paying an era's pooled fees to `n` authors
+    #[benchmark]
+    fn distribute_fees(n: Linear<1, { T::MaxFeeRecipients::get() }>) {
+        let mut authors = FeeAuthors::<T>::get();
+        for i in 0..n {
+            let author: T::AccountId = account("author", i, 0);
+            let _ = authors.try_insert(author, 1);
+        }
+        FeeAuthors::<T>::put(authors);
+        PooledFees::<T>::put(1_000_000_000_000_000_000u128.saturating_mul(n as u128));
+        let era = T::Schedule::total_eras();
+
+        #[block]
+        {
+            Emission::<T>::distribute_fees(era);
+        }
+
+        assert!(FeeAuthors::<T>::get().is_empty());
     }
 
     impl_benchmark_test_suite!(Emission, crate::mock::new_test_ext(), crate::mock::Test);
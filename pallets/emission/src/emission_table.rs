@@ -1,15 +1,14 @@
-// AUTO-GENERATED by scripts/generate_curve.py
-// DO NOT EDIT MANUALLY (except MAX_SUPPLY & BONUS_AMOUNT for v3.0 update)
+// AUTO-GENERATED by `cargo run -p emission-gen`
+// DO NOT EDIT MANUALLY - CI runs `emission-gen --check`
 //
 // Tesserax Protocol v3.0 - Pre-computed Sigmoid Emission Table
-// Max Supply: 13,817,580 TSRX (π × e × φ × 10^6, accurate calculation)
+// Max Supply: 13,817,580 TSRX (π × e × φ × 10^6)
 // Duration: 20 years (7300 eras)
 // Bonus: 627 TSRX minted post-emission to reach 100% coverage
 //
 
 /// Maximum supply of $TSRX in smallest units (planck)
 /// S_max = floor(π × e × φ × 10^6) = 13,817,580 TSRX × 10^18
-/// Updated in v3.0 to use accurate mathematical constants
 pub const MAX_SUPPLY: u128 = 13_817_580_000_000_000_000_000_000;
 
 /// Total number of eras in the emission schedule
@@ -20,11 +19,13 @@ pub const BLOCKS_PER_ERA: u32 = 14400;
 
 /// Bonus amount to mint after emission ends (to reach 100% of max supply)
 /// This compensates for cumulative flooring in the emission schedule
-/// 627 TSRX = (13,817,580 - sum of all scheduled emissions)
+/// (MAX_SUPPLY - sum of all scheduled emissions)
+/// The pallet mints the exact remainder (MAX_SUPPLY - GenesisSupply - TotalMinted);
+/// this constant is informational and rounds that remainder down to whole TSRX.
 pub const BONUS_AMOUNT: u128 = 627_000_000_000_000_000_000;
 
 /// Pre-computed block rewards per era
-/// Peak reward at era 3650 (~year 10): 0.657228 TSRX/block
+/// Peak reward at era 3649 (~year 10): 0.657228 TSRX/block
 pub const REWARD_SCHEDULE: [u128; 7300] = [
     43681219431296712_u128,
     119833191063315_u128,
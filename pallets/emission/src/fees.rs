@@ -0,0 +1,26 @@
+//! Fee handler funding block authors once the emission schedule has ended
+
+use core::marker::PhantomData;
+use frame_support::traits::{Get, Imbalance, OnUnbalanced};
+use sp_runtime::SaturatedConversion;
+
+use crate::{BalanceOf, Config, Pallet, PooledFees};
+
+/// `OnUnbalanced` handler for transaction and EVM fees
+///
+/// Fees are burned. After the schedule ends, and if `PoolFeesAfterEmission` is
+/// set, the burned amount is added to `PooledFees` and re-minted to the era's
+/// block authors when the next era starts. Before that it behaves like `()`.
+pub struct PoolFees<T>(PhantomData<T>);
+
+impl<T: Config, I: Imbalance<BalanceOf<T>>> OnUnbalanced<I> for PoolFees<T> {
+    fn on_nonzero_unbalanced(fees: I) {
+        if T::PoolFeesAfterEmission::get()
+            && Pallet::<T>::is_emission_ended(frame_system::Pallet::<T>::block_number())
+        {
+            let amount: u128 = fees.peek().saturated_into();
+            PooledFees::<T>::mutate(|pooled| *pooled = pooled.saturating_add(amount));
+        }
+        drop(fees);
+    }
+}
@@ -1,23 +1,104 @@
 //! # Tesserax Emission Pallet (v3.0)
 //!
-//! A **stateless** pallet that distributes block rewards according to a
-//! pre-computed sigmoid emission schedule, plus a one-time bonus mint.
+//! Distributes block rewards according to a pre-computed sigmoid emission
+//! schedule, plus a one-time bonus mint.
 //!
 //! ## Design Philosophy
 //!
 //! Unlike the complex ASM (Adaptive Scarcity Mechanism) in v1.0, this pallet
 //! uses a simple lookup table approach:
 //!
-//! 1. Emission schedule is pre-computed off-chain (Python script)
-//! 2. Reward per era is stored in a constant array
-//! 3. On each block, the pallet looks up the current era's reward
-//! 4. Reward is minted and given to the block author
-//! 5. After emission ends, a bonus of 627 TSRX is minted once
+//! 1. Emission schedule is pre-computed off-chain (`scripts/emission-gen`)
+//! 2. Reward per era is stored in a constant array, served by `SigmoidSchedule`
+//! 3. On each block, the pallet looks up the current era's reward from `Config::Schedule`
+//! 4. Reward is minted and split between the block author, treasury and pool
+//! 5. After emission ends, the remainder up to `MaxSupply` is minted once as a bonus
+//!
+//! ## Storage
+//!
+//! The schedule itself is a constant; storage holds what it cannot:
+//!
+//! - Supply accounting: `TotalMinted`, `EraMinted`, `BonusMinted`
+//! - Deferred rewards: `UnmintedRewards`
+//! - Distribution: `RewardShares`, `Payees`
+//! - Amendments: `RewardScale`, `ActiveSchedule`, `ProposedSchedule`, `ScheduleChunks`
+//! - Post-emission fees: `PooledFees`, `FeeAuthors`
+//! - Offences: `Forfeited`
+//!
+//! ## Block Flow
+//!
+//! `on_initialize` starts the era if the block is its first (paying the
+//! previous era's pooled fees), looks up the era's reward, adds any deferred
+//! rewards and mints the total to the author's payee, the treasury and the
+//! pool. If no author is found or the author forfeited its rewards, the
+//! reward is deferred instead. After the schedule, the block is counted
+//! towards its author's share of the era's fee pool.
+//!
+//! ## Configuration
+//!
+//! Era length (`BlocksPerEra`), the supply cap (`MaxSupply`) and the default
+//! schedule (`Schedule`) are supplied by the runtime. `SigmoidSchedule` rescales
+//! the table to the configured era length so each era emits the same amount
+//! whatever its duration; a testnet with one-block eras runs the 20-year
+//! schedule in about 12 hours. `BONUS_AMOUNT` in the table is informational;
+//! the bonus actually minted is always the remainder up to `MaxSupply`.
+//!
+//! ## Era Hooks
+//!
+//! The first block of every era emits `EraStarted` and calls
+//! `Config::OnEraChange`, so other pallets can rotate or reset per-era state.
+//!
+//! ## Post-emission Fees
+//!
+//! Once the schedule has ended, block rewards stop. With `PoolFeesAfterEmission`
+//! set, fees routed to `PoolFees` are pooled per era and paid at the start of
+//! the next era to the era's block authors (their payees), pro rata by blocks
+//! authored.
+//!
+//! ## Reward Payees
+//!
+//! The author account found by `FindAuthor` is the session validator account
+//! of the block author. Validators (`Config::Authorities`) can register a payee
+//! (stash, multisig or quantum vault) in `Payees`, keyed by that validator
+//! account; the author share and the terminal bonus are minted to the payee
+//! instead. Other accounts cannot add entries.
+//!
+//! ## Forfeiture
+//!
+//! The runtime calls `forfeit_rewards` for validators reported for an
+//! offence. They lose their share of the era's pooled fees, and blocks they
+//! author earn nothing until the era ends; those rewards go to the next author.
+//!
+//! ## Schedule Amendments
+//!
+//! `Config::Schedule` is the default. `UpdateOrigin` can amend it on-chain:
+//!
+//! - `scale_remaining_eras` multiplies the current and later eras by a factor
+//!   within `1 ± MaxRewardScaleDeviation`
+//! - `propose_schedule` commits to a replacement for a range of future eras by
+//!   the hashes of its chunks; anyone may then `upload_schedule_chunk` and
+//!   `enact_schedule` once every chunk is present
+//!
+//! An amendment is rejected if it raises the projected remaining emission above
+//! the supply left under `MaxSupply`. Amendments reshape when supply is
+//! emitted, not the final supply: the terminal bonus still mints up to
+//! `MaxSupply`. Schedule queries reflect the schedule in force now; see
+//! `EraMinted` for what past eras actually minted.
+//!
+//! ## Catch-up Minting
+//!
+//! Eras are indexed by block number, so skipped Aura slots only delay the
+//! schedule; every block number still gets its reward. Blocks that exist but
+//! cannot be minted (no author found) carry their reward forward in
+//! `UnmintedRewards`, which is paid out with the next authored block. The
+//! terminal bonus is `MaxSupply - GenesisSupply - TotalMinted` rather than a
+//! constant, so supply lands exactly on `MaxSupply`.
 //!
 //! ## Why Pre-computed?
 //!
 //! - **Deterministic**: No floating-point, no approximation errors
-//! - **Auditable**: Anyone can verify the exact emission schedule
+//! - **Auditable**: `cargo run -p emission-gen -- --check` regenerates the
+//!   table from the curve parameters and compares it with the committed one
 //! - **Simple**: No complex on-chain math, just array lookup
 //! - **Gas-efficient**: O(1) lookup vs O(n) computation
 //!
@@ -27,7 +108,7 @@
 //! - Years 0-5: Rewards increase (early adopter incentive)
 //! - Year 10: Peak rewards (~0.66 TSRX/block)
 //! - Years 15-20: Rewards decrease (scarcity phase)
-//! - Post-Year 20: Bonus mint of 627 TSRX (one-time only)
+//! - Post-Year 20: Bonus mint of the remainder (~627 TSRX, one-time only)
 //!
 //! Total supply reaches exactly 13,817,580 TSRX (π × e × φ × 10^6)
 
@@ -37,7 +118,7 @@ extern crate alloc;
 
 pub use pallet::*;
 
-/// Pre-computed emission table (generated by scripts/generate_curve.py)
+/// Pre-computed emission table (generated by scripts/emission-gen)
 mod emission_table;
 pub use emission_table::*;
 
@@ -45,6 +126,38 @@ pub use emission_table::*;
 pub mod weights;
 pub use weights::*;
 
+/// Runtime API consumed by the node's emission_* RPC
+pub mod runtime_api;
+
+/// Default emission schedules
+pub mod schedule;
+pub use schedule::*;
+
+/// Fee handler funding block authors after the schedule
+pub mod fees;
+pub use fees::*;
+
+/// Handler notified when a new era starts
+///
+/// Called from `on_initialize` at the first block of every era, including eras
+/// after the schedule has ended, before that block's reward is minted.
+pub trait OnEraChange {
+    /// Start of `era`; returns the weight consumed
+    fn on_era_change(era: u32) -> frame_support::weights::Weight;
+}
+
+impl OnEraChange for () {
+    fn on_era_change(_era: u32) -> frame_support::weights::Weight {
+        frame_support::weights::Weight::zero()
+    }
+}
+
+/// Number of eras per uploaded chunk of an amended schedule
+pub const SCHEDULE_CHUNK_ERAS: u32 = 100;
+
+/// Maximum number of chunks of an amended schedule
+pub const MAX_SCHEDULE_CHUNKS: u32 = (TOTAL_ERAS as u32).div_ceil(SCHEDULE_CHUNK_ERAS);
+
 #[cfg(test)]
 mod mock;
 
@@ -59,9 +172,14 @@ pub mod pallet {
     use super::*;
     use frame_support::{
         pallet_prelude::*,
-        traits::{Currency, FindAuthor},
+        traits::{Currency, FindAuthor, Imbalance, SortedMembers},
     };
     use frame_system::pallet_prelude::*;
+    use sp_runtime::{
+        helpers_128bit::multiply_by_rational_with_rounding,
+        traits::{Bounded, Hash, One, Saturating, Zero},
+        FixedPointNumber, FixedU128, Perbill, Rounding, SaturatedConversion,
+    };
 
     /// The balance type of this pallet
     pub type BalanceOf<T> =
@@ -86,6 +204,62 @@ pub mod pallet {
         /// Find the author of the current block (validator who gets reward)
         type FindAuthor: FindAuthor<Self::AccountId>;
 
+        /// Block authorities allowed to register a reward payee (the validator set)
+        type Authorities: SortedMembers<Self::AccountId>;
+
+        /// Number of blocks in an era
+        ///
+        /// `DAYS` on mainnet; testnets can shorten eras to run the whole
+        /// schedule in hours.
+        #[pallet::constant]
+        type BlocksPerEra: Get<u32>;
+
+        /// Hard cap on total issuance, reached exactly by the terminal bonus
+        #[pallet::constant]
+        type MaxSupply: Get<u128>;
+
+        /// Default per-block reward of each era
+        type Schedule: EmissionSchedule;
+
+        /// Handler called at the first block of every era
+        type OnEraChange: OnEraChange;
+
+        /// Whether `PoolFees` pools fees after the schedule and pays them to block authors
+        ///
+        /// When false, `PoolFees` burns fees like `()`.
+        #[pallet::constant]
+        type PoolFeesAfterEmission: Get<bool>;
+
+        /// Maximum number of authorities sharing an era's fee pool
+        #[pallet::constant]
+        type MaxFeeRecipients: Get<u32>;
+
+        /// Supply created at genesis, counted against `MaxSupply`
+        ///
+        /// Minting stops once `GenesisSupply + TotalMinted` reaches `MaxSupply`.
+        #[pallet::constant]
+        type GenesisSupply: Get<u128>;
+
+        /// Account receiving the treasury share of each block reward
+        type TreasuryAccount: Get<Self::AccountId>;
+
+        /// Account receiving the validator/staker pool share of each block reward
+        type PoolAccount: Get<Self::AccountId>;
+
+        /// Reward split used until governance sets `RewardShares`
+        type RewardDistribution: Get<RewardSplit>;
+
+        /// Upper bound on the combined treasury and pool share
+        #[pallet::constant]
+        type MaxNonAuthorShare: Get<Perbill>;
+
+        /// Maximum deviation of a `scale_remaining_eras` factor from 1
+        #[pallet::constant]
+        type MaxRewardScaleDeviation: Get<Perbill>;
+
+        /// Origin allowed to update the reward split and amend the schedule
+        type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
         /// Weight information
         type WeightInfo: WeightInfo;
     }
@@ -100,6 +274,137 @@ pub mod pallet {
     #[pallet::getter(fn bonus_minted)]
     pub type BonusMinted<T> = StorageValue<_, bool, ValueQuery>;
 
+    /// Total amount actually minted by this pallet (rewards and bonus)
+    #[pallet::storage]
+    #[pallet::getter(fn total_minted)]
+    pub type TotalMinted<T> = StorageValue<_, u128, ValueQuery>;
+
+    /// Scheduled rewards of blocks that could not be minted, paid with the next authored block
+    #[pallet::storage]
+    #[pallet::getter(fn unminted_rewards)]
+    pub type UnmintedRewards<T> = StorageValue<_, u128, ValueQuery>;
+
+    /// Current split of each block reward (author receives the remainder)
+    #[pallet::storage]
+    #[pallet::getter(fn reward_shares)]
+    pub type RewardShares<T: Config> =
+        StorageValue<_, RewardSplit, ValueQuery, T::RewardDistribution>;
+
+    /// Reward payee of each block authority (keyed by its validator account)
+    #[pallet::storage]
+    #[pallet::getter(fn payee)]
+    pub type Payees<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId>;
+
+    /// Factor applied to the rewards of `from_era` and later eras
+    #[pallet::storage]
+    #[pallet::getter(fn reward_scale)]
+    pub type RewardScale<T> = StorageValue<_, ScaleAdjustment, OptionQuery>;
+
+    /// Replacement schedule in force, overriding `T::Schedule` for its eras
+    #[pallet::storage]
+    #[pallet::getter(fn active_schedule)]
+    pub type ActiveSchedule<T: Config> = StorageValue<_, ScheduleOverride<T::Hash>, OptionQuery>;
+
+    /// Replacement schedule committed by governance and awaiting its chunks
+    #[pallet::storage]
+    #[pallet::getter(fn proposed_schedule)]
+    pub type ProposedSchedule<T: Config> = StorageValue<_, ScheduleProposal<T::Hash>, OptionQuery>;
+
+    /// Uploaded chunks of proposed and active schedules, keyed by schedule hash
+    #[pallet::storage]
+    pub type ScheduleChunks<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        T::Hash,
+        Twox64Concat,
+        u32,
+        BoundedVec<u128, ConstU32<SCHEDULE_CHUNK_ERAS>>,
+    >;
+
+    /// Amount actually minted per era
+    #[pallet::storage]
+    #[pallet::getter(fn era_minted)]
+    pub type EraMinted<T> = StorageMap<_, Twox64Concat, u32, u128, ValueQuery>;
+
+    /// Fees collected by `PoolFees` after the schedule, not yet paid to authors
+    #[pallet::storage]
+    #[pallet::getter(fn pooled_fees)]
+    pub type PooledFees<T> = StorageValue<_, u128, ValueQuery>;
+
+    /// Blocks authored by each authority in the current era, after the schedule
+    #[pallet::storage]
+    #[pallet::getter(fn fee_authors)]
+    pub type FeeAuthors<T: Config> =
+        StorageValue<_, BoundedBTreeMap<T::AccountId, u32, T::MaxFeeRecipients>, ValueQuery>;
+
+    /// Authorities reported for an offence, mapped to the era whose rewards they forfeit
+    #[pallet::storage]
+    #[pallet::getter(fn forfeited_until)]
+    pub type Forfeited<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32>;
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // TYPES
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Share of each block reward sent to sinks other than the block author
+    #[derive(
+        Clone,
+        Copy,
+        Default,
+        PartialEq,
+        Eq,
+        RuntimeDebug,
+        Encode,
+        Decode,
+        DecodeWithMemTracking,
+        TypeInfo,
+        MaxEncodedLen,
+    )]
+    pub struct RewardSplit {
+        /// Share minted to `TreasuryAccount`
+        pub treasury: Perbill,
+        /// Share minted to `PoolAccount`
+        pub pool: Perbill,
+    }
+
+    impl RewardSplit {
+        /// Combined share not going to the block author
+        pub fn non_author(&self) -> Perbill {
+            self.treasury.saturating_add(self.pool)
+        }
+    }
+
+    /// Scaling of the remaining eras set by governance
+    #[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    pub struct ScaleAdjustment {
+        /// First era the factor applies to
+        pub from_era: u32,
+        /// Multiplier applied to the per-block reward
+        pub factor: FixedU128,
+    }
+
+    /// Replacement rewards for eras `from_era..from_era + len`
+    #[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    pub struct ScheduleOverride<Hash> {
+        /// Hash of `(from_era, len, chunk_hashes)`, keys the chunks in `ScheduleChunks`
+        pub hash: Hash,
+        /// First era replaced
+        pub from_era: u32,
+        /// Number of eras replaced
+        pub len: u32,
+    }
+
+    /// Replacement schedule awaiting upload
+    #[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    pub struct ScheduleProposal<Hash> {
+        /// Eras the schedule replaces once enacted
+        pub schedule: ScheduleOverride<Hash>,
+        /// Hash of each `SCHEDULE_CHUNK_ERAS`-sized chunk of rewards
+        pub chunk_hashes: BoundedVec<Hash, ConstU32<MAX_SCHEDULE_CHUNKS>>,
+        /// Number of chunks uploaded so far
+        pub uploaded: u32,
+    }
+
     // ═══════════════════════════════════════════════════════════════════════
     // EVENTS
     // ═══════════════════════════════════════════════════════════════════════
@@ -107,12 +412,16 @@ pub mod pallet {
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
-        /// Block reward was minted and distributed to the block author
+        /// Block reward was minted and split between author, treasury and pool
         RewardMinted {
             block_number: BlockNumberFor<T>,
             era: u32,
             author: T::AccountId,
+            payee: T::AccountId,
             reward: BalanceOf<T>,
+            author_reward: BalanceOf<T>,
+            treasury_reward: BalanceOf<T>,
+            pool_reward: BalanceOf<T>,
         },
 
         /// Emission schedule has ended (all eras completed)
@@ -121,6 +430,77 @@ pub mod pallet {
             total_eras: u32,
         },
 
+        /// First block of an era, before its reward is minted
+        ///
+        /// Also emitted after the schedule ends, with a zero reward.
+        EraStarted {
+            era: u32,
+            reward_per_block: u128,
+            total_minted: u128,
+        },
+
+        /// Block reward could not be minted and was carried forward
+        RewardDeferred {
+            block_number: BlockNumberFor<T>,
+            era: u32,
+            amount: u128,
+            total_unminted: u128,
+        },
+
+        /// Authority registered a reward payee
+        PayeeSet {
+            authority: T::AccountId,
+            payee: T::AccountId,
+        },
+
+        /// Authority removed its reward payee (rewards go to the authority again)
+        PayeeCleared { authority: T::AccountId },
+
+        /// Reward split was updated by governance
+        RewardSharesUpdated { shares: RewardSplit },
+
+        /// Governance scaled the current and later eras
+        RewardScaleSet {
+            from_era: u32,
+            factor: FixedU128,
+            projected_emission: u128,
+        },
+
+        /// Governance committed to a replacement schedule
+        ScheduleProposed {
+            hash: T::Hash,
+            from_era: u32,
+            len: u32,
+        },
+
+        /// A chunk of the proposed schedule was uploaded
+        ScheduleChunkUploaded { hash: T::Hash, index: u32 },
+
+        /// The proposed schedule replaced the rewards of its eras
+        ScheduleEnacted {
+            hash: T::Hash,
+            from_era: u32,
+            len: u32,
+            projected_emission: u128,
+        },
+
+        /// All amendments were dropped and `T::Schedule` applies again
+        ScheduleReset { projected_emission: u128 },
+
+        /// Offending authority loses its rewards for the rest of `era`
+        RewardsForfeited {
+            authority: T::AccountId,
+            era: u32,
+            fee_blocks: u32,
+        },
+
+        /// Fees pooled during `era` were paid to its block authors
+        FeesDistributed {
+            era: u32,
+            amount: u128,
+            recipients: u32,
+        },
+
         /// Bonus amount minted to reach 100% of max supply
         BonusMinted {
             block_number: BlockNumberFor<T>,
@@ -139,6 +519,41 @@ pub mod pallet {
         NoAuthor,
         /// Arithmetic overflow
         Overflow,
+        /// Treasury and pool shares together exceed `MaxNonAuthorShare`
+        SharesExceedBound,
+        /// Caller has no payee registered
+        NoPayee,
+        /// Scale factor deviates from 1 by more than `MaxRewardScaleDeviation`
+        ScaleOutOfBounds,
+        /// Amendment would raise projected emission above the remaining supply
+        ExceedsMaxSupply,
+        /// Schedule is empty, starts in a past or the current era, or runs past the last era
+        InvalidScheduleRange,
+        /// Number of chunk hashes does not match the schedule length
+        InvalidChunkCount,
+        /// Schedule is already in force
+        ScheduleAlreadyActive,
+        /// No schedule has been proposed
+        NoScheduleProposal,
+        /// Chunk index is out of range or has the wrong number of rewards
+        InvalidChunk,
+        /// Chunk does not match its committed hash
+        ChunkHashMismatch,
+        /// Chunk was already uploaded
+        ChunkAlreadyUploaded,
+        /// Not every chunk of the proposed schedule has been uploaded
+        ScheduleIncomplete,
+        /// Caller is not a block authority
+        NotAuthority,
+    }
+
+    #[pallet::extra_constants]
+    impl<T: Config> Pallet<T> {
+        /// Number of eras in the default emission schedule
+        #[allow(non_snake_case)]
+        pub fn TotalEras() -> u32 {
+            T::Schedule::total_eras()
+        }
     }
 
     // ═══════════════════════════════════════════════════════════════════════
@@ -149,39 +564,626 @@ pub mod pallet {
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
         /// Called at the start of each block
         ///
-        /// This is where we mint and distribute block rewards
+        /// This is where we start eras and mint and distribute block rewards
         fn on_initialize(block_number: BlockNumberFor<T>) -> Weight {
-            // Convert block number to u32 for era calculation
-            let block_num: u32 = block_number.try_into().unwrap_or(0);
+            Self::start_era(block_number)
+                .saturating_add(Self::reward_block(block_number))
+                // Forfeited lookup of the author
+                .saturating_add(T::DbWeight::get().reads_writes(1, 1))
+                .saturating_add(Self::note_fee_author(block_number))
+        }
+
+        fn integrity_test() {
+            assert!(T::BlocksPerEra::get() > 0, "BlocksPerEra must not be zero");
+            assert!(
+                T::MaxFeeRecipients::get() > 0,
+                "MaxFeeRecipients must not be zero"
+            );
+            assert!(
+                (T::Schedule::total_eras() as u128).saturating_mul(T::BlocksPerEra::get() as u128)
+                    < BlockNumberFor::<T>::max_value().saturated_into::<u128>(),
+                "Schedule ends past the largest block number"
+            );
+            assert!(
+                (0..T::Schedule::total_eras())
+                    .try_fold(0u128, |total, era| {
+                        T::Schedule::reward_at(era)
+                            .checked_mul(T::BlocksPerEra::get() as u128)
+                            .and_then(|emission| total.checked_add(emission))
+                    })
+                    .is_some(),
+                "Scheduled emission overflows u128"
+            );
+            assert!(
+                T::Schedule::total_eras() <= MAX_SCHEDULE_CHUNKS * SCHEDULE_CHUNK_ERAS,
+                "Schedule has more eras than an amended schedule can cover"
+            );
+            assert!(
+                T::GenesisSupply::get() <= T::MaxSupply::get(),
+                "GenesisSupply must not exceed MaxSupply"
+            );
+            assert!(
+                T::RewardDistribution::get().non_author() <= T::MaxNonAuthorShare::get(),
+                "Default RewardDistribution exceeds MaxNonAuthorShare"
+            );
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+            Self::do_try_state()
+        }
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // CALLS
+    // ═══════════════════════════════════════════════════════════════════════
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Update the treasury and pool shares of each block reward
+        ///
+        /// The block author receives the remainder. The combined share is
+        /// bounded by `MaxNonAuthorShare`.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::set_reward_shares())]
+        pub fn set_reward_shares(origin: OriginFor<T>, shares: RewardSplit) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                shares.non_author() <= T::MaxNonAuthorShare::get(),
+                Error::<T>::SharesExceedBound
+            );
+
+            RewardShares::<T>::put(shares);
+
+            Self::deposit_event(Event::RewardSharesUpdated { shares });
+            Ok(())
+        }
+
+        /// Register the account that receives rewards earned by the caller
+        ///
+        /// Must be signed by the validator account the block author maps to,
+        /// which must be one of `Authorities`, so only validators add entries.
+        /// The payee may be any account, including a quantum vault.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::set_payee())]
+        pub fn set_payee(origin: OriginFor<T>, payee: T::AccountId) -> DispatchResult {
+            let authority = ensure_signed(origin)?;
+            ensure!(
+                T::Authorities::contains(&authority),
+                Error::<T>::NotAuthority
+            );
+            Self::do_set_payee(authority, Some(payee));
+            Ok(())
+        }
+
+        /// Remove the caller's payee so rewards go to the authority account again
+        ///
+        /// Also open to former authorities, so they can clean up their entry.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::set_payee())]
+        pub fn clear_payee(origin: OriginFor<T>) -> DispatchResult {
+            let authority = ensure_signed(origin)?;
+            ensure!(Payees::<T>::contains_key(&authority), Error::<T>::NoPayee);
+            Self::do_set_payee(authority, None);
+            Ok(())
+        }
+
+        /// Set or clear the payee of an authority (governance)
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::set_payee())]
+        pub fn force_set_payee(
+            origin: OriginFor<T>,
+            authority: T::AccountId,
+            payee: Option<T::AccountId>,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            Self::do_set_payee(authority, payee);
+            Ok(())
+        }
+
+        /// Scale the rewards of the current and later eras by `factor`
+        ///
+        /// Replaces any previous scale. The factor must lie within
+        /// `1 ± MaxRewardScaleDeviation`.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::scale_remaining_eras())]
+        pub fn scale_remaining_eras(origin: OriginFor<T>, factor: FixedU128) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+
+            let deviation = FixedU128::from_perbill(T::MaxRewardScaleDeviation::get());
+            ensure!(
+                factor >= FixedU128::one().saturating_sub(deviation)
+                    && factor <= FixedU128::one().saturating_add(deviation),
+                Error::<T>::ScaleOutOfBounds
+            );
+
+            let from_era = Self::current_era(frame_system::Pallet::<T>::block_number());
+            let scale = ScaleAdjustment { from_era, factor };
+            let projected_emission =
+                Self::ensure_within_supply(ActiveSchedule::<T>::get().as_ref(), Some(&scale))?;
+
+            RewardScale::<T>::put(scale);
+
+            Self::deposit_event(Event::RewardScaleSet {
+                from_era,
+                factor,
+                projected_emission,
+            });
+            Ok(())
+        }
+
+        /// Commit to a replacement schedule for eras `from_era..from_era + len`
+        ///
+        /// `chunk_hashes` holds the hash of each `SCHEDULE_CHUNK_ERAS`-sized
+        /// chunk of rewards, in order. Replaces any pending proposal.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::propose_schedule())]
+        pub fn propose_schedule(
+            origin: OriginFor<T>,
+            from_era: u32,
+            len: u32,
+            chunk_hashes: BoundedVec<T::Hash, ConstU32<MAX_SCHEDULE_CHUNKS>>,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+
+            let current_era = Self::current_era(frame_system::Pallet::<T>::block_number());
+            ensure!(
+                len > 0
+                    && from_era > current_era
+                    && from_era.saturating_add(len) <= T::Schedule::total_eras(),
+                Error::<T>::InvalidScheduleRange
+            );
+            ensure!(
+                chunk_hashes.len() as u32 == len.div_ceil(SCHEDULE_CHUNK_ERAS),
+                Error::<T>::InvalidChunkCount
+            );
+
+            let hash = T::Hashing::hash_of(&(from_era, len, &chunk_hashes));
+            ensure!(
+                ActiveSchedule::<T>::get().map(|active| active.hash) != Some(hash),
+                Error::<T>::ScheduleAlreadyActive
+            );
+
+            Self::discard_proposal();
+            ProposedSchedule::<T>::put(ScheduleProposal {
+                schedule: ScheduleOverride {
+                    hash,
+                    from_era,
+                    len,
+                },
+                chunk_hashes,
+                uploaded: 0,
+            });
+
+            Self::deposit_event(Event::ScheduleProposed {
+                hash,
+                from_era,
+                len,
+            });
+            Ok(())
+        }
+
+        /// Upload chunk `index` of the proposed schedule
+        ///
+        /// Any signed account may upload; the chunk must match its committed hash.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::upload_schedule_chunk())]
+        pub fn upload_schedule_chunk(
+            origin: OriginFor<T>,
+            index: u32,
+            rewards: BoundedVec<u128, ConstU32<SCHEDULE_CHUNK_ERAS>>,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            let mut proposal =
+                ProposedSchedule::<T>::get().ok_or(Error::<T>::NoScheduleProposal)?;
+            let hash = proposal.schedule.hash;
+
+            let expected_hash = proposal
+                .chunk_hashes
+                .get(index as usize)
+                .copied()
+                .ok_or(Error::<T>::InvalidChunk)?;
+            let expected_len = proposal
+                .schedule
+                .len
+                .saturating_sub(index.saturating_mul(SCHEDULE_CHUNK_ERAS))
+                .min(SCHEDULE_CHUNK_ERAS);
+            ensure!(
+                rewards.len() as u32 == expected_len,
+                Error::<T>::InvalidChunk
+            );
+            ensure!(
+                T::Hashing::hash_of(&rewards) == expected_hash,
+                Error::<T>::ChunkHashMismatch
+            );
+            ensure!(
+                !ScheduleChunks::<T>::contains_key(hash, index),
+                Error::<T>::ChunkAlreadyUploaded
+            );
+
+            ScheduleChunks::<T>::insert(hash, index, rewards);
+            proposal.uploaded = proposal.uploaded.saturating_add(1);
+            ProposedSchedule::<T>::put(proposal);
+
+            Self::deposit_event(Event::ScheduleChunkUploaded { hash, index });
+            Ok(())
+        }
+
+        /// Put the fully uploaded proposed schedule in force
+        ///
+        /// Replaces any active schedule and drops the reward scale. Any signed
+        /// account may enact, since the content was committed by governance.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::enact_schedule())]
+        pub fn enact_schedule(origin: OriginFor<T>) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            let proposal = ProposedSchedule::<T>::get().ok_or(Error::<T>::NoScheduleProposal)?;
+            ensure!(
+                proposal.uploaded as usize == proposal.chunk_hashes.len(),
+                Error::<T>::ScheduleIncomplete
+            );
+
+            let schedule = proposal.schedule;
+            let current_era = Self::current_era(frame_system::Pallet::<T>::block_number());
+            ensure!(
+                schedule.from_era > current_era,
+                Error::<T>::InvalidScheduleRange
+            );
+            let projected_emission = Self::ensure_within_supply(Some(&schedule), None)?;
+
+            if let Some(previous) = ActiveSchedule::<T>::take() {
+                let _ = ScheduleChunks::<T>::clear_prefix(previous.hash, MAX_SCHEDULE_CHUNKS, None);
+            }
+            ActiveSchedule::<T>::put(schedule);
+            ProposedSchedule::<T>::kill();
+            RewardScale::<T>::kill();
+
+            Self::deposit_event(Event::ScheduleEnacted {
+                hash: schedule.hash,
+                from_era: schedule.from_era,
+                len: schedule.len,
+                projected_emission,
+            });
+            Ok(())
+        }
 
+        /// Drop every amendment so `T::Schedule` applies again
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::reset_schedule())]
+        pub fn reset_schedule(origin: OriginFor<T>) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+
+            let projected_emission = Self::ensure_within_supply(None, None)?;
+
+            Self::discard_proposal();
+            if let Some(active) = ActiveSchedule::<T>::take() {
+                let _ = ScheduleChunks::<T>::clear_prefix(active.hash, MAX_SCHEDULE_CHUNKS, None);
+            }
+            RewardScale::<T>::kill();
+
+            Self::deposit_event(Event::ScheduleReset { projected_emission });
+            Ok(())
+        }
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // HELPER FUNCTIONS
+    // ═══════════════════════════════════════════════════════════════════════
+
+    impl<T: Config> Pallet<T> {
+        /// Get the current era based on block number
+        ///
+        /// Saturates at `u32::MAX`, far past the end of any schedule.
+        pub fn current_era(block_number: BlockNumberFor<T>) -> u32 {
+            (block_number.saturating_sub(One::one()) / Self::blocks_per_era()).saturated_into()
+        }
+
+        /// `BlocksPerEra` as a block number
+        fn blocks_per_era() -> BlockNumberFor<T> {
+            T::BlocksPerEra::get().into()
+        }
+
+        /// First block of `era`
+        fn era_start(era: u32) -> BlockNumberFor<T> {
+            BlockNumberFor::<T>::from(era)
+                .saturating_mul(Self::blocks_per_era())
+                .saturating_add(One::one())
+        }
+
+        /// Whether `block_number` is the first block of an era
+        fn is_era_start(block_number: BlockNumberFor<T>) -> bool {
+            !block_number.is_zero()
+                && (block_number.saturating_sub(One::one()) % Self::blocks_per_era()).is_zero()
+        }
+
+        /// Get reward per block for a given era, including governance amendments
+        pub fn reward_for_era(era: u32) -> u128 {
+            if era >= T::Schedule::total_eras() {
+                return 0;
+            }
+
+            let mut reward = T::Schedule::reward_at(era);
+
+            if let Some(active) = ActiveSchedule::<T>::get() {
+                if let Some(offset) = era.checked_sub(active.from_era).filter(|o| *o < active.len) {
+                    if let Some(amended) =
+                        ScheduleChunks::<T>::get(active.hash, offset / SCHEDULE_CHUNK_ERAS)
+                            .and_then(|chunk| {
+                                chunk.get((offset % SCHEDULE_CHUNK_ERAS) as usize).copied()
+                            })
+                    {
+                        reward = amended;
+                    }
+                }
+            }
+
+            if let Some(scale) = RewardScale::<T>::get().filter(|s| era >= s.from_era) {
+                reward = scale.factor.saturating_mul_int(reward);
+            }
+
+            reward
+        }
+
+        /// Get the per-block reward of every era under the schedule in force
+        pub fn effective_schedule() -> alloc::vec::Vec<u128> {
+            Self::schedule_with(
+                ActiveSchedule::<T>::get().as_ref(),
+                RewardScale::<T>::get().as_ref(),
+            )
+        }
+
+        /// Get the per-block reward of every era under the given amendments
+        fn schedule_with(
+            active: Option<&ScheduleOverride<T::Hash>>,
+            scale: Option<&ScaleAdjustment>,
+        ) -> alloc::vec::Vec<u128> {
+            let mut rewards: alloc::vec::Vec<u128> = (0..T::Schedule::total_eras())
+                .map(T::Schedule::reward_at)
+                .collect();
+
+            if let Some(active) = active {
+                for (index, chunk) in ScheduleChunks::<T>::iter_prefix(active.hash) {
+                    let start = active
+                        .from_era
+                        .saturating_add(index.saturating_mul(SCHEDULE_CHUNK_ERAS));
+                    for (slot, reward) in rewards.iter_mut().skip(start as usize).zip(chunk) {
+                        *slot = reward;
+                    }
+                }
+            }
+
+            if let Some(scale) = scale {
+                for reward in rewards.iter_mut().skip(scale.from_era as usize) {
+                    *reward = scale.factor.saturating_mul_int(*reward);
+                }
+            }
+
+            rewards
+        }
+
+        /// Get emission still owed after the current block under `rewards`
+        ///
+        /// Includes `UnmintedRewards`, excludes the terminal bonus.
+        pub fn projected_emission(rewards: &[u128]) -> u128 {
+            let block_number = frame_system::Pallet::<T>::block_number();
+            let era = Self::current_era(block_number);
+            let blocks_per_era = T::BlocksPerEra::get();
+
+            let mut total = UnmintedRewards::<T>::get();
+            if let Some(reward) = rewards.get(era as usize) {
+                let era_end = Self::era_start(era.saturating_add(1)).saturating_sub(One::one());
+                let blocks_left: u128 = era_end.saturating_sub(block_number).saturated_into();
+                total = total.saturating_add(reward.saturating_mul(blocks_left));
+
+                for reward in rewards.iter().skip(era as usize + 1) {
+                    total = total.saturating_add(reward.saturating_mul(blocks_per_era as u128));
+                }
+            }
+            total
+        }
+
+        /// Check that switching to the given amendments keeps emission under the cap
+        ///
+        /// An amendment may always lower projected emission; it may only raise it
+        /// up to the remaining supply. Returns the new projection.
+        fn ensure_within_supply(
+            active: Option<&ScheduleOverride<T::Hash>>,
+            scale: Option<&ScaleAdjustment>,
+        ) -> Result<u128, DispatchError> {
+            let current = Self::projected_emission(&Self::effective_schedule());
+            let projected = Self::projected_emission(&Self::schedule_with(active, scale));
+            ensure!(
+                projected <= current || projected <= Self::remaining_supply(),
+                Error::<T>::ExceedsMaxSupply
+            );
+            Ok(projected)
+        }
+
+        /// Remove the pending proposal and its uploaded chunks
+        fn discard_proposal() {
+            if let Some(proposal) = ProposedSchedule::<T>::take() {
+                let hash = proposal.schedule.hash;
+                if ActiveSchedule::<T>::get().map(|active| active.hash) != Some(hash) {
+                    let _ = ScheduleChunks::<T>::clear_prefix(hash, MAX_SCHEDULE_CHUNKS, None);
+                }
+            }
+        }
+
+        /// Get maximum supply
+        pub fn max_supply() -> u128 {
+            T::MaxSupply::get()
+        }
+
+        /// Get total eras in emission schedule
+        pub fn total_eras() -> u32 {
+            T::Schedule::total_eras()
+        }
+
+        /// Check if emission has ended
+        pub fn is_emission_ended(block_number: BlockNumberFor<T>) -> bool {
+            Self::current_era(block_number) >= T::Schedule::total_eras()
+        }
+
+        /// Get scheduled emission so far (assumes every block was minted)
+        ///
+        /// Uses the schedule in force now. See `TotalMinted` for the amount
+        /// actually minted.
+        pub fn total_emitted(block_number: BlockNumberFor<T>) -> u128 {
+            let current_era = Self::current_era(block_number) as usize;
+            let blocks_per_era = T::BlocksPerEra::get();
+            let rewards = Self::effective_schedule();
+
+            let mut total: u128 = 0;
+
+            // Sum all complete eras
+            for reward in rewards.iter().take(current_era) {
+                total = total.saturating_add(reward.saturating_mul(blocks_per_era as u128));
+            }
+
+            // Add partial current era
+            if let Some(reward) = rewards.get(current_era) {
+                let blocks_in_era: u128 = block_number
+                    .saturating_add(One::one())
+                    .saturating_sub(Self::era_start(current_era as u32))
+                    .saturated_into();
+                total = total.saturating_add(reward.saturating_mul(blocks_in_era));
+            }
+
+            total
+        }
+
+        /// Get amount that can still be minted before reaching `MaxSupply`
+        pub fn remaining_supply() -> u128 {
+            T::MaxSupply::get()
+                .saturating_sub(T::GenesisSupply::get())
+                .saturating_sub(TotalMinted::<T>::get())
+        }
+
+        /// Notify `OnEraChange` if `block_number` is the first block of an era
+        fn start_era(block_number: BlockNumberFor<T>) -> Weight {
+            if !Self::is_era_start(block_number) {
+                return Weight::zero();
+            }
+
+            let era = Self::current_era(block_number);
+            let fees_weight = match era.checked_sub(1) {
+                Some(previous) if previous >= T::Schedule::total_eras() => {
+                    Self::distribute_fees(previous)
+                }
+                _ => Weight::zero(),
+            };
+
+            Self::deposit_event(Event::EraStarted {
+                era,
+                reward_per_block: Self::reward_for_era(era),
+                total_minted: TotalMinted::<T>::get(),
+            });
+
+            // EraStarted reads the reward (3 amendment reads) and TotalMinted
+            T::DbWeight::get()
+                .reads(4)
+                .saturating_add(fees_weight)
+                .saturating_add(T::OnEraChange::on_era_change(era))
+        }
+
+        /// Count the block towards its author's share of the era's fee pool
+        fn note_fee_author(block_number: BlockNumberFor<T>) -> Weight {
+            if !T::PoolFeesAfterEmission::get() || !Self::is_emission_ended(block_number) {
+                return Weight::zero();
+            }
+
+            let era = Self::current_era(block_number);
+            if let Some(author) =
+                Self::block_author().filter(|author| !Self::is_forfeited(author, era))
+            {
+                FeeAuthors::<T>::mutate(|authors| match authors.get_mut(&author) {
+                    Some(blocks) => *blocks = blocks.saturating_add(1),
+                    // Authors beyond MaxFeeRecipients are not counted
+                    None => {
+                        let _ = authors.try_insert(author, 1);
+                    }
+                });
+            }
+
+            T::DbWeight::get().reads_writes(2, 1)
+        }
+
+        /// Pay the fees pooled during `era` to its authors, pro rata by blocks authored
+        ///
+        /// Fees were burned when collected, so this re-mints them without
+        /// counting towards `TotalMinted`. Rounding dust and shares the currency
+        /// refuses to create (e.g. below ED) roll over to the next era, as do
+        /// fees of an era without counted authors.
+        pub(crate) fn distribute_fees(era: u32) -> Weight {
+            let authors = FeeAuthors::<T>::take();
+            let recipients = authors.len() as u32;
+            let pooled = PooledFees::<T>::get();
+            let blocks = authors
+                .values()
+                .fold(0u32, |total, n| total.saturating_add(*n));
+            if pooled == 0 || blocks == 0 {
+                return T::DbWeight::get().reads_writes(2, 1);
+            }
+
+            let mut paid: u128 = 0;
+            for (author, authored) in authors {
+                let share = multiply_by_rational_with_rounding(
+                    pooled,
+                    authored as u128,
+                    blocks as u128,
+                    Rounding::Down,
+                )
+                .unwrap_or(0);
+                paid = paid.saturating_add(Self::mint_to(&Self::payee_of(author), share));
+            }
+            PooledFees::<T>::put(pooled.saturating_sub(paid));
+
+            Self::deposit_event(Event::FeesDistributed {
+                era,
+                amount: paid,
+                recipients,
+            });
+
+            T::WeightInfo::distribute_fees(recipients)
+        }
+
+        /// Mint and distribute the reward of `block_number`
+        fn reward_block(block_number: BlockNumberFor<T>) -> Weight {
             // Calculate current era (0-indexed)
-            // Era 0 = blocks 1-14400, Era 1 = blocks 14401-28800, etc.
-            let current_era = block_num.saturating_sub(1) / BLOCKS_PER_ERA;
+            // Era 0 = blocks 1-BlocksPerEra, Era 1 = the next BlocksPerEra blocks, etc.
+            let current_era = Self::current_era(block_number);
 
             // Check if we're still within the emission schedule
-            if (current_era as usize) >= TOTAL_ERAS {
+            if current_era >= T::Schedule::total_eras() {
                 // Emission has ended - check if bonus needs to be minted
                 if !BonusMinted::<T>::get() {
-                    // Mint bonus amount once to reach 100% of max supply
-                    let bonus: BalanceOf<T> = match BONUS_AMOUNT.try_into() {
+                    // Mint the remainder once to reach 100% of max supply. This
+                    // includes rewards still carried forward in UnmintedRewards.
+                    let bonus_amount = Self::remaining_supply();
+                    let bonus: BalanceOf<T> = match bonus_amount.try_into() {
                         Ok(b) => b,
                         Err(_) => return T::WeightInfo::on_initialize_no_reward(),
                     };
 
-                    // Find the block author (or use first validator if not found)
-                    let digests: alloc::vec::Vec<(frame_support::ConsensusEngineId, &[u8])> =
-                        Default::default();
-                    let recipient = match T::FindAuthor::find_author(digests) {
-                        Some(author) => author,
-                        None => return T::WeightInfo::on_initialize_no_reward(),
+                    // Find the block author; retried next block if not found
+                    let recipient = match Self::block_author() {
+                        Some(author) if !Self::is_forfeited(&author, current_era) => {
+                            Self::payee_of(author)
+                        }
+                        _ => return T::WeightInfo::on_initialize_no_reward(),
                     };
 
                     // Mint the bonus
                     let imbalance = T::Currency::deposit_creating(&recipient, bonus);
+                    Self::note_minted(current_era, imbalance.peek().saturated_into());
                     drop(imbalance);
 
                     // Set flag to prevent duplicate minting
                     BonusMinted::<T>::put(true);
+                    UnmintedRewards::<T>::kill();
 
                     // Emit events
                     Self::deposit_event(Event::BonusMinted {
@@ -192,123 +1194,257 @@ pub mod pallet {
 
                     Self::deposit_event(Event::EmissionEnded {
                         block_number,
-                        total_eras: TOTAL_ERAS as u32,
+                        total_eras: T::Schedule::total_eras(),
                     });
 
                     return T::WeightInfo::on_initialize_with_reward();
                 }
 
                 // Bonus already minted
-                if block_num % BLOCKS_PER_ERA == 1 {
+                if Self::is_era_start(block_number) {
                     // Only emit event once per era
                     Self::deposit_event(Event::EmissionEnded {
                         block_number,
-                        total_eras: TOTAL_ERAS as u32,
+                        total_eras: T::Schedule::total_eras(),
                     });
                 }
                 return T::WeightInfo::on_initialize_no_reward();
             }
 
-            // Look up reward for this era from the pre-computed table
-            let reward_per_block = REWARD_SCHEDULE[current_era as usize];
+            // Look up reward for this era, including governance amendments
+            let scheduled = Self::reward_for_era(current_era);
+
+            // Add rewards carried forward from blocks that could not be minted,
+            // but never mint past MaxSupply
+            let owed = scheduled
+                .saturating_add(UnmintedRewards::<T>::get())
+                .min(Self::remaining_supply());
 
             // Skip if reward is zero
-            if reward_per_block == 0 {
+            if owed == 0 {
                 return T::WeightInfo::on_initialize_no_reward();
             }
 
             // Convert to balance type
-            let reward: BalanceOf<T> = match reward_per_block.try_into() {
+            let reward: BalanceOf<T> = match owed.try_into() {
                 Ok(r) => r,
-                Err(_) => return T::WeightInfo::on_initialize_no_reward(),
+                Err(_) => return Self::defer_reward(block_number, current_era, scheduled),
             };
 
             // Find the block author (validator)
-            // We use an empty iterator since we rely on the FindAuthor implementation
-            // to determine the author from block digests
-            let digests: alloc::vec::Vec<(frame_support::ConsensusEngineId, &[u8])> =
-                Default::default();
-            let author = match T::FindAuthor::find_author(digests) {
+            let author = match Self::block_author() {
                 Some(a) => a,
-                None => return T::WeightInfo::on_initialize_no_reward(),
+                None => return Self::defer_reward(block_number, current_era, scheduled),
             };
 
+            // Offenders' blocks earn nothing this era; the next author collects
+            if Self::is_forfeited(&author, current_era) {
+                return Self::defer_reward(block_number, current_era, scheduled);
+            }
+
+            // Split the reward; the author receives the remainder
+            let shares = RewardShares::<T>::get();
+            let treasury_reward = shares.treasury.mul_floor(owed);
+            let pool_reward = shares.pool.mul_floor(owed);
+            let author_reward = owed
+                .saturating_sub(treasury_reward)
+                .saturating_sub(pool_reward);
+
             // Mint the reward (create new tokens)
-            let imbalance = T::Currency::deposit_creating(&author, reward);
+            let payee = Self::payee_of(author.clone());
+            let minted = Self::mint_to(&payee, author_reward)
+                .saturating_add(Self::mint_to(&T::TreasuryAccount::get(), treasury_reward))
+                .saturating_add(Self::mint_to(&T::PoolAccount::get(), pool_reward));
+            Self::note_minted(current_era, minted);
+
+            // Anything the currency refused to create (e.g. below ED) stays owed
+            UnmintedRewards::<T>::put(owed.saturating_sub(minted));
 
             // Emit event
             Self::deposit_event(Event::RewardMinted {
                 block_number,
                 era: current_era,
-                author: author.clone(),
+                author,
+                payee,
                 reward,
+                author_reward: author_reward.saturated_into(),
+                treasury_reward: treasury_reward.saturated_into(),
+                pool_reward: pool_reward.saturated_into(),
             });
 
-            // Drop imbalance to finalize the mint
-            drop(imbalance);
+            T::WeightInfo::on_initialize_with_reward()
+        }
+
+        /// Find the author of the current block from its pre-runtime digests
+        ///
+        /// Aura names the author in the `PreRuntime` digest, which is set
+        /// before `on_initialize` runs.
+        fn block_author() -> Option<T::AccountId> {
+            let digest = frame_system::Pallet::<T>::digest();
+            T::FindAuthor::find_author(digest.logs.iter().filter_map(|d| d.as_pre_runtime()))
+        }
+
+        /// Carry the reward of a block that could not be minted forward
+        fn defer_reward(block_number: BlockNumberFor<T>, era: u32, amount: u128) -> Weight {
+            let total_unminted = UnmintedRewards::<T>::mutate(|unminted| {
+                *unminted = unminted.saturating_add(amount);
+                *unminted
+            });
+
+            Self::deposit_event(Event::RewardDeferred {
+                block_number,
+                era,
+                amount,
+                total_unminted,
+            });
 
             T::WeightInfo::on_initialize_with_reward()
         }
-    }
 
-    // ═══════════════════════════════════════════════════════════════════════
-    // HELPER FUNCTIONS
-    // ═══════════════════════════════════════════════════════════════════════
+        /// Forfeit `authority`'s pending rewards after a reported offence
+        ///
+        /// Its share of the fees pooled this era goes to the era's other
+        /// authors, and its blocks earn no reward until the era ends: the
+        /// reward is carried forward to the next block by another author.
+        pub fn forfeit_rewards(authority: &T::AccountId) -> Weight {
+            let era = Self::current_era(frame_system::Pallet::<T>::block_number());
+            let fee_blocks = FeeAuthors::<T>::mutate(|authors| authors.remove(authority));
+            Forfeited::<T>::insert(authority, era);
 
-    impl<T: Config> Pallet<T> {
-        /// Get the current era based on block number
-        pub fn current_era(block_number: BlockNumberFor<T>) -> u32 {
-            let block_num: u32 = block_number.try_into().unwrap_or(0);
-            block_num.saturating_sub(1) / BLOCKS_PER_ERA
+            Self::deposit_event(Event::RewardsForfeited {
+                authority: authority.clone(),
+                era,
+                fee_blocks: fee_blocks.unwrap_or(0),
+            });
+
+            T::DbWeight::get().reads_writes(2, 2)
         }
 
-        /// Get reward per block for a given era
-        pub fn reward_for_era(era: u32) -> u128 {
-            if (era as usize) < TOTAL_ERAS {
-                REWARD_SCHEDULE[era as usize]
-            } else {
-                0
+        /// Whether `authority` forfeited its rewards for `era`
+        ///
+        /// Entries of past eras are removed on lookup.
+        fn is_forfeited(authority: &T::AccountId, era: u32) -> bool {
+            match Forfeited::<T>::get(authority) {
+                Some(until) if until >= era => true,
+                Some(_) => {
+                    Forfeited::<T>::remove(authority);
+                    false
+                }
+                None => false,
             }
         }
 
-        /// Get maximum supply
-        pub fn max_supply() -> u128 {
-            MAX_SUPPLY
+        /// Get the account that receives rewards earned by `authority`
+        pub fn payee_of(authority: T::AccountId) -> T::AccountId {
+            Payees::<T>::get(&authority).unwrap_or(authority)
         }
 
-        /// Get total eras in emission schedule
-        pub fn total_eras() -> u32 {
-            TOTAL_ERAS as u32
+        /// Store or remove the payee of `authority`
+        fn do_set_payee(authority: T::AccountId, payee: Option<T::AccountId>) {
+            match payee {
+                Some(payee) => {
+                    Payees::<T>::insert(&authority, &payee);
+                    Self::deposit_event(Event::PayeeSet { authority, payee });
+                }
+                None => {
+                    Payees::<T>::remove(&authority);
+                    Self::deposit_event(Event::PayeeCleared { authority });
+                }
+            }
         }
 
-        /// Check if emission has ended
-        pub fn is_emission_ended(block_number: BlockNumberFor<T>) -> bool {
-            Self::current_era(block_number) >= TOTAL_ERAS as u32
+        /// Mint `amount` to `who`, returning the amount actually created
+        fn mint_to(who: &T::AccountId, amount: u128) -> u128 {
+            if amount == 0 {
+                return 0;
+            }
+            let imbalance = T::Currency::deposit_creating(who, amount.saturated_into());
+            imbalance.peek().saturated_into()
         }
 
-        /// Get estimated total emission so far
-        pub fn total_emitted(block_number: BlockNumberFor<T>) -> u128 {
-            let block_num: u32 = block_number.try_into().unwrap_or(0);
-            let current_era = Self::current_era(block_number) as usize;
+        /// Record a mint in `TotalMinted` and `EraMinted`
+        fn note_minted(era: u32, amount: u128) {
+            TotalMinted::<T>::mutate(|total| *total = total.saturating_add(amount));
+            EraMinted::<T>::mutate(era, |minted| *minted = minted.saturating_add(amount));
+        }
 
-            let mut total: u128 = 0;
+        /// Get scheduled vs actually minted emission up to a block
+        pub fn emission_gap(block_number: BlockNumberFor<T>) -> runtime_api::EmissionGap {
+            let scheduled = Self::total_emitted(block_number);
+            let minted = TotalMinted::<T>::get();
+            runtime_api::EmissionGap {
+                scheduled,
+                minted,
+                shortfall: scheduled.saturating_sub(minted),
+            }
+        }
 
-            // Sum all complete eras
-            for era in 0..current_era.min(TOTAL_ERAS) {
-                total = total
-                    .saturating_add(REWARD_SCHEDULE[era].saturating_mul(BLOCKS_PER_ERA as u128));
+        /// Check supply invariants
+        ///
+        /// - `GenesisSupply + TotalMinted <= MaxSupply`
+        /// - `EraMinted` sums to `TotalMinted`
+        #[cfg(any(feature = "try-runtime", test))]
+        pub fn do_try_state() -> Result<(), sp_runtime::DispatchError> {
+            let total = TotalMinted::<T>::get();
+            ensure!(
+                total.saturating_add(T::GenesisSupply::get()) <= T::MaxSupply::get(),
+                "TotalMinted + GenesisSupply exceeds MaxSupply"
+            );
+
+            let per_era = EraMinted::<T>::iter_values().fold(0u128, |acc, v| acc.saturating_add(v));
+            ensure!(per_era == total, "EraMinted does not sum to TotalMinted");
+
+            Ok(())
+        }
+
+        /// Get reward minted to the author of a block (zero for genesis)
+        pub fn reward_at(block_number: BlockNumberFor<T>) -> u128 {
+            if block_number.is_zero() {
+                return 0;
             }
+            Self::reward_for_era(Self::current_era(block_number))
+        }
 
-            // Add partial current era
-            if current_era < TOTAL_ERAS {
-                let blocks_in_era =
-                    block_num.saturating_sub((current_era as u32).saturating_mul(BLOCKS_PER_ERA));
-                total = total.saturating_add(
-                    REWARD_SCHEDULE[current_era].saturating_mul(blocks_in_era as u128),
-                );
+        /// Get the last block that receives a scheduled reward
+        pub fn emission_end_block() -> BlockNumberFor<T> {
+            Self::era_start(T::Schedule::total_eras()).saturating_sub(One::one())
+        }
+
+        /// Get number of blocks left until the last scheduled reward
+        pub fn blocks_until_end(block_number: BlockNumberFor<T>) -> BlockNumberFor<T> {
+            Self::emission_end_block().saturating_sub(block_number)
+        }
+
+        /// Get the schedule for `from_era..=to_era`
+        ///
+        /// Clamped to the last era and to `MAX_SCHEDULE_ERAS` entries.
+        pub fn schedule(from_era: u32, to_era: u32) -> alloc::vec::Vec<runtime_api::EraSchedule> {
+            let last_era = to_era
+                .min(T::Schedule::total_eras().saturating_sub(1))
+                .min(from_era.saturating_add(runtime_api::MAX_SCHEDULE_ERAS - 1));
+            if from_era > last_era {
+                return alloc::vec::Vec::new();
             }
 
-            total
+            let rewards = Self::effective_schedule();
+            let era_emission =
+                |era: u32| rewards[era as usize].saturating_mul(T::BlocksPerEra::get() as u128);
+
+            // Scheduled emission of all eras before the requested range
+            let mut cumulative =
+                (0..from_era).fold(0u128, |acc, era| acc.saturating_add(era_emission(era)));
+
+            (from_era..=last_era)
+                .map(|era| {
+                    cumulative = cumulative.saturating_add(era_emission(era));
+                    runtime_api::EraSchedule {
+                        era,
+                        reward_per_block: rewards[era as usize],
+                        era_emission: era_emission(era),
+                        cumulative_emission: cumulative,
+                    }
+                })
+                .collect()
         }
     }
 }
@@ -1,13 +1,17 @@
 //! Mock runtime for testing pallet-emission
 
 use frame_support::{
-    derive_impl,
-    traits::{ConstU128, ConstU32, Hooks},
+    derive_impl, parameter_types,
+    traits::{ConstU128, ConstU32, ConstU64, Hooks, SortedMembers},
+    weights::Weight,
 };
-use sp_runtime::{traits::IdentityLookup, BuildStorage};
+use frame_system::EnsureRoot;
+use sp_runtime::{traits::IdentityLookup, BuildStorage, Perbill};
 
 use crate as pallet_emission;
+use crate::{RewardSplit, SigmoidSchedule, BLOCKS_PER_ERA, MAX_SUPPLY};
 
+/// Blocks are numbered with `u64`, so tests can go past `u32::MAX`
 type Block = frame_system::mocking::MockBlock<Test>;
 
 // Configure a mock runtime for testing
@@ -44,14 +48,53 @@ impl pallet_balances::Config for Test {
     type DoneSlashHandler = ();
 }
 
-/// Mock author - always returns account 1 (Alice) as block author
+parameter_types! {
+    /// Era length (settable per test to cross era boundaries cheaply)
+    pub static BlocksPerEra: u32 = BLOCKS_PER_ERA;
+    /// Genesis supply counted against MaxSupply (settable per test)
+    pub static GenesisSupply: u128 = 0;
+    /// Account MockFindAuthor returns (settable per test)
+    pub static BlockAuthor: u64 = 1;
+    /// When true, MockFindAuthor finds no author (settable per test)
+    pub static AuthorMissing: bool = false;
+    /// Validators allowed to register a payee (settable per test)
+    pub static Authorities: Vec<u64> = vec![1, 2, 3];
+    /// Whether fees are pooled after the schedule (settable per test)
+    pub static PoolFeesAfterEmission: bool = true;
+    /// Eras passed to OnEraChange so far
+    pub static EraChanges: Vec<u32> = Vec::new();
+    /// Default reward split (settable per test)
+    pub static DefaultRewardSplit: RewardSplit = RewardSplit::default();
+    pub const MaxNonAuthorShare: Perbill = Perbill::from_percent(50);
+    pub const MaxRewardScaleDeviation: Perbill = Perbill::from_percent(20);
+}
+
+/// Treasury sink in tests
+pub const TREASURY: u64 = 90;
+/// Validator/staker pool sink in tests
+pub const POOL: u64 = 91;
+
+/// Engine of the pre-runtime digest naming the author in tests
+pub const MOCK_ENGINE_ID: frame_support::ConsensusEngineId = *b"mock";
+
+/// Mock author - the account in a `MOCK_ENGINE_ID` pre-runtime digest if the
+/// block has one, else `BlockAuthor` (Alice by default) unless `AuthorMissing` is set
 pub struct MockFindAuthor;
 impl frame_support::traits::FindAuthor<u64> for MockFindAuthor {
-    fn find_author<'a, I>(_digests: I) -> Option<u64>
+    fn find_author<'a, I>(digests: I) -> Option<u64>
     where
         I: 'a + IntoIterator<Item = (frame_support::ConsensusEngineId, &'a [u8])>,
     {
-        Some(1) // Always Alice
+        for (id, mut data) in digests {
+            if id == MOCK_ENGINE_ID {
+                return codec::Decode::decode(&mut data).ok();
+            }
+        }
+        if AuthorMissing::get() {
+            None
+        } else {
+            Some(BlockAuthor::get())
+        }
     }
 }
 
@@ -66,9 +109,51 @@ impl frame_support::traits::FindAuthor<u64> for MockNoAuthor {
     }
 }
 
+/// Validator set read from `Authorities`
+pub struct MockAuthorities;
+impl SortedMembers<u64> for MockAuthorities {
+    fn sorted_members() -> Vec<u64> {
+        let mut authorities = Authorities::get();
+        authorities.sort();
+        authorities
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn add(who: &u64) {
+        let mut authorities = Authorities::get();
+        authorities.push(*who);
+        Authorities::set(authorities);
+    }
+}
+
+/// Records every era start in `EraChanges`
+pub struct RecordEraChange;
+impl crate::OnEraChange for RecordEraChange {
+    fn on_era_change(era: u32) -> Weight {
+        let mut eras = EraChanges::get();
+        eras.push(era);
+        EraChanges::set(eras);
+        Weight::zero()
+    }
+}
+
 impl pallet_emission::Config for Test {
     type Currency = Balances;
     type FindAuthor = MockFindAuthor;
+    type Authorities = MockAuthorities;
+    type BlocksPerEra = BlocksPerEra;
+    type MaxSupply = ConstU128<MAX_SUPPLY>;
+    type Schedule = SigmoidSchedule<BlocksPerEra>;
+    type OnEraChange = RecordEraChange;
+    type PoolFeesAfterEmission = PoolFeesAfterEmission;
+    type MaxFeeRecipients = ConstU32<2>;
+    type GenesisSupply = GenesisSupply;
+    type TreasuryAccount = ConstU64<TREASURY>;
+    type PoolAccount = ConstU64<POOL>;
+    type RewardDistribution = DefaultRewardSplit;
+    type MaxNonAuthorShare = MaxNonAuthorShare;
+    type MaxRewardScaleDeviation = MaxRewardScaleDeviation;
+    type UpdateOrigin = EnsureRoot<u64>;
     type WeightInfo = ();
 }
 
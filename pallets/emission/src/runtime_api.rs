@@ -0,0 +1,82 @@
+//! Runtime API for pallet-emission
+//!
+//! Exposes the pre-computed schedule and reward quotes so that explorers and
+//! treasury models do not have to embed their own copy of the emission table.
+//! Served over RPC by the node under the `emission_*` namespace.
+
+use alloc::vec::Vec;
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+
+/// Maximum number of eras returned by a single `schedule` call
+pub const MAX_SCHEDULE_ERAS: u32 = 1_000;
+
+/// Scheduled emission of a single era
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct EraSchedule {
+    /// Era index (0-based)
+    pub era: u32,
+    /// Reward minted per block during the era
+    pub reward_per_block: u128,
+    /// Reward minted over the whole era
+    pub era_emission: u128,
+    /// Scheduled emission from era 0 up to and including this era
+    pub cumulative_emission: u128,
+}
+
+/// Remaining duration of the emission schedule
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct TimeToEnd<BlockNumber> {
+    /// Last block that receives a scheduled reward
+    pub end_block: BlockNumber,
+    /// Blocks left until `end_block` (zero once emission has ended)
+    pub blocks_remaining: BlockNumber,
+    /// Estimated wall-clock time left at the target block time
+    pub ms_remaining: u64,
+}
+
+/// Scheduled vs actually minted emission
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct EmissionGap {
+    /// Emission the schedule assigns up to the block
+    pub scheduled: u128,
+    /// Emission actually minted (`TotalMinted`)
+    pub minted: u128,
+    /// `scheduled - minted`, e.g. from authorless blocks
+    pub shortfall: u128,
+}
+
+sp_api::decl_runtime_apis! {
+    /// Read-only access to the emission schedule
+    pub trait EmissionApi<BlockNumber>
+    where
+        BlockNumber: Codec,
+    {
+        /// Era of the block the API is called at
+        fn current_era() -> u32;
+
+        /// Reward minted to the author of `block`
+        fn reward_at(block: BlockNumber) -> u128;
+
+        /// Scheduled emission from genesis up to and including `block`
+        fn total_emitted(block: BlockNumber) -> u128;
+
+        /// Schedule for `from_era..=to_era`, at most `MAX_SCHEDULE_ERAS` entries
+        fn schedule(from_era: u32, to_era: u32) -> Vec<EraSchedule>;
+
+        /// Time left until the last scheduled reward
+        fn time_to_end() -> TimeToEnd<BlockNumber>;
+
+        /// Maximum token supply
+        fn max_supply() -> u128;
+
+        /// Amount actually minted by the pallet so far
+        fn total_minted() -> u128;
+
+        /// Amount actually minted during `era`
+        fn era_minted(era: u32) -> u128;
+
+        /// Scheduled vs minted emission at the block the API is called at
+        fn emission_gap() -> EmissionGap;
+    }
+}
@@ -0,0 +1,38 @@
+//! Emission schedules supplied to the pallet through `Config::Schedule`
+
+use core::marker::PhantomData;
+use frame_support::traits::Get;
+
+use crate::{BLOCKS_PER_ERA, REWARD_SCHEDULE, TOTAL_ERAS};
+
+/// Default per-block reward of each era
+///
+/// Governance amendments (`RewardScale`, `ActiveSchedule`) are applied on top
+/// of this schedule by the pallet.
+pub trait EmissionSchedule {
+    /// Number of eras in the schedule; the terminal bonus follows the last one
+    fn total_eras() -> u32;
+
+    /// Reward per block during `era`, zero past the end of the schedule
+    fn reward_at(era: u32) -> u128;
+}
+
+/// The pre-computed sigmoid table, rescaled to eras of `B` blocks
+///
+/// The table is generated for eras of `BLOCKS_PER_ERA` blocks. Shorter eras
+/// pay proportionally more per block, so every era still emits its share of
+/// the curve: with `B = 1` the 20-year schedule runs in 7,300 blocks.
+pub struct SigmoidSchedule<B>(PhantomData<B>);
+
+impl<B: Get<u32>> EmissionSchedule for SigmoidSchedule<B> {
+    fn total_eras() -> u32 {
+        TOTAL_ERAS as u32
+    }
+
+    fn reward_at(era: u32) -> u128 {
+        REWARD_SCHEDULE
+            .get(era as usize)
+            .map(|reward| reward.saturating_mul(BLOCKS_PER_ERA as u128) / B::get().max(1) as u128)
+            .unwrap_or(0)
+    }
+}
@@ -1,9 +1,41 @@
 //! Unit tests for pallet-emission
 
 use crate::{
-    mock::*, pallet::Pallet, Event, BLOCKS_PER_ERA, MAX_SUPPLY, REWARD_SCHEDULE, TOTAL_ERAS,
+    mock::*, pallet::Pallet, runtime_api::MAX_SCHEDULE_ERAS, ActiveSchedule, BonusMinted,
+    EraMinted, Error, Event, FeeAuthors, Forfeited, Payees, PoolFees, PooledFees, ProposedSchedule,
+    RewardScale, RewardShares, RewardSplit, TotalMinted, UnmintedRewards, BLOCKS_PER_ERA,
+    BONUS_AMOUNT, MAX_SCHEDULE_CHUNKS, MAX_SUPPLY, REWARD_SCHEDULE, SCHEDULE_CHUNK_ERAS,
+    TOTAL_ERAS,
 };
-use frame_support::{assert_ok, traits::Hooks};
+use codec::Encode;
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{ConstU32, Currency, ExistenceRequirement, Hooks, OnUnbalanced, WithdrawReasons},
+    BoundedVec,
+};
+use sp_runtime::{traits::Hash, DigestItem, DispatchError, FixedPointNumber, FixedU128, Perbill};
+
+type ScheduleChunk = BoundedVec<u128, ConstU32<SCHEDULE_CHUNK_ERAS>>;
+
+/// Split `rewards` into upload chunks and their hashes
+fn schedule_chunks(
+    rewards: &[u128],
+) -> (
+    Vec<ScheduleChunk>,
+    BoundedVec<<Test as frame_system::Config>::Hash, ConstU32<MAX_SCHEDULE_CHUNKS>>,
+) {
+    let chunks: Vec<ScheduleChunk> = rewards
+        .chunks(SCHEDULE_CHUNK_ERAS as usize)
+        .map(|chunk| chunk.to_vec().try_into().unwrap())
+        .collect();
+    let hashes = chunks
+        .iter()
+        .map(<Test as frame_system::Config>::Hashing::hash_of)
+        .collect::<Vec<_>>()
+        .try_into()
+        .unwrap();
+    (chunks, hashes)
+}
 
 #[test]
 fn test_emission_constants() {
@@ -77,11 +109,30 @@ fn test_reward_minting_on_initialize() {
             block_number: 2,
             era: 0,
             author: alice,
+            payee: alice,
             reward: REWARD_SCHEDULE[0] as u128,
+            author_reward: REWARD_SCHEDULE[0] as u128,
+            treasury_reward: 0,
+            pool_reward: 0,
         }));
     });
 }
 
+#[test]
+fn test_reward_goes_to_author_in_pre_runtime_digest() {
+    new_test_ext().execute_with(|| {
+        let carol = 7u64;
+
+        // The author is read from the block's digest, as Aura provides it
+        System::set_block_number(2);
+        System::deposit_log(DigestItem::PreRuntime(MOCK_ENGINE_ID, carol.encode()));
+        <Pallet<Test> as Hooks<u64>>::on_initialize(2);
+
+        assert_eq!(Balances::free_balance(carol), REWARD_SCHEDULE[0]);
+        assert_eq!(UnmintedRewards::<Test>::get(), 0);
+    });
+}
+
 #[test]
 fn test_multiple_blocks_accumulate_rewards() {
     new_test_ext().execute_with(|| {
@@ -235,3 +286,800 @@ fn test_first_era_has_initial_burst() {
         // This is acceptable as it's for initial distribution
     });
 }
+
+#[test]
+fn test_reward_at_block() {
+    new_test_ext().execute_with(|| {
+        // Genesis is never rewarded
+        assert_eq!(Emission::reward_at(0), 0);
+
+        assert_eq!(Emission::reward_at(1), REWARD_SCHEDULE[0]);
+        assert_eq!(Emission::reward_at(14401), REWARD_SCHEDULE[1]);
+
+        // Last scheduled block and first block after the schedule
+        let end = Emission::emission_end_block();
+        assert_eq!(Emission::reward_at(end), REWARD_SCHEDULE[TOTAL_ERAS - 1]);
+        assert_eq!(Emission::reward_at(end + 1), 0);
+    });
+}
+
+#[test]
+fn test_blocks_until_end() {
+    new_test_ext().execute_with(|| {
+        let end = Emission::emission_end_block();
+        assert_eq!(end, TOTAL_ERAS as u64 * BLOCKS_PER_ERA as u64);
+        assert_eq!(Emission::blocks_until_end(1), end - 1);
+        assert_eq!(Emission::blocks_until_end(end), 0);
+        assert_eq!(Emission::blocks_until_end(end + 100), 0);
+    });
+}
+
+#[test]
+fn test_schedule_matches_total_emitted() {
+    new_test_ext().execute_with(|| {
+        let schedule = Emission::schedule(10, 20);
+        assert_eq!(schedule.len(), 11);
+
+        for entry in schedule {
+            assert_eq!(entry.reward_per_block, REWARD_SCHEDULE[entry.era as usize]);
+            assert_eq!(
+                entry.era_emission,
+                entry.reward_per_block * BLOCKS_PER_ERA as u128
+            );
+
+            // Cumulative emission equals total emitted at the last block of the era
+            let last_block = (entry.era + 1) * BLOCKS_PER_ERA;
+            assert_eq!(
+                entry.cumulative_emission,
+                Emission::total_emitted(last_block)
+            );
+        }
+    });
+}
+
+#[test]
+fn test_schedule_is_clamped() {
+    new_test_ext().execute_with(|| {
+        // Range is capped per call
+        assert_eq!(
+            Emission::schedule(0, u32::MAX).len(),
+            MAX_SCHEDULE_ERAS as usize
+        );
+
+        // Range is capped at the last era
+        let tail = Emission::schedule(TOTAL_ERAS as u32 - 5, TOTAL_ERAS as u32 + 5);
+        assert_eq!(tail.len(), 5);
+        assert_eq!(tail.last().unwrap().era, TOTAL_ERAS as u32 - 1);
+
+        // Empty and inverted ranges
+        assert!(Emission::schedule(TOTAL_ERAS as u32, TOTAL_ERAS as u32 + 1).is_empty());
+        assert!(Emission::schedule(20, 10).is_empty());
+    });
+}
+
+#[test]
+fn test_total_minted_tracks_every_mint() {
+    new_test_ext().execute_with(|| {
+        run_to_block(11);
+
+        // Blocks 2..=11 minted the era 0 reward
+        let expected = REWARD_SCHEDULE[0] * 10;
+        assert_eq!(TotalMinted::<Test>::get(), expected);
+        assert_eq!(EraMinted::<Test>::get(0), expected);
+        assert_eq!(EraMinted::<Test>::get(1), 0);
+        assert_ok!(Emission::do_try_state());
+    });
+}
+
+#[test]
+fn test_authorless_blocks_show_up_as_shortfall() {
+    new_test_ext().execute_with(|| {
+        run_to_block(5);
+        AuthorMissing::set(true);
+        run_to_block(8);
+
+        // Blocks 6..=8 minted nothing yet
+        assert_eq!(TotalMinted::<Test>::get(), REWARD_SCHEDULE[0] * 4);
+
+        // Scheduled emission counts blocks 1..=8 (block 1 is never run in tests)
+        let gap = Emission::emission_gap(8);
+        assert_eq!(gap.scheduled, REWARD_SCHEDULE[0] * 8);
+        assert_eq!(gap.minted, REWARD_SCHEDULE[0] * 4);
+        assert_eq!(gap.shortfall, REWARD_SCHEDULE[0] * 4);
+    });
+}
+
+#[test]
+fn test_minting_stops_at_max_supply() {
+    new_test_ext().execute_with(|| {
+        // Leave room for two full rewards and a partial one
+        GenesisSupply::set(MAX_SUPPLY - REWARD_SCHEDULE[0] * 2 - 5);
+        let alice = 1u64;
+        let initial_balance = Balances::free_balance(alice);
+
+        run_to_block(6);
+
+        assert_eq!(Emission::remaining_supply(), 0);
+        assert_eq!(TotalMinted::<Test>::get(), REWARD_SCHEDULE[0] * 2 + 5);
+        assert_eq!(
+            Balances::free_balance(alice) - initial_balance,
+            REWARD_SCHEDULE[0] * 2 + 5
+        );
+        assert_ok!(Emission::do_try_state());
+    });
+}
+
+#[test]
+fn test_integrity_test_accepts_mock_config() {
+    new_test_ext().execute_with(|| {
+        <Pallet<Test> as Hooks<u64>>::integrity_test();
+    });
+}
+
+#[test]
+fn test_authorless_rewards_are_carried_forward() {
+    new_test_ext().execute_with(|| {
+        let alice = 1u64;
+        run_to_block(5);
+        AuthorMissing::set(true);
+        run_to_block(8);
+
+        assert_eq!(UnmintedRewards::<Test>::get(), REWARD_SCHEDULE[0] * 3);
+        System::assert_has_event(RuntimeEvent::Emission(Event::RewardDeferred {
+            block_number: 8,
+            era: 0,
+            amount: REWARD_SCHEDULE[0],
+            total_unminted: REWARD_SCHEDULE[0] * 3,
+        }));
+
+        // The next authored block pays its own reward plus the three deferred ones
+        AuthorMissing::set(false);
+        let before = Balances::free_balance(alice);
+        run_to_block(9);
+
+        assert_eq!(
+            Balances::free_balance(alice) - before,
+            REWARD_SCHEDULE[0] * 4
+        );
+        assert_eq!(UnmintedRewards::<Test>::get(), 0);
+        assert_eq!(TotalMinted::<Test>::get(), REWARD_SCHEDULE[0] * 8);
+        assert_ok!(Emission::do_try_state());
+    });
+}
+
+#[test]
+fn test_terminal_bonus_mints_remainder_of_max_supply() {
+    new_test_ext().execute_with(|| {
+        GenesisSupply::set(1_000);
+
+        // Pretend the whole schedule was minted except for some deferred rewards
+        let scheduled: u128 = REWARD_SCHEDULE
+            .iter()
+            .map(|r| r * BLOCKS_PER_ERA as u128)
+            .sum();
+        let deferred = REWARD_SCHEDULE[TOTAL_ERAS - 1] * 3;
+        TotalMinted::<Test>::put(scheduled - deferred);
+        UnmintedRewards::<Test>::put(deferred);
+
+        let end = Emission::emission_end_block();
+        System::set_block_number(end + 1);
+        <Pallet<Test> as Hooks<u64>>::on_initialize(end + 1);
+
+        let expected_bonus = MAX_SUPPLY - 1_000 - (scheduled - deferred);
+        assert!(expected_bonus > BONUS_AMOUNT);
+        System::assert_has_event(RuntimeEvent::Emission(Event::BonusMinted {
+            block_number: end + 1,
+            recipient: 1,
+            amount: expected_bonus,
+        }));
+        assert!(BonusMinted::<Test>::get());
+        assert_eq!(UnmintedRewards::<Test>::get(), 0);
+        assert_eq!(TotalMinted::<Test>::get() + 1_000, MAX_SUPPLY);
+    });
+}
+
+#[test]
+fn test_reward_is_split_between_author_treasury_and_pool() {
+    new_test_ext().execute_with(|| {
+        let shares = RewardSplit {
+            treasury: Perbill::from_percent(20),
+            pool: Perbill::from_percent(10),
+        };
+        assert_ok!(Emission::set_reward_shares(RuntimeOrigin::root(), shares));
+        System::assert_has_event(RuntimeEvent::Emission(Event::RewardSharesUpdated {
+            shares,
+        }));
+
+        let alice = 1u64;
+        let before = Balances::free_balance(alice);
+        run_to_block(2);
+
+        let reward = REWARD_SCHEDULE[0];
+        let treasury_reward = Perbill::from_percent(20).mul_floor(reward);
+        let pool_reward = Perbill::from_percent(10).mul_floor(reward);
+        let author_reward = reward - treasury_reward - pool_reward;
+
+        assert_eq!(Balances::free_balance(alice) - before, author_reward);
+        assert_eq!(Balances::free_balance(TREASURY), treasury_reward);
+        assert_eq!(Balances::free_balance(POOL), pool_reward);
+        assert_eq!(TotalMinted::<Test>::get(), reward);
+
+        System::assert_has_event(RuntimeEvent::Emission(Event::RewardMinted {
+            block_number: 2,
+            era: 0,
+            author: alice,
+            payee: alice,
+            reward,
+            author_reward,
+            treasury_reward,
+            pool_reward,
+        }));
+    });
+}
+
+#[test]
+fn test_default_reward_split_comes_from_config() {
+    let shares = RewardSplit {
+        treasury: Perbill::from_percent(5),
+        pool: Perbill::from_percent(0),
+    };
+    DefaultRewardSplit::set(shares);
+    new_test_ext().execute_with(|| {
+        assert_eq!(RewardShares::<Test>::get(), shares);
+    });
+}
+
+#[test]
+fn test_set_reward_shares_is_bounded_and_governed() {
+    new_test_ext().execute_with(|| {
+        let too_high = RewardSplit {
+            treasury: Perbill::from_percent(30),
+            pool: Perbill::from_percent(25),
+        };
+        assert_noop!(
+            Emission::set_reward_shares(RuntimeOrigin::root(), too_high),
+            Error::<Test>::SharesExceedBound
+        );
+
+        assert_noop!(
+            Emission::set_reward_shares(RuntimeOrigin::signed(1), RewardSplit::default()),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn test_rewards_are_paid_to_registered_payee() {
+    new_test_ext().execute_with(|| {
+        let alice = 1u64;
+        let vault = 42u64;
+        assert_ok!(Emission::set_payee(RuntimeOrigin::signed(alice), vault));
+        System::assert_has_event(RuntimeEvent::Emission(Event::PayeeSet {
+            authority: alice,
+            payee: vault,
+        }));
+
+        let before = Balances::free_balance(alice);
+        run_to_block(2);
+
+        let reward = REWARD_SCHEDULE[0];
+        assert_eq!(Balances::free_balance(alice), before);
+        assert_eq!(Balances::free_balance(vault), reward);
+        System::assert_has_event(RuntimeEvent::Emission(Event::RewardMinted {
+            block_number: 2,
+            era: 0,
+            author: alice,
+            payee: vault,
+            reward,
+            author_reward: reward,
+            treasury_reward: 0,
+            pool_reward: 0,
+        }));
+
+        // Clearing the payee sends rewards back to the authority
+        assert_ok!(Emission::clear_payee(RuntimeOrigin::signed(alice)));
+        assert_eq!(Payees::<Test>::get(alice), None);
+        run_to_block(3);
+        assert_eq!(Balances::free_balance(alice) - before, reward);
+    });
+}
+
+#[test]
+fn test_payee_management_origins() {
+    new_test_ext().execute_with(|| {
+        // Only validators can register a payee
+        assert_noop!(
+            Emission::set_payee(RuntimeOrigin::signed(7), 8),
+            Error::<Test>::NotAuthority
+        );
+        assert!(!Payees::<Test>::contains_key(7));
+
+        assert_noop!(
+            Emission::clear_payee(RuntimeOrigin::signed(1)),
+            Error::<Test>::NoPayee
+        );
+        assert_noop!(
+            Emission::force_set_payee(RuntimeOrigin::signed(1), 1, Some(7)),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(Emission::force_set_payee(RuntimeOrigin::root(), 1, Some(7)));
+        assert_eq!(Payees::<Test>::get(1), Some(7));
+        assert_eq!(Emission::payee_of(1), 7);
+
+        assert_ok!(Emission::force_set_payee(RuntimeOrigin::root(), 1, None));
+        System::assert_last_event(RuntimeEvent::Emission(Event::PayeeCleared { authority: 1 }));
+        assert_eq!(Emission::payee_of(1), 1);
+    });
+}
+
+#[test]
+fn test_scale_applies_to_current_and_later_eras() {
+    new_test_ext().execute_with(|| {
+        let factor = FixedU128::from_rational(9, 10);
+        assert_ok!(Emission::scale_remaining_eras(
+            RuntimeOrigin::root(),
+            factor
+        ));
+        assert_eq!(RewardScale::<Test>::get().unwrap().from_era, 0);
+
+        let scaled = factor.saturating_mul_int(REWARD_SCHEDULE[0]);
+        assert_eq!(Emission::reward_for_era(0), scaled);
+        assert_eq!(
+            Emission::reward_for_era(100),
+            factor.saturating_mul_int(REWARD_SCHEDULE[100])
+        );
+
+        run_to_block(2);
+        assert_eq!(TotalMinted::<Test>::get(), scaled);
+
+        // Dropping the amendment restores the compile-time table
+        assert_ok!(Emission::reset_schedule(RuntimeOrigin::root()));
+        assert_eq!(Emission::reward_for_era(0), REWARD_SCHEDULE[0]);
+        assert!(RewardScale::<Test>::get().is_none());
+    });
+}
+
+#[test]
+fn test_scale_is_bounded_by_deviation_and_max_supply() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Emission::scale_remaining_eras(RuntimeOrigin::root(), FixedU128::from_rational(13, 10)),
+            Error::<Test>::ScaleOutOfBounds
+        );
+        assert_noop!(
+            Emission::scale_remaining_eras(RuntimeOrigin::root(), FixedU128::from_rational(7, 10)),
+            Error::<Test>::ScaleOutOfBounds
+        );
+        assert_noop!(
+            Emission::scale_remaining_eras(RuntimeOrigin::signed(1), FixedU128::one()),
+            DispatchError::BadOrigin
+        );
+
+        // The schedule already emits nearly all of MAX_SUPPLY, so scaling up overshoots
+        assert_noop!(
+            Emission::scale_remaining_eras(RuntimeOrigin::root(), FixedU128::from_rational(11, 10)),
+            Error::<Test>::ExceedsMaxSupply
+        );
+    });
+}
+
+#[test]
+fn test_chunked_schedule_is_uploaded_and_enacted() {
+    new_test_ext().execute_with(|| {
+        // Halve eras 1..151 (two chunks, the second one partial)
+        let rewards: Vec<u128> = REWARD_SCHEDULE[1..151].iter().map(|r| r / 2).collect();
+        let (chunks, hashes) = schedule_chunks(&rewards);
+        assert_ok!(Emission::propose_schedule(
+            RuntimeOrigin::root(),
+            1,
+            150,
+            hashes
+        ));
+        let hash = ProposedSchedule::<Test>::get().unwrap().schedule.hash;
+
+        assert_noop!(
+            Emission::upload_schedule_chunk(RuntimeOrigin::signed(2), 0, chunks[1].clone()),
+            Error::<Test>::InvalidChunk
+        );
+        let mut tampered = chunks[0].to_vec();
+        tampered[0] += 1;
+        let tampered: ScheduleChunk = tampered.try_into().unwrap();
+        assert_noop!(
+            Emission::upload_schedule_chunk(RuntimeOrigin::signed(2), 0, tampered),
+            Error::<Test>::ChunkHashMismatch
+        );
+
+        assert_ok!(Emission::upload_schedule_chunk(
+            RuntimeOrigin::signed(2),
+            0,
+            chunks[0].clone()
+        ));
+        assert_noop!(
+            Emission::upload_schedule_chunk(RuntimeOrigin::signed(2), 0, chunks[0].clone()),
+            Error::<Test>::ChunkAlreadyUploaded
+        );
+        assert_noop!(
+            Emission::enact_schedule(RuntimeOrigin::signed(2)),
+            Error::<Test>::ScheduleIncomplete
+        );
+        assert_ok!(Emission::upload_schedule_chunk(
+            RuntimeOrigin::signed(2),
+            1,
+            chunks[1].clone()
+        ));
+
+        assert_ok!(Emission::enact_schedule(RuntimeOrigin::signed(2)));
+        assert!(ProposedSchedule::<Test>::get().is_none());
+        assert_eq!(ActiveSchedule::<Test>::get().unwrap().hash, hash);
+        assert!(System::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::Emission(Event::ScheduleEnacted {
+                from_era: 1,
+                len: 150,
+                ..
+            })
+        )));
+
+        assert_eq!(Emission::reward_for_era(0), REWARD_SCHEDULE[0]);
+        assert_eq!(Emission::reward_for_era(1), REWARD_SCHEDULE[1] / 2);
+        assert_eq!(Emission::reward_for_era(150), REWARD_SCHEDULE[150] / 2);
+        assert_eq!(Emission::reward_for_era(151), REWARD_SCHEDULE[151]);
+        assert_eq!(
+            Emission::schedule(120, 120)[0].reward_per_block,
+            REWARD_SCHEDULE[120] / 2
+        );
+
+        // The first block of era 1 mints the amended reward
+        let first_block = BLOCKS_PER_ERA as u64 + 1;
+        System::set_block_number(first_block);
+        <Pallet<Test> as Hooks<u64>>::on_initialize(first_block);
+        assert_eq!(EraMinted::<Test>::get(1), REWARD_SCHEDULE[1] / 2);
+    });
+}
+
+#[test]
+fn test_schedule_proposal_is_validated() {
+    new_test_ext().execute_with(|| {
+        let (_, hashes) = schedule_chunks(&REWARD_SCHEDULE[0..10]);
+        assert_noop!(
+            Emission::propose_schedule(RuntimeOrigin::root(), 0, 10, hashes.clone()),
+            Error::<Test>::InvalidScheduleRange
+        );
+        assert_noop!(
+            Emission::propose_schedule(
+                RuntimeOrigin::root(),
+                TOTAL_ERAS as u32 - 5,
+                10,
+                hashes.clone()
+            ),
+            Error::<Test>::InvalidScheduleRange
+        );
+        assert_noop!(
+            Emission::propose_schedule(RuntimeOrigin::root(), 1, 101, hashes.clone()),
+            Error::<Test>::InvalidChunkCount
+        );
+        assert_noop!(
+            Emission::propose_schedule(RuntimeOrigin::signed(1), 1, 10, hashes),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn test_schedule_raising_emission_past_max_supply_is_rejected() {
+    new_test_ext().execute_with(|| {
+        let rewards: Vec<u128> = REWARD_SCHEDULE[1..101].iter().map(|r| r * 2).collect();
+        let (chunks, hashes) = schedule_chunks(&rewards);
+        assert_ok!(Emission::propose_schedule(
+            RuntimeOrigin::root(),
+            1,
+            100,
+            hashes
+        ));
+        assert_ok!(Emission::upload_schedule_chunk(
+            RuntimeOrigin::signed(2),
+            0,
+            chunks[0].clone()
+        ));
+
+        assert_noop!(
+            Emission::enact_schedule(RuntimeOrigin::signed(2)),
+            Error::<Test>::ExceedsMaxSupply
+        );
+        assert!(Pallet::<Test>::do_try_state().is_ok());
+    });
+}
+
+#[test]
+fn test_short_eras_preserve_era_emission() {
+    new_test_ext().execute_with(|| {
+        BlocksPerEra::set(10);
+
+        assert_eq!(Emission::total_eras(), TOTAL_ERAS as u32);
+        assert_eq!(Emission::emission_end_block(), TOTAL_ERAS as u64 * 10);
+        assert_eq!(Emission::current_era(10), 0);
+        assert_eq!(Emission::current_era(11), 1);
+
+        // Each era emits what a full-length era would, up to rounding
+        for era in [0, 1, 3_650, TOTAL_ERAS as u32 - 1] {
+            let full = REWARD_SCHEDULE[era as usize] * BLOCKS_PER_ERA as u128;
+            let short = Emission::reward_for_era(era) * 10;
+            assert!(full - short < 10, "era {era} emission changed");
+        }
+    });
+}
+
+#[test]
+fn test_one_block_eras_run_whole_schedule() {
+    new_test_ext().execute_with(|| {
+        BlocksPerEra::set(1);
+        let end = Emission::emission_end_block();
+        assert_eq!(end, TOTAL_ERAS as u64);
+
+        // Blocks 2 and 3 are the first blocks of eras 1 and 2
+        run_to_block(3);
+        assert_eq!(EraMinted::<Test>::get(1), Emission::reward_for_era(1));
+        assert_eq!(EraMinted::<Test>::get(2), Emission::reward_for_era(2));
+
+        run_to_block(end + 1);
+        assert!(BonusMinted::<Test>::get());
+        assert_eq!(TotalMinted::<Test>::get(), MAX_SUPPLY);
+        assert!(Pallet::<Test>::do_try_state().is_ok());
+    });
+}
+
+#[test]
+fn test_era_started_at_first_era_boundary() {
+    new_test_ext().execute_with(|| {
+        BlocksPerEra::set(10);
+        let era0_reward = Emission::reward_for_era(0);
+
+        <Pallet<Test> as Hooks<u64>>::on_initialize(1);
+        System::assert_has_event(RuntimeEvent::Emission(Event::EraStarted {
+            era: 0,
+            reward_per_block: era0_reward,
+            total_minted: 0,
+        }));
+
+        // No era starts inside era 0
+        run_to_block(10);
+        assert_eq!(EraChanges::get(), vec![0]);
+
+        // Block 11 starts era 1, before its own reward is minted
+        run_to_block(11);
+        assert_eq!(EraChanges::get(), vec![0, 1]);
+        System::assert_has_event(RuntimeEvent::Emission(Event::EraStarted {
+            era: 1,
+            reward_per_block: Emission::reward_for_era(1),
+            total_minted: era0_reward * 10,
+        }));
+    });
+}
+
+#[test]
+fn test_era_started_at_last_era_boundary() {
+    new_test_ext().execute_with(|| {
+        BlocksPerEra::set(10);
+        let last_era = TOTAL_ERAS as u32 - 1;
+        let end = Emission::emission_end_block();
+
+        System::set_block_number(end - 10);
+        run_to_block(end + 1);
+        assert_eq!(EraChanges::get(), vec![last_era, last_era + 1]);
+
+        System::assert_has_event(RuntimeEvent::Emission(Event::EraStarted {
+            era: last_era,
+            reward_per_block: Emission::reward_for_era(last_era),
+            total_minted: 0,
+        }));
+        // Eras keep starting after the schedule, with no reward
+        System::assert_has_event(RuntimeEvent::Emission(Event::EraStarted {
+            era: last_era + 1,
+            reward_per_block: 0,
+            total_minted: Emission::reward_for_era(last_era) * 10,
+        }));
+        assert!(BonusMinted::<Test>::get());
+    });
+}
+
+/// Withdraw `amount` from Bob as a fee and hand it to `PoolFees`
+fn pay_fee(amount: u128) {
+    let fee = <Balances as Currency<u64>>::withdraw(
+        &2,
+        amount,
+        WithdrawReasons::FEE,
+        ExistenceRequirement::KeepAlive,
+    )
+    .unwrap();
+    PoolFees::<Test>::on_unbalanced(fee);
+}
+
+#[test]
+fn test_fees_are_burned_during_emission() {
+    new_test_ext().execute_with(|| {
+        let issuance = Balances::total_issuance();
+        pay_fee(1_000);
+
+        assert_eq!(Balances::total_issuance(), issuance - 1_000);
+        assert_eq!(PooledFees::<Test>::get(), 0);
+
+        run_to_block(3);
+        assert!(FeeAuthors::<Test>::get().is_empty());
+    });
+}
+
+#[test]
+fn test_fees_after_emission_are_paid_pro_rata() {
+    new_test_ext().execute_with(|| {
+        BlocksPerEra::set(10);
+        let era = TOTAL_ERAS as u32;
+        let end = Emission::emission_end_block();
+        Payees::<Test>::insert(3, 4);
+
+        // Alice authors 4 blocks of the first post-emission era, account 3 the other 6
+        System::set_block_number(end);
+        run_to_block(end + 4);
+        BlockAuthor::set(3);
+        run_to_block(end + 10);
+        assert!(BonusMinted::<Test>::get());
+
+        pay_fee(600);
+        pay_fee(401);
+        assert_eq!(PooledFees::<Test>::get(), 1_001);
+
+        let alice = Balances::free_balance(1);
+        let issuance = Balances::total_issuance();
+        let minted = TotalMinted::<Test>::get();
+        run_to_block(end + 11);
+
+        System::assert_has_event(RuntimeEvent::Emission(Event::FeesDistributed {
+            era,
+            amount: 1_000,
+            recipients: 2,
+        }));
+        assert_eq!(Balances::free_balance(1), alice + 400);
+        assert_eq!(Balances::free_balance(4), 600);
+        assert_eq!(Balances::total_issuance(), issuance + 1_000);
+        // Re-minted fees are not new supply
+        assert_eq!(TotalMinted::<Test>::get(), minted);
+        // Rounding dust rolls over; the new era counts its own authors
+        assert_eq!(PooledFees::<Test>::get(), 1);
+        assert_eq!(FeeAuthors::<Test>::get().into_inner(), [(3, 1)].into());
+    });
+}
+
+#[test]
+fn test_fee_pooling_can_be_disabled() {
+    new_test_ext().execute_with(|| {
+        PoolFeesAfterEmission::set(false);
+        BlocksPerEra::set(10);
+        let end = Emission::emission_end_block();
+
+        System::set_block_number(end);
+        run_to_block(end + 5);
+        let issuance = Balances::total_issuance();
+        pay_fee(1_000);
+
+        assert_eq!(Balances::total_issuance(), issuance - 1_000);
+        assert_eq!(PooledFees::<Test>::get(), 0);
+        assert!(FeeAuthors::<Test>::get().is_empty());
+    });
+}
+
+#[test]
+fn test_blocks_past_u32_do_not_restart_schedule() {
+    new_test_ext().execute_with(|| {
+        // The mock runtime uses u64 block numbers
+        let block = u32::MAX as u64 + 2;
+        assert_eq!(
+            Emission::current_era(block),
+            ((block - 1) / BLOCKS_PER_ERA as u64) as u32
+        );
+        assert!(Emission::is_emission_ended(block));
+        assert_eq!(Emission::reward_at(block), 0);
+        assert_eq!(Emission::blocks_until_end(block), 0);
+
+        BonusMinted::<Test>::put(true);
+        System::set_block_number(block);
+        <Pallet<Test> as Hooks<u64>>::on_initialize(block);
+        assert_eq!(TotalMinted::<Test>::get(), 0);
+    });
+}
+
+#[test]
+fn test_era_saturates_at_u32_max() {
+    new_test_ext().execute_with(|| {
+        BlocksPerEra::set(1);
+        assert_eq!(Emission::current_era(u64::MAX), u32::MAX);
+        assert_eq!(Emission::current_era(u32::MAX as u64 + 1), u32::MAX);
+        assert!(Emission::is_emission_ended(u64::MAX));
+    });
+}
+
+#[test]
+fn test_integrity_test_accepts_mock_config() {
+    new_test_ext().execute_with(|| {
+        <Pallet<Test> as Hooks<u64>>::integrity_test();
+    });
+}
+
+#[test]
+#[should_panic(expected = "BlocksPerEra must not be zero")]
+fn test_integrity_test_rejects_zero_era_length() {
+    new_test_ext().execute_with(|| {
+        BlocksPerEra::set(0);
+        <Pallet<Test> as Hooks<u64>>::integrity_test();
+    });
+}
+
+#[test]
+fn test_forfeited_author_earns_nothing_for_rest_of_era() {
+    new_test_ext().execute_with(|| {
+        BlocksPerEra::set(10);
+        let reward = Emission::reward_at(2);
+        run_to_block(2);
+
+        Emission::forfeit_rewards(&1);
+        System::assert_last_event(RuntimeEvent::Emission(Event::RewardsForfeited {
+            authority: 1,
+            era: 0,
+            fee_blocks: 0,
+        }));
+        assert_eq!(Forfeited::<Test>::get(1), Some(0));
+
+        // Alice's block is deferred to the next author
+        let alice = Balances::free_balance(1);
+        run_to_block(3);
+        assert_eq!(Balances::free_balance(1), alice);
+        assert_eq!(UnmintedRewards::<Test>::get(), reward);
+
+        BlockAuthor::set(2);
+        let bob = Balances::free_balance(2);
+        run_to_block(4);
+        assert_eq!(Balances::free_balance(2), bob + 2 * reward);
+        assert_eq!(UnmintedRewards::<Test>::get(), 0);
+
+        // Rewards resume in the next era
+        BlockAuthor::set(1);
+        run_to_block(10);
+        assert_eq!(Balances::free_balance(1), alice);
+        run_to_block(11);
+        assert!(Balances::free_balance(1) > alice);
+        assert_eq!(Forfeited::<Test>::get(1), None);
+    });
+}
+
+#[test]
+fn test_forfeit_drops_pending_fee_share() {
+    new_test_ext().execute_with(|| {
+        BlocksPerEra::set(10);
+        let era = TOTAL_ERAS as u32;
+        let end = Emission::emission_end_block();
+
+        // Alice authors 4 blocks, then forfeits; account 3 authors the rest
+        System::set_block_number(end);
+        run_to_block(end + 4);
+        Emission::forfeit_rewards(&1);
+        System::assert_last_event(RuntimeEvent::Emission(Event::RewardsForfeited {
+            authority: 1,
+            era,
+            fee_blocks: 4,
+        }));
+        BlockAuthor::set(3);
+        run_to_block(end + 10);
+        assert_eq!(FeeAuthors::<Test>::get().into_inner(), [(3, 6)].into());
+
+        pay_fee(1_000);
+        let alice = Balances::free_balance(1);
+        run_to_block(end + 11);
+
+        System::assert_has_event(RuntimeEvent::Emission(Event::FeesDistributed {
+            era,
+            amount: 1_000,
+            recipients: 1,
+        }));
+        assert_eq!(Balances::free_balance(1), alice);
+        assert_eq!(Balances::free_balance(3), 1_000);
+    });
+}
@@ -10,6 +10,14 @@ use frame_support::weights::Weight;
 pub trait WeightInfo {
     fn on_initialize_with_reward() -> Weight;
     fn on_initialize_no_reward() -> Weight;
+    fn set_reward_shares() -> Weight;
+    fn set_payee() -> Weight;
+    fn scale_remaining_eras() -> Weight;
+    fn propose_schedule() -> Weight;
+    fn upload_schedule_chunk() -> Weight;
+    fn enact_schedule() -> Weight;
+    fn reset_schedule() -> Weight;
+    fn distribute_fees(n: u32) -> Weight;
 }
 
 /// Production weight implementations (benchmarked)
@@ -23,30 +31,133 @@ pub trait WeightInfo {
 pub struct SubstrateWeight<T>(core::marker::PhantomData<T>);
 
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-    /// Weight for on_initialize when minting rewards
+    /// Weight for on_initialize when minting rewards (or the terminal bonus)
     ///
     /// Components:
     /// - 1 arithmetic operation (era calculation)
     /// - 1 array lookup (reward schedule)
-    /// - 1 FindAuthor call
-    /// - 1 Currency::deposit_creating call
+    /// - 1 FindAuthor call over the block digest
+    /// - up to 3 Currency::deposit_creating calls (author, treasury, pool)
+    /// - 1 RewardShares read
+    /// - 1 Payees read
+    /// - 1 RewardScale, ActiveSchedule and ScheduleChunks read
+    /// - 1 UnmintedRewards and BonusMinted read, 1 UnmintedRewards write
+    /// - 1 TotalMinted / EraMinted update
     /// - 1 event deposit
     fn on_initialize_with_reward() -> Weight {
         // Base weight: ~15ms execution time estimate
         Weight::from_parts(15_000_000, 0)
-            .saturating_add(T::DbWeight::get().reads(1)) // Read author
+            .saturating_add(T::DbWeight::get().reads(1)) // Digest
             .saturating_add(T::DbWeight::get().reads(1)) // Read balance
             .saturating_add(T::DbWeight::get().writes(1)) // Write new balance
+            .saturating_add(T::DbWeight::get().reads_writes(2, 2)) // TotalMinted, EraMinted
+            .saturating_add(T::DbWeight::get().reads_writes(2, 2)) // Treasury, pool balances
+            .saturating_add(T::DbWeight::get().reads(1)) // RewardShares
+            .saturating_add(T::DbWeight::get().reads(1)) // Payees
+            .saturating_add(T::DbWeight::get().reads(3)) // Schedule amendments
+            .saturating_add(T::DbWeight::get().reads_writes(2, 1)) // UnmintedRewards, BonusMinted
     }
 
-    /// Weight for on_initialize when emission has ended
+    /// Weight for on_initialize when no reward is minted
     ///
     /// Components:
     /// - 1 arithmetic operation (era calculation)
     /// - 1 comparison (era vs total_eras)
+    /// - 1 BonusMinted read (after the schedule)
+    /// - 1 RewardScale, ActiveSchedule and ScheduleChunks read and 1
+    ///   UnmintedRewards and TotalMinted read (zero reward during the schedule)
     fn on_initialize_no_reward() -> Weight {
         // Base weight: ~5ms execution time estimate
-        Weight::from_parts(5_000_000, 0)
+        Weight::from_parts(5_000_000, 0).saturating_add(T::DbWeight::get().reads(6))
+    }
+
+    /// Weight for updating the reward split
+    ///
+    /// Components:
+    /// - 1 RewardShares write
+    /// - 1 event deposit
+    fn set_reward_shares() -> Weight {
+        Weight::from_parts(10_000_000, 0).saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Weight for setting or clearing a reward payee
+    ///
+    /// Components:
+    /// - 1 Payees read/write
+    /// - 1 event deposit
+    fn set_payee() -> Weight {
+        Weight::from_parts(10_000_000, 0).saturating_add(T::DbWeight::get().reads_writes(1, 1))
+    }
+
+    /// Weight for scaling the remaining eras
+    ///
+    /// Components:
+    /// - 2 supply projections over all eras
+    /// - up to 2 × MAX_SCHEDULE_CHUNKS ScheduleChunks reads (both projections
+    ///   read the active schedule)
+    /// - 1 RewardScale write
+    fn scale_remaining_eras() -> Weight {
+        Weight::from_parts(50_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(5 + 2 * crate::MAX_SCHEDULE_CHUNKS as u64))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Weight for committing to a replacement schedule
+    ///
+    /// Components:
+    /// - 1 ActiveSchedule read
+    /// - removal of a previous proposal and its chunks
+    /// - 1 ProposedSchedule write
+    fn propose_schedule() -> Weight {
+        Weight::from_parts(20_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(2 + crate::MAX_SCHEDULE_CHUNKS as u64))
+    }
+
+    /// Weight for uploading one schedule chunk
+    ///
+    /// Components:
+    /// - hashing of up to SCHEDULE_CHUNK_ERAS rewards
+    /// - 1 ProposedSchedule read/write
+    /// - 1 ScheduleChunks read/write
+    fn upload_schedule_chunk() -> Weight {
+        Weight::from_parts(20_000_000, 0).saturating_add(T::DbWeight::get().reads_writes(2, 2))
+    }
+
+    /// Weight for enacting the proposed schedule
+    ///
+    /// Components:
+    /// - 2 supply projections over all eras
+    /// - up to 2 × MAX_SCHEDULE_CHUNKS ScheduleChunks reads
+    /// - removal of the previous schedule's chunks
+    fn enact_schedule() -> Weight {
+        Weight::from_parts(50_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(5 + 2 * crate::MAX_SCHEDULE_CHUNKS as u64))
+            .saturating_add(T::DbWeight::get().writes(3 + crate::MAX_SCHEDULE_CHUNKS as u64))
+    }
+
+    /// Weight for dropping all schedule amendments
+    ///
+    /// Components:
+    /// - 2 supply projections over all eras
+    /// - removal of the proposed and active schedules' chunks
+    fn reset_schedule() -> Weight {
+        Weight::from_parts(50_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(5 + crate::MAX_SCHEDULE_CHUNKS as u64))
+            .saturating_add(T::DbWeight::get().writes(3 + 2 * crate::MAX_SCHEDULE_CHUNKS as u64))
+    }
+
+    /// Weight for paying an era's pooled fees to `n` authors
+    ///
+    /// Components:
+    /// - 1 FeeAuthors take, 1 PooledFees read/write
+    /// - n Payees reads and Currency::deposit_creating calls
+    /// - 1 event deposit
+    fn distribute_fees(n: u32) -> Weight {
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(n as u64))
+            .saturating_add(T::DbWeight::get().reads_writes(2, 2))
+            .saturating_add(T::DbWeight::get().reads_writes(2 * n as u64, n as u64))
     }
 }
 
@@ -59,4 +170,37 @@ impl WeightInfo for () {
     fn on_initialize_no_reward() -> Weight {
         Weight::from_parts(5_000_000, 512)
     }
+
+    fn set_reward_shares() -> Weight {
+        Weight::from_parts(10_000_000, 0)
+    }
+
+    fn set_payee() -> Weight {
+        Weight::from_parts(10_000_000, 0)
+    }
+
+    fn scale_remaining_eras() -> Weight {
+        Weight::from_parts(50_000_000, 0)
+    }
+
+    fn propose_schedule() -> Weight {
+        Weight::from_parts(20_000_000, 0)
+    }
+
+    fn upload_schedule_chunk() -> Weight {
+        Weight::from_parts(20_000_000, 0)
+    }
+
+    fn enact_schedule() -> Weight {
+        Weight::from_parts(50_000_000, 0)
+    }
+
+    fn reset_schedule() -> Weight {
+        Weight::from_parts(50_000_000, 0)
+    }
+
+    fn distribute_fees(n: u32) -> Weight {
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(n as u64))
+    }
 }
@@ -6,9 +6,9 @@
 use core::cell::RefCell;
 use frame_support::{
     derive_impl, parameter_types,
-    traits::{ConstU32, ConstU64},
+    traits::{ConstBool, ConstU32, ConstU64},
 };
-use sp_runtime::{traits::IdentityLookup, BuildStorage};
+use sp_runtime::{traits::IdentityLookup, BuildStorage, Perbill};
 
 use crate as pallet_quantum_vault;
 
@@ -65,14 +65,37 @@ parameter_types! {
     pub const TreasuryAccountId: u64 = 99;
     /// Max aggregators for Re-ML (test value)
     pub const MaxAggregators: u32 = 10;
-    /// Expected VKey hash (zeros for testing)
-    pub const ExpectedVKeyHash: [u8; 32] = [0u8; 32];
+    /// Max allowed guest vkeys for Re-ML (test value)
+    pub const MaxVKeys: u32 = 4;
+    /// Re-ML aggregator slashes (test values)
+    pub const LivenessSlash: Perbill = Perbill::from_percent(10);
+    pub const InvalidBatchSlash: Perbill = Perbill::from_percent(100);
 }
 
 impl pallet_reml_verifier::Config for Test {
     type WeightInfo = ();
     type MaxAggregators = MaxAggregators;
-    type ExpectedVKeyHash = ExpectedVKeyHash;
+    type MaxVKeys = MaxVKeys;
+    type AdminOrigin = frame_system::EnsureRoot<u64>;
+    type Currency = Balances;
+    type AggregatorBond = ConstU64<100>;
+    type ProofReward = ConstU64<1>;
+    /// Rewards are paid from the vault treasury, as in the runtime
+    type RewardAccount = TreasuryAccountId;
+    type LivenessPeriod = ConstU64<100>;
+    type LivenessSlash = LivenessSlash;
+    type InvalidBatchSlash = InvalidBatchSlash;
+    type UnbondingPeriod = ConstU64<10>;
+    type RetentionPeriod = ConstU64<100>;
+    type AllocationDeposit = ConstU64<1>;
+    type AllocationExpiry = ConstU64<100>;
+    type ChallengePeriod = ConstU64<10>;
+    type OptimisticBond = ConstU64<50>;
+    type DataChallengeDeposit = ConstU64<5>;
+    type DataResponsePeriod = ConstU64<5>;
+    /// Fraud proofs are checked with the vault's Dilithium verifier
+    type SignatureVerifier = QuantumVault;
+    type OptimisticBatchesEnabled = ConstBool<true>;
 }
 
 impl pallet_quantum_vault::Config for Test {
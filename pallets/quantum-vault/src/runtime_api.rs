@@ -0,0 +1,60 @@
+//! Runtime API for pallet-quantum-vault
+//!
+//! Exposes vault state and the exact signing payloads so that wallets do not
+//! have to decode raw storage or re-implement the message construction.
+//! Served over RPC by the node under the `vault_*` namespace.
+
+use alloc::vec::Vec;
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+
+/// Snapshot of a single vault
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct VaultStatus {
+    /// Nonce the next vault signature must commit to
+    pub nonce: u64,
+    /// blake2_256 of the registered Dilithium public key
+    pub public_key_hash: [u8; 32],
+}
+
+/// Fees charged by vault operations
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct VaultFeeQuote<Balance> {
+    /// One-off fee for `create_vault`
+    pub creation_fee: Balance,
+    /// Premium per `vault_transfer` (VaultTransferBaseFee × VaultTransferFeeMultiplier)
+    pub transfer_premium: Balance,
+}
+
+sp_api::decl_runtime_apis! {
+    /// Read-only access to quantum vault state
+    pub trait QuantumVaultApi<AccountId, Balance>
+    where
+        AccountId: Codec,
+        Balance: Codec,
+    {
+        /// Vault status of `who`, `None` if the account is not a vault
+        fn vault_status(who: AccountId) -> Option<VaultStatus>;
+
+        /// Current nonce of the vault, `None` if the account is not a vault
+        fn vault_nonce(who: AccountId) -> Option<u64>;
+
+        /// blake2_256 hash of the vault public key
+        fn vault_key_hash(who: AccountId) -> Option<[u8; 32]>;
+
+        /// Number of active vaults
+        fn total_vaults() -> u32;
+
+        /// Cumulative fees sent to the treasury by vault operations
+        fn total_fees_collected() -> Balance;
+
+        /// Fee quote for vault creation and transfers
+        fn fee_quote() -> VaultFeeQuote<Balance>;
+
+        /// Bytes that must be Dilithium-signed for `vault_transfer` at the current nonce
+        fn transfer_payload(from: AccountId, to: AccountId, amount: Balance) -> Option<Vec<u8>>;
+
+        /// Bytes that must be Dilithium-signed for `destroy_vault` at the current nonce
+        fn destroy_payload(who: AccountId) -> Option<Vec<u8>>;
+    }
+}
@@ -169,6 +169,59 @@ fn vault_transfer_works_with_real_signature() {
     });
 }
 
+#[test]
+fn vault_transfer_consumes_reml_request() {
+    new_test_ext().execute_with(|| {
+        let alice = 1;
+        let bob = 2;
+        assert_ok!(QuantumVault::create_vault(
+            RuntimeOrigin::signed(alice),
+            mock_public_key()
+        ));
+        pallet_reml_verifier::VerifiedRequests::<Test>::insert(7, (1, 1));
+
+        let signature = create_transfer_signature(alice, bob, 100, 0);
+        assert_ok!(QuantumVault::vault_transfer(
+            RuntimeOrigin::signed(alice),
+            signature,
+            bob,
+            100,
+            Some(7)
+        ));
+
+        let consumed = pallet_reml_verifier::ConsumedRequests::<Test>::get(7).unwrap();
+        assert_eq!(consumed.consumer, alice);
+        assert_eq!(
+            consumed.call,
+            sp_core::blake2_256(&QuantumVault::construct_transfer_message(
+                &alice, &bob, 100, 0
+            ))
+        );
+
+        // The request cannot authorize a second transfer
+        assert_noop!(
+            QuantumVault::vault_transfer(
+                RuntimeOrigin::signed(alice),
+                create_transfer_signature(alice, bob, 100, 1),
+                bob,
+                100,
+                Some(7)
+            ),
+            pallet_reml_verifier::Error::<Test>::RequestAlreadyConsumed
+        );
+        assert_noop!(
+            QuantumVault::vault_transfer(
+                RuntimeOrigin::signed(alice),
+                create_transfer_signature(alice, bob, 100, 1),
+                bob,
+                100,
+                Some(8)
+            ),
+            Error::<Test>::RequestNotVerified
+        );
+    });
+}
+
 #[test]
 fn vault_transfer_fails_for_non_vault() {
     new_test_ext().execute_with(|| {
@@ -473,6 +526,79 @@ fn can_transfer_works() {
     });
 }
 
+#[test]
+fn vault_status_reflects_storage() {
+    new_test_ext().execute_with(|| {
+        let alice = 1;
+        let bob = 2;
+        let public_key = mock_public_key();
+
+        // Not a vault yet
+        assert_eq!(QuantumVault::vault_status(&alice), None);
+        assert_eq!(QuantumVault::public_key_hash(&alice), None);
+
+        assert_ok!(QuantumVault::create_vault(
+            RuntimeOrigin::signed(alice),
+            public_key.clone()
+        ));
+
+        let status = QuantumVault::vault_status(&alice).unwrap();
+        assert_eq!(status.nonce, 0);
+        assert_eq!(status.public_key_hash, sp_core::blake2_256(&public_key));
+
+        // Nonce advances after a transfer
+        let signature = create_transfer_signature(alice, bob, 100, 0);
+        assert_ok!(QuantumVault::vault_transfer(
+            RuntimeOrigin::signed(alice),
+            signature,
+            bob,
+            100,
+            None
+        ));
+        assert_eq!(QuantumVault::vault_status(&alice).unwrap().nonce, 1);
+    });
+}
+
+#[test]
+fn transfer_premium_is_base_fee_times_multiplier() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(QuantumVault::transfer_premium(), PREMIUM_FEE);
+    });
+}
+
+#[test]
+fn signing_payloads_match_signed_messages() {
+    new_test_ext().execute_with(|| {
+        let alice = 1;
+        let bob = 2;
+
+        assert_ok!(QuantumVault::create_vault(
+            RuntimeOrigin::signed(alice),
+            mock_public_key()
+        ));
+
+        // Signing the exposed transfer payload must produce an accepted signature
+        let payload = QuantumVault::construct_transfer_message(&alice, &bob, 100, 0);
+        let signature = alice_keypair().sign(&payload).to_vec();
+        assert_ok!(QuantumVault::vault_transfer(
+            RuntimeOrigin::signed(alice),
+            signature,
+            bob,
+            100,
+            None
+        ));
+
+        // Same for the destroy payload at the advanced nonce
+        let payload = QuantumVault::construct_destroy_message(&alice, 1);
+        let signature = alice_keypair().sign(&payload).to_vec();
+        assert_ok!(QuantumVault::destroy_vault(
+            RuntimeOrigin::signed(alice),
+            signature
+        ));
+        assert!(!QuantumVault::is_vault(&alice));
+    });
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // MULTI-TRANSFER AND NONCE TESTS
 // ═══════════════════════════════════════════════════════════════════════════
@@ -595,6 +721,44 @@ fn signature_verification_is_strict() {
     });
 }
 
+#[test]
+fn wasm_structural_check_accepts_forged_signatures() {
+    use crate::{Pallet, DILITHIUM_PUBLIC_KEY_SIZE, DILITHIUM_SIGNATURE_SIZE};
+    use pallet_reml_verifier::MlDsaVerifier;
+
+    new_test_ext().execute_with(|| {
+        let public_key: [u8; DILITHIUM_PUBLIC_KEY_SIZE] = mock_public_key().try_into().unwrap();
+        let message = b"TESSERAX_REML_REQUEST";
+        let signature: [u8; DILITHIUM_SIGNATURE_SIZE] = alice_keypair().sign(message);
+        let check = |public_key: &[u8; DILITHIUM_PUBLIC_KEY_SIZE],
+                     signature: &[u8; DILITHIUM_SIGNATURE_SIZE]| {
+            Pallet::<Test>::check_dilithium_structure(public_key, message, signature)
+        };
+
+        assert_ok!(check(&public_key, &signature));
+
+        // A forged signature is rejected natively but passes the Wasm check,
+        // which is why the runtime keeps Re-ML optimistic batches disabled
+        let forged = [0x5A; DILITHIUM_SIGNATURE_SIZE];
+        assert!(!QuantumVault::verify(&public_key, message, &forged));
+        assert_ok!(check(&public_key, &forged));
+
+        // Only all-zero inputs and challenge seeds are caught
+        let mut zero_seed = forged;
+        zero_seed[..32].fill(0);
+        for (public_key, signature) in [
+            (&public_key, &[0u8; DILITHIUM_SIGNATURE_SIZE]),
+            (&[0u8; DILITHIUM_PUBLIC_KEY_SIZE], &forged),
+            (&public_key, &zero_seed),
+        ] {
+            assert_eq!(
+                check(public_key, signature),
+                Err(Error::<Test>::SignatureVerificationFailed)
+            );
+        }
+    });
+}
+
 #[test]
 fn different_users_have_different_keypairs() {
     new_test_ext().execute_with(|| {
@@ -1,7 +1,10 @@
 //! Weight information for pallet-quantum-vault
 //!
 //! These weights are used to calculate the transaction fee for each extrinsic.
-//! In production, these should be generated using frame-benchmarking.
+//! Regenerate with `benchmark pallet --pallet pallet_quantum_vault` on a
+//! runtime built with `runtime-benchmarks`. The benchmarks sign real vault
+//! messages, so the measured weights include Dilithium verification as it
+//! runs in Wasm.
 
 use frame_support::pallet_prelude::Get;
 use frame_support::weights::Weight;
@@ -53,10 +56,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     /// - Signature verification (expensive)
     /// - Balance transfer
     /// - Storage write for nonce update
+    /// - Re-ML request verification and consumption, if any
     fn vault_transfer() -> Weight {
         Weight::from_parts(150_000_000, 0)
-            .saturating_add(T::DbWeight::get().reads(3))
-            .saturating_add(T::DbWeight::get().writes(3))
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().writes(4))
     }
 }
 
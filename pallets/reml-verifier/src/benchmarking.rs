@@ -0,0 +1,734 @@
+//! Benchmarking setup for pallet-reml-verifier
+//!
+//! These benchmarks measure every extrinsic on worst-case inputs:
+//! - `submit_proof`: Verify a `p`-byte proof settling `n` requests. The proof
+//!   matches neither the vkey nor the public values hash, so both scans run
+//!   over the whole proof, and the proof reward is paid. The SP1 check is
+//!   structural, so the weight adds a provisional verification margin
+//! - `report_invalid_batch`: Revoke a batch of `n` requests and slash its
+//!   bonded aggregator
+//! - `submit_optimistic_batch`: Post an optimistic batch of `n` requests
+//! - `challenge_optimistic_batch`: Prove fraud with the last request of a
+//!   pending batch of `n`, releasing all of them and refunding a data
+//!   challenge on each. The signature is rejected by `SignatureVerifier`;
+//!   how early depends on the configured verifier
+//! - `finalize_optimistic_batch`: Settle a pending batch of `n` requests and
+//!   pay the proof reward
+//! - `challenge_batch_data`: Demand the data of the last request of a full
+//!   pending batch
+//! - `respond_batch_data`: Reveal forged data for a pending batch of `n`
+//!   requests whose data was all demanded, discarding it
+//! - `claim_data_challenge`: Void a pending batch of `n` requests whose data
+//!   was all demanded and withheld
+//! - `submit_aggregated_proof`: Verify a `p`-byte aggregated proof settling
+//!   `b` batches of `n` requests in total, its public values hash found last,
+//!   with the same provisional margin
+//! - aggregator and vkey administration, bonding, heartbeats and liveness
+//!   slashing
+//!
+//! and the `on_idle` pruning steps on worst-case state:
+//! - `prune_cursor`: Load the cursor with nothing expired
+//! - `prune_batch`: Prune an expired batch holding `MAX_VERIFIED_REQUESTS`
+//!   leaves (requests already pruned)
+//! - `prune_request`: Prune one request of an expired batch
+//! - `consume_verified_request`: Use up a verified request
+//! - `allocate_request_id`: Allocate the next request ID
+//! - `prune_allocation`: Drop an allocation that expired unsettled
+//! - `prune_tombstone`: Lift the expired tombstone of a pruned batch ID
+//!
+//! Each pruned batch frees the entries its `submit_proof` wrote, so the
+//! verification state is bounded by the batches accepted within one
+//! `RetentionPeriod` (see `verification_state_is_bounded_by_retention`).
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as ReMLVerifier;
+use alloc::vec::Vec;
+use frame_benchmarking::v2::*;
+use frame_support::{
+    traits::{ConstU32, Currency, EnsureOrigin, Get},
+    weights::Weight,
+    BoundedVec,
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use sp_core::H256;
+use sp_runtime::traits::{Saturating, Zero};
+
+/// Guest vkey allowed by `allow_vkey`
+const BENCH_VKEY: [u8; 32] = [0xBE; 32];
+
+/// Origin passing `AdminOrigin`
+fn admin_origin<T: Config>() -> Result<T::RuntimeOrigin, BenchmarkError> {
+    T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)
+}
+
+/// Give `who` `amount` on top of the existential deposit
+fn fund<T: Config>(who: &T::AccountId, amount: BalanceOf<T>) {
+    let _ = T::Currency::make_free_balance_be(
+        who,
+        T::Currency::minimum_balance().saturating_add(amount),
+    );
+}
+
+/// Bond `who` as an aggregator
+fn bond<T: Config>(who: &T::AccountId) -> Result<(), BenchmarkError> {
+    fund::<T>(who, T::AggregatorBond::get());
+    ReMLVerifier::<T>::bond_aggregator(RawOrigin::Signed(who.clone()).into())?;
+    Ok(())
+}
+
+/// Allow `BENCH_VKEY` from genesis
+fn allow_vkey<T: Config>() {
+    AllowedVKeys::<T>::insert(
+        BENCH_VKEY,
+        VKeyInfo {
+            version: 1,
+            active_from: Zero::zero(),
+            expires_at: None,
+        },
+    );
+}
+
+/// Submission of `n` freshly allocated requests with a `p`-byte proof
+///
+/// The proof passes the structural checks without containing the vkey or
+/// public values hash, so `verify_sp1_proof` scans all of it. Each request
+/// has its own owner, whose deposit is returned on settlement.
+fn worst_case_submission<T: Config>(batch_id: u64, p: u32, n: u32) -> ProofSubmission {
+    let request_ids: BoundedVec<u64, ConstU32<MAX_VERIFIED_REQUESTS>> = (0..n)
+        .map(|i| {
+            let owner: T::AccountId = account("owner", batch_id as u32, i);
+            fund::<T>(&owner, T::AllocationDeposit::get());
+            ReMLVerifier::<T>::do_allocate_request_id(&owner).expect("the owner covers the deposit")
+        })
+        .collect::<Vec<_>>()
+        .try_into()
+        .expect("n is at most MAX_VERIFIED_REQUESTS");
+    let proof = (0..p)
+        .map(|i| (i % 251) as u8)
+        .collect::<Vec<_>>()
+        .try_into()
+        .expect("p is at most MAX_PROOF_SIZE");
+
+    ProofSubmission {
+        batch_id,
+        proof,
+        public_values: PublicValues {
+            version: REML_VERSION,
+            chain_id: TESSERAX_CHAIN_ID,
+            batch_id,
+            verified_count: n,
+            requests_root: ReMLVerifier::<T>::compute_merkle_root(&request_ids),
+            verified_request_ids: request_ids,
+            rejected_request_ids: Default::default(),
+        },
+        vkey_hash: BENCH_VKEY,
+    }
+}
+
+/// Signature data `(message, public_key, signature)` of `request_id`, sized
+/// as Dilithium2 with a signature no verifier accepts
+fn forged_signature_data(request_id: u64) -> ([u8; 32], Vec<u8>, Vec<u8>) {
+    (
+        sp_core::blake2_256(&request_id.to_le_bytes()),
+        alloc::vec![0x11; 1312],
+        alloc::vec![0u8; 2420],
+    )
+}
+
+/// Optimistic submission of `n` freshly allocated requests, each committing
+/// to `forged_signature_data`
+fn optimistic_submission<T: Config>(batch_id: u64, n: u32) -> OptimisticSubmission {
+    let public_values =
+        worst_case_submission::<T>(batch_id, MIN_PROOF_SIZE as u32, n).public_values;
+    let request_commitments = public_values
+        .verified_request_ids
+        .iter()
+        .map(|id| {
+            let (message, public_key, signature) = forged_signature_data(*id);
+            ReMLVerifier::<T>::request_commitment(*id, &message, &public_key, &signature)
+        })
+        .collect::<Vec<_>>()
+        .try_into()
+        .expect("n is at most MAX_VERIFIED_REQUESTS");
+
+    OptimisticSubmission {
+        batch_id,
+        public_values,
+        request_commitments,
+    }
+}
+
+/// Aggregated submission of batches `1..=b` with a `p`-byte proof, spreading
+/// `n` freshly allocated requests over them (`n >= b`)
+///
+/// Both guests use `BENCH_VKEY`. The proof ends with the exact public values
+/// hash, so `verify_aggregated_proof` scans all of it before accepting.
+fn worst_case_aggregated_submission<T: Config>(
+    p: u32,
+    b: u32,
+    n: u32,
+) -> AggregatedProofSubmission {
+    let batches: BoundedVec<PublicValues, ConstU32<MAX_AGGREGATED_BATCHES>> = (1..=b)
+        .map(|batch_id| {
+            // The last batch takes the remainder
+            let count = if batch_id == b {
+                n - (b - 1) * (n / b)
+            } else {
+                n / b
+            };
+            worst_case_submission::<T>(batch_id as u64, MIN_PROOF_SIZE as u32, count).public_values
+        })
+        .collect::<Vec<_>>()
+        .try_into()
+        .expect("b is at most MAX_AGGREGATED_BATCHES");
+    let public_values = AggregatedPublicValues {
+        version: REML_VERSION,
+        chain_id: TESSERAX_CHAIN_ID,
+        batch_vkey_hash: BENCH_VKEY,
+        batches_root: ReMLVerifier::<T>::compute_batches_root(&batches),
+        batches,
+    };
+    let public_hash =
+        ReMLVerifier::<T>::compute_aggregated_public_values_hash(&BENCH_VKEY, &public_values);
+    let proof = (0..p - 32)
+        .map(|i| (i % 251) as u8)
+        .chain(public_hash)
+        .collect::<Vec<_>>()
+        .try_into()
+        .expect("p is at most MAX_PROOF_SIZE");
+
+    AggregatedProofSubmission {
+        proof,
+        public_values,
+        vkey_hash: BENCH_VKEY,
+    }
+}
+
+/// Bond a funded aggregator and post optimistic batch 1 of `n` requests
+fn submit_pending_batch<T: Config>(n: u32) -> Result<OptimisticSubmission, BenchmarkError> {
+    let aggregator: T::AccountId = account("aggregator", 0, 0);
+    // Bonded, so that proving fraud also slashes the aggregator bond
+    bond::<T>(&aggregator)?;
+    fund::<T>(&aggregator, T::OptimisticBond::get());
+    let submission = optimistic_submission::<T>(1, n);
+    ReMLVerifier::<T>::submit_optimistic_batch(
+        RawOrigin::Signed(aggregator).into(),
+        submission.clone(),
+    )?;
+    Ok(submission)
+}
+
+/// Demand the signature data of `request_ids` in pending batch 1, each from
+/// its own funded challenger
+fn challenge_data<T: Config>(request_ids: &[u64]) -> Result<(), BenchmarkError> {
+    for (i, request_id) in request_ids.iter().enumerate() {
+        let challenger: T::AccountId = account("challenger", i as u32, 0);
+        fund::<T>(&challenger, T::DataChallengeDeposit::get());
+        ReMLVerifier::<T>::challenge_batch_data(
+            RawOrigin::Signed(challenger).into(),
+            1,
+            *request_id,
+        )?;
+    }
+    Ok(())
+}
+
+/// Store and queue batch `batch_id` of `count` allocated requests, verified at
+/// block zero
+fn insert_expired_batch<T: Config>(batch_id: u64, count: u32) -> Vec<u64> {
+    let verified_at = BlockNumberFor::<T>::zero();
+    let proof_commitment = sp_core::blake2_256(&batch_id.to_le_bytes());
+    let first = batch_id * MAX_VERIFIED_REQUESTS as u64;
+    let request_ids: BoundedVec<u64, ConstU32<MAX_VERIFIED_REQUESTS>> = (first
+        ..first + count as u64)
+        .collect::<Vec<_>>()
+        .try_into()
+        .expect("count fits MAX_VERIFIED_REQUESTS");
+
+    let owner: T::AccountId = account("owner", 0, 0);
+    for request_id in request_ids.iter() {
+        RequestOwners::<T>::insert(
+            request_id,
+            RequestAllocation {
+                owner: owner.clone(),
+                deposit: Zero::zero(),
+                expires_at: verified_at.saturating_add(T::AllocationExpiry::get()),
+            },
+        );
+        VerifiedRequests::<T>::insert(request_id, (batch_id, verified_at));
+    }
+    VerifiedBatches::<T>::insert(
+        batch_id,
+        BatchInfo {
+            aggregator: account("aggregator", 0, 0),
+            verified_at,
+            signature_count: count,
+            requests_root: ReMLVerifier::<T>::compute_merkle_root(&request_ids),
+            proof_commitment,
+            vkey_hash: [7u8; 32],
+        },
+    );
+    BatchRequestIds::<T>::insert(batch_id, request_ids.clone());
+    ProofCommitments::<T>::insert(H256::from(proof_commitment), verified_at);
+    PruneCursor::<T>::mutate(|cursor| {
+        BatchQueue::<T>::insert(
+            cursor.tail,
+            QueuedBatch {
+                batch_id,
+                verified_at,
+                proof_commitment,
+            },
+        );
+        cursor.tail += 1;
+    });
+
+    request_ids.into_inner()
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn register_aggregator() -> Result<(), BenchmarkError> {
+        let origin = admin_origin::<T>()?;
+        let aggregator: T::AccountId = account("aggregator", 0, 0);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, aggregator.clone());
+
+        assert!(ReMLVerifier::<T>::is_aggregator(&aggregator));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn deactivate_aggregator() -> Result<(), BenchmarkError> {
+        let aggregator: T::AccountId = account("aggregator", 0, 0);
+        ReMLVerifier::<T>::register_aggregator(admin_origin::<T>()?, aggregator.clone())?;
+        let origin = admin_origin::<T>()?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, aggregator.clone());
+
+        assert!(!ReMLVerifier::<T>::is_aggregator(&aggregator));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn submit_proof(
+        p: Linear<{ MIN_PROOF_SIZE as u32 }, MAX_PROOF_SIZE>,
+        n: Linear<1, MAX_VERIFIED_REQUESTS>,
+    ) -> Result<(), BenchmarkError> {
+        let aggregator: T::AccountId = whitelisted_caller();
+        fund::<T>(&aggregator, Zero::zero());
+        ReMLVerifier::<T>::register_aggregator(admin_origin::<T>()?, aggregator.clone())?;
+        allow_vkey::<T>();
+        // Worst case pays the proof reward
+        fund::<T>(&T::RewardAccount::get(), T::ProofReward::get());
+        let submission = worst_case_submission::<T>(1, p, n);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(aggregator), submission);
+
+        assert!(VerifiedBatches::<T>::contains_key(1));
+        assert_eq!(
+            BatchRequestIds::<T>::get(1).map(|ids| ids.len() as u32),
+            Some(n)
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn add_vkey() -> Result<(), BenchmarkError> {
+        let origin = admin_origin::<T>()?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, BENCH_VKEY, 1, None);
+
+        assert!(AllowedVKeys::<T>::contains_key(BENCH_VKEY));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn retire_vkey() -> Result<(), BenchmarkError> {
+        allow_vkey::<T>();
+        let origin = admin_origin::<T>()?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, BENCH_VKEY, None);
+
+        assert!(!AllowedVKeys::<T>::contains_key(BENCH_VKEY));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn bond_aggregator() {
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller, T::AggregatorBond::get());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()));
+
+        assert!(ReMLVerifier::<T>::is_aggregator(&caller));
+    }
+
+    #[benchmark]
+    fn unbond_aggregator() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        bond::<T>(&caller)?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()));
+
+        assert!(Aggregators::<T>::get(&caller).is_some_and(|info| info.unbonding_until.is_some()));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn withdraw_bond() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        bond::<T>(&caller)?;
+        ReMLVerifier::<T>::unbond_aggregator(RawOrigin::Signed(caller.clone()).into())?;
+        frame_system::Pallet::<T>::set_block_number(
+            frame_system::Pallet::<T>::block_number().saturating_add(T::UnbondingPeriod::get()),
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()));
+
+        assert!(!Aggregators::<T>::contains_key(&caller));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn report_invalid_batch(n: Linear<1, MAX_VERIFIED_REQUESTS>) -> Result<(), BenchmarkError> {
+        let aggregator: T::AccountId = account("aggregator", 0, 0);
+        bond::<T>(&aggregator)?;
+        allow_vkey::<T>();
+        ReMLVerifier::<T>::submit_proof(
+            RawOrigin::Signed(aggregator.clone()).into(),
+            worst_case_submission::<T>(1, MIN_PROOF_SIZE as u32, n),
+        )?;
+        let origin = admin_origin::<T>()?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, 1);
+
+        assert!(!VerifiedBatches::<T>::contains_key(1));
+        assert!(!ReMLVerifier::<T>::is_aggregator(&aggregator));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn report_missed_liveness() -> Result<(), BenchmarkError> {
+        let aggregator: T::AccountId = account("aggregator", 0, 0);
+        bond::<T>(&aggregator)?;
+        frame_system::Pallet::<T>::set_block_number(
+            frame_system::Pallet::<T>::block_number()
+                .saturating_add(T::LivenessPeriod::get())
+                .saturating_add(1u32.into()),
+        );
+        let caller: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), aggregator.clone());
+
+        assert!(!ReMLVerifier::<T>::is_aggregator(&aggregator));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn heartbeat() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        bond::<T>(&caller)?;
+        let now =
+            frame_system::Pallet::<T>::block_number().saturating_add(T::LivenessPeriod::get());
+        frame_system::Pallet::<T>::set_block_number(now);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()));
+
+        assert_eq!(
+            Aggregators::<T>::get(&caller).map(|info| info.last_active),
+            Some(now)
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn submit_optimistic_batch(n: Linear<1, MAX_VERIFIED_REQUESTS>) -> Result<(), BenchmarkError> {
+        let aggregator: T::AccountId = whitelisted_caller();
+        fund::<T>(&aggregator, T::OptimisticBond::get());
+        ReMLVerifier::<T>::register_aggregator(admin_origin::<T>()?, aggregator.clone())?;
+        let submission = optimistic_submission::<T>(1, n);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(aggregator), submission);
+
+        assert!(PendingBatches::<T>::contains_key(1));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn challenge_optimistic_batch(
+        n: Linear<1, MAX_VERIFIED_REQUESTS>,
+    ) -> Result<(), BenchmarkError> {
+        let submission = submit_pending_batch::<T>(n)?;
+        // The last request is found after scanning the whole batch
+        let request_id = *submission
+            .public_values
+            .verified_request_ids
+            .last()
+            .expect("n is at least one");
+        // Every data challenge deposit is returned
+        challenge_data::<T>(&submission.public_values.verified_request_ids)?;
+        let (message, public_key, signature) = forged_signature_data(request_id);
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller, Zero::zero());
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller),
+            1,
+            request_id,
+            message,
+            public_key,
+            signature,
+        );
+
+        assert!(!PendingBatches::<T>::contains_key(1));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn finalize_optimistic_batch(
+        n: Linear<1, MAX_VERIFIED_REQUESTS>,
+    ) -> Result<(), BenchmarkError> {
+        submit_pending_batch::<T>(n)?;
+        frame_system::Pallet::<T>::set_block_number(
+            frame_system::Pallet::<T>::block_number().saturating_add(T::ChallengePeriod::get()),
+        );
+        // Worst case pays the proof reward
+        fund::<T>(&T::RewardAccount::get(), T::ProofReward::get());
+        let caller: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), 1);
+
+        assert!(VerifiedBatches::<T>::contains_key(1));
+        assert_eq!(
+            BatchRequestIds::<T>::get(1).map(|ids| ids.len() as u32),
+            Some(n)
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn challenge_batch_data() -> Result<(), BenchmarkError> {
+        let submission = submit_pending_batch::<T>(MAX_VERIFIED_REQUESTS)?;
+        // The last request is found after scanning the whole batch
+        let request_id = *submission
+            .public_values
+            .verified_request_ids
+            .last()
+            .expect("the batch is full");
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller, T::DataChallengeDeposit::get());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), 1, request_id);
+
+        assert!(DataChallenges::<T>::contains_key(1, request_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn respond_batch_data(n: Linear<1, MAX_VERIFIED_REQUESTS>) -> Result<(), BenchmarkError> {
+        let submission = submit_pending_batch::<T>(n)?;
+        let request_ids = &submission.public_values.verified_request_ids;
+        let request_id = *request_ids.last().expect("n is at least one");
+        challenge_data::<T>(request_ids)?;
+        // Worst case reveals a forged signature, discarding the batch
+        let (message, public_key, signature) = forged_signature_data(request_id);
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller, Zero::zero());
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller),
+            1,
+            request_id,
+            message,
+            public_key,
+            signature,
+        );
+
+        assert!(!PendingBatches::<T>::contains_key(1));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn claim_data_challenge(n: Linear<1, MAX_VERIFIED_REQUESTS>) -> Result<(), BenchmarkError> {
+        let submission = submit_pending_batch::<T>(n)?;
+        let request_ids = &submission.public_values.verified_request_ids;
+        let request_id = *request_ids.last().expect("n is at least one");
+        challenge_data::<T>(request_ids)?;
+        frame_system::Pallet::<T>::set_block_number(
+            frame_system::Pallet::<T>::block_number().saturating_add(T::DataResponsePeriod::get()),
+        );
+        let caller: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), 1, request_id);
+
+        assert!(!PendingBatches::<T>::contains_key(1));
+        assert!(FraudProvenAt::<T>::contains_key(account::<T::AccountId>(
+            "aggregator",
+            0,
+            0
+        )));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn submit_aggregated_proof(
+        p: Linear<{ MIN_PROOF_SIZE as u32 }, MAX_PROOF_SIZE>,
+        b: Linear<1, MAX_AGGREGATED_BATCHES>,
+        n: Linear<MAX_AGGREGATED_BATCHES, MAX_VERIFIED_REQUESTS>,
+    ) -> Result<(), BenchmarkError> {
+        let aggregator: T::AccountId = whitelisted_caller();
+        fund::<T>(&aggregator, Zero::zero());
+        ReMLVerifier::<T>::register_aggregator(admin_origin::<T>()?, aggregator.clone())?;
+        allow_vkey::<T>();
+        let submission = worst_case_aggregated_submission::<T>(p, b, n);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(aggregator), submission);
+
+        assert!((1..=b as u64).all(|batch_id| VerifiedBatches::<T>::contains_key(batch_id)));
+        assert_eq!(TotalProofsVerified::<T>::get(), b as u64);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn prune_cursor() {
+        let now = T::RetentionPeriod::get();
+
+        #[block]
+        {
+            ReMLVerifier::<T>::prune_expired(now, Weight::MAX);
+        }
+
+        assert_eq!(PruneCursor::<T>::get().head, 0);
+    }
+
+    #[benchmark]
+    fn prune_batch() {
+        insert_expired_batch::<T>(1, MAX_VERIFIED_REQUESTS);
+        // Requests were pruned by earlier calls, only the batch is left
+        PruneCursor::<T>::mutate(|cursor| cursor.request_index = MAX_VERIFIED_REQUESTS);
+        let now = T::RetentionPeriod::get();
+
+        #[block]
+        {
+            ReMLVerifier::<T>::prune_expired(now, Weight::MAX);
+        }
+
+        assert!(!VerifiedBatches::<T>::contains_key(1));
+        assert_eq!(PruneCursor::<T>::get().head, 1);
+    }
+
+    #[benchmark]
+    fn prune_request() -> Result<(), BenchmarkError> {
+        let request_id = insert_expired_batch::<T>(1, 1)[0];
+        // Worst case also drops the consumption record
+        ReMLVerifier::<T>::consume_verified_request(
+            request_id,
+            &account("consumer", 0, 0),
+            [1u8; 32],
+        )?;
+
+        #[block]
+        {
+            ReMLVerifier::<T>::prune_request(request_id, 1);
+        }
+
+        assert!(!VerifiedRequests::<T>::contains_key(request_id));
+        assert!(!RequestOwners::<T>::contains_key(request_id));
+        assert!(!ConsumedRequests::<T>::contains_key(request_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn consume_verified_request() -> Result<(), BenchmarkError> {
+        let request_id = insert_expired_batch::<T>(1, 1)[0];
+        let consumer: T::AccountId = account("consumer", 0, 0);
+
+        #[block]
+        {
+            ReMLVerifier::<T>::consume_verified_request(request_id, &consumer, [1u8; 32])?;
+        }
+
+        assert!(ConsumedRequests::<T>::contains_key(request_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn allocate_request_id() {
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller, T::AllocationDeposit::get());
+        let request_id = NextRequestId::<T>::get();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()));
+
+        assert_eq!(
+            RequestOwners::<T>::get(request_id).map(|allocation| allocation.owner),
+            Some(caller)
+        );
+        assert_eq!(NextRequestId::<T>::get(), request_id + 1);
+    }
+
+    #[benchmark]
+    fn prune_allocation() -> Result<(), BenchmarkError> {
+        let owner: T::AccountId = account("owner", 0, 0);
+        fund::<T>(&owner, T::AllocationDeposit::get());
+        let request_id = ReMLVerifier::<T>::do_allocate_request_id(&owner)?;
+        AllocationCursor::<T>::put(request_id);
+        let now =
+            frame_system::Pallet::<T>::block_number().saturating_add(T::AllocationExpiry::get());
+        // Only the one allocation step fits
+        let limit = T::WeightInfo::prune_cursor().saturating_add(T::WeightInfo::prune_allocation());
+
+        #[block]
+        {
+            ReMLVerifier::<T>::prune_expired_allocations(now, limit);
+        }
+
+        assert!(!RequestOwners::<T>::contains_key(request_id));
+        assert_eq!(AllocationCursor::<T>::get(), request_id + 1);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn prune_tombstone() {
+        insert_expired_batch::<T>(1, 1);
+        let retention = T::RetentionPeriod::get();
+        ReMLVerifier::<T>::prune_expired(retention, Weight::MAX);
+        let now = retention.saturating_add(retention);
+        // Only the one tombstone step fits
+        let limit = T::WeightInfo::prune_cursor().saturating_add(T::WeightInfo::prune_tombstone());
+
+        #[block]
+        {
+            ReMLVerifier::<T>::prune_expired(now, limit);
+        }
+
+        assert!(!PrunedBatches::<T>::contains_key(1));
+        assert_eq!(PruneCursor::<T>::get().tombstone_head, 1);
+    }
+
+    impl_benchmark_test_suite!(ReMLVerifier, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -8,17 +8,139 @@
 //!
 //! The security of this pallet relies on:
 //! 1. **SP1 STARK Soundness**: The proof system guarantees computational integrity
-//! 2. **VKey Binding**: Proofs are tied to a specific verification key
+//! 2. **VKey Binding**: Proofs are tied to a governance-allowed verification key
 //! 3. **Public Output Commitment**: The proof commits to verified request IDs
 //!
 //! ## Verification Flow
 //!
 //! 1. Aggregator submits proof with claimed outputs
 //! 2. Pallet verifies:
-//!    - VKey hash matches expected (program integrity)
+//!    - VKey hash is allowed and active (program integrity)
 //!    - Proof structure is valid
 //!    - Public outputs are correctly committed
-//! 3. On success, request IDs are marked as verified
+//! 3. On success, request IDs are marked as verified and the batch records
+//!    the vkey it was proven under
+//!
+//! ## Guest Upgrades
+//!
+//! `AdminOrigin` maintains `AllowedVKeys`, one entry per guest program
+//! version with an activation block and an optional expiry block. To roll out
+//! a new guest, add its vkey (optionally activating at a future block) and
+//! schedule the old vkey's expiry with `retire_vkey`; both are accepted in the
+//! overlap, so provers can switch over without rejected batches.
+//!
+//! ## Aggregator Economics
+//!
+//! Anyone can become an aggregator with `bond_aggregator`, reserving
+//! `AggregatorBond`; `AdminOrigin` can still register trusted aggregators
+//! without a bond. The set is capped at `MaxAggregators`.
+//!
+//! - **Rewards**: every accepted proof pays `ProofReward` from `RewardAccount`
+//!   (the vault fee treasury in the runtime). The reward is skipped while the
+//!   account cannot cover it.
+//! - **Slashing**: `AdminOrigin` can invalidate a batch shown to be wrong,
+//!   revoking its requests and slashing `InvalidBatchSlash` of the bond; a
+//!   fraud proof against an optimistic batch slashes the same. Anyone
+//!   can report an active aggregator that has neither had a proof accepted nor
+//!   sent a `heartbeat` for `LivenessPeriod` blocks, slashing `LivenessSlash`,
+//!   so an aggregator without requests to settle stays live by heartbeating.
+//!   Slashed aggregators are deactivated and slashed funds go to
+//!   `RewardAccount`.
+//! - **Exit**: `unbond_aggregator` stops submissions and `withdraw_bond`
+//!   releases the bond after `UnbondingPeriod`, so it stays slashable for
+//!   batches submitted shortly before leaving.
+//!
+//! ## Request IDs
+//!
+//! Request IDs are allocated on-chain from a monotonic counter with
+//! `allocate_request_id` (or the `0x24` precompile), which records the
+//! allocating account in `RequestOwners`. `submit_proof` rejects batches with
+//! IDs that were never allocated, are already verified or consumed, or appear
+//! twice, so an ID is settled by exactly one batch and never overwritten.
+//!
+//! Each allocation reserves `AllocationDeposit` from its owner, returned when
+//! a batch settles the request. An allocation left unsettled for
+//! `AllocationExpiry` blocks is dropped by `on_idle`, lowest ID first, and its
+//! deposit returned, so unused allocations cannot grow the state without
+//! bound.
+//!
+//! ## Rejected Requests
+//!
+//! The guest also reports requests it rejected, with a [`RequestRejection`]
+//! reason code, in `rejected_request_ids`. `submit_proof` records them in
+//! `RejectedRequests`, so users can tell a bad signature from one not yet
+//! processed. A rejected ID is settled like a verified one: it cannot appear
+//! in a later batch, and its record is pruned with the batch. A batch may
+//! reject every request it processed, but must settle at least one; the proof
+//! always binds the rejection list, even when it is empty. Optimistic
+//! batches cannot carry rejections, as fraud proofs only cover verified
+//! requests.
+//!
+//! ## Request Consumption
+//!
+//! A verified request authorizes one action. Pallets (the quantum vault) and
+//! EVM contracts (through the `0x23` precompile) call
+//! [`Pallet::consume_verified_request`], which records the consuming account
+//! and a 32-byte tag of the consuming call in `ConsumedRequests` and rejects
+//! any further use with `RequestAlreadyConsumed`. The record is pruned with
+//! the request's batch; by then the request has lost its allocation, so it
+//! can neither be verified nor consumed again.
+//!
+//! ## Optimistic Batches
+//!
+//! `submit_optimistic_batch` is an alternative to `submit_proof` for
+//! aggregators that skip proving: it posts the public values and, for each
+//! request, a commitment to the signature data (see
+//! [`Pallet::request_commitment`]), reserving `OptimisticBond`. The requests
+//! stay pending for `ChallengePeriod` blocks, during which anyone can call
+//! `challenge_optimistic_batch` with one request whose signature fails ML-DSA
+//! verification through `SignatureVerifier`. A successful challenge discards
+//! the batch, pays the bond to the challenger and, like `report_invalid_batch`,
+//! slashes `InvalidBatchSlash` of the aggregator bond and deactivates it.
+//! Unchallenged batches are settled by `finalize_optimistic_batch` like a
+//! proven batch, without a vkey.
+//!
+//! Fraud proofs are only as strong as `SignatureVerifier`, so new batches
+//! are accepted only while `OptimisticBatchesEnabled` holds. Batches already
+//! pending when it is turned off can still be challenged and finalized.
+//!
+//! Only commitments are on-chain, so an aggregator could withhold the
+//! signature data a fraud proof needs. Anyone can demand the data of a
+//! pending request with `challenge_batch_data`, reserving
+//! `DataChallengeDeposit`; the batch cannot be finalized while a demand is
+//! open. `respond_batch_data` reveals the data: a valid signature closes the
+//! demand and pays the deposit to the responder, an invalid one proves fraud.
+//! A demand left unanswered for `DataResponsePeriod` blocks lets anyone void
+//! the batch with `claim_data_challenge`, paying its bond to the data
+//! challenger and slashing the aggregator as for a fraud proof.
+//!
+//! Once an aggregator is proven fraudulent, none of its pending batches can
+//! be trusted: `finalize_optimistic_batch` voids every batch it submitted up
+//! to the fraud, at any time, releasing the requests and paying the bond to
+//! `RewardAccount`.
+//!
+//! ## Aggregated Proofs
+//!
+//! An aggregation guest can verify several batch proofs with SP1 proof
+//! composition and commit their public values under a root of roots: a
+//! Merkle root over `(batch_id, requests_root)` of every child batch.
+//! `submit_aggregated_proof` checks the aggregation vkey and the vkey the
+//! child proofs were verified under, then settles every child as if it had
+//! been proven on its own. The proof must contain the exact hash of the public
+//! values, both vkeys and every child's public values included. Aggregated
+//! proofs earn no `ProofReward` until they are verified in full on-chain.
+//!
+//! ## Retention
+//!
+//! Verified batches are kept for `RetentionPeriod` blocks. After that
+//! `on_idle` prunes them oldest first, along with their request IDs and proof
+//! commitment, so the verification state is bounded by the batches accepted
+//! within one retention period. A pruned request also loses its allocation,
+//! so it cannot be verified again; consumed requests are pruned the same way,
+//! with their consumption record. A pruned
+//! batch ID keeps a tombstone in `PrunedBatches` for another retention
+//! period, during which it cannot be proven again. Pruned proofs cannot be
+//! replayed after that either, as their requests are no longer allocated.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -26,18 +148,19 @@ extern crate alloc;
 
 pub use pallet::*;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod migrations;
 #[cfg(test)]
-// TODO: Add mock.rs for testing
-// mod mock;
+mod mock;
 #[cfg(test)]
-// TODO: Add tests.rs for unit tests
-// mod tests;
-// #[cfg(feature = "runtime-benchmarks")]
-// TODO: Add benchmarking.rs for benchmarks
-// mod benchmarking;
+mod tests;
 pub mod weights;
 pub use weights::*;
 
+/// Runtime API consumed by the node's reml_* RPC
+pub mod runtime_api;
+
 // ═══════════════════════════════════════════════════════════════════════════
 // CONSTANTS
 // ═══════════════════════════════════════════════════════════════════════════
@@ -54,20 +177,53 @@ pub const MAX_PROOF_SIZE: u32 = 102_400;
 /// Maximum verified request IDs per proof
 pub const MAX_VERIFIED_REQUESTS: u32 = 1_000;
 
+/// Maximum child batches per aggregated proof
+pub const MAX_AGGREGATED_BATCHES: u32 = 16;
+
 /// Minimum SP1 proof size (compressed proofs are at least 1KB)
 pub const MIN_PROOF_SIZE: usize = 1024;
 
 /// SP1 Groth16 proof size (for compressed proofs)
 pub const GROTH16_PROOF_SIZE: usize = 260;
 
+/// Native ML-DSA (Dilithium2) verification used to check fraud proofs
+pub trait MlDsaVerifier {
+    /// Whether `signature` is a valid signature of `message` under `public_key`
+    fn verify(public_key: &[u8], message: &[u8], signature: &[u8]) -> bool;
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
-    use frame_support::pallet_prelude::*;
+    use alloc::{collections::BTreeSet, vec::Vec};
+    use frame_support::{
+        pallet_prelude::*,
+        traits::{BalanceStatus, Currency, ExistenceRequirement, ReservableCurrency},
+        weights::WeightMeter,
+    };
     use frame_system::pallet_prelude::*;
     use sp_core::H256;
+    use sp_runtime::{
+        traits::{Saturating, Zero},
+        Perbill,
+    };
+
+    /// Balance type of the bond currency
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+    /// In-code storage version
+    ///
+    /// v1: `BatchInfo` records the vkey hash of the batch proof.
+    /// v2: `AggregatorInfo` tracks bond, liveness and rewards; `Aggregators`
+    /// is counted.
+    /// v3: verified batches are queued in `BatchQueue` for pruning.
+    /// v4: request IDs are allocated from `NextRequestId`.
+    /// v5: `RequestOwners` records the allocation deposit and expiry.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(7);
 
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     // ═══════════════════════════════════════════════════════════════════════
@@ -79,27 +235,103 @@ pub mod pallet {
         /// Weight information for extrinsics
         type WeightInfo: WeightInfo;
 
-        /// Maximum number of authorized aggregators
+        /// Maximum number of registered aggregators, bonded or not
         #[pallet::constant]
         type MaxAggregators: Get<u32>;
 
-        /// Expected verification key hash for the Re-ML guest program
+        /// Currency aggregator bonds are reserved in and rewards paid with
+        type Currency: ReservableCurrency<Self::AccountId>;
+
+        /// Bond reserved by `bond_aggregator`
+        #[pallet::constant]
+        type AggregatorBond: Get<BalanceOf<Self>>;
+
+        /// Reward paid to the aggregator of each accepted proof
+        #[pallet::constant]
+        type ProofReward: Get<BalanceOf<Self>>;
+
+        /// Account rewards are paid from and slashed bonds are paid into
+        type RewardAccount: Get<Self::AccountId>;
+
+        /// Longest gap between accepted proofs of an active aggregator
+        #[pallet::constant]
+        type LivenessPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Share of the bond slashed for missing `LivenessPeriod`
+        #[pallet::constant]
+        type LivenessSlash: Get<Perbill>;
+
+        /// Share of the bond slashed when one of its batches is invalidated
+        #[pallet::constant]
+        type InvalidBatchSlash: Get<Perbill>;
+
+        /// Blocks between `unbond_aggregator` and `withdraw_bond`
+        #[pallet::constant]
+        type UnbondingPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Blocks a verified batch is kept before `on_idle` prunes it
+        #[pallet::constant]
+        type RetentionPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Deposit reserved per allocated request ID, returned once the
+        /// request is settled or its allocation expires
+        #[pallet::constant]
+        type AllocationDeposit: Get<BalanceOf<Self>>;
+
+        /// Blocks an allocated request ID can stay unsettled before `on_idle`
+        /// drops it
+        #[pallet::constant]
+        type AllocationExpiry: Get<BlockNumberFor<Self>>;
+
+        /// Maximum number of allowed guest verification keys
+        #[pallet::constant]
+        type MaxVKeys: Get<u32>;
+
+        /// Origin allowed to manage aggregators and verification keys
+        type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Blocks an optimistic batch can be challenged before it is final
         #[pallet::constant]
-        type ExpectedVKeyHash: Get<[u8; 32]>;
+        type ChallengePeriod: Get<BlockNumberFor<Self>>;
+
+        /// Bond reserved per optimistic batch, paid to a successful challenger
+        #[pallet::constant]
+        type OptimisticBond: Get<BalanceOf<Self>>;
+
+        /// Deposit reserved per data challenge, paid to whoever answers it or
+        /// returned if the batch is discarded
+        #[pallet::constant]
+        type DataChallengeDeposit: Get<BalanceOf<Self>>;
+
+        /// Blocks the signature data of a challenged request can be withheld
+        /// before the batch is void
+        #[pallet::constant]
+        type DataResponsePeriod: Get<BlockNumberFor<Self>>;
+
+        /// Verifier fraud proofs are checked with
+        type SignatureVerifier: MlDsaVerifier;
+
+        /// Whether `submit_optimistic_batch` accepts new batches
+        ///
+        /// Keep false while `SignatureVerifier` cannot verify ML-DSA on-chain:
+        /// fraud proofs against a verifier that accepts forged signatures
+        /// fail, so invalid batches would settle unchallenged.
+        #[pallet::constant]
+        type OptimisticBatchesEnabled: Get<bool>;
     }
 
     // ═══════════════════════════════════════════════════════════════════════
     // STORAGE
     // ═══════════════════════════════════════════════════════════════════════
 
-    /// Authorized aggregators who can submit proofs
+    /// Registered aggregators, capped at `MaxAggregators`
     #[pallet::storage]
     #[pallet::getter(fn aggregators)]
-    pub type Aggregators<T: Config> = StorageMap<
+    pub type Aggregators<T: Config> = CountedStorageMap<
         _,
         Blake2_128Concat,
         T::AccountId,
-        AggregatorInfo<BlockNumberFor<T>>,
+        AggregatorInfo<BalanceOf<T>, BlockNumberFor<T>>,
         OptionQuery,
     >;
 
@@ -109,6 +341,17 @@ pub mod pallet {
     pub type VerifiedBatches<T: Config> =
         StorageMap<_, Twox64Concat, u64, BatchInfo<T::AccountId, BlockNumberFor<T>>, OptionQuery>;
 
+    /// Ordered request IDs of each verified batch (leaves of `requests_root`)
+    #[pallet::storage]
+    #[pallet::getter(fn batch_request_ids)]
+    pub type BatchRequestIds<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u64,
+        BoundedVec<u64, ConstU32<MAX_VERIFIED_REQUESTS>>,
+        OptionQuery,
+    >;
+
     /// Individual request verification status
     #[pallet::storage]
     #[pallet::getter(fn verified_requests)]
@@ -130,21 +373,203 @@ pub mod pallet {
     pub type ProofCommitments<T: Config> =
         StorageMap<_, Blake2_128Concat, H256, BlockNumberFor<T>, OptionQuery>;
 
+    /// Guest program verification keys proofs may be generated with
+    #[pallet::storage]
+    #[pallet::getter(fn allowed_vkeys)]
+    pub type AllowedVKeys<T: Config> =
+        CountedStorageMap<_, Blake2_128Concat, [u8; 32], VKeyInfo<BlockNumberFor<T>>, OptionQuery>;
+
+    /// Verified batches in verification order, keyed by queue position;
+    /// pruned batches stay queued until their `PrunedBatches` entry expires
+    #[pallet::storage]
+    pub type BatchQueue<T: Config> =
+        StorageMap<_, Twox64Concat, u64, QueuedBatch<BlockNumberFor<T>>, OptionQuery>;
+
+    /// Requests consumed by `consume_verified_request`, until their batch is
+    /// pruned or revoked and their allocation dropped
+    #[pallet::storage]
+    #[pallet::getter(fn consumed_requests)]
+    pub type ConsumedRequests<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u64,
+        ConsumptionInfo<T::AccountId, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// Pruning position in `BatchQueue`
+    #[pallet::storage]
+    #[pallet::getter(fn prune_cursor)]
+    pub type PruneCursor<T: Config> = StorageValue<_, PruneState, ValueQuery>;
+
+    /// Tombstones of pruned batch IDs with the block they expire at; until
+    /// then the ID cannot be used by another batch
+    #[pallet::storage]
+    #[pallet::getter(fn pruned_batches)]
+    pub type PrunedBatches<T: Config> =
+        StorageMap<_, Twox64Concat, u64, BlockNumberFor<T>, OptionQuery>;
+
+    /// Next request ID handed out by `allocate_request_id`
+    #[pallet::storage]
+    #[pallet::getter(fn next_request_id)]
+    pub type NextRequestId<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// Allocation of each request ID, until the request is pruned or the
+    /// allocation expires unsettled
+    #[pallet::storage]
+    #[pallet::getter(fn request_owners)]
+    pub type RequestOwners<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u64,
+        RequestAllocation<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// Lowest request ID not yet checked for allocation expiry
+    #[pallet::storage]
+    #[pallet::getter(fn allocation_cursor)]
+    pub type AllocationCursor<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// Optimistic batches within or awaiting finalization after their
+    /// challenge period
+    #[pallet::storage]
+    #[pallet::getter(fn pending_batches)]
+    pub type PendingBatches<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u64,
+        PendingBatch<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// Request IDs of each pending batch with their signature commitments
+    #[pallet::storage]
+    #[pallet::getter(fn pending_batch_requests)]
+    pub type PendingBatchRequests<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u64,
+        BoundedVec<(u64, [u8; 32]), ConstU32<MAX_VERIFIED_REQUESTS>>,
+        OptionQuery,
+    >;
+
+    /// Pending batch each request ID is claimed by
+    #[pallet::storage]
+    #[pallet::getter(fn pending_requests)]
+    pub type PendingRequests<T: Config> = StorageMap<_, Twox64Concat, u64, u64, OptionQuery>;
+
+    /// Open demands for the signature data of pending requests, by batch ID
+    /// and request ID
+    #[pallet::storage]
+    #[pallet::getter(fn data_challenges)]
+    pub type DataChallenges<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        u64,
+        Twox64Concat,
+        u64,
+        DataChallenge<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// Block at which each aggregator was last proven fraudulent; its
+    /// optimistic batches submitted until then are void
+    #[pallet::storage]
+    #[pallet::getter(fn fraud_proven_at)]
+    pub type FraudProvenAt<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
+    /// Requests the guest rejected, until their batch is pruned
+    #[pallet::storage]
+    #[pallet::getter(fn rejected_requests)]
+    pub type RejectedRequests<T: Config> =
+        StorageMap<_, Twox64Concat, u64, RejectionInfo<BlockNumberFor<T>>, OptionQuery>;
+
+    /// Rejected request IDs of each verified batch
+    #[pallet::storage]
+    #[pallet::getter(fn batch_rejected_ids)]
+    pub type BatchRejectedIds<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u64,
+        BoundedVec<u64, ConstU32<MAX_VERIFIED_REQUESTS>>,
+        OptionQuery,
+    >;
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // GENESIS
+    // ═══════════════════════════════════════════════════════════════════════
+
+    #[pallet::genesis_config]
+    #[derive(frame_support::DefaultNoBound)]
+    pub struct GenesisConfig<T: Config> {
+        /// Verification keys allowed from genesis, as `(vkey_hash, version)`
+        pub initial_vkeys: alloc::vec::Vec<([u8; 32], u32)>,
+        #[serde(skip)]
+        pub _config: core::marker::PhantomData<T>,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            assert!(
+                self.initial_vkeys.len() as u32 <= T::MaxVKeys::get(),
+                "Initial vkeys exceed MaxVKeys"
+            );
+            for (vkey_hash, version) in &self.initial_vkeys {
+                assert!(*vkey_hash != [0u8; 32], "Zero initial vkey hash");
+                assert!(
+                    !AllowedVKeys::<T>::contains_key(vkey_hash),
+                    "Duplicate initial vkey"
+                );
+                AllowedVKeys::<T>::insert(
+                    vkey_hash,
+                    VKeyInfo {
+                        version: *version,
+                        active_from: Zero::zero(),
+                        expires_at: None,
+                    },
+                );
+            }
+        }
+    }
+
     // ═══════════════════════════════════════════════════════════════════════
     // TYPES
     // ═══════════════════════════════════════════════════════════════════════
 
     /// Aggregator information
-    #[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    #[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
     #[scale_info(skip_type_params(T))]
-    pub struct AggregatorInfo<BlockNumber> {
+    pub struct AggregatorInfo<Balance, BlockNumber> {
         pub registered_at: BlockNumber,
         pub proofs_submitted: u64,
         pub active: bool,
+        /// Reserved bond left after slashes (zero for admin-registered)
+        pub bond: Balance,
+        /// Block of the last accepted proof or heartbeat, or of registration
+        /// before that
+        pub last_active: BlockNumber,
+        /// Total rewards paid for accepted proofs
+        pub rewards_earned: Balance,
+        /// Block from which the bond can be withdrawn, once unbonding
+        pub unbonding_until: Option<BlockNumber>,
+    }
+
+    /// Why an aggregator was slashed
+    #[derive(
+        Clone, Debug, PartialEq, Eq, Encode, Decode, DecodeWithMemTracking, TypeInfo, MaxEncodedLen,
+    )]
+    pub enum SlashReason {
+        /// One of its batches was invalidated or proven fraudulent
+        InvalidBatch,
+        /// No proof accepted or heartbeat sent within `LivenessPeriod`
+        MissedLiveness,
     }
 
     /// Verified batch information
-    #[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    #[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
     #[scale_info(skip_type_params(T))]
     pub struct BatchInfo<AccountId, BlockNumber> {
         pub aggregator: AccountId,
@@ -152,6 +577,144 @@ pub mod pallet {
         pub signature_count: u32,
         pub requests_root: [u8; 32],
         pub proof_commitment: [u8; 32],
+        /// Verification key the proof was checked against (zero for
+        /// optimistic batches and batches verified before keys were recorded)
+        pub vkey_hash: [u8; 32],
+    }
+
+    /// Single use of a verified request
+    #[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    pub struct ConsumptionInfo<AccountId, BlockNumber> {
+        /// Account the request was used by
+        pub consumer: AccountId,
+        /// Caller-defined tag of the call that used it (e.g. a message hash)
+        pub call: [u8; 32],
+        pub consumed_at: BlockNumber,
+    }
+
+    /// Why the guest rejected a request
+    ///
+    /// The codec index is the reason code the guest reports.
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Encode,
+        Decode,
+        DecodeWithMemTracking,
+        TypeInfo,
+        MaxEncodedLen,
+    )]
+    pub enum RequestRejection {
+        /// Public key or signature is not sized for ML-DSA (Dilithium2)
+        #[codec(index = 1)]
+        InvalidSize,
+        /// ML-DSA verification failed
+        #[codec(index = 2)]
+        InvalidSignature,
+    }
+
+    /// Rejection of a request by a verified batch
+    #[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    pub struct RejectionInfo<BlockNumber> {
+        pub batch_id: u64,
+        pub reason: RequestRejection,
+        pub rejected_at: BlockNumber,
+    }
+
+    /// Allocated request ID
+    #[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    pub struct RequestAllocation<AccountId, Balance, BlockNumber> {
+        /// Account that allocated the ID
+        pub owner: AccountId,
+        /// Part of `AllocationDeposit` still reserved from the owner (zero
+        /// once the request is settled)
+        pub deposit: Balance,
+        /// First block at which `on_idle` drops the allocation if unsettled
+        pub expires_at: BlockNumber,
+    }
+
+    /// Verified batch awaiting pruning
+    #[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    pub struct QueuedBatch<BlockNumber> {
+        pub batch_id: u64,
+        pub verified_at: BlockNumber,
+        /// Commitment of the proof that verified the batch, which tells it
+        /// apart from a later proof of the same batch ID
+        pub proof_commitment: [u8; 32],
+    }
+
+    /// Pruning progress through `BatchQueue`
+    #[derive(
+        Clone, Default, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen,
+    )]
+    pub struct PruneState {
+        /// Queue position of the oldest unpruned batch
+        pub head: u64,
+        /// Queue position of the next verified batch
+        pub tail: u64,
+        /// Requests of the head batch already pruned
+        pub request_index: u32,
+        /// Queue position of the oldest pruned batch whose ID is still
+        /// tombstoned
+        pub tombstone_head: u64,
+    }
+
+    /// Allowed guest program verification key
+    #[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    pub struct VKeyInfo<BlockNumber> {
+        /// Guest program version the key belongs to
+        pub version: u32,
+        /// First block at which proofs under the key are accepted
+        pub active_from: BlockNumber,
+        /// First block at which proofs under the key are rejected
+        pub expires_at: Option<BlockNumber>,
+    }
+
+    impl<BlockNumber: PartialOrd> VKeyInfo<BlockNumber> {
+        /// Whether proofs under the key are accepted at block `now`
+        pub fn is_active_at(&self, now: &BlockNumber) -> bool {
+            self.active_from <= *now && self.expires_at.as_ref().map_or(true, |end| now < end)
+        }
+    }
+
+    /// Optimistic batch awaiting the end of its challenge period
+    #[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    pub struct PendingBatch<AccountId, Balance, BlockNumber> {
+        pub aggregator: AccountId,
+        pub submitted_at: BlockNumber,
+        /// First block at which the batch can be finalized and no longer
+        /// challenged
+        pub challenge_ends: BlockNumber,
+        pub signature_count: u32,
+        pub requests_root: [u8; 32],
+        /// Commitment to the batch ID, root and request commitments, recorded
+        /// as the proof commitment once final
+        pub commitment: [u8; 32],
+        /// `OptimisticBond` reserved from the aggregator
+        pub bond: Balance,
+    }
+
+    /// Open demand for the signature data of a pending request
+    #[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    pub struct DataChallenge<AccountId, Balance, BlockNumber> {
+        pub challenger: AccountId,
+        /// `DataChallengeDeposit` reserved from the challenger
+        pub deposit: Balance,
+        /// First block at which the batch can be voided if unanswered
+        pub respond_by: BlockNumber,
+    }
+
+    /// Optimistic batch submission data
+    #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, DecodeWithMemTracking, TypeInfo)]
+    pub struct OptimisticSubmission {
+        pub batch_id: u64,
+        /// Public values the batch claims, as a proof would commit them
+        pub public_values: PublicValues,
+        /// `request_commitment` of each verified request, in request order
+        pub request_commitments: BoundedVec<[u8; 32], ConstU32<1_000>>,
     }
 
     /// Proof submission data
@@ -166,6 +729,38 @@ pub mod pallet {
         pub vkey_hash: [u8; 32],
     }
 
+    /// Aggregated proof submission data
+    #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, DecodeWithMemTracking, TypeInfo)]
+    pub struct AggregatedProofSubmission {
+        /// SP1 proof of the aggregation guest
+        pub proof: BoundedVec<u8, ConstU32<102_400>>,
+        /// Public values committed in the aggregated proof
+        pub public_values: AggregatedPublicValues,
+        /// Verification key hash of the aggregation guest
+        pub vkey_hash: [u8; 32],
+    }
+
+    /// Public values of an aggregated proof (matches aggregation guest output)
+    #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, DecodeWithMemTracking, TypeInfo)]
+    pub struct AggregatedPublicValues {
+        pub version: u8,
+        pub chain_id: u32,
+        /// Verification key hash of the batch guest the child proofs were
+        /// verified under
+        pub batch_vkey_hash: [u8; 32],
+        /// Merkle root over `(batch_id, requests_root)` of the child batches
+        pub batches_root: [u8; 32],
+        /// Public values of each child batch proof
+        pub batches: BoundedVec<PublicValues, ConstU32<MAX_AGGREGATED_BATCHES>>,
+    }
+
+    impl AggregatedPublicValues {
+        /// Number of requests settled by all child batches
+        pub fn request_count(&self) -> u32 {
+            self.batches.iter().map(PublicValues::request_count).sum()
+        }
+    }
+
     /// Public values structure (matches guest output)
     #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, DecodeWithMemTracking, TypeInfo)]
     pub struct PublicValues {
@@ -175,6 +770,15 @@ pub mod pallet {
         pub verified_count: u32,
         pub requests_root: [u8; 32],
         pub verified_request_ids: BoundedVec<u64, ConstU32<1_000>>,
+        /// Requests the guest rejected, with the reason
+        pub rejected_request_ids: BoundedVec<(u64, RequestRejection), ConstU32<1_000>>,
+    }
+
+    impl PublicValues {
+        /// Number of requests settled by the batch, verified or rejected
+        pub fn request_count(&self) -> u32 {
+            (self.verified_request_ids.len() + self.rejected_request_ids.len()) as u32
+        }
     }
 
     /// Proof rejection reason
@@ -215,6 +819,136 @@ pub mod pallet {
             aggregator: T::AccountId,
             reason: RejectReason,
         },
+        VKeyAdded {
+            vkey_hash: [u8; 32],
+            version: u32,
+            active_from: BlockNumberFor<T>,
+        },
+        VKeyRetirementScheduled {
+            vkey_hash: [u8; 32],
+            expires_at: BlockNumberFor<T>,
+        },
+        VKeyRemoved {
+            vkey_hash: [u8; 32],
+        },
+        AggregatorBonded {
+            aggregator: T::AccountId,
+            bond: BalanceOf<T>,
+        },
+        AggregatorUnbonding {
+            aggregator: T::AccountId,
+            unbonding_until: BlockNumberFor<T>,
+        },
+        AggregatorBondWithdrawn {
+            aggregator: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        AggregatorRewarded {
+            aggregator: T::AccountId,
+            batch_id: u64,
+            amount: BalanceOf<T>,
+        },
+        AggregatorSlashed {
+            aggregator: T::AccountId,
+            amount: BalanceOf<T>,
+            reason: SlashReason,
+        },
+        /// Batch removed and its request IDs revoked
+        BatchInvalidated {
+            batch_id: u64,
+            aggregator: T::AccountId,
+        },
+        /// Batch older than `RetentionPeriod` pruned
+        BatchPruned {
+            batch_id: u64,
+        },
+        /// Verified request used up by `consumer`
+        RequestConsumed {
+            request_id: u64,
+            consumer: T::AccountId,
+            call: [u8; 32],
+        },
+        /// Request ID allocated to `owner`
+        RequestIdAllocated {
+            request_id: u64,
+            owner: T::AccountId,
+        },
+        /// Unsettled allocation dropped after `AllocationExpiry`, its deposit
+        /// returned to `owner`
+        AllocationExpired {
+            request_id: u64,
+            owner: T::AccountId,
+        },
+        /// Optimistic batch posted, final from `challenge_ends` unless challenged
+        OptimisticBatchSubmitted {
+            batch_id: u64,
+            aggregator: T::AccountId,
+            signature_count: u32,
+            challenge_ends: BlockNumberFor<T>,
+        },
+        /// Unchallenged optimistic batch settled
+        OptimisticBatchFinalized {
+            batch_id: u64,
+            aggregator: T::AccountId,
+            signature_count: u32,
+        },
+        /// Requests of a verified batch were rejected by the guest, see
+        /// `RejectedRequests`
+        RequestsRejected {
+            batch_id: u64,
+            count: u32,
+        },
+        /// Aggregated proof verified; each child batch also emits
+        /// `ProofVerified`
+        AggregatedProofVerified {
+            aggregator: T::AccountId,
+            batch_count: u32,
+            signature_count: u32,
+            batches_root: [u8; 32],
+        },
+        /// `request_id` of an optimistic batch failed ML-DSA verification;
+        /// the batch was discarded and `slashed` of its bond paid to `challenger`
+        FraudProven {
+            batch_id: u64,
+            aggregator: T::AccountId,
+            challenger: T::AccountId,
+            request_id: u64,
+            slashed: BalanceOf<T>,
+        },
+        /// Active aggregator signalled liveness without a proof
+        AggregatorHeartbeat {
+            aggregator: T::AccountId,
+        },
+        /// Signature data of a pending request demanded, to be revealed by
+        /// `respond_by`
+        DataChallenged {
+            batch_id: u64,
+            request_id: u64,
+            challenger: T::AccountId,
+            respond_by: BlockNumberFor<T>,
+        },
+        /// Signature data of a challenged request revealed and valid
+        DataChallengeAnswered {
+            batch_id: u64,
+            request_id: u64,
+            responder: T::AccountId,
+        },
+        /// Signature data of `request_id` was withheld past `respond_by`; the
+        /// batch was discarded and `slashed` of its bond paid to `challenger`
+        BatchDataWithheld {
+            batch_id: u64,
+            aggregator: T::AccountId,
+            challenger: T::AccountId,
+            request_id: u64,
+            slashed: BalanceOf<T>,
+        },
+        /// Pending batch of an aggregator proven fraudulent discarded, and
+        /// `slashed` of its bond paid to `RewardAccount`
+        OptimisticBatchVoided {
+            batch_id: u64,
+            aggregator: T::AccountId,
+            slashed: BalanceOf<T>,
+        },
     }
 
     // ═══════════════════════════════════════════════════════════════════════
@@ -233,6 +967,95 @@ pub mod pallet {
         InvalidPublicValues,
         ProofAlreadyUsed,
         InvalidMerkleRoot,
+        /// The vkey is allowed but outside its activation window
+        VKeyNotActive,
+        VKeyAlreadyAllowed,
+        VKeyNotFound,
+        TooManyVKeys,
+        TooManyAggregators,
+        /// Free balance does not cover `AggregatorBond`
+        InsufficientBond,
+        AggregatorNotActive,
+        AlreadyUnbonding,
+        /// `withdraw_bond` before `unbond_aggregator`
+        NotUnbonding,
+        /// `UnbondingPeriod` has not passed yet
+        BondStillLocked,
+        /// The aggregator proved or heartbeated within its `LivenessPeriod`
+        AggregatorLive,
+        BatchNotFound,
+        /// A batch with this ID was pruned less than `RetentionPeriod` ago
+        BatchExpired,
+        RequestNotVerified,
+        /// The request was already used by `consume_verified_request`
+        RequestAlreadyConsumed,
+        /// A proven request ID was not allocated with `allocate_request_id`
+        RequestNotAllocated,
+        /// A proven request ID is already verified or consumed
+        RequestAlreadyVerified,
+        /// A request ID appears more than once in the batch
+        DuplicateRequestId,
+        /// A proven request ID was already rejected by another batch
+        RequestAlreadyRejected,
+        /// The batch ID is claimed by a pending optimistic batch
+        BatchPending,
+        /// A request ID is claimed by a pending optimistic batch
+        RequestPending,
+        /// The request commitments do not match the request IDs
+        InvalidRequestCommitments,
+        /// No pending optimistic batch with this ID
+        PendingBatchNotFound,
+        /// The optimistic batch can still be challenged
+        ChallengePeriodActive,
+        /// The optimistic batch can no longer be challenged
+        ChallengePeriodOver,
+        /// The request is not part of the optimistic batch
+        RequestNotInBatch,
+        /// The signature data does not match the request's commitment
+        RequestCommitmentMismatch,
+        /// The challenged signature is valid, so no fraud was proven
+        SignatureValid,
+        /// An aggregated proof has no child batches
+        NoBatches,
+        /// A batch ID appears more than once in the aggregated proof
+        DuplicateBatchId,
+        /// Free balance does not cover `AllocationDeposit` or
+        /// `DataChallengeDeposit`
+        InsufficientDeposit,
+        /// The request's signature data was already demanded
+        DataAlreadyChallenged,
+        /// No open data challenge for this request
+        DataChallengeNotFound,
+        /// The optimistic batch has an unanswered data challenge
+        DataChallengeOpen,
+        /// The data challenge can still be answered
+        DataResponsePeriodActive,
+        /// `OptimisticBatchesEnabled` is false
+        OptimisticBatchesDisabled,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// Prune expired batches, then expired allocations, with the block's
+        /// leftover weight
+        fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let used = Self::prune_expired(now, remaining_weight);
+            used.saturating_add(Self::prune_expired_allocations(
+                now,
+                remaining_weight.saturating_sub(used),
+            ))
+        }
+
+        fn integrity_test() {
+            assert!(
+                !T::RetentionPeriod::get().is_zero(),
+                "RetentionPeriod must not be zero"
+            );
+            assert!(
+                !T::AllocationExpiry::get().is_zero(),
+                "AllocationExpiry must not be zero"
+            );
+        }
     }
 
     // ═══════════════════════════════════════════════════════════════════════
@@ -241,43 +1064,29 @@ pub mod pallet {
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
-        /// Register a new aggregator (sudo only)
+        /// Register a new aggregator (`AdminOrigin` only)
         #[pallet::call_index(0)]
         #[pallet::weight(T::WeightInfo::register_aggregator())]
         pub fn register_aggregator(
             origin: OriginFor<T>,
             aggregator: T::AccountId,
         ) -> DispatchResult {
-            ensure_root(origin)?;
-
-            ensure!(
-                !Aggregators::<T>::contains_key(&aggregator),
-                Error::<T>::AggregatorAlreadyRegistered
-            );
-
-            let current_block = frame_system::Pallet::<T>::block_number();
+            T::AdminOrigin::ensure_origin(origin)?;
 
-            Aggregators::<T>::insert(
-                &aggregator,
-                AggregatorInfo {
-                    registered_at: current_block,
-                    proofs_submitted: 0,
-                    active: true,
-                },
-            );
+            Self::do_register(&aggregator, Zero::zero())?;
 
             Self::deposit_event(Event::AggregatorRegistered { aggregator });
             Ok(())
         }
 
-        /// Deactivate an aggregator
+        /// Deactivate an aggregator (`AdminOrigin` only)
         #[pallet::call_index(1)]
         #[pallet::weight(T::WeightInfo::deactivate_aggregator())]
         pub fn deactivate_aggregator(
             origin: OriginFor<T>,
             aggregator: T::AccountId,
         ) -> DispatchResult {
-            ensure_root(origin)?;
+            T::AdminOrigin::ensure_origin(origin)?;
 
             Aggregators::<T>::try_mutate(&aggregator, |maybe_info| -> DispatchResult {
                 let info = maybe_info.as_mut().ok_or(Error::<T>::AggregatorNotFound)?;
@@ -291,7 +1100,10 @@ pub mod pallet {
 
         /// Submit and verify a STARK proof
         #[pallet::call_index(2)]
-        #[pallet::weight(T::WeightInfo::submit_proof(submission.public_values.verified_request_ids.len() as u32))]
+        #[pallet::weight(T::WeightInfo::submit_proof(
+            submission.proof.len() as u32,
+            submission.public_values.request_count(),
+        ))]
         pub fn submit_proof(origin: OriginFor<T>, submission: ProofSubmission) -> DispatchResult {
             let aggregator = ensure_signed(origin)?;
 
@@ -300,137 +1112,1481 @@ pub mod pallet {
                 Aggregators::<T>::get(&aggregator).ok_or(Error::<T>::NotAuthorized)?;
             ensure!(aggregator_info.active, Error::<T>::NotAuthorized);
 
-            // Validate batch not already verified
-            ensure!(
-                !VerifiedBatches::<T>::contains_key(submission.batch_id),
-                Error::<T>::BatchAlreadyVerified
-            );
+            // Validate batch not already verified or pending
+            Self::ensure_batch_id_available(submission.batch_id)?;
 
             // Validate public values
-            ensure!(
-                submission.public_values.version == REML_VERSION,
+            Self::ensure_valid_public_values(submission.batch_id, &submission.public_values)?;
+
+            // Verify VKey hash against the allowed guest versions
+            let current_block = frame_system::Pallet::<T>::block_number();
+            Self::ensure_vkey_active(&submission.vkey_hash, &current_block)?;
+
+            // Compute proof commitment for replay prevention
+            let proof_commitment = Self::compute_proof_commitment(&submission);
+            let commitment_hash = H256::from_slice(&proof_commitment);
+
+            ensure!(
+                !ProofCommitments::<T>::contains_key(commitment_hash),
+                Error::<T>::ProofAlreadyUsed
+            );
+
+            // Verify merkle root matches claimed request IDs, and that each
+            // verified or rejected request ID is allocated and settled by
+            // this batch only
+            Self::ensure_settleable_requests(&submission.public_values, &mut BTreeSet::new())?;
+
+            // ═══════════════════════════════════════════════════════════════
+            // STARK PROOF VERIFICATION
+            // ═══════════════════════════════════════════════════════════════
+
+            let proof_valid = Self::verify_sp1_proof(
+                &submission.proof,
+                &submission.public_values,
+                &submission.vkey_hash,
+            );
+
+            if !proof_valid {
+                Self::deposit_event(Event::ProofRejected {
+                    batch_id: submission.batch_id,
+                    aggregator: aggregator.clone(),
+                    reason: RejectReason::StarkVerificationFailed,
+                });
+                return Err(Error::<T>::ProofVerificationFailed.into());
+            }
+
+            // ═══════════════════════════════════════════════════════════════
+            // UPDATE STORAGE
+            // ═══════════════════════════════════════════════════════════════
+
+            Self::settle_batch(
+                submission.batch_id,
+                BatchInfo {
+                    aggregator: aggregator.clone(),
+                    verified_at: current_block,
+                    signature_count: submission.public_values.verified_count,
+                    requests_root: submission.public_values.requests_root,
+                    proof_commitment,
+                    vkey_hash: submission.vkey_hash,
+                },
+                submission.public_values.verified_request_ids,
+                submission.public_values.rejected_request_ids,
+            );
+
+            // Update stats and pay the proof reward
+            aggregator_info.proofs_submitted += 1;
+            aggregator_info.last_active = current_block;
+            Self::pay_reward(&aggregator, &mut aggregator_info, submission.batch_id);
+            Aggregators::<T>::insert(&aggregator, aggregator_info);
+
+            Self::deposit_event(Event::ProofVerified {
+                batch_id: submission.batch_id,
+                aggregator,
+                signature_count: submission.public_values.verified_count,
+                block_number: current_block,
+            });
+
+            Ok(())
+        }
+
+        /// Allow proofs generated with a guest verification key (`AdminOrigin` only)
+        ///
+        /// Proofs are accepted from `active_from` (default: now), so a new guest
+        /// version can be announced ahead of the provers switching to it.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::add_vkey())]
+        pub fn add_vkey(
+            origin: OriginFor<T>,
+            vkey_hash: [u8; 32],
+            version: u32,
+            active_from: Option<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            ensure!(vkey_hash != [0u8; 32], Error::<T>::InvalidVKeyHash);
+            ensure!(
+                !AllowedVKeys::<T>::contains_key(vkey_hash),
+                Error::<T>::VKeyAlreadyAllowed
+            );
+            ensure!(
+                AllowedVKeys::<T>::count() < T::MaxVKeys::get(),
+                Error::<T>::TooManyVKeys
+            );
+
+            let active_from = active_from.unwrap_or_else(frame_system::Pallet::<T>::block_number);
+            AllowedVKeys::<T>::insert(
+                vkey_hash,
+                VKeyInfo {
+                    version,
+                    active_from,
+                    expires_at: None,
+                },
+            );
+
+            Self::deposit_event(Event::VKeyAdded {
+                vkey_hash,
+                version,
+                active_from,
+            });
+            Ok(())
+        }
+
+        /// Retire a guest verification key (`AdminOrigin` only)
+        ///
+        /// With a future `expires_at` the key keeps being accepted until that
+        /// block, overlapping with its successor; otherwise it is removed now.
+        /// Batches already verified under the key are unaffected.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::retire_vkey())]
+        pub fn retire_vkey(
+            origin: OriginFor<T>,
+            vkey_hash: [u8; 32],
+            expires_at: Option<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let mut info = AllowedVKeys::<T>::get(vkey_hash).ok_or(Error::<T>::VKeyNotFound)?;
+            let current_block = frame_system::Pallet::<T>::block_number();
+
+            match expires_at {
+                Some(expires_at) if expires_at > current_block => {
+                    info.expires_at = Some(expires_at);
+                    AllowedVKeys::<T>::insert(vkey_hash, info);
+                    Self::deposit_event(Event::VKeyRetirementScheduled {
+                        vkey_hash,
+                        expires_at,
+                    });
+                }
+                _ => {
+                    AllowedVKeys::<T>::remove(vkey_hash);
+                    Self::deposit_event(Event::VKeyRemoved { vkey_hash });
+                }
+            }
+            Ok(())
+        }
+
+        /// Become an aggregator by reserving `AggregatorBond`
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::bond_aggregator())]
+        pub fn bond_aggregator(origin: OriginFor<T>) -> DispatchResult {
+            let aggregator = ensure_signed(origin)?;
+
+            let bond = T::AggregatorBond::get();
+            ensure!(
+                T::Currency::can_reserve(&aggregator, bond),
+                Error::<T>::InsufficientBond
+            );
+            Self::do_register(&aggregator, bond)?;
+            T::Currency::reserve(&aggregator, bond)?;
+
+            Self::deposit_event(Event::AggregatorBonded { aggregator, bond });
+            Ok(())
+        }
+
+        /// Stop submitting proofs and start the unbonding period
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::unbond_aggregator())]
+        pub fn unbond_aggregator(origin: OriginFor<T>) -> DispatchResult {
+            let aggregator = ensure_signed(origin)?;
+
+            let unbonding_until =
+                frame_system::Pallet::<T>::block_number().saturating_add(T::UnbondingPeriod::get());
+            Aggregators::<T>::try_mutate(&aggregator, |maybe_info| -> DispatchResult {
+                let info = maybe_info.as_mut().ok_or(Error::<T>::AggregatorNotFound)?;
+                ensure!(info.unbonding_until.is_none(), Error::<T>::AlreadyUnbonding);
+                info.active = false;
+                info.unbonding_until = Some(unbonding_until);
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::AggregatorUnbonding {
+                aggregator,
+                unbonding_until,
+            });
+            Ok(())
+        }
+
+        /// Release the remaining bond after `UnbondingPeriod` and leave the set
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::withdraw_bond())]
+        pub fn withdraw_bond(origin: OriginFor<T>) -> DispatchResult {
+            let aggregator = ensure_signed(origin)?;
+
+            let info = Aggregators::<T>::get(&aggregator).ok_or(Error::<T>::AggregatorNotFound)?;
+            let unbonding_until = info.unbonding_until.ok_or(Error::<T>::NotUnbonding)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= unbonding_until,
+                Error::<T>::BondStillLocked
+            );
+
+            let unreserved = info
+                .bond
+                .saturating_sub(T::Currency::unreserve(&aggregator, info.bond));
+            Aggregators::<T>::remove(&aggregator);
+
+            Self::deposit_event(Event::AggregatorBondWithdrawn {
+                aggregator,
+                amount: unreserved,
+            });
+            Ok(())
+        }
+
+        /// Invalidate a batch shown to be wrong (`AdminOrigin` only)
+        ///
+        /// Revokes the batch's verified and rejected request IDs and slashes
+        /// `InvalidBatchSlash` of its
+        /// aggregator's bond. The batch ID can be proven again afterwards, and
+        /// so can its requests unless their allocation has expired.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::report_invalid_batch(MAX_VERIFIED_REQUESTS))]
+        pub fn report_invalid_batch(
+            origin: OriginFor<T>,
+            batch_id: u64,
+        ) -> DispatchResultWithPostInfo {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let batch = VerifiedBatches::<T>::take(batch_id).ok_or(Error::<T>::BatchNotFound)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            let request_ids = BatchRequestIds::<T>::take(batch_id).unwrap_or_default();
+            for request_id in request_ids.iter() {
+                if VerifiedRequests::<T>::get(request_id).map(|(id, _)| id) == Some(batch_id) {
+                    VerifiedRequests::<T>::remove(request_id);
+                    Self::expire_allocation(*request_id, now);
+                }
+            }
+            let rejected_ids = BatchRejectedIds::<T>::take(batch_id).unwrap_or_default();
+            for request_id in rejected_ids.iter() {
+                if RejectedRequests::<T>::get(request_id).map(|info| info.batch_id)
+                    == Some(batch_id)
+                {
+                    RejectedRequests::<T>::remove(request_id);
+                    Self::expire_allocation(*request_id, now);
+                }
+            }
+
+            Aggregators::<T>::mutate(&batch.aggregator, |maybe_info| {
+                if let Some(info) = maybe_info {
+                    Self::slash(
+                        &batch.aggregator,
+                        info,
+                        T::InvalidBatchSlash::get(),
+                        SlashReason::InvalidBatch,
+                    );
+                }
+            });
+
+            Self::deposit_event(Event::BatchInvalidated {
+                batch_id,
+                aggregator: batch.aggregator,
+            });
+            Ok(Some(T::WeightInfo::report_invalid_batch(
+                (request_ids.len() + rejected_ids.len()) as u32,
+            ))
+            .into())
+        }
+
+        /// Report an active aggregator without an accepted proof or heartbeat
+        /// for `LivenessPeriod` blocks, slashing `LivenessSlash` of its bond
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::report_missed_liveness())]
+        pub fn report_missed_liveness(
+            origin: OriginFor<T>,
+            aggregator: T::AccountId,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            Aggregators::<T>::try_mutate(&aggregator, |maybe_info| -> DispatchResult {
+                let info = maybe_info.as_mut().ok_or(Error::<T>::AggregatorNotFound)?;
+                ensure!(info.active, Error::<T>::AggregatorNotActive);
+                let deadline = info.last_active.saturating_add(T::LivenessPeriod::get());
+                ensure!(
+                    frame_system::Pallet::<T>::block_number() > deadline,
+                    Error::<T>::AggregatorLive
+                );
+                Self::slash(
+                    &aggregator,
+                    info,
+                    T::LivenessSlash::get(),
+                    SlashReason::MissedLiveness,
+                );
+                Ok(())
+            })
+        }
+
+        /// Allocate the next request ID to the caller, reserving
+        /// `AllocationDeposit`
+        ///
+        /// Signature requests must use an allocated ID to be accepted by
+        /// `submit_proof`. The deposit is returned when the request is settled,
+        /// or when the allocation expires unsettled after `AllocationExpiry`.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::allocate_request_id())]
+        pub fn allocate_request_id(origin: OriginFor<T>) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+
+            Self::do_allocate_request_id(&owner)?;
+            Ok(())
+        }
+
+        /// Post a batch without a proof, reserving `OptimisticBond`
+        ///
+        /// The batch is checked like a `submit_proof` batch, except for the
+        /// proof and vkey, and becomes final after `ChallengePeriod` blocks
+        /// unless a fraud proof is submitted with `challenge_optimistic_batch`.
+        /// Fails with `OptimisticBatchesDisabled` unless
+        /// `OptimisticBatchesEnabled`.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::submit_optimistic_batch(
+            submission.public_values.verified_request_ids.len() as u32,
+        ))]
+        pub fn submit_optimistic_batch(
+            origin: OriginFor<T>,
+            submission: OptimisticSubmission,
+        ) -> DispatchResult {
+            let aggregator = ensure_signed(origin)?;
+            ensure!(
+                T::OptimisticBatchesEnabled::get(),
+                Error::<T>::OptimisticBatchesDisabled
+            );
+
+            let mut aggregator_info =
+                Aggregators::<T>::get(&aggregator).ok_or(Error::<T>::NotAuthorized)?;
+            ensure!(aggregator_info.active, Error::<T>::NotAuthorized);
+
+            Self::ensure_batch_id_available(submission.batch_id)?;
+            let public_values = &submission.public_values;
+            Self::ensure_valid_public_values(submission.batch_id, public_values)?;
+            ensure!(
+                public_values.verified_count != 0
+                    && public_values.verified_count as usize
+                        == public_values.verified_request_ids.len(),
                 Error::<T>::InvalidPublicValues
             );
+            // Rejections cannot be challenged, so they need a proof
             ensure!(
-                submission.public_values.chain_id == TESSERAX_CHAIN_ID,
+                public_values.rejected_request_ids.is_empty(),
                 Error::<T>::InvalidPublicValues
             );
             ensure!(
-                submission.public_values.batch_id == submission.batch_id,
+                submission.request_commitments.len() == public_values.verified_request_ids.len(),
+                Error::<T>::InvalidRequestCommitments
+            );
+
+            let commitment = Self::compute_optimistic_commitment(&submission);
+            ensure!(
+                !ProofCommitments::<T>::contains_key(H256::from(commitment)),
+                Error::<T>::ProofAlreadyUsed
+            );
+            Self::ensure_settleable_requests(public_values, &mut BTreeSet::new())?;
+
+            let bond = T::OptimisticBond::get();
+            T::Currency::reserve(&aggregator, bond).map_err(|_| Error::<T>::InsufficientBond)?;
+
+            let current_block = frame_system::Pallet::<T>::block_number();
+            let challenge_ends = current_block.saturating_add(T::ChallengePeriod::get());
+            let requests: BoundedVec<(u64, [u8; 32]), ConstU32<MAX_VERIFIED_REQUESTS>> =
+                public_values
+                    .verified_request_ids
+                    .iter()
+                    .copied()
+                    .zip(submission.request_commitments.iter().copied())
+                    .collect::<alloc::vec::Vec<_>>()
+                    .try_into()
+                    .map_err(|_| Error::<T>::InvalidRequestCommitments)?;
+            for request_id in public_values.verified_request_ids.iter() {
+                PendingRequests::<T>::insert(request_id, submission.batch_id);
+            }
+            PendingBatchRequests::<T>::insert(submission.batch_id, requests);
+            PendingBatches::<T>::insert(
+                submission.batch_id,
+                PendingBatch {
+                    aggregator: aggregator.clone(),
+                    submitted_at: current_block,
+                    challenge_ends,
+                    signature_count: public_values.verified_count,
+                    requests_root: public_values.requests_root,
+                    commitment,
+                    bond,
+                },
+            );
+
+            // Posting a batch keeps the aggregator live while it is pending
+            aggregator_info.last_active = current_block;
+            Aggregators::<T>::insert(&aggregator, aggregator_info);
+
+            Self::deposit_event(Event::OptimisticBatchSubmitted {
+                batch_id: submission.batch_id,
+                aggregator,
+                signature_count: public_values.verified_count,
+                challenge_ends,
+            });
+            Ok(())
+        }
+
+        /// Prove an optimistic batch wrong with one of its requests whose
+        /// signature fails ML-DSA verification
+        ///
+        /// The signature data must match the request's commitment. On success
+        /// the batch is discarded, its requests are released, the batch bond is
+        /// paid to the challenger, and `InvalidBatchSlash` of the aggregator
+        /// bond is slashed as by `report_invalid_batch`. The aggregator is
+        /// deactivated, voiding its other pending batches.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::challenge_optimistic_batch(MAX_VERIFIED_REQUESTS))]
+        pub fn challenge_optimistic_batch(
+            origin: OriginFor<T>,
+            batch_id: u64,
+            request_id: u64,
+            message: [u8; 32],
+            public_key: Vec<u8>,
+            signature: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            let challenger = ensure_signed(origin)?;
+
+            let batch =
+                PendingBatches::<T>::get(batch_id).ok_or(Error::<T>::PendingBatchNotFound)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() < batch.challenge_ends,
+                Error::<T>::ChallengePeriodOver
+            );
+
+            let requests = PendingBatchRequests::<T>::get(batch_id).unwrap_or_default();
+            let (_, commitment) = requests
+                .iter()
+                .find(|(id, _)| *id == request_id)
+                .ok_or(Error::<T>::RequestNotInBatch)?;
+            ensure!(
+                *commitment
+                    == Self::request_commitment(request_id, &message, &public_key, &signature),
+                Error::<T>::RequestCommitmentMismatch
+            );
+            ensure!(
+                !T::SignatureVerifier::verify(&public_key, &message, &signature),
+                Error::<T>::SignatureValid
+            );
+
+            let request_count = Self::prove_fraud(batch_id, &batch, request_id, challenger);
+            Ok(Some(T::WeightInfo::challenge_optimistic_batch(request_count)).into())
+        }
+
+        /// Settle an optimistic batch whose challenge period is over, or void
+        /// one whose aggregator was since proven fraudulent
+        ///
+        /// Callable by anyone. A settled batch's requests become verified, its
+        /// bond is released and the aggregator is paid `ProofReward`; it needs
+        /// every data challenge answered. A void batch is discarded at any
+        /// time, its requests released and its bond paid to `RewardAccount`.
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::finalize_optimistic_batch(MAX_VERIFIED_REQUESTS))]
+        pub fn finalize_optimistic_batch(
+            origin: OriginFor<T>,
+            batch_id: u64,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+
+            let batch =
+                PendingBatches::<T>::get(batch_id).ok_or(Error::<T>::PendingBatchNotFound)?;
+            let current_block = frame_system::Pallet::<T>::block_number();
+            if FraudProvenAt::<T>::get(&batch.aggregator)
+                .is_some_and(|proven_at| batch.submitted_at <= proven_at)
+            {
+                let (slashed, request_count) =
+                    Self::discard_pending_batch(batch_id, &batch, &T::RewardAccount::get());
+                Self::deposit_event(Event::OptimisticBatchVoided {
+                    batch_id,
+                    aggregator: batch.aggregator,
+                    slashed,
+                });
+                return Ok(Some(T::WeightInfo::finalize_optimistic_batch(request_count)).into());
+            }
+            ensure!(
+                current_block >= batch.challenge_ends,
+                Error::<T>::ChallengePeriodActive
+            );
+            ensure!(
+                !DataChallenges::<T>::contains_prefix(batch_id),
+                Error::<T>::DataChallengeOpen
+            );
+
+            PendingBatches::<T>::remove(batch_id);
+            let requests = PendingBatchRequests::<T>::take(batch_id).unwrap_or_default();
+            let request_count = requests.len() as u32;
+            let mut request_ids = BoundedVec::<u64, ConstU32<MAX_VERIFIED_REQUESTS>>::new();
+            for (id, _) in requests {
+                PendingRequests::<T>::remove(id);
+                // Bounded like `requests`
+                let _ = request_ids.try_push(id);
+            }
+
+            Self::settle_batch(
+                batch_id,
+                BatchInfo {
+                    aggregator: batch.aggregator.clone(),
+                    verified_at: current_block,
+                    signature_count: batch.signature_count,
+                    requests_root: batch.requests_root,
+                    proof_commitment: batch.commitment,
+                    vkey_hash: [0u8; 32],
+                },
+                request_ids,
+                BoundedVec::new(),
+            );
+            T::Currency::unreserve(&batch.aggregator, batch.bond);
+
+            // The aggregator may have left the set while the batch was pending
+            Aggregators::<T>::mutate(&batch.aggregator, |maybe_info| {
+                if let Some(info) = maybe_info {
+                    info.proofs_submitted += 1;
+                    Self::pay_reward(&batch.aggregator, info, batch_id);
+                }
+            });
+
+            Self::deposit_event(Event::OptimisticBatchFinalized {
+                batch_id,
+                aggregator: batch.aggregator,
+                signature_count: batch.signature_count,
+            });
+            Ok(Some(T::WeightInfo::finalize_optimistic_batch(request_count)).into())
+        }
+
+        /// Submit an aggregated proof settling several batches at once
+        ///
+        /// The aggregation guest verified every child batch proof under
+        /// `batch_vkey_hash`; both vkeys must be allowed and active. Each child
+        /// is checked and settled like a `submit_proof` batch, but earns no
+        /// `ProofReward`.
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::submit_aggregated_proof(
+            submission.proof.len() as u32,
+            submission.public_values.batches.len() as u32,
+            submission.public_values.request_count(),
+        ))]
+        pub fn submit_aggregated_proof(
+            origin: OriginFor<T>,
+            submission: AggregatedProofSubmission,
+        ) -> DispatchResult {
+            let aggregator = ensure_signed(origin)?;
+
+            let mut aggregator_info =
+                Aggregators::<T>::get(&aggregator).ok_or(Error::<T>::NotAuthorized)?;
+            ensure!(aggregator_info.active, Error::<T>::NotAuthorized);
+
+            let public_values = &submission.public_values;
+            ensure!(!public_values.batches.is_empty(), Error::<T>::NoBatches);
+            ensure!(
+                public_values.version == REML_VERSION,
+                Error::<T>::InvalidPublicValues
+            );
+            ensure!(
+                public_values.chain_id == TESSERAX_CHAIN_ID,
                 Error::<T>::InvalidPublicValues
             );
 
-            // Verify VKey hash
-            let expected_vkey = T::ExpectedVKeyHash::get();
-            if expected_vkey != [0u8; 32] {
-                ensure!(
-                    submission.vkey_hash == expected_vkey,
-                    Error::<T>::InvalidVKeyHash
-                );
+            // Both the aggregation guest and the batch guest must be allowed
+            let current_block = frame_system::Pallet::<T>::block_number();
+            Self::ensure_vkey_active(&submission.vkey_hash, &current_block)?;
+            Self::ensure_vkey_active(&public_values.batch_vkey_hash, &current_block)?;
+
+            ensure!(
+                Self::compute_batches_root(&public_values.batches) == public_values.batches_root,
+                Error::<T>::InvalidMerkleRoot
+            );
+
+            // Every child is checked like a `submit_proof` batch, and no
+            // request may be settled by two of them
+            let aggregate_commitment = Self::compute_aggregated_proof_commitment(&submission);
+            let mut batch_ids = BTreeSet::new();
+            let mut request_ids = BTreeSet::new();
+            for batch in public_values.batches.iter() {
+                ensure!(
+                    batch_ids.insert(batch.batch_id),
+                    Error::<T>::DuplicateBatchId
+                );
+                Self::ensure_batch_id_available(batch.batch_id)?;
+                Self::ensure_valid_public_values(batch.batch_id, batch)?;
+                let commitment =
+                    Self::child_proof_commitment(&aggregate_commitment, batch.batch_id);
+                ensure!(
+                    !ProofCommitments::<T>::contains_key(H256::from(commitment)),
+                    Error::<T>::ProofAlreadyUsed
+                );
+                Self::ensure_settleable_requests(batch, &mut request_ids)?;
+            }
+
+            ensure!(
+                Self::verify_aggregated_proof(
+                    &submission.proof,
+                    &submission.vkey_hash,
+                    public_values
+                ),
+                Error::<T>::ProofVerificationFailed
+            );
+
+            let batch_count = public_values.batches.len() as u32;
+            let signature_count = public_values.batches.iter().fold(0u32, |total, batch| {
+                total.saturating_add(batch.verified_count)
+            });
+            let AggregatedPublicValues {
+                batch_vkey_hash,
+                batches_root,
+                batches,
+                ..
+            } = submission.public_values;
+
+            for batch in batches {
+                let batch_id = batch.batch_id;
+                let batch_signatures = batch.verified_count;
+                Self::settle_batch(
+                    batch_id,
+                    BatchInfo {
+                        aggregator: aggregator.clone(),
+                        verified_at: current_block,
+                        signature_count: batch_signatures,
+                        requests_root: batch.requests_root,
+                        proof_commitment: Self::child_proof_commitment(
+                            &aggregate_commitment,
+                            batch_id,
+                        ),
+                        vkey_hash: batch_vkey_hash,
+                    },
+                    batch.verified_request_ids,
+                    batch.rejected_request_ids,
+                );
+
+                // No `ProofReward`: the binding check does not verify the
+                // child proofs, so it must not pay for them until a real
+                // aggregation verifier exists
+                aggregator_info.proofs_submitted += 1;
+
+                Self::deposit_event(Event::ProofVerified {
+                    batch_id,
+                    aggregator: aggregator.clone(),
+                    signature_count: batch_signatures,
+                    block_number: current_block,
+                });
+            }
+
+            aggregator_info.last_active = current_block;
+            Aggregators::<T>::insert(&aggregator, aggregator_info);
+
+            Self::deposit_event(Event::AggregatedProofVerified {
+                aggregator,
+                batch_count,
+                signature_count,
+                batches_root,
+            });
+            Ok(())
+        }
+
+        /// Signal that the calling aggregator is online
+        ///
+        /// Resets its `LivenessPeriod` like an accepted proof, so an aggregator
+        /// with no requests to settle is not slashed by `report_missed_liveness`.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::heartbeat())]
+        pub fn heartbeat(origin: OriginFor<T>) -> DispatchResult {
+            let aggregator = ensure_signed(origin)?;
+
+            Aggregators::<T>::try_mutate(&aggregator, |maybe_info| -> DispatchResult {
+                let info = maybe_info.as_mut().ok_or(Error::<T>::AggregatorNotFound)?;
+                ensure!(info.active, Error::<T>::AggregatorNotActive);
+                info.last_active = frame_system::Pallet::<T>::block_number();
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::AggregatorHeartbeat { aggregator });
+            Ok(())
+        }
+
+        /// Demand the signature data of a request of a pending optimistic
+        /// batch, reserving `DataChallengeDeposit`
+        ///
+        /// The batch cannot be finalized until the data is revealed with
+        /// `respond_batch_data`, and becomes void with `claim_data_challenge`
+        /// if it is not revealed within `DataResponsePeriod` blocks.
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::challenge_batch_data())]
+        pub fn challenge_batch_data(
+            origin: OriginFor<T>,
+            batch_id: u64,
+            request_id: u64,
+        ) -> DispatchResult {
+            let challenger = ensure_signed(origin)?;
+
+            let batch =
+                PendingBatches::<T>::get(batch_id).ok_or(Error::<T>::PendingBatchNotFound)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(now < batch.challenge_ends, Error::<T>::ChallengePeriodOver);
+            ensure!(
+                PendingBatchRequests::<T>::get(batch_id)
+                    .unwrap_or_default()
+                    .iter()
+                    .any(|(id, _)| *id == request_id),
+                Error::<T>::RequestNotInBatch
+            );
+            ensure!(
+                !DataChallenges::<T>::contains_key(batch_id, request_id),
+                Error::<T>::DataAlreadyChallenged
+            );
+
+            let deposit = T::DataChallengeDeposit::get();
+            T::Currency::reserve(&challenger, deposit)
+                .map_err(|_| Error::<T>::InsufficientDeposit)?;
+            let respond_by = now.saturating_add(T::DataResponsePeriod::get());
+            DataChallenges::<T>::insert(
+                batch_id,
+                request_id,
+                DataChallenge {
+                    challenger: challenger.clone(),
+                    deposit,
+                    respond_by,
+                },
+            );
+
+            Self::deposit_event(Event::DataChallenged {
+                batch_id,
+                request_id,
+                challenger,
+                respond_by,
+            });
+            Ok(())
+        }
+
+        /// Reveal the signature data of a challenged request
+        ///
+        /// Callable by anyone holding the data, which must match the request's
+        /// commitment. A valid signature closes the challenge and pays its
+        /// deposit to the responder; an invalid one proves fraud as
+        /// `challenge_optimistic_batch` would, paying the batch bond to the
+        /// data challenger.
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::respond_batch_data(MAX_VERIFIED_REQUESTS))]
+        pub fn respond_batch_data(
+            origin: OriginFor<T>,
+            batch_id: u64,
+            request_id: u64,
+            message: [u8; 32],
+            public_key: Vec<u8>,
+            signature: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            let responder = ensure_signed(origin)?;
+
+            let batch =
+                PendingBatches::<T>::get(batch_id).ok_or(Error::<T>::PendingBatchNotFound)?;
+            let challenge = DataChallenges::<T>::get(batch_id, request_id)
+                .ok_or(Error::<T>::DataChallengeNotFound)?;
+            let requests = PendingBatchRequests::<T>::get(batch_id).unwrap_or_default();
+            let (_, commitment) = requests
+                .iter()
+                .find(|(id, _)| *id == request_id)
+                .ok_or(Error::<T>::RequestNotInBatch)?;
+            ensure!(
+                *commitment
+                    == Self::request_commitment(request_id, &message, &public_key, &signature),
+                Error::<T>::RequestCommitmentMismatch
+            );
+
+            if !T::SignatureVerifier::verify(&public_key, &message, &signature) {
+                let request_count =
+                    Self::prove_fraud(batch_id, &batch, request_id, challenge.challenger);
+                return Ok(Some(T::WeightInfo::respond_batch_data(request_count)).into());
+            }
+
+            DataChallenges::<T>::remove(batch_id, request_id);
+            // Return the deposit instead if the responder cannot receive it
+            if T::Currency::repatriate_reserved(
+                &challenge.challenger,
+                &responder,
+                challenge.deposit,
+                BalanceStatus::Free,
+            )
+            .is_err()
+            {
+                T::Currency::unreserve(&challenge.challenger, challenge.deposit);
+            }
+
+            Self::deposit_event(Event::DataChallengeAnswered {
+                batch_id,
+                request_id,
+                responder,
+            });
+            Ok(Some(T::WeightInfo::respond_batch_data(0)).into())
+        }
+
+        /// Void an optimistic batch whose challenged signature data was not
+        /// revealed by `respond_by`
+        ///
+        /// Callable by anyone. The batch is discarded, its requests are
+        /// released, its bond is paid to the data challenger and the
+        /// aggregator is slashed and deactivated as by
+        /// `challenge_optimistic_batch`.
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::claim_data_challenge(MAX_VERIFIED_REQUESTS))]
+        pub fn claim_data_challenge(
+            origin: OriginFor<T>,
+            batch_id: u64,
+            request_id: u64,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+
+            let batch =
+                PendingBatches::<T>::get(batch_id).ok_or(Error::<T>::PendingBatchNotFound)?;
+            let challenge = DataChallenges::<T>::get(batch_id, request_id)
+                .ok_or(Error::<T>::DataChallengeNotFound)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(
+                now >= challenge.respond_by,
+                Error::<T>::DataResponsePeriodActive
+            );
+
+            let (slashed, request_count) =
+                Self::discard_pending_batch(batch_id, &batch, &challenge.challenger);
+            Self::mark_fraudulent(&batch.aggregator, now);
+
+            Self::deposit_event(Event::BatchDataWithheld {
+                batch_id,
+                aggregator: batch.aggregator,
+                challenger: challenge.challenger,
+                request_id,
+                slashed,
+            });
+            Ok(Some(T::WeightInfo::claim_data_challenge(request_count)).into())
+        }
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // HELPER FUNCTIONS
+    // ═══════════════════════════════════════════════════════════════════════
+
+    impl<T: Config> Pallet<T> {
+        /// Check if a request ID has been verified
+        pub fn is_request_verified(request_id: u64) -> bool {
+            VerifiedRequests::<T>::contains_key(request_id)
+        }
+
+        /// Get verification info
+        pub fn get_verification_info(request_id: u64) -> Option<(u64, BlockNumberFor<T>)> {
+            VerifiedRequests::<T>::get(request_id)
+        }
+
+        /// Check if a request ID was rejected by a verified batch
+        pub fn is_request_rejected(request_id: u64) -> bool {
+            RejectedRequests::<T>::contains_key(request_id)
+        }
+
+        /// Check if a request ID has been consumed
+        pub fn is_request_consumed(request_id: u64) -> bool {
+            ConsumedRequests::<T>::contains_key(request_id)
+        }
+
+        /// Use up a verified request on behalf of `consumer`
+        ///
+        /// `call` tags the consuming call (e.g. the hash of a signed message)
+        /// and is recorded with the consumer. Fails with `RequestNotVerified`
+        /// or, on reuse, `RequestAlreadyConsumed`; callers run inside a
+        /// dispatch or EVM transaction, so the mark is rolled back with them.
+        pub fn consume_verified_request(
+            request_id: u64,
+            consumer: &T::AccountId,
+            call: [u8; 32],
+        ) -> DispatchResult {
+            ensure!(
+                Self::is_request_verified(request_id),
+                Error::<T>::RequestNotVerified
+            );
+            ensure!(
+                !Self::is_request_consumed(request_id),
+                Error::<T>::RequestAlreadyConsumed
+            );
+
+            ConsumedRequests::<T>::insert(
+                request_id,
+                ConsumptionInfo {
+                    consumer: consumer.clone(),
+                    call,
+                    consumed_at: frame_system::Pallet::<T>::block_number(),
+                },
+            );
+
+            Self::deposit_event(Event::RequestConsumed {
+                request_id,
+                consumer: consumer.clone(),
+                call,
+            });
+            Ok(())
+        }
+
+        /// Allocate the next request ID to `owner`, reserving
+        /// `AllocationDeposit`, and return it
+        pub fn do_allocate_request_id(owner: &T::AccountId) -> Result<u64, DispatchError> {
+            let deposit = T::AllocationDeposit::get();
+            T::Currency::reserve(owner, deposit).map_err(|_| Error::<T>::InsufficientDeposit)?;
+
+            let request_id = NextRequestId::<T>::mutate(|next| {
+                let id = *next;
+                *next = next.saturating_add(1);
+                id
+            });
+            let expires_at = frame_system::Pallet::<T>::block_number()
+                .saturating_add(T::AllocationExpiry::get());
+            RequestOwners::<T>::insert(
+                request_id,
+                RequestAllocation {
+                    owner: owner.clone(),
+                    deposit,
+                    expires_at,
+                },
+            );
+
+            Self::deposit_event(Event::RequestIdAllocated {
+                request_id,
+                owner: owner.clone(),
+            });
+            Ok(request_id)
+        }
+
+        /// Return the allocation deposit of a request being settled
+        fn release_allocation_deposit(request_id: u64) {
+            RequestOwners::<T>::mutate(request_id, |maybe_allocation| {
+                if let Some(allocation) = maybe_allocation {
+                    T::Currency::unreserve(&allocation.owner, allocation.deposit);
+                    allocation.deposit = Zero::zero();
+                }
+            });
+        }
+
+        /// Drop the allocation of an unsettled request if it expired by `now`,
+        /// returning its deposit
+        ///
+        /// Also called for requests released by a revoked or discarded batch,
+        /// which `on_idle` may already have passed over while they were settled
+        /// or pending.
+        fn expire_allocation(request_id: u64, now: BlockNumberFor<T>) {
+            let Some(allocation) = RequestOwners::<T>::get(request_id) else {
+                return;
+            };
+            if allocation.expires_at > now {
+                return;
+            }
+
+            T::Currency::unreserve(&allocation.owner, allocation.deposit);
+            RequestOwners::<T>::remove(request_id);
+            // A request consumed before its batch was revoked cannot be
+            // verified again without an allocation
+            ConsumedRequests::<T>::remove(request_id);
+            Self::deposit_event(Event::AllocationExpired {
+                request_id,
+                owner: allocation.owner,
+            });
+        }
+
+        /// Discard an optimistic batch proven wrong by `request_id`, paying
+        /// its bond to `challenger` and slashing its aggregator, and return
+        /// the number of released requests
+        fn prove_fraud(
+            batch_id: u64,
+            batch: &PendingBatch<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+            request_id: u64,
+            challenger: T::AccountId,
+        ) -> u32 {
+            let (slashed, request_count) =
+                Self::discard_pending_batch(batch_id, batch, &challenger);
+            Self::mark_fraudulent(&batch.aggregator, frame_system::Pallet::<T>::block_number());
+
+            Self::deposit_event(Event::FraudProven {
+                batch_id,
+                aggregator: batch.aggregator.clone(),
+                challenger,
+                request_id,
+                slashed,
+            });
+            request_count
+        }
+
+        /// Remove a pending batch, release its requests, return the deposits
+        /// of its data challenges and pay its bond to `beneficiary`
+        ///
+        /// Returns the amount paid and the number of released requests.
+        fn discard_pending_batch(
+            batch_id: u64,
+            batch: &PendingBatch<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+            beneficiary: &T::AccountId,
+        ) -> (BalanceOf<T>, u32) {
+            let now = frame_system::Pallet::<T>::block_number();
+            PendingBatches::<T>::remove(batch_id);
+            let requests = PendingBatchRequests::<T>::take(batch_id).unwrap_or_default();
+            for (id, _) in requests.iter() {
+                PendingRequests::<T>::remove(id);
+                Self::expire_allocation(*id, now);
+            }
+            // At most one challenge per request
+            for (_, challenge) in DataChallenges::<T>::drain_prefix(batch_id) {
+                T::Currency::unreserve(&challenge.challenger, challenge.deposit);
+            }
+
+            // Burn instead if the beneficiary cannot receive the funds
+            let unslashed = T::Currency::repatriate_reserved(
+                &batch.aggregator,
+                beneficiary,
+                batch.bond,
+                BalanceStatus::Free,
+            )
+            .unwrap_or_else(|_| T::Currency::slash_reserved(&batch.aggregator, batch.bond).1);
+            (batch.bond.saturating_sub(unslashed), requests.len() as u32)
+        }
+
+        /// Slash `InvalidBatchSlash` of the bond of an aggregator proven
+        /// fraudulent at `now` and deactivate it, voiding the optimistic
+        /// batches it still has pending
+        fn mark_fraudulent(aggregator: &T::AccountId, now: BlockNumberFor<T>) {
+            Aggregators::<T>::mutate(aggregator, |maybe_info| {
+                if let Some(info) = maybe_info {
+                    Self::slash(
+                        aggregator,
+                        info,
+                        T::InvalidBatchSlash::get(),
+                        SlashReason::InvalidBatch,
+                    );
+                }
+            });
+            FraudProvenAt::<T>::insert(aggregator, now);
+        }
+
+        /// Whether a request is verified, rejected or claimed by a pending batch
+        fn is_request_settled_or_pending(request_id: u64) -> bool {
+            Self::is_request_verified(request_id)
+                || Self::is_request_rejected(request_id)
+                || PendingRequests::<T>::contains_key(request_id)
+        }
+
+        /// Drop allocations that expired unsettled by `now`, lowest request ID
+        /// first, within `limit`
+        ///
+        /// Settled and pending requests are passed over: their allocation goes
+        /// with their batch. Returns the weight consumed.
+        pub(crate) fn prune_expired_allocations(now: BlockNumberFor<T>, limit: Weight) -> Weight {
+            let mut meter = WeightMeter::with_limit(limit);
+            if meter.try_consume(T::WeightInfo::prune_cursor()).is_err() {
+                return Weight::zero();
             }
 
-            // Compute proof commitment for replay prevention
-            let proof_commitment = Self::compute_proof_commitment(&submission);
-            let commitment_hash = H256::from_slice(&proof_commitment);
+            let start = AllocationCursor::<T>::get();
+            let next_request_id = NextRequestId::<T>::get();
+            let mut cursor = start;
+            while cursor < next_request_id {
+                if meter
+                    .try_consume(T::WeightInfo::prune_allocation())
+                    .is_err()
+                {
+                    break;
+                }
+                if let Some(allocation) = RequestOwners::<T>::get(cursor) {
+                    // Expiry follows allocation order
+                    if allocation.expires_at > now {
+                        break;
+                    }
+                    if !Self::is_request_settled_or_pending(cursor) {
+                        Self::expire_allocation(cursor, now);
+                    }
+                }
+                cursor = cursor.saturating_add(1);
+            }
+
+            if cursor != start {
+                AllocationCursor::<T>::put(cursor);
+            }
+            meter.consumed()
+        }
+
+        /// Check if account is active aggregator
+        pub fn is_aggregator(account: &T::AccountId) -> bool {
+            Aggregators::<T>::get(account)
+                .map(|info| info.active)
+                .unwrap_or(false)
+        }
 
+        /// Add an active aggregator holding `bond`, within `MaxAggregators`
+        fn do_register(aggregator: &T::AccountId, bond: BalanceOf<T>) -> DispatchResult {
             ensure!(
-                !ProofCommitments::<T>::contains_key(commitment_hash),
-                Error::<T>::ProofAlreadyUsed
+                !Aggregators::<T>::contains_key(aggregator),
+                Error::<T>::AggregatorAlreadyRegistered
             );
-
-            // Verify merkle root matches claimed request IDs
-            let computed_root =
-                Self::compute_merkle_root(&submission.public_values.verified_request_ids);
             ensure!(
-                computed_root == submission.public_values.requests_root,
-                Error::<T>::InvalidMerkleRoot
+                Aggregators::<T>::count() < T::MaxAggregators::get(),
+                Error::<T>::TooManyAggregators
             );
 
-            // ═══════════════════════════════════════════════════════════════
-            // STARK PROOF VERIFICATION
-            // ═══════════════════════════════════════════════════════════════
-
-            let proof_valid = Self::verify_sp1_proof(
-                &submission.proof,
-                &submission.public_values,
-                &submission.vkey_hash,
+            let current_block = frame_system::Pallet::<T>::block_number();
+            Aggregators::<T>::insert(
+                aggregator,
+                AggregatorInfo {
+                    registered_at: current_block,
+                    proofs_submitted: 0,
+                    active: true,
+                    bond,
+                    last_active: current_block,
+                    rewards_earned: Zero::zero(),
+                    unbonding_until: None,
+                },
             );
+            Ok(())
+        }
 
-            if !proof_valid {
-                Self::deposit_event(Event::ProofRejected {
-                    batch_id: submission.batch_id,
+        /// Pay `ProofReward` from `RewardAccount`, skipping it if unaffordable
+        fn pay_reward(
+            aggregator: &T::AccountId,
+            info: &mut AggregatorInfo<BalanceOf<T>, BlockNumberFor<T>>,
+            batch_id: u64,
+        ) {
+            let reward = T::ProofReward::get();
+            if reward.is_zero() {
+                return;
+            }
+
+            let paid = T::Currency::transfer(
+                &T::RewardAccount::get(),
+                aggregator,
+                reward,
+                ExistenceRequirement::KeepAlive,
+            );
+            if paid.is_ok() {
+                info.rewards_earned = info.rewards_earned.saturating_add(reward);
+                Self::deposit_event(Event::AggregatorRewarded {
                     aggregator: aggregator.clone(),
-                    reason: RejectReason::StarkVerificationFailed,
+                    batch_id,
+                    amount: reward,
                 });
-                return Err(Error::<T>::ProofVerificationFailed.into());
             }
+        }
 
-            // ═══════════════════════════════════════════════════════════════
-            // UPDATE STORAGE
-            // ═══════════════════════════════════════════════════════════════
+        /// Move `fraction` of the bond to `RewardAccount` and deactivate
+        fn slash(
+            aggregator: &T::AccountId,
+            info: &mut AggregatorInfo<BalanceOf<T>, BlockNumberFor<T>>,
+            fraction: Perbill,
+            reason: SlashReason,
+        ) {
+            let amount = fraction.mul_floor(info.bond);
+            // Burn instead if the reward account cannot receive the funds
+            let unslashed = T::Currency::repatriate_reserved(
+                aggregator,
+                &T::RewardAccount::get(),
+                amount,
+                BalanceStatus::Free,
+            )
+            .unwrap_or_else(|_| T::Currency::slash_reserved(aggregator, amount).1);
+            let slashed = amount.saturating_sub(unslashed);
+
+            info.bond = info.bond.saturating_sub(slashed);
+            info.active = false;
+
+            Self::deposit_event(Event::AggregatorSlashed {
+                aggregator: aggregator.clone(),
+                amount: slashed,
+                reason,
+            });
+        }
 
-            let current_block = frame_system::Pallet::<T>::block_number();
+        /// Prune batches verified `RetentionPeriod` or more blocks before `now`,
+        /// oldest first, within `limit`
+        ///
+        /// A pruned batch leaves a tombstone on its ID for another
+        /// `RetentionPeriod`; expired tombstones are lifted first. A batch
+        /// whose requests do not fit is resumed on the next call. Returns the
+        /// weight consumed.
+        pub(crate) fn prune_expired(now: BlockNumberFor<T>, limit: Weight) -> Weight {
+            let mut meter = WeightMeter::with_limit(limit);
+            if meter.try_consume(T::WeightInfo::prune_cursor()).is_err() {
+                return Weight::zero();
+            }
 
-            // Store proof commitment
-            ProofCommitments::<T>::insert(commitment_hash, current_block);
+            let mut cursor = PruneCursor::<T>::get();
+            let start = cursor.clone();
+            let retention = T::RetentionPeriod::get();
 
-            // Store batch info
-            VerifiedBatches::<T>::insert(
-                submission.batch_id,
-                BatchInfo {
-                    aggregator: aggregator.clone(),
-                    verified_at: current_block,
-                    signature_count: submission.public_values.verified_count,
-                    requests_root: submission.public_values.requests_root,
-                    proof_commitment,
-                },
-            );
+            while cursor.tombstone_head < cursor.head {
+                if meter.try_consume(T::WeightInfo::prune_tombstone()).is_err() {
+                    break;
+                }
+                // Invalidated batches leave no tombstone and are dequeued
+                // when pruned
+                if let Some(entry) = BatchQueue::<T>::get(cursor.tombstone_head) {
+                    // Tombstones expire in pruning order
+                    if PrunedBatches::<T>::get(entry.batch_id)
+                        .is_some_and(|expires_at| expires_at > now)
+                    {
+                        break;
+                    }
+                    PrunedBatches::<T>::remove(entry.batch_id);
+                    BatchQueue::<T>::remove(cursor.tombstone_head);
+                }
+                cursor.tombstone_head = cursor.tombstone_head.saturating_add(1);
+            }
+
+            while cursor.head < cursor.tail {
+                if meter.try_consume(T::WeightInfo::prune_batch()).is_err() {
+                    break;
+                }
+                let Some(entry) = BatchQueue::<T>::get(cursor.head) else {
+                    cursor.head = cursor.head.saturating_add(1);
+                    cursor.request_index = 0;
+                    continue;
+                };
+                if entry.verified_at.saturating_add(retention) > now {
+                    break;
+                }
+
+                // The batch may have been invalidated, and its ID proven again
+                let live = VerifiedBatches::<T>::get(entry.batch_id)
+                    .is_some_and(|batch| batch.proof_commitment == entry.proof_commitment);
+                if live {
+                    // Verified requests first, then rejected ones
+                    let request_ids = BatchRequestIds::<T>::get(entry.batch_id).unwrap_or_default();
+                    let rejected_ids =
+                        BatchRejectedIds::<T>::get(entry.batch_id).unwrap_or_default();
+                    let requests = request_ids.iter().map(|id| (id, false));
+                    let rejections = rejected_ids.iter().map(|id| (id, true));
+                    for (request_id, rejected) in requests
+                        .chain(rejections)
+                        .skip(cursor.request_index as usize)
+                    {
+                        if meter.try_consume(T::WeightInfo::prune_request()).is_err() {
+                            PruneCursor::<T>::put(cursor);
+                            return meter.consumed();
+                        }
+                        if rejected {
+                            Self::prune_rejected_request(*request_id, entry.batch_id);
+                        } else {
+                            Self::prune_request(*request_id, entry.batch_id);
+                        }
+                        cursor.request_index = cursor.request_index.saturating_add(1);
+                    }
+                    VerifiedBatches::<T>::remove(entry.batch_id);
+                    BatchRequestIds::<T>::remove(entry.batch_id);
+                    BatchRejectedIds::<T>::remove(entry.batch_id);
+                    PrunedBatches::<T>::insert(entry.batch_id, now.saturating_add(retention));
+                } else {
+                    BatchQueue::<T>::remove(cursor.head);
+                }
+                ProofCommitments::<T>::remove(H256::from(entry.proof_commitment));
 
-            // Mark requests as verified
-            for request_id in submission.public_values.verified_request_ids.iter() {
-                VerifiedRequests::<T>::insert(request_id, (submission.batch_id, current_block));
+                cursor.head = cursor.head.saturating_add(1);
+                cursor.request_index = 0;
+                Self::deposit_event(Event::BatchPruned {
+                    batch_id: entry.batch_id,
+                });
             }
 
-            // Update stats
-            aggregator_info.proofs_submitted += 1;
-            Aggregators::<T>::insert(&aggregator, aggregator_info);
+            if cursor != start {
+                PruneCursor::<T>::put(cursor);
+            }
+            meter.consumed()
+        }
 
-            TotalProofsVerified::<T>::mutate(|n| *n += 1);
-            TotalSignaturesVerified::<T>::mutate(|n| {
-                *n += submission.public_values.verified_count as u64
-            });
+        /// Remove a request verified by `batch_id`, with its allocation and
+        /// consumption record
+        pub(crate) fn prune_request(request_id: u64, batch_id: u64) {
+            let verified_by_batch =
+                VerifiedRequests::<T>::get(request_id).is_some_and(|(id, _)| id == batch_id);
+            if verified_by_batch {
+                VerifiedRequests::<T>::remove(request_id);
+                RequestOwners::<T>::remove(request_id);
+                ConsumedRequests::<T>::remove(request_id);
+            }
+        }
 
-            Self::deposit_event(Event::ProofVerified {
-                batch_id: submission.batch_id,
-                aggregator,
-                signature_count: submission.public_values.verified_count,
-                block_number: current_block,
-            });
+        /// Check that `vkey_hash` is allowed and active at `now`
+        fn ensure_vkey_active(vkey_hash: &[u8; 32], now: &BlockNumberFor<T>) -> DispatchResult {
+            let vkey = AllowedVKeys::<T>::get(vkey_hash).ok_or(Error::<T>::InvalidVKeyHash)?;
+            ensure!(vkey.is_active_at(now), Error::<T>::VKeyNotActive);
+            Ok(())
+        }
 
+        /// Reject batch IDs that are verified, pending or recently pruned
+        fn ensure_batch_id_available(batch_id: u64) -> DispatchResult {
+            ensure!(
+                !VerifiedBatches::<T>::contains_key(batch_id),
+                Error::<T>::BatchAlreadyVerified
+            );
+            ensure!(
+                !PendingBatches::<T>::contains_key(batch_id),
+                Error::<T>::BatchPending
+            );
+            ensure!(
+                !PrunedBatches::<T>::contains_key(batch_id),
+                Error::<T>::BatchExpired
+            );
             Ok(())
         }
-    }
 
-    // ═══════════════════════════════════════════════════════════════════════
-    // HELPER FUNCTIONS
-    // ═══════════════════════════════════════════════════════════════════════
+        /// Check the protocol fields of the public values of `batch_id`
+        fn ensure_valid_public_values(
+            batch_id: u64,
+            public_values: &PublicValues,
+        ) -> DispatchResult {
+            ensure!(
+                public_values.version == REML_VERSION,
+                Error::<T>::InvalidPublicValues
+            );
+            ensure!(
+                public_values.chain_id == TESSERAX_CHAIN_ID,
+                Error::<T>::InvalidPublicValues
+            );
+            ensure!(
+                public_values.batch_id == batch_id,
+                Error::<T>::InvalidPublicValues
+            );
+            Ok(())
+        }
 
-    impl<T: Config> Pallet<T> {
-        /// Check if a request ID has been verified
-        pub fn is_request_verified(request_id: u64) -> bool {
-            VerifiedRequests::<T>::contains_key(request_id)
+        /// Check the requests root, and that each verified or rejected request
+        /// ID is allocated, appears once and is not verified, rejected,
+        /// consumed or pending elsewhere
+        ///
+        /// `seen` holds the request IDs of batches settled in the same call.
+        fn ensure_settleable_requests(
+            public_values: &PublicValues,
+            seen: &mut BTreeSet<u64>,
+        ) -> DispatchResult {
+            ensure!(
+                public_values.request_count() <= MAX_VERIFIED_REQUESTS,
+                Error::<T>::InvalidPublicValues
+            );
+            let computed_root = Self::compute_merkle_root(&public_values.verified_request_ids);
+            ensure!(
+                computed_root == public_values.requests_root,
+                Error::<T>::InvalidMerkleRoot
+            );
+
+            let rejected_ids = public_values.rejected_request_ids.iter().map(|(id, _)| id);
+            for request_id in public_values
+                .verified_request_ids
+                .iter()
+                .chain(rejected_ids)
+            {
+                ensure!(
+                    RequestOwners::<T>::contains_key(request_id),
+                    Error::<T>::RequestNotAllocated
+                );
+                ensure!(
+                    !Self::is_request_verified(*request_id)
+                        && !Self::is_request_consumed(*request_id),
+                    Error::<T>::RequestAlreadyVerified
+                );
+                ensure!(
+                    !Self::is_request_rejected(*request_id),
+                    Error::<T>::RequestAlreadyRejected
+                );
+                ensure!(
+                    !PendingRequests::<T>::contains_key(request_id),
+                    Error::<T>::RequestPending
+                );
+                ensure!(seen.insert(*request_id), Error::<T>::DuplicateRequestId);
+            }
+            Ok(())
         }
 
-        /// Get verification info
-        pub fn get_verification_info(request_id: u64) -> Option<(u64, BlockNumberFor<T>)> {
-            VerifiedRequests::<T>::get(request_id)
+        /// Record a verified batch and its verified and rejected requests, and
+        /// queue it for pruning
+        fn settle_batch(
+            batch_id: u64,
+            batch: BatchInfo<T::AccountId, BlockNumberFor<T>>,
+            request_ids: BoundedVec<u64, ConstU32<MAX_VERIFIED_REQUESTS>>,
+            rejected: BoundedVec<(u64, RequestRejection), ConstU32<MAX_VERIFIED_REQUESTS>>,
+        ) {
+            let verified_at = batch.verified_at;
+            let proof_commitment = batch.proof_commitment;
+            let signature_count = batch.signature_count;
+
+            ProofCommitments::<T>::insert(H256::from(proof_commitment), verified_at);
+            VerifiedBatches::<T>::insert(batch_id, batch);
+
+            for request_id in request_ids.iter() {
+                VerifiedRequests::<T>::insert(request_id, (batch_id, verified_at));
+                Self::release_allocation_deposit(*request_id);
+            }
+            // Keep the leaves so inclusion proofs can be served later
+            BatchRequestIds::<T>::insert(batch_id, request_ids);
+
+            if !rejected.is_empty() {
+                let mut rejected_ids = BoundedVec::<u64, ConstU32<MAX_VERIFIED_REQUESTS>>::new();
+                for (request_id, reason) in rejected.iter() {
+                    RejectedRequests::<T>::insert(
+                        request_id,
+                        RejectionInfo {
+                            batch_id,
+                            reason: *reason,
+                            rejected_at: verified_at,
+                        },
+                    );
+                    Self::release_allocation_deposit(*request_id);
+                    // Bounded like `rejected`
+                    let _ = rejected_ids.try_push(*request_id);
+                }
+                BatchRejectedIds::<T>::insert(batch_id, rejected_ids);
+                Self::deposit_event(Event::RequestsRejected {
+                    batch_id,
+                    count: rejected.len() as u32,
+                });
+            }
+
+            // Queue the batch for pruning after `RetentionPeriod`
+            let mut cursor = PruneCursor::<T>::get();
+            BatchQueue::<T>::insert(
+                cursor.tail,
+                QueuedBatch {
+                    batch_id,
+                    verified_at,
+                    proof_commitment,
+                },
+            );
+            cursor.tail = cursor.tail.saturating_add(1);
+            PruneCursor::<T>::put(cursor);
+
+            TotalProofsVerified::<T>::mutate(|n| *n += 1);
+            TotalSignaturesVerified::<T>::mutate(|n| *n += signature_count as u64);
         }
 
-        /// Check if account is active aggregator
-        pub fn is_aggregator(account: &T::AccountId) -> bool {
-            Aggregators::<T>::get(account)
-                .map(|info| info.active)
-                .unwrap_or(false)
+        /// Commitment to the signature data of one request:
+        /// keccak256(request_id (LE) || message || public_key || signature)
+        ///
+        /// Aggregators post it for each request of an optimistic batch and
+        /// challengers open it in a fraud proof.
+        pub fn request_commitment(
+            request_id: u64,
+            message: &[u8; 32],
+            public_key: &[u8],
+            signature: &[u8],
+        ) -> [u8; 32] {
+            let mut data =
+                alloc::vec::Vec::with_capacity(8 + 32 + public_key.len() + signature.len());
+            data.extend_from_slice(&request_id.to_le_bytes());
+            data.extend_from_slice(message);
+            data.extend_from_slice(public_key);
+            data.extend_from_slice(signature);
+            sp_core::keccak_256(&data)
+        }
+
+        /// Commitment of an optimistic batch:
+        /// blake2_256(batch_id || requests_root || request commitments)
+        fn compute_optimistic_commitment(submission: &OptimisticSubmission) -> [u8; 32] {
+            let mut data =
+                alloc::vec::Vec::with_capacity(8 + 32 + 32 * submission.request_commitments.len());
+            data.extend_from_slice(&submission.batch_id.to_le_bytes());
+            data.extend_from_slice(&submission.public_values.requests_root);
+            for commitment in submission.request_commitments.iter() {
+                data.extend_from_slice(commitment);
+            }
+            sp_core::blake2_256(&data)
+        }
+
+        /// Remove a request rejected by `batch_id`, and its allocation
+        pub(crate) fn prune_rejected_request(request_id: u64, batch_id: u64) {
+            if RejectedRequests::<T>::get(request_id).is_some_and(|info| info.batch_id == batch_id)
+            {
+                RejectedRequests::<T>::remove(request_id);
+                RequestOwners::<T>::remove(request_id);
+            }
         }
 
         /// Compute proof commitment hash
@@ -449,43 +2605,153 @@ pub mod pallet {
             blake2_256(&data)
         }
 
-        /// Compute merkle root from request IDs
-        fn compute_merkle_root(ids: &[u64]) -> [u8; 32] {
+        /// Commitment of an aggregated proof:
+        /// blake2_256(vkey || batches_root || proof_hash)
+        fn compute_aggregated_proof_commitment(submission: &AggregatedProofSubmission) -> [u8; 32] {
             use sp_core::blake2_256;
 
-            if ids.is_empty() {
+            let mut data = [0u8; 32 + 32 + 32];
+            data[..32].copy_from_slice(&submission.vkey_hash);
+            data[32..64].copy_from_slice(&submission.public_values.batches_root);
+            data[64..].copy_from_slice(&blake2_256(&submission.proof));
+
+            blake2_256(&data)
+        }
+
+        /// Proof commitment recorded for child `batch_id` of an aggregated
+        /// proof: blake2_256(aggregate commitment || batch_id)
+        fn child_proof_commitment(aggregate_commitment: &[u8; 32], batch_id: u64) -> [u8; 32] {
+            let mut data = [0u8; 32 + 8];
+            data[..32].copy_from_slice(aggregate_commitment);
+            data[32..].copy_from_slice(&batch_id.to_le_bytes());
+            sp_core::blake2_256(&data)
+        }
+
+        /// Root of roots: Merkle root over
+        /// blake2_256(batch_id (LE) || requests_root) of each child batch
+        pub(crate) fn compute_batches_root(batches: &[PublicValues]) -> [u8; 32] {
+            if batches.is_empty() {
                 return [0u8; 32];
             }
 
-            // Hash leaves
-            let mut leaves: alloc::vec::Vec<[u8; 32]> = ids
+            let mut level: alloc::vec::Vec<[u8; 32]> = batches
                 .iter()
-                .map(|id| {
-                    let mut leaf = [0u8; 32];
-                    leaf[..8].copy_from_slice(&id.to_le_bytes());
-                    blake2_256(&leaf)
+                .map(|batch| {
+                    let mut leaf = [0u8; 8 + 32];
+                    leaf[..8].copy_from_slice(&batch.batch_id.to_le_bytes());
+                    leaf[8..].copy_from_slice(&batch.requests_root);
+                    sp_core::blake2_256(&leaf)
                 })
                 .collect();
 
-            // Build tree
-            while leaves.len() > 1 {
-                let mut next = alloc::vec::Vec::with_capacity((leaves.len() + 1) / 2);
+            while level.len() > 1 {
+                level = Self::merkle_parent_level(&level);
+            }
+
+            level[0]
+        }
+
+        /// Merkle inclusion proof of a verified request against its batch root
+        pub fn request_inclusion_proof(
+            request_id: u64,
+        ) -> Option<runtime_api::RequestInclusionProof> {
+            let (batch_id, _) = VerifiedRequests::<T>::get(request_id)?;
+            let batch = VerifiedBatches::<T>::get(batch_id)?;
+            let ids = BatchRequestIds::<T>::get(batch_id)?;
+            let leaf_index = ids.iter().position(|id| *id == request_id)?;
+
+            Some(runtime_api::RequestInclusionProof {
+                batch_id,
+                requests_root: batch.requests_root,
+                leaf_index: leaf_index as u32,
+                leaf_count: ids.len() as u32,
+                path: Self::compute_merkle_path(&ids, leaf_index),
+            })
+        }
 
-                for i in (0..leaves.len()).step_by(2) {
-                    if i + 1 < leaves.len() {
-                        let mut combined = [0u8; 64];
-                        combined[..32].copy_from_slice(&leaves[i]);
-                        combined[32..].copy_from_slice(&leaves[i + 1]);
-                        next.push(blake2_256(&combined));
+        /// Check a Merkle inclusion path for `request_id` against `root`
+        pub fn verify_inclusion_proof(
+            root: &[u8; 32],
+            request_id: u64,
+            path: &[runtime_api::MerkleProofNode],
+        ) -> bool {
+            let computed = path
+                .iter()
+                .fold(Self::merkle_leaf(request_id), |acc, node| {
+                    if node.is_left {
+                        Self::merkle_node(&node.hash, &acc)
                     } else {
-                        next.push(leaves[i]);
+                        Self::merkle_node(&acc, &node.hash)
                     }
-                }
+                });
+            computed == *root
+        }
+
+        /// Leaf hash: blake2_256 of the request ID (LE) zero-padded to 32 bytes
+        fn merkle_leaf(id: u64) -> [u8; 32] {
+            let mut leaf = [0u8; 32];
+            leaf[..8].copy_from_slice(&id.to_le_bytes());
+            sp_core::blake2_256(&leaf)
+        }
+
+        /// Inner node hash: blake2_256(left || right)
+        fn merkle_node(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+            let mut combined = [0u8; 64];
+            combined[..32].copy_from_slice(left);
+            combined[32..].copy_from_slice(right);
+            sp_core::blake2_256(&combined)
+        }
+
+        /// Hash one tree level into the next, promoting an odd trailing node
+        fn merkle_parent_level(level: &[[u8; 32]]) -> alloc::vec::Vec<[u8; 32]> {
+            level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => Self::merkle_node(left, right),
+                    [single] => *single,
+                    _ => unreachable!("chunks(2) yields one or two elements"),
+                })
+                .collect()
+        }
+
+        /// Compute merkle root from request IDs
+        pub(crate) fn compute_merkle_root(ids: &[u64]) -> [u8; 32] {
+            if ids.is_empty() {
+                return [0u8; 32];
+            }
+
+            let mut level: alloc::vec::Vec<[u8; 32]> =
+                ids.iter().map(|id| Self::merkle_leaf(*id)).collect();
+
+            while level.len() > 1 {
+                level = Self::merkle_parent_level(&level);
+            }
 
-                leaves = next;
+            level[0]
+        }
+
+        /// Sibling path from leaf `index` to the root
+        fn compute_merkle_path(
+            ids: &[u64],
+            mut index: usize,
+        ) -> alloc::vec::Vec<runtime_api::MerkleProofNode> {
+            let mut level: alloc::vec::Vec<[u8; 32]> =
+                ids.iter().map(|id| Self::merkle_leaf(*id)).collect();
+            let mut path = alloc::vec::Vec::new();
+
+            while level.len() > 1 {
+                let sibling = index ^ 1;
+                if sibling < level.len() {
+                    path.push(runtime_api::MerkleProofNode {
+                        hash: level[sibling],
+                        is_left: sibling < index,
+                    });
+                }
+                level = Self::merkle_parent_level(&level);
+                index /= 2;
             }
 
-            leaves[0]
+            path
         }
 
         /// Verify SP1 proof
@@ -520,8 +2786,8 @@ pub mod pallet {
                 return false;
             }
 
-            // Verify public values are non-zero
-            if public_values.verified_count == 0 {
+            // A batch must settle at least one request, verified or rejected
+            if public_values.verified_count == 0 && public_values.rejected_request_ids.is_empty() {
                 return false;
             }
 
@@ -564,17 +2830,78 @@ pub mod pallet {
 
             // Verify public values encoding is in proof
             // The proof should commit to the public values
-            use sp_core::blake2_256;
-            let public_hash = {
-                let mut data = alloc::vec::Vec::new();
-                data.push(public_values.version);
-                data.extend_from_slice(&public_values.chain_id.to_le_bytes());
-                data.extend_from_slice(&public_values.batch_id.to_le_bytes());
-                data.extend_from_slice(&public_values.verified_count.to_le_bytes());
-                data.extend_from_slice(&public_values.requests_root);
-                blake2_256(&data)
-            };
+            Self::proof_commits_to(proof, &Self::compute_public_values_hash(public_values))
+        }
+
+        /// Hash of the public values a batch proof must commit to
+        ///
+        /// blake2_256(version || chain_id (LE) || batch_id (LE) ||
+        /// verified_count (LE) || requests_root || rejected_request_ids), the
+        /// rejections SCALE-encoded. The guest always commits its rejections,
+        /// so they are bound even when there are none.
+        pub(crate) fn compute_public_values_hash(public_values: &PublicValues) -> [u8; 32] {
+            let mut data = alloc::vec::Vec::new();
+            data.push(public_values.version);
+            data.extend_from_slice(&public_values.chain_id.to_le_bytes());
+            data.extend_from_slice(&public_values.batch_id.to_le_bytes());
+            data.extend_from_slice(&public_values.verified_count.to_le_bytes());
+            data.extend_from_slice(&public_values.requests_root);
+            data.extend_from_slice(&public_values.rejected_request_ids.encode());
+            sp_core::blake2_256(&data)
+        }
+
+        /// Verify an aggregated SP1 proof
+        ///
+        /// Checks the proof structure and requires the proof to contain the
+        /// exact hash of its public values (see
+        /// [`Self::compute_aggregated_public_values_hash`]). Unlike
+        /// `verify_sp1_proof` there is no testnet fallback: an aggregated proof
+        /// settles up to `MAX_AGGREGATED_BATCHES` batches at once.
+        fn verify_aggregated_proof(
+            proof: &[u8],
+            vkey_hash: &[u8; 32],
+            public_values: &AggregatedPublicValues,
+        ) -> bool {
+            if proof.len() < MIN_PROOF_SIZE && proof.len() != GROTH16_PROOF_SIZE {
+                return false;
+            }
+
+            // Each child must hold up as its own proof would
+            let children_valid = public_values.batches.iter().all(|batch| {
+                (batch.verified_count != 0 || !batch.rejected_request_ids.is_empty())
+                    && batch.verified_count as usize == batch.verified_request_ids.len()
+            });
+            if !children_valid {
+                return false;
+            }
+
+            let public_hash = Self::compute_aggregated_public_values_hash(vkey_hash, public_values);
+            proof.windows(32).any(|window| window == public_hash)
+        }
+
+        /// Hash of the public values an aggregated proof must commit to
+        ///
+        /// blake2_256(version || chain_id (LE) || vkey_hash || batch_vkey_hash ||
+        /// batches_root || the public values hash of each child), binding both
+        /// guests and everything each child settles, not only its root.
+        pub(crate) fn compute_aggregated_public_values_hash(
+            vkey_hash: &[u8; 32],
+            public_values: &AggregatedPublicValues,
+        ) -> [u8; 32] {
+            let mut data = alloc::vec::Vec::new();
+            data.push(public_values.version);
+            data.extend_from_slice(&public_values.chain_id.to_le_bytes());
+            data.extend_from_slice(vkey_hash);
+            data.extend_from_slice(&public_values.batch_vkey_hash);
+            data.extend_from_slice(&public_values.batches_root);
+            for batch in public_values.batches.iter() {
+                data.extend_from_slice(&Self::compute_public_values_hash(batch));
+            }
+            sp_core::blake2_256(&data)
+        }
 
+        /// Whether `proof` commits to the public values hashed to `public_hash`
+        fn proof_commits_to(proof: &[u8], public_hash: &[u8; 32]) -> bool {
             // Check if proof contains or commits to public values
             // In real SP1 proofs, public values are cryptographically bound
             let mut valid_public_binding = false;
@@ -584,7 +2911,7 @@ pub mod pallet {
                 let window = &proof[i..i + 32];
 
                 // Direct match
-                if window == public_hash {
+                if window == *public_hash {
                     valid_public_binding = true;
                     break;
                 }
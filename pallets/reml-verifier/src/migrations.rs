@@ -0,0 +1,439 @@
+//! Storage migrations for pallet-reml-verifier
+
+use crate::{
+    AggregatorInfo, Aggregators, AllocationCursor, BatchInfo, BatchQueue, BatchRequestIds, Config,
+    ConsumedRequests, NextRequestId, Pallet, PruneCursor, PruneState, QueuedBatch,
+    RequestAllocation, RequestOwners, VerifiedBatches, VerifiedRequests,
+};
+use frame_support::{
+    migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+
+/// v0 → v1: record the vkey hash in `BatchInfo`
+///
+/// Batches verified before the upgrade did not store their vkey; they are
+/// migrated with a zero `vkey_hash`.
+pub type MigrateV0ToV1<T> = VersionedMigration<
+    0,
+    1,
+    v1::UncheckedMigrateV0ToV1<T>,
+    Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+>;
+
+/// v1 → v2: bond, liveness and reward fields in `AggregatorInfo`, counted
+/// `Aggregators`
+///
+/// Existing aggregators were registered by the admin and hold no bond. Their
+/// liveness window starts at the upgrade block.
+pub type MigrateV1ToV2<T> = VersionedMigration<
+    1,
+    2,
+    v2::UncheckedMigrateV1ToV2<T>,
+    Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+>;
+
+/// v2 → v3: queue verified batches for retention pruning
+///
+/// Existing batches are queued oldest first, so they expire `RetentionPeriod`
+/// blocks after they were verified like new ones.
+pub type MigrateV2ToV3<T> = VersionedMigration<
+    2,
+    3,
+    v3::UncheckedMigrateV2ToV3<T>,
+    Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+>;
+
+/// v3 → v4: start on-chain request ID allocation
+///
+/// Request IDs were chosen by clients before. `NextRequestId` starts above
+/// every verified or consumed ID, so allocated IDs never collide with them.
+pub type MigrateV3ToV4<T> = VersionedMigration<
+    3,
+    4,
+    v4::UncheckedMigrateV3ToV4<T>,
+    Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+>;
+
+/// v4 → v5: allocation deposit and expiry in `RequestOwners`
+///
+/// Allocations made before the upgrade reserved no deposit. They expire
+/// `AllocationExpiry` blocks after the upgrade, and the expiry cursor starts at
+/// the lowest allocated ID.
+pub type MigrateV4ToV5<T> = VersionedMigration<
+    4,
+    5,
+    v5::UncheckedMigrateV4ToV5<T>,
+    Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+>;
+
+/// v5 → v6: per-ID tombstones for pruned batch IDs
+///
+/// `PruneState` drops the highest pruned batch ID and gains the tombstone
+/// cursor. IDs pruned before the upgrade get no tombstone and can be used
+/// again; their requests are no longer allocated, so a batch reusing one
+/// cannot settle them.
+pub type MigrateV5ToV6<T> = VersionedMigration<
+    5,
+    6,
+    v6::UncheckedMigrateV5ToV6<T>,
+    Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+>;
+
+/// v6 → v7: drop consumed requests left behind by pruned batches
+///
+/// Consumed requests used to keep their verification, allocation and
+/// consumption record when their batch was pruned. They are removed, as are
+/// consumption records of revoked requests whose allocation already expired.
+pub type MigrateV6ToV7<T> = VersionedMigration<
+    6,
+    7,
+    v7::UncheckedMigrateV6ToV7<T>,
+    Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+>;
+
+mod v0 {
+    use super::*;
+
+    /// `BatchInfo` before the vkey hash was recorded
+    #[derive(Encode, Decode)]
+    pub struct BatchInfo<AccountId, BlockNumber> {
+        pub aggregator: AccountId,
+        pub verified_at: BlockNumber,
+        pub signature_count: u32,
+        pub requests_root: [u8; 32],
+        pub proof_commitment: [u8; 32],
+    }
+}
+
+pub mod v1 {
+    use super::*;
+
+    /// `AggregatorInfo` before bonding
+    #[derive(Encode, Decode)]
+    pub(super) struct OldAggregatorInfo<BlockNumber> {
+        pub registered_at: BlockNumber,
+        pub proofs_submitted: u64,
+        pub active: bool,
+    }
+
+    /// Unversioned v0 → v1 translation, use [`MigrateV0ToV1`]
+    pub struct UncheckedMigrateV0ToV1<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            VerifiedBatches::<T>::translate::<v0::BatchInfo<T::AccountId, BlockNumberFor<T>>, _>(
+                |_, old| {
+                    translated += 1;
+                    Some(BatchInfo {
+                        aggregator: old.aggregator,
+                        verified_at: old.verified_at,
+                        signature_count: old.signature_count,
+                        requests_root: old.requests_root,
+                        proof_commitment: old.proof_commitment,
+                        vkey_hash: [0u8; 32],
+                    })
+                },
+            );
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<alloc::vec::Vec<u8>, sp_runtime::TryRuntimeError> {
+            Ok((VerifiedBatches::<T>::iter_keys().count() as u64).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: alloc::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let before = u64::decode(&mut &state[..])
+                .map_err(|_| sp_runtime::TryRuntimeError::Other("Invalid pre-upgrade state"))?;
+            ensure!(
+                VerifiedBatches::<T>::iter().count() as u64 == before,
+                "VerifiedBatches lost entries during migration"
+            );
+            Ok(())
+        }
+    }
+}
+
+pub mod v2 {
+    use super::*;
+    use sp_runtime::traits::Zero;
+
+    /// Unversioned v1 → v2 translation, use [`MigrateV1ToV2`]
+    pub struct UncheckedMigrateV1ToV2<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV1ToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let now = frame_system::Pallet::<T>::block_number();
+            Aggregators::<T>::translate::<v1::OldAggregatorInfo<BlockNumberFor<T>>, _>(|_, old| {
+                Some(AggregatorInfo {
+                    registered_at: old.registered_at,
+                    proofs_submitted: old.proofs_submitted,
+                    active: old.active,
+                    bond: Zero::zero(),
+                    last_active: now,
+                    rewards_earned: Zero::zero(),
+                    unbonding_until: None,
+                })
+            });
+            let count = Aggregators::<T>::initialize_counter() as u64;
+            // Translation plus the counter pass, and the counter write
+            T::DbWeight::get().reads_writes(count.saturating_mul(2), count.saturating_add(1))
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<alloc::vec::Vec<u8>, sp_runtime::TryRuntimeError> {
+            Ok((Aggregators::<T>::iter_keys().count() as u32).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: alloc::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let before = u32::decode(&mut &state[..])
+                .map_err(|_| sp_runtime::TryRuntimeError::Other("Invalid pre-upgrade state"))?;
+            ensure!(
+                Aggregators::<T>::count() == before,
+                "Aggregator counter does not match the migrated entries"
+            );
+            Ok(())
+        }
+    }
+}
+
+pub mod v3 {
+    use super::*;
+    use alloc::vec::Vec;
+
+    /// Unversioned v2 → v3 translation, use [`MigrateV2ToV3`]
+    pub struct UncheckedMigrateV2ToV3<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV2ToV3<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut batches: Vec<_> = VerifiedBatches::<T>::iter()
+                .map(|(batch_id, batch)| QueuedBatch {
+                    batch_id,
+                    verified_at: batch.verified_at,
+                    proof_commitment: batch.proof_commitment,
+                })
+                .collect();
+            batches.sort_by(|a, b| (a.verified_at, a.batch_id).cmp(&(b.verified_at, b.batch_id)));
+
+            let count = batches.len() as u64;
+            for (position, entry) in batches.into_iter().enumerate() {
+                BatchQueue::<T>::insert(position as u64, entry);
+            }
+            PruneCursor::<T>::put(PruneState {
+                tail: count,
+                ..Default::default()
+            });
+            T::DbWeight::get().reads_writes(count, count.saturating_add(1))
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            Ok((VerifiedBatches::<T>::iter_keys().count() as u64).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let before = u64::decode(&mut &state[..])
+                .map_err(|_| sp_runtime::TryRuntimeError::Other("Invalid pre-upgrade state"))?;
+            ensure!(
+                PruneCursor::<T>::get().tail == before,
+                "Not every verified batch was queued"
+            );
+            Ok(())
+        }
+    }
+}
+
+pub mod v4 {
+    use super::*;
+
+    /// Unversioned v3 → v4 translation, use [`MigrateV3ToV4`]
+    pub struct UncheckedMigrateV3ToV4<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV3ToV4<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut reads = 0u64;
+            let highest = VerifiedRequests::<T>::iter_keys()
+                .chain(ConsumedRequests::<T>::iter_keys())
+                .inspect(|_| reads += 1)
+                .max();
+
+            if let Some(highest) = highest {
+                NextRequestId::<T>::put(highest.saturating_add(1));
+            }
+            T::DbWeight::get().reads_writes(reads, 1)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<alloc::vec::Vec<u8>, sp_runtime::TryRuntimeError> {
+            Ok(alloc::vec::Vec::new())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: alloc::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let next = NextRequestId::<T>::get();
+            ensure!(
+                VerifiedRequests::<T>::iter_keys()
+                    .chain(ConsumedRequests::<T>::iter_keys())
+                    .all(|request_id| request_id < next),
+                "NextRequestId is not above every settled request ID"
+            );
+            Ok(())
+        }
+    }
+}
+
+pub mod v5 {
+    use super::*;
+    use sp_runtime::traits::{Saturating, Zero};
+
+    /// Unversioned v4 → v5 translation, use [`MigrateV4ToV5`]
+    pub struct UncheckedMigrateV4ToV5<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV4ToV5<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let expires_at = frame_system::Pallet::<T>::block_number()
+                .saturating_add(T::AllocationExpiry::get());
+            let mut translated = 0u64;
+            let mut lowest = NextRequestId::<T>::get();
+            RequestOwners::<T>::translate::<T::AccountId, _>(|request_id, owner| {
+                translated += 1;
+                lowest = lowest.min(request_id);
+                Some(RequestAllocation {
+                    owner,
+                    deposit: Zero::zero(),
+                    expires_at,
+                })
+            });
+            AllocationCursor::<T>::put(lowest);
+            T::DbWeight::get()
+                .reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<alloc::vec::Vec<u8>, sp_runtime::TryRuntimeError> {
+            Ok((RequestOwners::<T>::iter_keys().count() as u64).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: alloc::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let before = u64::decode(&mut &state[..])
+                .map_err(|_| sp_runtime::TryRuntimeError::Other("Invalid pre-upgrade state"))?;
+            ensure!(
+                RequestOwners::<T>::iter().count() as u64 == before,
+                "RequestOwners lost entries during migration"
+            );
+            let cursor = AllocationCursor::<T>::get();
+            ensure!(
+                RequestOwners::<T>::iter_keys().all(|request_id| request_id >= cursor),
+                "AllocationCursor is above an allocated request ID"
+            );
+            Ok(())
+        }
+    }
+}
+
+pub mod v6 {
+    use super::*;
+
+    /// `PruneState` before tombstones
+    #[derive(Encode, Decode)]
+    pub(super) struct OldPruneState {
+        pub head: u64,
+        pub tail: u64,
+        pub request_index: u32,
+        pub highest_pruned: Option<u64>,
+    }
+
+    /// Unversioned v5 → v6 translation, use [`MigrateV5ToV6`]
+    pub struct UncheckedMigrateV5ToV6<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV5ToV6<T> {
+        fn on_runtime_upgrade() -> Weight {
+            // Pruned batches were dequeued, so no position is tombstoned
+            let _ = PruneCursor::<T>::translate::<OldPruneState, _>(|old| {
+                old.map(|old| PruneState {
+                    head: old.head,
+                    tail: old.tail,
+                    request_index: old.request_index,
+                    tombstone_head: old.head,
+                })
+            });
+            T::DbWeight::get().reads_writes(1, 1)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<alloc::vec::Vec<u8>, sp_runtime::TryRuntimeError> {
+            Ok(alloc::vec::Vec::new())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: alloc::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let cursor = PruneCursor::<T>::get();
+            ensure!(
+                cursor.tombstone_head == cursor.head && cursor.head <= cursor.tail,
+                "PruneCursor was not translated"
+            );
+            Ok(())
+        }
+    }
+}
+
+pub mod v7 {
+    use super::*;
+    use alloc::vec::Vec;
+
+    /// Whether consumed `request_id` outlived the batch that verified it
+    fn is_orphaned<T: Config>(request_id: u64) -> bool {
+        match VerifiedRequests::<T>::get(request_id) {
+            Some((batch_id, _)) => !BatchRequestIds::<T>::get(batch_id)
+                .is_some_and(|request_ids| request_ids.contains(&request_id)),
+            None => !RequestOwners::<T>::contains_key(request_id),
+        }
+    }
+
+    /// Unversioned v6 → v7 cleanup, use [`MigrateV6ToV7`]
+    pub struct UncheckedMigrateV6ToV7<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV6ToV7<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let consumed: Vec<u64> = ConsumedRequests::<T>::iter_keys().collect();
+            let mut removed = 0u64;
+            for request_id in consumed.iter().copied() {
+                if is_orphaned::<T>(request_id) {
+                    VerifiedRequests::<T>::remove(request_id);
+                    RequestOwners::<T>::remove(request_id);
+                    ConsumedRequests::<T>::remove(request_id);
+                    removed += 1;
+                }
+            }
+            let count = consumed.len() as u64;
+            T::DbWeight::get().reads_writes(count.saturating_mul(3), removed.saturating_mul(3))
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            Ok(Vec::new())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            ensure!(
+                ConsumedRequests::<T>::iter_keys().all(|request_id| !is_orphaned::<T>(request_id)),
+                "A consumed request outlived its batch"
+            );
+            Ok(())
+        }
+    }
+}
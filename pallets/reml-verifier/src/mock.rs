@@ -0,0 +1,284 @@
+//! Mock runtime for testing pallet-reml-verifier
+
+use frame_support::{
+    derive_impl, parameter_types,
+    traits::{ConstU32, ConstU64},
+    BoundedVec,
+};
+use frame_system::EnsureRoot;
+use sp_runtime::{traits::IdentityLookup, BuildStorage, Perbill};
+
+use crate as pallet_reml_verifier;
+use crate::{
+    AggregatedProofSubmission, AggregatedPublicValues, OptimisticSubmission, ProofSubmission,
+    PublicValues, RequestRejection, GROTH16_PROOF_SIZE, MAX_AGGREGATED_BATCHES, MIN_PROOF_SIZE,
+    REML_VERSION, TESSERAX_CHAIN_ID,
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime for testing
+frame_support::construct_runtime!(
+    pub enum Test {
+        System: frame_system,
+        Balances: pallet_balances,
+        ReMLVerifier: pallet_reml_verifier,
+    }
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type AccountId = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type AccountData = pallet_balances::AccountData<u64>;
+}
+
+impl pallet_balances::Config for Test {
+    type MaxLocks = ConstU32<50>;
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type Balance = u64;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU64<1>;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type FreezeIdentifier = ();
+    type MaxFreezes = ConstU32<0>;
+    type RuntimeHoldReason = ();
+    type RuntimeFreezeReason = ();
+    type DoneSlashHandler = ();
+}
+
+parameter_types! {
+    /// Proof reward (settable per test)
+    pub static ProofReward: u64 = 5;
+    /// Optimistic batches (settable per test)
+    pub static OptimisticBatchesEnabled: bool = true;
+    pub const RewardAccount: u64 = REWARD_ACCOUNT;
+    pub const LivenessSlash: Perbill = Perbill::from_percent(10);
+    pub const InvalidBatchSlash: Perbill = Perbill::from_percent(50);
+}
+
+/// Accepts a signature iff it equals the message, see `signature_data`
+pub struct TestMlDsaVerifier;
+
+impl crate::MlDsaVerifier for TestMlDsaVerifier {
+    fn verify(_public_key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+        message == signature
+    }
+}
+
+impl pallet_reml_verifier::Config for Test {
+    type WeightInfo = ();
+    type MaxAggregators = ConstU32<3>;
+    type MaxVKeys = ConstU32<3>;
+    type AdminOrigin = EnsureRoot<u64>;
+    type Currency = Balances;
+    type AggregatorBond = ConstU64<BOND>;
+    type ProofReward = ProofReward;
+    type RewardAccount = RewardAccount;
+    type LivenessPeriod = ConstU64<LIVENESS_PERIOD>;
+    type LivenessSlash = LivenessSlash;
+    type InvalidBatchSlash = InvalidBatchSlash;
+    type UnbondingPeriod = ConstU64<UNBONDING_PERIOD>;
+    type RetentionPeriod = ConstU64<RETENTION_PERIOD>;
+    type AllocationDeposit = ConstU64<ALLOCATION_DEPOSIT>;
+    type AllocationExpiry = ConstU64<ALLOCATION_EXPIRY>;
+    type ChallengePeriod = ConstU64<CHALLENGE_PERIOD>;
+    type OptimisticBond = ConstU64<OPTIMISTIC_BOND>;
+    type DataChallengeDeposit = ConstU64<DATA_CHALLENGE_DEPOSIT>;
+    type DataResponsePeriod = ConstU64<DATA_RESPONSE_PERIOD>;
+    type SignatureVerifier = TestMlDsaVerifier;
+    type OptimisticBatchesEnabled = OptimisticBatchesEnabled;
+}
+
+/// Registered aggregator used by the tests (admin-registered, no bond)
+pub const AGGREGATOR: u64 = 1;
+
+/// Funded account that can bond as an aggregator
+pub const BONDER: u64 = 2;
+
+/// Account paying rewards and receiving slashes
+pub const REWARD_ACCOUNT: u64 = 99;
+
+/// Account request IDs `0..ALLOCATED_REQUESTS` are allocated to
+pub const REQUESTER: u64 = 4;
+
+/// Request IDs allocated by `new_test_ext`
+pub const ALLOCATED_REQUESTS: u64 = 1_000;
+
+pub const BOND: u64 = 100;
+pub const LIVENESS_PERIOD: u64 = 10;
+pub const UNBONDING_PERIOD: u64 = 5;
+pub const RETENTION_PERIOD: u64 = 20;
+pub const CHALLENGE_PERIOD: u64 = 5;
+pub const OPTIMISTIC_BOND: u64 = 50;
+pub const DATA_CHALLENGE_DEPOSIT: u64 = 5;
+pub const DATA_RESPONSE_PERIOD: u64 = 3;
+pub const ALLOCATION_DEPOSIT: u64 = 1;
+/// Outlasts every test that does not test expiry
+pub const ALLOCATION_EXPIRY: u64 = 1_000;
+
+/// Guest vkey allowed at genesis, used by `submission`
+pub const VKEY: [u8; 32] = [7u8; 32];
+
+/// Aggregation guest vkey, used by `aggregated_submission`
+pub const AGGREGATION_VKEY: [u8; 32] = [8u8; 32];
+
+/// Build test externalities with `AGGREGATOR` registered, `VKEY` allowed and
+/// request IDs `0..ALLOCATED_REQUESTS` allocated to `REQUESTER`
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![
+            (AGGREGATOR, 1_000),
+            (BONDER, 1_000),
+            (REWARD_ACCOUNT, 1_000),
+            // Allocation deposits of the genesis requests
+            (REQUESTER, 1 + ALLOCATED_REQUESTS * ALLOCATION_DEPOSIT),
+        ],
+        dev_accounts: None,
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    pallet_reml_verifier::GenesisConfig::<Test> {
+        initial_vkeys: vec![(VKEY, 1)],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        ReMLVerifier::register_aggregator(RuntimeOrigin::root(), AGGREGATOR).unwrap();
+        for _ in 0..ALLOCATED_REQUESTS {
+            ReMLVerifier::do_allocate_request_id(&REQUESTER).unwrap();
+        }
+        System::reset_events();
+    });
+    ext
+}
+
+/// Build a structurally valid submission for `request_ids`
+pub fn submission(batch_id: u64, request_ids: &[u64]) -> ProofSubmission {
+    let verified_request_ids: BoundedVec<u64, ConstU32<1_000>> =
+        request_ids.to_vec().try_into().unwrap();
+
+    ProofSubmission {
+        batch_id,
+        // Large enough to pass the structural checks; the batch ID makes it unique
+        proof: [batch_id.to_le_bytes().to_vec(), vec![0xAB; MIN_PROOF_SIZE]]
+            .concat()
+            .try_into()
+            .unwrap(),
+        public_values: PublicValues {
+            version: REML_VERSION,
+            chain_id: TESSERAX_CHAIN_ID,
+            batch_id,
+            verified_count: request_ids.len() as u32,
+            requests_root: ReMLVerifier::compute_merkle_root(request_ids),
+            verified_request_ids,
+            rejected_request_ids: Default::default(),
+        },
+        vkey_hash: VKEY,
+    }
+}
+
+/// Build a structurally valid submission for `request_ids` that also rejects
+/// `rejected`
+pub fn submission_with_rejections(
+    batch_id: u64,
+    request_ids: &[u64],
+    rejected: &[(u64, RequestRejection)],
+) -> ProofSubmission {
+    let mut submission = submission(batch_id, request_ids);
+    submission.public_values.rejected_request_ids = rejected.to_vec().try_into().unwrap();
+    submission
+}
+
+/// Groth16-sized proof committing to `public_hash` and nothing else, which
+/// only an exact public values binding accepts
+pub fn groth16_proof(public_hash: [u8; 32]) -> BoundedVec<u8, ConstU32<102_400>> {
+    [public_hash.to_vec(), vec![0u8; GROTH16_PROOF_SIZE - 32]]
+        .concat()
+        .try_into()
+        .unwrap()
+}
+
+/// Allow `AGGREGATION_VKEY` from now on
+pub fn allow_aggregation_vkey() {
+    ReMLVerifier::add_vkey(RuntimeOrigin::root(), AGGREGATION_VKEY, 1, None).unwrap();
+}
+
+/// Build an aggregated proof of `batches`, whose child proofs were verified
+/// under `VKEY`, committing to its exact public values hash
+pub fn aggregated_submission(batches: &[ProofSubmission]) -> AggregatedProofSubmission {
+    let batches: BoundedVec<PublicValues, ConstU32<MAX_AGGREGATED_BATCHES>> = batches
+        .iter()
+        .map(|batch| batch.public_values.clone())
+        .collect::<Vec<_>>()
+        .try_into()
+        .unwrap();
+    let public_values = AggregatedPublicValues {
+        version: REML_VERSION,
+        chain_id: TESSERAX_CHAIN_ID,
+        batch_vkey_hash: VKEY,
+        batches_root: ReMLVerifier::compute_batches_root(&batches),
+        batches,
+    };
+    let public_hash =
+        ReMLVerifier::compute_aggregated_public_values_hash(&AGGREGATION_VKEY, &public_values);
+
+    AggregatedProofSubmission {
+        // Large enough to pass the structural checks; the hash makes it unique
+        proof: [public_hash.to_vec(), vec![0xCD; MIN_PROOF_SIZE]]
+            .concat()
+            .try_into()
+            .unwrap(),
+        public_values,
+        vkey_hash: AGGREGATION_VKEY,
+    }
+}
+
+/// Signature data `(message, public_key, signature)` of `request_id`, valid
+/// under `TestMlDsaVerifier` unless `forged`
+pub fn signature_data(request_id: u64, forged: bool) -> ([u8; 32], Vec<u8>, Vec<u8>) {
+    let message = [request_id as u8; 32];
+    let signature = if forged {
+        vec![0xFF; 32]
+    } else {
+        message.to_vec()
+    };
+    (message, vec![0x11; 32], signature)
+}
+
+/// Build an optimistic submission for `request_ids`, forging the signatures
+/// of `forged`
+pub fn optimistic_submission(
+    batch_id: u64,
+    request_ids: &[u64],
+    forged: &[u64],
+) -> OptimisticSubmission {
+    let request_commitments = request_ids
+        .iter()
+        .map(|id| {
+            let (message, public_key, signature) = signature_data(*id, forged.contains(id));
+            ReMLVerifier::request_commitment(*id, &message, &public_key, &signature)
+        })
+        .collect::<Vec<_>>()
+        .try_into()
+        .unwrap();
+
+    OptimisticSubmission {
+        batch_id,
+        public_values: submission(batch_id, request_ids).public_values,
+        request_commitments,
+    }
+}
@@ -0,0 +1,82 @@
+//! Runtime API for pallet-reml-verifier
+//!
+//! Lets aggregators (reml-prover) and dApps confirm settlement of a request
+//! without decoding raw storage, including a Merkle inclusion proof of the
+//! request ID against the batch `requests_root` stored on-chain.
+//! Served over RPC by the node under the `reml_*` namespace.
+
+use alloc::vec::Vec;
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+
+use crate::{AggregatorInfo, BatchInfo};
+
+/// Verification status of a single request ID
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct RequestStatus<BlockNumber> {
+    /// Batch the request was settled in
+    pub batch_id: u64,
+    /// Block in which the batch proof was accepted
+    pub verified_at: BlockNumber,
+}
+
+/// Aggregate verifier counters
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct RemlTotals {
+    /// Number of accepted batch proofs
+    pub proofs_verified: u64,
+    /// Number of ML-DSA signatures covered by accepted proofs
+    pub signatures_verified: u64,
+}
+
+/// One step of a Merkle inclusion path
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct MerkleProofNode {
+    /// Sibling hash at this level
+    pub hash: [u8; 32],
+    /// Whether the sibling sits to the left of the running hash
+    pub is_left: bool,
+}
+
+/// Merkle inclusion proof of a request ID in its batch `requests_root`
+///
+/// Levels where the running node had no sibling (odd node promoted) are
+/// omitted from `path`.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct RequestInclusionProof {
+    /// Batch the request was settled in
+    pub batch_id: u64,
+    /// Root stored in `VerifiedBatches` for the batch
+    pub requests_root: [u8; 32],
+    /// Position of the request ID within the batch
+    pub leaf_index: u32,
+    /// Number of request IDs in the batch
+    pub leaf_count: u32,
+    /// Sibling hashes from leaf to root
+    pub path: Vec<MerkleProofNode>,
+}
+
+sp_api::decl_runtime_apis! {
+    /// Read-only access to Re-ML verifier state
+    pub trait RemlVerifierApi<AccountId, Balance, BlockNumber>
+    where
+        AccountId: Codec,
+        Balance: Codec,
+        BlockNumber: Codec,
+    {
+        /// Settlement status of a request ID, `None` if not verified
+        fn request_status(request_id: u64) -> Option<RequestStatus<BlockNumber>>;
+
+        /// Metadata of a verified batch
+        fn batch(batch_id: u64) -> Option<BatchInfo<AccountId, BlockNumber>>;
+
+        /// All registered aggregators, active or not
+        fn aggregators() -> Vec<(AccountId, AggregatorInfo<Balance, BlockNumber>)>;
+
+        /// Aggregate proof and signature counters
+        fn totals() -> RemlTotals;
+
+        /// Merkle inclusion proof of a verified request ID
+        fn request_proof(request_id: u64) -> Option<RequestInclusionProof>;
+    }
+}
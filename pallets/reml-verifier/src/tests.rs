@@ -0,0 +1,2306 @@
+//! Unit tests for pallet-reml-verifier
+
+use crate::{
+    migrations::{
+        MigrateV0ToV1, MigrateV1ToV2, MigrateV2ToV3, MigrateV3ToV4, MigrateV4ToV5, MigrateV5ToV6,
+        MigrateV6ToV7,
+    },
+    mock::*,
+    AggregatedProofSubmission, Aggregators, AllocationCursor, AllowedVKeys, BatchQueue,
+    BatchRejectedIds, BatchRequestIds, ConsumedRequests, DataChallenges, Error, Event,
+    FraudProvenAt, NextRequestId, Pallet, PendingBatches, PendingRequests, ProofCommitments,
+    ProofSubmission, PruneCursor, PrunedBatches, RejectedRequests, RejectionInfo, RequestOwners,
+    RequestRejection, SlashReason, TotalProofsVerified, TotalSignaturesVerified, VerifiedBatches,
+    VerifiedRequests, WeightInfo,
+};
+use codec::Encode;
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{
+        Currency, GetStorageVersion, Hooks, OnRuntimeUpgrade, ReservableCurrency, StorageVersion,
+    },
+    weights::Weight,
+};
+use sp_core::H256;
+use sp_runtime::DispatchError;
+
+// ═══════════════════════════════════════════════════════════════════════════
+// AGGREGATOR ADMINISTRATION TESTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn aggregator_admin_requires_admin_origin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ReMLVerifier::register_aggregator(RuntimeOrigin::signed(AGGREGATOR), 2),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            ReMLVerifier::deactivate_aggregator(RuntimeOrigin::signed(AGGREGATOR), AGGREGATOR),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn deactivated_aggregator_cannot_submit() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ReMLVerifier::deactivate_aggregator(
+            RuntimeOrigin::root(),
+            AGGREGATOR
+        ));
+        assert!(!Aggregators::<Test>::get(AGGREGATOR).unwrap().active);
+
+        assert_noop!(
+            ReMLVerifier::submit_proof(RuntimeOrigin::signed(AGGREGATOR), submission(1, &[10])),
+            Error::<Test>::NotAuthorized
+        );
+    });
+}
+
+#[test]
+fn aggregator_set_is_capped_at_max_aggregators() {
+    new_test_ext().execute_with(|| {
+        // MaxAggregators is 3, AGGREGATOR is registered at genesis
+        assert_ok!(ReMLVerifier::register_aggregator(RuntimeOrigin::root(), 10));
+        assert_ok!(ReMLVerifier::bond_aggregator(RuntimeOrigin::signed(BONDER)));
+        assert_eq!(Aggregators::<Test>::count(), 3);
+
+        assert_noop!(
+            ReMLVerifier::register_aggregator(RuntimeOrigin::root(), 11),
+            Error::<Test>::TooManyAggregators
+        );
+    });
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// BONDING TESTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn bond_aggregator_reserves_bond() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ReMLVerifier::bond_aggregator(RuntimeOrigin::signed(BONDER)));
+
+        let info = Aggregators::<Test>::get(BONDER).unwrap();
+        assert!(info.active);
+        assert_eq!(info.bond, BOND);
+        assert_eq!(Balances::reserved_balance(BONDER), BOND);
+        System::assert_last_event(RuntimeEvent::ReMLVerifier(Event::AggregatorBonded {
+            aggregator: BONDER,
+            bond: BOND,
+        }));
+
+        assert_noop!(
+            ReMLVerifier::bond_aggregator(RuntimeOrigin::signed(BONDER)),
+            Error::<Test>::AggregatorAlreadyRegistered
+        );
+    });
+}
+
+#[test]
+fn bond_aggregator_requires_free_balance() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ReMLVerifier::bond_aggregator(RuntimeOrigin::signed(42)),
+            Error::<Test>::InsufficientBond
+        );
+    });
+}
+
+#[test]
+fn withdraw_bond_after_unbonding_period() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ReMLVerifier::bond_aggregator(RuntimeOrigin::signed(BONDER)));
+        assert_noop!(
+            ReMLVerifier::withdraw_bond(RuntimeOrigin::signed(BONDER)),
+            Error::<Test>::NotUnbonding
+        );
+
+        assert_ok!(ReMLVerifier::unbond_aggregator(RuntimeOrigin::signed(
+            BONDER
+        )));
+        assert!(!ReMLVerifier::is_aggregator(&BONDER));
+        assert_noop!(
+            ReMLVerifier::unbond_aggregator(RuntimeOrigin::signed(BONDER)),
+            Error::<Test>::AlreadyUnbonding
+        );
+        assert_noop!(
+            ReMLVerifier::submit_proof(RuntimeOrigin::signed(BONDER), submission(1, &[10])),
+            Error::<Test>::NotAuthorized
+        );
+
+        System::set_block_number(UNBONDING_PERIOD);
+        assert_noop!(
+            ReMLVerifier::withdraw_bond(RuntimeOrigin::signed(BONDER)),
+            Error::<Test>::BondStillLocked
+        );
+
+        System::set_block_number(1 + UNBONDING_PERIOD);
+        assert_ok!(ReMLVerifier::withdraw_bond(RuntimeOrigin::signed(BONDER)));
+        assert!(!Aggregators::<Test>::contains_key(BONDER));
+        assert_eq!(Aggregators::<Test>::count(), 1);
+        assert_eq!(Balances::reserved_balance(BONDER), 0);
+        assert_eq!(Balances::free_balance(BONDER), 1_000);
+    });
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// REWARD TESTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn accepted_proof_pays_reward() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ReMLVerifier::submit_proof(
+            RuntimeOrigin::signed(AGGREGATOR),
+            submission(1, &[10])
+        ));
+
+        let reward = ProofReward::get();
+        assert_eq!(Balances::free_balance(AGGREGATOR), 1_000 + reward);
+        assert_eq!(Balances::free_balance(REWARD_ACCOUNT), 1_000 - reward);
+        assert_eq!(
+            Aggregators::<Test>::get(AGGREGATOR).unwrap().rewards_earned,
+            reward
+        );
+        System::assert_has_event(RuntimeEvent::ReMLVerifier(Event::AggregatorRewarded {
+            aggregator: AGGREGATOR,
+            batch_id: 1,
+            amount: reward,
+        }));
+    });
+}
+
+#[test]
+fn reward_is_skipped_when_reward_account_is_short() {
+    new_test_ext().execute_with(|| {
+        ProofReward::set(1_000);
+
+        assert_ok!(ReMLVerifier::submit_proof(
+            RuntimeOrigin::signed(AGGREGATOR),
+            submission(1, &[10])
+        ));
+
+        assert!(VerifiedBatches::<Test>::contains_key(1));
+        assert_eq!(Balances::free_balance(AGGREGATOR), 1_000);
+        assert_eq!(
+            Aggregators::<Test>::get(AGGREGATOR).unwrap().rewards_earned,
+            0
+        );
+    });
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SLASHING TESTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn invalid_batch_revokes_requests_and_slashes_bond() {
+    new_test_ext().execute_with(|| {
+        ProofReward::set(0);
+        assert_ok!(ReMLVerifier::bond_aggregator(RuntimeOrigin::signed(BONDER)));
+        assert_ok!(ReMLVerifier::submit_proof(
+            RuntimeOrigin::signed(BONDER),
+            submission(1, &[10, 11])
+        ));
+
+        assert_noop!(
+            ReMLVerifier::report_invalid_batch(RuntimeOrigin::signed(AGGREGATOR), 1),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(ReMLVerifier::report_invalid_batch(RuntimeOrigin::root(), 1));
+
+        assert!(!VerifiedBatches::<Test>::contains_key(1));
+        assert!(!BatchRequestIds::<Test>::contains_key(1));
+        assert!(!ReMLVerifier::is_request_verified(10));
+        assert!(!ReMLVerifier::is_request_verified(11));
+
+        // InvalidBatchSlash is 50%, paid to the reward account
+        let info = Aggregators::<Test>::get(BONDER).unwrap();
+        assert!(!info.active);
+        assert_eq!(info.bond, BOND / 2);
+        assert_eq!(Balances::reserved_balance(BONDER), BOND / 2);
+        assert_eq!(Balances::free_balance(REWARD_ACCOUNT), 1_000 + BOND / 2);
+        System::assert_has_event(RuntimeEvent::ReMLVerifier(Event::AggregatorSlashed {
+            aggregator: BONDER,
+            amount: BOND / 2,
+            reason: SlashReason::InvalidBatch,
+        }));
+
+        assert_noop!(
+            ReMLVerifier::report_invalid_batch(RuntimeOrigin::root(), 1),
+            Error::<Test>::BatchNotFound
+        );
+    });
+}
+
+#[test]
+fn invalid_batch_keeps_requests_settled_by_other_batches() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ReMLVerifier::submit_proof(
+            RuntimeOrigin::signed(AGGREGATOR),
+            submission(1, &[10])
+        ));
+        assert_ok!(ReMLVerifier::report_invalid_batch(RuntimeOrigin::root(), 1));
+
+        // The revoked request is settled again by batch 2, then batch 1 is
+        // proven again with other requests and invalidated once more
+        assert_ok!(ReMLVerifier::submit_proof(
+            RuntimeOrigin::signed(AGGREGATOR),
+            submission(2, &[10, 11])
+        ));
+        assert_ok!(ReMLVerifier::submit_proof(
+            RuntimeOrigin::signed(AGGREGATOR),
+            submission(1, &[12])
+        ));
+        assert_ok!(ReMLVerifier::report_invalid_batch(RuntimeOrigin::root(), 1));
+
+        assert_eq!(VerifiedRequests::<Test>::get(10).map(|(b, _)| b), Some(2));
+        assert!(ReMLVerifier::is_request_verified(11));
+        assert!(!ReMLVerifier::is_request_verified(12));
+    });
+}
+
+#[test]
+fn unbonding_aggregator_stays_slashable() {
+    new_test_ext().execute_with(|| {
+        ProofReward::set(0);
+        assert_ok!(ReMLVerifier::bond_aggregator(RuntimeOrigin::signed(BONDER)));
+        assert_ok!(ReMLVerifier::submit_proof(
+            RuntimeOrigin::signed(BONDER),
+            submission(1, &[10])
+        ));
+        assert_ok!(ReMLVerifier::unbond_aggregator(RuntimeOrigin::signed(
+            BONDER
+        )));
+
+        assert_ok!(ReMLVerifier::report_invalid_batch(RuntimeOrigin::root(), 1));
+
+        System::set_block_number(1 + UNBONDING_PERIOD);
+        assert_ok!(ReMLVerifier::withdraw_bond(RuntimeOrigin::signed(BONDER)));
+        assert_eq!(Balances::free_balance(BONDER), 1_000 - BOND / 2);
+    });
+}
+
+#[test]
+fn missed_liveness_slashes_and_deactivates() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ReMLVerifier::bond_aggregator(RuntimeOrigin::signed(BONDER)));
+
+        System::set_block_number(1 + LIVENESS_PERIOD);
+        assert_noop!(
+            ReMLVerifier::report_missed_liveness(RuntimeOrigin::signed(3), BONDER),
+            Error::<Test>::AggregatorLive
+        );
+
+        System::set_block_number(2 + LIVENESS_PERIOD);
+        assert_ok!(ReMLVerifier::report_missed_liveness(
+            RuntimeOrigin::signed(3),
+            BONDER
+        ));
+
+        // LivenessSlash is 10%
+        let info = Aggregators::<Test>::get(BONDER).unwrap();
+        assert!(!info.active);
+        assert_eq!(info.bond, BOND - BOND / 10);
+        System::assert_last_event(RuntimeEvent::ReMLVerifier(Event::AggregatorSlashed {
+            aggregator: BONDER,
+            amount: BOND / 10,
+            reason: SlashReason::MissedLiveness,
+        }));
+
+        assert_noop!(
+            ReMLVerifier::report_missed_liveness(RuntimeOrigin::signed(3), BONDER),
+            Error::<Test>::AggregatorNotActive
+        );
+    });
+}
+
+#[test]
+fn accepted_proof_resets_liveness() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(LIVENESS_PERIOD);
+        assert_ok!(ReMLVerifier::submit_proof(
+            RuntimeOrigin::signed(AGGREGATOR),
+            submission(1, &[10])
+        ));
+
+        System::set_block_number(2 * LIVENESS_PERIOD);
+        assert_noop!(
+            ReMLVerifier::report_missed_liveness(RuntimeOrigin::signed(3), AGGREGATOR),
+            Error::<Test>::AggregatorLive
+        );
+    });
+}
+
+#[test]
+fn heartbeat_resets_liveness() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ReMLVerifier::bond_aggregator(RuntimeOrigin::signed(BONDER)));
+
+        System::set_block_number(LIVENESS_PERIOD);
+        assert_ok!(ReMLVerifier::heartbeat(RuntimeOrigin::signed(BONDER)));
+        System::assert_last_event(RuntimeEvent::ReMLVerifier(Event::AggregatorHeartbeat {
+            aggregator: BONDER,
+        }));
+
+        // Idle for longer than LivenessPeriod since bonding, but not since the heartbeat
+        System::set_block_number(2 * LIVENESS_PERIOD);
+        assert_noop!(
+            ReMLVerifier::report_missed_liveness(RuntimeOrigin::signed(3), BONDER),
+            Error::<Test>::AggregatorLive
+        );
+
+        System::set_block_number(2 * LIVENESS_PERIOD + 1);
+        assert_ok!(ReMLVerifier::report_missed_liveness(
+            RuntimeOrigin::signed(3),
+            BONDER
+        ));
+    });
+}
+
+#[test]
+fn heartbeat_requires_active_aggregator() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ReMLVerifier::heartbeat(RuntimeOrigin::signed(3)),
+            Error::<Test>::AggregatorNotFound
+        );
+
+        assert_ok!(ReMLVerifier::bond_aggregator(RuntimeOrigin::signed(BONDER)));
+        assert_ok!(ReMLVerifier::unbond_aggregator(RuntimeOrigin::signed(
+            BONDER
+        )));
+        assert_noop!(
+            ReMLVerifier::heartbeat(RuntimeOrigin::signed(BONDER)),
+            Error::<Test>::AggregatorNotActive
+        );
+    });
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// PROOF SUBMISSION TESTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn submit_proof_records_batch_and_requests() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ReMLVerifier::submit_proof(
+            RuntimeOrigin::signed(AGGREGATOR),
+            submission(1, &[10, 11, 12])
+        ));
+
+        let batch = VerifiedBatches::<Test>::get(1).unwrap();
+        assert_eq!(batch.aggregator, AGGREGATOR);
+        assert_eq!(batch.signature_count, 3);
+        assert_eq!(batch.vkey_hash, VKEY);
+        assert_eq!(
+            BatchRequestIds::<Test>::get(1).unwrap().into_inner(),
+            vec![10, 11, 12]
+        );
+        assert_eq!(VerifiedRequests::<Test>::get(11), Some((1, 1)));
+        assert_eq!(TotalProofsVerified::<Test>::get(), 1);
+        assert_eq!(TotalSignaturesVerified::<Test>::get(), 3);
+    });
+}
+
+#[test]
+fn submit_proof_requires_registered_aggregator() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ReMLVerifier::submit_proof(RuntimeOrigin::signed(2), submission(1, &[10])),
+            Error::<Test>::NotAuthorized
+        );
+    });
+}
+
+#[test]
+fn submit_proof_rejects_wrong_root() {
+    new_test_ext().execute_with(|| {
+        let mut bad = submission(1, &[10, 11]);
+        bad.public_values.requests_root = [0xFF; 32];
+
+        assert_noop!(
+            ReMLVerifier::submit_proof(RuntimeOrigin::signed(AGGREGATOR), bad),
+            Error::<Test>::InvalidMerkleRoot
+        );
+    });
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// REQUEST ID TESTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn allocate_request_id_is_monotonic() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ReMLVerifier::allocate_request_id(RuntimeOrigin::signed(
+            BONDER
+        )));
+        assert_ok!(ReMLVerifier::allocate_request_id(RuntimeOrigin::signed(
+            AGGREGATOR
+        )));
+
+        let owner = |request_id| RequestOwners::<Test>::get(request_id).map(|a| a.owner);
+        assert_eq!(owner(ALLOCATED_REQUESTS), Some(BONDER));
+        assert_eq!(owner(ALLOCATED_REQUESTS + 1), Some(AGGREGATOR));
+        assert_eq!(NextRequestId::<Test>::get(), ALLOCATED_REQUESTS + 2);
+        System::assert_last_event(RuntimeEvent::ReMLVerifier(Event::RequestIdAllocated {
+            request_id: ALLOCATED_REQUESTS + 1,
+            owner: AGGREGATOR,
+        }));
+    });
+}
+
+#[test]
+fn allocate_request_id_reserves_deposit() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ReMLVerifier::allocate_request_id(RuntimeOrigin::signed(
+            BONDER
+        )));
+
+        let allocation = RequestOwners::<Test>::get(ALLOCATED_REQUESTS).unwrap();
+        assert_eq!(allocation.deposit, ALLOCATION_DEPOSIT);
+        assert_eq!(allocation.expires_at, 1 + ALLOCATION_EXPIRY);
+        assert_eq!(Balances::reserved_balance(BONDER), ALLOCATION_DEPOSIT);
+
+        // Account 3 holds nothing
+        assert_noop!(
+            ReMLVerifier::allocate_request_id(RuntimeOrigin::signed(3)),
+            Error::<Test>::InsufficientDeposit
+        );
+    });
+}
+
+#[test]
+fn settlement_returns_allocation_deposits() {
+    new_test_ext().execute_with(|| {
+        let reserved = Balances::reserved_balance(REQUESTER);
+        assert_ok!(ReMLVerifier::submit_proof(
+            RuntimeOrigin::signed(AGGREGATOR),
+            submission_with_rejections(1, &[10], &[(11, RequestRejection::InvalidSignature)])
+        ));
+
+        // Verified and rejected requests alike
+        assert_eq!(
+            Balances::reserved_balance(REQUESTER),
+            reserved - 2 * ALLOCATION_DEPOSIT
+        );
+        assert_eq!(RequestOwners::<Test>::get(10).unwrap().deposit, 0);
+        assert_eq!(RequestOwners::<Test>::get(11).unwrap().deposit, 0);
+    });
+}
+
+#[test]
+fn expired_allocations_are_dropped_on_idle() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ReMLVerifier::submit_proof(
+            RuntimeOrigin::signed(AGGREGATOR),
+            submission(1, &[10])
+        ));
+        assert_ok!(ReMLVerifier::submit_optimistic_batch(
+            RuntimeOrigin::signed(AGGREGATOR),
+            optimistic_submission(2, &[11], &[])
+        ));
+
+        on_idle(ALLOCATION_EXPIRY, Weight::MAX);
+        assert!(RequestOwners::<Test>::contains_key(12));
+        assert_eq!(AllocationCursor::<Test>::get(), 0);
+
+        on_idle(1 + ALLOCATION_EXPIRY, Weight::MAX);
+        assert!(!RequestOwners::<Test>::contains_key(12));
+        System::assert_has_event(RuntimeEvent::ReMLVerifier(Event::AllocationExpired {
+            request_id: 12,
+            owner: REQUESTER,
+        }));
+        // The pending request keeps its deposit; the verified one went with its batch
+        assert!(RequestOwners::<Test>::contains_key(11));
+        assert!(!RequestOwners::<Test>::contains_key(10));
+        assert_eq!(Balances::reserved_balance(REQUESTER), ALLOCATION_DEPOSIT);
+        assert_eq!(AllocationCursor::<Test>::get(), ALLOCATED_REQUESTS);
+
+        assert_noop!(
+            ReMLVerifier::submit_proof(RuntimeOrigin::signed(AGGREGATOR), submission(3, &[12])),
+            Error::<Test>::RequestNotAllocated
+        );
+    });
+}
+
+#[test]
+fn allocation_pruning_resumes_where_the_weight_ran_out() {
+    new_test_ext().execute_with(|| {
+        let now = 1 + ALLOCATION_EXPIRY;
+        // Batch pruning finds nothing, leaving room for two allocations
+        let limit = <() as WeightInfo>::prune_cursor().saturating_mul(2)
+            + <() as WeightInfo>::prune_allocation().saturating_mul(2);
+        on_idle(now, limit);
+        assert_eq!(AllocationCursor::<Test>::get(), 2);
+        assert!(!RequestOwners::<Test>::contains_key(1));
+        assert!(RequestOwners::<Test>::contains_key(2));
+
+        on_idle(now, Weight::MAX);
+        assert_eq!(AllocationCursor::<Test>::get(), ALLOCATED_REQUESTS);
+        assert_eq!(Balances::reserved_balance(REQUESTER), 0);
+    });
+}
+
+#[test]
+fn released_request_with_expired_allocation_is_dropped() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(ALLOCATION_EXPIRY);
+        assert_ok!(ReMLVerifier::submit_optimistic_batch(
+            RuntimeOrigin::signed(AGGREGATOR),
+            optimistic_submission(1, &[10, 11], &[11])
+        ));
+        // The cursor passes over the pending requests
+        on_idle(1 + ALLOCATION_EXPIRY, Weight::MAX);
+        assert_eq!(AllocationCursor::<Test>::get(), ALLOCATED_REQUESTS);
+
+        System::set_block_number(1 + ALLOCATION_EXPIRY);
+        assert_ok!(challenge(1, 11, true));
+        assert!(!RequestOwners::<Test>::contains_key(10));
+        assert!(!RequestOwners::<Test>::contains_key(11));
+        assert_eq!(Balances::reserved_balance(REQUESTER), 0);
+    });
+}
+
+#[test]
+fn submit_proof_requires_allocated_requests() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ReMLVerifier::submit_proof(
+                RuntimeOrigin::signed(AGGREGATOR),
+                submission(1, &[10, ALLOCATED_REQUESTS])
+            ),
+            Error::<Test>::RequestNotAllocated
+        );
+
+        assert_ok!(ReMLVerifier::allocate_request_id(RuntimeOrigin::signed(
+            BONDER
+        )));
+        assert_ok!(ReMLVerifier::submit_proof(
+            RuntimeOrigin::signed(AGGREGATOR),
+            submission(1, &[10, ALLOCATED_REQUESTS])
+        ));
+    });
+}
+
+#[test]
+fn submit_proof_rejects_settled_requests() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ReMLVerifier::submit_proof(
+            RuntimeOrigin::signed(AGGREGATOR),
+            submission(1, &[10, 11])
+        ));
+        assert_ok!(ReMLVerifier::consume_verified_request(
+            11, &BONDER, [1u8; 32]
+        ));
+
+        for request_id in [10, 11] {
+            assert_noop!(
+                ReMLVerifier::submit_proof(
+                    RuntimeOrigin::signed(AGGREGATOR),
+                    submission(2, &[12, request_id])
+                ),
+                Error::<Test>::RequestAlreadyVerified
+            );
+        }
+        assert_eq!(VerifiedRequests::<Test>::get(10), Some((1, 1)));
+
+        // A consumed request stays settled even when its batch is invalidated
+        assert_ok!(ReMLVerifier::report_invalid_batch(RuntimeOrigin::root(), 1));
+        assert_noop!(
+            ReMLVerifier::submit_proof(RuntimeOrigin::signed(AGGREGATOR), submission(2, &[11])),
+            Error::<Test>::RequestAlreadyVerified
+        );
+    });
+}
+
+#[test]
+fn submit_proof_rejects_duplicate_request_ids() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ReMLVerifier::submit_proof(
+                RuntimeOrigin::signed(AGGREGATOR),
+                submission(1, &[10, 11, 10])
+            ),
+            Error::<Test>::DuplicateRequestId
+        );
+    });
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// REJECTED REQUEST TESTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn submit_proof_records_rejected_requests() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ReMLVerifier::submit_proof(
+            RuntimeOrigin::signed(AGGREGATOR),
+            submission_with_rejections(
+                1,
+                &[10],
+                &[
+                    (11, RequestRejection::InvalidSignature),
+                    (12, RequestRejection::InvalidSize)
+                ]
+            )
+        ));
+
+        assert!(ReMLVerifier::is_request_verified(10));
+        assert!(!ReMLVerifier::is_request_verified(11));
+        assert_eq!(
+            RejectedRequests::<Test>::get(11),
+            Some(RejectionInfo {
+                batch_id: 1,
+                reason: RequestRejection::InvalidSignature,
+                rejected_at: 1,
+            })
+        );
+        assert_eq!(
+            RejectedRequests::<Test>::get(12).map(|info| info.reason),
+            Some(RequestRejection::InvalidSize)
+        );
+        assert_eq!(
+            BatchRejectedIds::<Test>::get(1).unwrap().to_vec(),
+            vec![11, 12]
+        );
+        System::assert_has_event(RuntimeEvent::ReMLVerifier(Event::RequestsRejected {
+            batch_id: 1,
+            count: 2,
+        }));
+
+        // Reason codes are the codec indices
+        assert_eq!(RequestRejection::InvalidSize.encode(), vec![1]);
+        assert_eq!(RequestRejection::InvalidSignature.encode(), vec![2]);
+    });
+}
+
+#[test]
+fn batch_of_only_rejections_is_accepted() {
+    new_test_ext().execute_with(|| {
+        // A batch settling nothing at all is still rejected
+        assert_noop!(
+            ReMLVerifier::submit_proof(RuntimeOrigin::signed(AGGREGATOR), submission(1, &[])),
+            Error::<Test>::ProofVerificationFailed
+        );
+
+        assert_ok!(ReMLVerifier::submit_proof(
+            RuntimeOrigin::signed(AGGREGATOR),
+            submission_with_rejections(1, &[], &[(10, RequestRejection::InvalidSignature)])
+        ));
+        assert!(ReMLVerifier::is_request_rejected(10));
+        assert_eq!(VerifiedBatches::<Test>::get(1).unwrap().signature_count, 0);
+        assert_eq!(BatchRequestIds::<Test>::get(1).unwrap().len(), 0);
+    });
+}
+
+#[test]
+fn proof_binds_rejected_requests() {
+    new_test_ext().execute_with(|| {
+        let mut with_rejection =
+            submission_with_rejections(1, &[10], &[(11, RequestRejection::InvalidSignature)]);
+        let without_rejection = submission(1, &[10]).public_values;
+
+        // A proof of the same batch without the rejection does not cover it
+        with_rejection.proof =
+            groth16_proof(ReMLVerifier::compute_public_values_hash(&without_rejection));
+        assert_noop!(
+            ReMLVerifier::submit_proof(RuntimeOrigin::signed(AGGREGATOR), with_rejection.clone()),
+            Error::<Test>::ProofVerificationFailed
+        );
+
+        with_rejection.proof = groth16_proof(ReMLVerifier::compute_public_values_hash(
+            &with_rejection.public_values,
+        ));
+        assert_ok!(ReMLVerifier::submit_proof(
+            RuntimeOrigin::signed(AGGREGATOR),
+            with_rejection
+        ));
+
+        // Batches without rejections bind the empty list too
+        let mut plain = submission(2, &[12]);
+        let values = &plain.public_values;
+        let unbound = [
+            vec![values.version],
+            values.chain_id.to_le_bytes().to_vec(),
+            values.batch_id.to_le_bytes().to_vec(),
+            values.verified_count.to_le_bytes().to_vec(),
+            values.requests_root.to_vec(),
+        ]
+        .concat();
+        plain.proof = groth16_proof(sp_core::blake2_256(&unbound));
+        assert_noop!(
+            ReMLVerifier::submit_proof(RuntimeOrigin::signed(AGGREGATOR), plain),
+            Error::<Test>::ProofVerificationFailed
+        );
+    });
+}
+
+#[test]
+fn rejected_request_is_settled() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ReMLVerifier::submit_proof(
+            RuntimeOrigin::signed(AGGREGATOR),
+            submission_with_rejections(1, &[10], &[(11, RequestRejection::InvalidSignature)])
+        ));
+
+        assert_noop!(
+            ReMLVerifier::submit_proof(RuntimeOrigin::signed(AGGREGATOR), submission(2, &[11])),
+            Error::<Test>::RequestAlreadyRejected
+        );
+        assert_noop!(
+            ReMLVerifier::submit_proof(
+                RuntimeOrigin::signed(AGGREGATOR),
+                submission_with_rejections(2, &[], &[(11, RequestRejection::InvalidSize)])
+            ),
+            Error::<Test>::RequestAlreadyRejected
+        );
+        assert_noop!(
+            ReMLVerifier::submit_proof(
+                RuntimeOrigin::signed(AGGREGATOR),
+                submission_with_rejections(2, &[12], &[(12, RequestRejection::InvalidSize)])
+            ),
+            Error::<Test>::DuplicateRequestId
+        );
+        assert_noop!(
+            ReMLVerifier::submit_proof(
+                RuntimeOrigin::signed(AGGREGATOR),
+                submission_with_rejections(
+                    2,
+                    &[12],
+                    &[(ALLOCATED_REQUESTS, RequestRejection::InvalidSize)]
+                )
+            ),
+            Error::<Test>::RequestNotAllocated
+        );
+    });
+}
+
+#[test]
+fn rejected_requests_are_pruned_with_their_batch() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ReMLVerifier::submit_proof(
+            RuntimeOrigin::signed(AGGREGATOR),
+            submission_with_rejections(1, &[10], &[(11, RequestRejection::InvalidSignature)])
+        ));
+
+        // One verified and one rejected request: the rejection is pruned on
+        // the second call
+        let limit = <() as WeightInfo>::prune_cursor()
+            + <() as WeightInfo>::prune_batch()
+            + <() as WeightInfo>::prune_request();
+        on_idle(1 + RETENTION_PERIOD, limit);
+        assert!(!ReMLVerifier::is_request_verified(10));
+        assert!(ReMLVerifier::is_request_rejected(11));
+
+        on_idle(1 + RETENTION_PERIOD, limit);
+        assert!(!ReMLVerifier::is_request_rejected(11));
+        assert!(!RequestOwners::<Test>::contains_key(11));
+        assert!(!BatchRejectedIds::<Test>::contains_key(1));
+        assert!(!VerifiedBatches::<Test>::contains_key(1));
+    });
+}
+
+#[test]
+fn invalid_batch_revokes_its_rejections() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ReMLVerifier::submit_proof(
+            RuntimeOrigin::signed(AGGREGATOR),
+            submission_with_rejections(1, &[10], &[(11, RequestRejection::InvalidSignature)])
+        ));
+        assert_ok!(ReMLVerifier::report_invalid_batch(RuntimeOrigin::root(), 1));
+
+        assert!(!ReMLVerifier::is_request_rejected(11));
+        assert!(!BatchRejectedIds::<Test>::contains_key(1));
+
+        // The request can be settled by a correct batch
+        assert_ok!(ReMLVerifier::submit_proof(
+            RuntimeOrigin::signed(AGGREGATOR),
+            submission(2, &[11])
+        ));
+        assert!(ReMLVerifier::is_request_verified(11));
+    });
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// OPTIMISTIC BATCH TESTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Challenge `batch_id` with the signature data of `request_id`
+fn challenge(
+    batch_id: u64,
+    request_id: u64,
+    forged: bool,
+) -> frame_support::dispatch::DispatchResultWithPostInfo {
+    let (message, public_key, signature) = signature_data(request_id, forged);
+    ReMLVerifier::challenge_optimistic_batch(
+        RuntimeOrigin::signed(3),
+        batch_id,
+        request_id,
+        message,
+        public_key,
+        signature,
+    )
+}
+
+#[test]
+fn disabled_optimistic_batches_still_settle_pending_ones() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ReMLVerifier::submit_optimistic_batch(
+            RuntimeOrigin::signed(AGGREGATOR),
+            optimistic_submission(1, &[10], &[])
+        ));
+        System::set_block_number(2);
+        assert_ok!(ReMLVerifier::submit_optimistic_batch(
+            RuntimeOrigin::signed(AGGREGATOR),
+            optimistic_submission(2, &[11], &[11])
+        ));
+        OptimisticBatchesEnabled::set(false);
+
+        assert_noop!(
+            ReMLVerifier::submit_optimistic_batch(
+                RuntimeOrigin::signed(AGGREGATOR),
+                optimistic_submission(3, &[12], &[])
+            ),
+            Error::<Test>::OptimisticBatchesDisabled
+        );
+
+        // Pending batches can still be finalized and challenged
+        System::set_block_number(1 + CHALLENGE_PERIOD);
+        assert_ok!(ReMLVerifier::finalize_optimistic_batch(
+            RuntimeOrigin::signed(3),
+            1
+        ));
+        assert!(ReMLVerifier::is_request_verified(10));
+        assert_ok!(challenge(2, 11, true));
+        assert!(PendingBatches::<Test>::get(2).is_none());
+    });
+}
+
+#[test]
+fn optimistic_batch_finalizes_after_challenge_period() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ReMLVerifier::submit_optimistic_batch(
+            RuntimeOrigin::signed(AGGREGATOR),
+            optimistic_submission(1, &[10, 11], &[])
+        ));
+        System::assert_last_event(RuntimeEvent::ReMLVerifier(
+            Event::OptimisticBatchSubmitted {
+                batch_id: 1,
+                aggregator: AGGREGATOR,
+                signature_count: 2,
+                challenge_ends: 1 + CHALLENGE_PERIOD,
+            },
+        ));
+        assert_eq!(Balances::reserved_balance(AGGREGATOR), OPTIMISTIC_BOND);
+        assert_eq!(PendingRequests::<Test>::get(10), Some(1));
+        assert!(!ReMLVerifier::is_request_verified(10));
+
+        System::set_block_number(CHALLENGE_PERIOD);
+        assert_noop!(
+            ReMLVerifier::finalize_optimistic_batch(RuntimeOrigin::signed(3), 1),
+            Error::<Test>::ChallengePeriodActive
+        );
+
+        System::set_block_number(1 + CHALLENGE_PERIOD);
+        assert_ok!(ReMLVerifier::finalize_optimistic_batch(
+            RuntimeOrigin::signed(3),
+            1
+        ));
+        System::assert_last_event(RuntimeEvent::ReMLVerifier(
+            Event::OptimisticBatchFinalized {
+                batch_id: 1,
+                aggregator: AGGREGATOR,
+                signature_count: 2,
+            },
+        ));
+
+        // Settled like a proven batch, without a vkey
+        let batch = VerifiedBatches::<Test>::get(1).unwrap();
+        assert_eq!(batch.vkey_hash, [0u8; 32]);
+        assert_eq!(batch.verified_at, 1 + CHALLENGE_PERIOD);
+        assert!(ReMLVerifier::is_request_verified(10));
+        assert!(ReMLVerifier::is_request_verified(11));
+        assert_eq!(PendingRequests::<Test>::get(10), None);
+        assert!(PendingBatches::<Test>::get(1).is_none());
+        assert_eq!(TotalSignaturesVerified::<Test>::get(), 2);
+
+        // Bond released and reward paid
+        assert_eq!(Balances::reserved_balance(AGGREGATOR), 0);
+        assert_eq!(Balances::free_balance(AGGREGATOR), 1_000 + 5);
+        assert_eq!(
+            Aggregators::<Test>::get(AGGREGATOR)
+                .unwrap()
+                .proofs_submitted,
+            1
+        );
+    });
+}
+
+#[test]
+fn fraud_proof_discards_batch_and_pays_challenger() {
+    new_test_ext().execute_with(|| {
+        Balances::make_free_balance_be(&3, 10);
+        assert_ok!(ReMLVerifier::submit_optimistic_batch(
+            RuntimeOrigin::signed(AGGREGATOR),
+            optimistic_submission(1, &[10, 11], &[11])
+        ));
+
+        assert_noop!(challenge(1, 10, false), Error::<Test>::SignatureValid);
+        assert_noop!(
+            challenge(1, 11, false),
+            Error::<Test>::RequestCommitmentMismatch
+        );
+        assert_noop!(challenge(1, 12, true), Error::<Test>::RequestNotInBatch);
+        assert_noop!(challenge(2, 11, true), Error::<Test>::PendingBatchNotFound);
+
+        assert_ok!(challenge(1, 11, true));
+        System::assert_last_event(RuntimeEvent::ReMLVerifier(Event::FraudProven {
+            batch_id: 1,
+            aggregator: AGGREGATOR,
+            challenger: 3,
+            request_id: 11,
+            slashed: OPTIMISTIC_BOND,
+        }));
+
+        // The bond goes to the challenger and the aggregator is deactivated
+        assert_eq!(Balances::free_balance(3), 10 + OPTIMISTIC_BOND);
+        assert_eq!(
+            Balances::total_balance(&AGGREGATOR),
+            1_000 - OPTIMISTIC_BOND
+        );
+        assert!(!Aggregators::<Test>::get(AGGREGATOR).unwrap().active);
+
+        // The batch is gone and its requests can be settled by another batch
+        assert!(PendingBatches::<Test>::get(1).is_none());
+        assert_eq!(PendingRequests::<Test>::get(10), None);
+        assert_noop!(
+            ReMLVerifier::finalize_optimistic_batch(RuntimeOrigin::signed(3), 1),
+            Error::<Test>::PendingBatchNotFound
+        );
+        assert_ok!(ReMLVerifier::bond_aggregator(RuntimeOrigin::signed(BONDER)));
+        assert_ok!(ReMLVerifier::submit_proof(
+            RuntimeOrigin::signed(BONDER),
+            submission(1, &[10, 11])
+        ));
+    });
+}
+
+#[test]
+fn fraud_proof_slashes_aggregator_bond() {
+    new_test_ext().execute_with(|| {
+        Balances::make_free_balance_be(&3, 10);
+        assert_ok!(ReMLVerifier::bond_aggregator(RuntimeOrigin::signed(BONDER)));
+        assert_ok!(ReMLVerifier::submit_optimistic_batch(
+            RuntimeOrigin::signed(BONDER),
+            optimistic_submission(1, &[10], &[10])
+        ));
+
+        assert_ok!(challenge(1, 10, true));
+        System::assert_has_event(RuntimeEvent::ReMLVerifier(Event::AggregatorSlashed {
+            aggregator: BONDER,
+            amount: BOND / 2,
+            reason: SlashReason::InvalidBatch,
+        }));
+
+        // The batch bond goes to the challenger, the slash to the treasury
+        let info = Aggregators::<Test>::get(BONDER).unwrap();
+        assert_eq!(info.bond, BOND / 2);
+        assert!(!info.active);
+        assert_eq!(Balances::reserved_balance(BONDER), BOND / 2);
+        assert_eq!(
+            Balances::total_balance(&BONDER),
+            1_000 - OPTIMISTIC_BOND - BOND / 2
+        );
+        assert_eq!(Balances::free_balance(3), 10 + OPTIMISTIC_BOND);
+        assert_eq!(Balances::free_balance(REWARD_ACCOUNT), 1_000 + BOND / 2);
+    });
+}
+
+#[test]
+fn fraud_proof_after_challenge_period_is_rejected() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ReMLVerifier::submit_optimistic_batch(
+            RuntimeOrigin::signed(AGGREGATOR),
+            optimistic_submission(1, &[10], &[10])
+        ));
+
+        System::set_block_number(1 + CHALLENGE_PERIOD);
+        assert_noop!(challenge(1, 10, true), Error::<Test>::ChallengePeriodOver);
+    });
+}
+
+#[test]
+fn pending_batch_claims_its_id_and_requests() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ReMLVerifier::submit_optimistic_batch(
+            RuntimeOrigin::signed(AGGREGATOR),
+            optimistic_submission(1, &[10, 11], &[])
+        ));
+
+        assert_noop!(
+            ReMLVerifier::submit_proof(RuntimeOrigin::signed(AGGREGATOR), submission(1, &[12])),
+            Error::<Test>::BatchPending
+        );
+        assert_noop!(
+            ReMLVerifier::submit_proof(RuntimeOrigin::signed(AGGREGATOR), submission(2, &[11])),
+            Error::<Test>::RequestPending
+        );
+        assert_noop!(
+            ReMLVerifier::submit_optimistic_batch(
+                RuntimeOrigin::signed(AGGREGATOR),
+                optimistic_submission(2, &[10], &[])
+            ),
+            Error::<Test>::RequestPending
+        );
+    });
+}
+
+#[test]
+fn submit_optimistic_batch_validates_input() {
+    new_test_ext().execute_with(|| {
+        let mut short = optimistic_submission(1, &[10, 11], &[]);
+        short.request_commitments.pop();
+        assert_noop!(
+            ReMLVerifier::submit_optimistic_batch(RuntimeOrigin::signed(AGGREGATOR), short),
+            Error::<Test>::InvalidRequestCommitments
+        );
+
+        let mut wrong_count = optimistic_submission(1, &[10, 11], &[]);
+        wrong_count.public_values.verified_count = 3;
+        assert_noop!(
+            ReMLVerifier::submit_optimistic_batch(RuntimeOrigin::signed(AGGREGATOR), wrong_count),
+            Error::<Test>::InvalidPublicValues
+        );
+
+        // Rejections need a proof
+        let mut with_rejection = optimistic_submission(1, &[10], &[]);
+        with_rejection.public_values.rejected_request_ids =
+            vec![(11, RequestRejection::InvalidSignature)]
+                .try_into()
+                .unwrap();
+        assert_noop!(
+            ReMLVerifier::submit_optimistic_batch(
+                RuntimeOrigin::signed(AGGREGATOR),
+                with_rejection
+            ),
+            Error::<Test>::InvalidPublicValues
+        );
+
+        // An admin-registered aggregator still needs the batch bond
+        assert_ok!(ReMLVerifier::register_aggregator(RuntimeOrigin::root(), 5));
+        assert_noop!(
+            ReMLVerifier::submit_optimistic_batch(
+                RuntimeOrigin::signed(5),
+                optimistic_submission(1, &[10], &[])
+            ),
+            Error::<Test>::InsufficientBond
+        );
+    });
+}
+
+#[test]
+fn fraud_proof_voids_other_pending_batches() {
+    new_test_ext().execute_with(|| {
+        Balances::make_free_balance_be(&3, 10);
+        assert_ok!(ReMLVerifier::submit_optimistic_batch(
+            RuntimeOrigin::signed(AGGREGATOR),
+            optimistic_submission(1, &[10], &[])
+        ));
+        assert_ok!(ReMLVerifier::submit_optimistic_batch(
+            RuntimeOrigin::signed(AGGREGATOR),
+            optimistic_submission(2, &[11, 12], &[12])
+        ));
+        assert_ok!(challenge(2, 12, true));
+        assert_eq!(FraudProvenAt::<Test>::get(AGGREGATOR), Some(1));
+
+        // Voided within its challenge period, the bond going to the treasury
+        assert_ok!(ReMLVerifier::finalize_optimistic_batch(
+            RuntimeOrigin::signed(3),
+            1
+        ));
+        System::assert_last_event(RuntimeEvent::ReMLVerifier(Event::OptimisticBatchVoided {
+            batch_id: 1,
+            aggregator: AGGREGATOR,
+            slashed: OPTIMISTIC_BOND,
+        }));
+        assert!(PendingBatches::<Test>::get(1).is_none());
+        assert_eq!(PendingRequests::<Test>::get(10), None);
+        assert!(!ReMLVerifier::is_request_verified(10));
+        assert_eq!(
+            Balances::total_balance(&AGGREGATOR),
+            1_000 - 2 * OPTIMISTIC_BOND
+        );
+        assert_eq!(
+            Balances::free_balance(REWARD_ACCOUNT),
+            1_000 + OPTIMISTIC_BOND
+        );
+        assert_eq!(Balances::free_balance(3), 10 + OPTIMISTIC_BOND);
+    });
+}
+
+/// Reveal the signature data of `request_id` in `batch_id` as `responder`
+fn respond(
+    responder: u64,
+    batch_id: u64,
+    request_id: u64,
+    forged: bool,
+) -> frame_support::dispatch::DispatchResultWithPostInfo {
+    let (message, public_key, signature) = signature_data(request_id, forged);
+    ReMLVerifier::respond_batch_data(
+        RuntimeOrigin::signed(responder),
+        batch_id,
+        request_id,
+        message,
+        public_key,
+        signature,
+    )
+}
+
+#[test]
+fn data_challenge_blocks_finalization_until_answered() {
+    new_test_ext().execute_with(|| {
+        Balances::make_free_balance_be(&3, 10);
+        assert_ok!(ReMLVerifier::submit_optimistic_batch(
+            RuntimeOrigin::signed(AGGREGATOR),
+            optimistic_submission(1, &[10, 11], &[])
+        ));
+
+        assert_ok!(ReMLVerifier::challenge_batch_data(
+            RuntimeOrigin::signed(3),
+            1,
+            10
+        ));
+        System::assert_last_event(RuntimeEvent::ReMLVerifier(Event::DataChallenged {
+            batch_id: 1,
+            request_id: 10,
+            challenger: 3,
+            respond_by: 1 + DATA_RESPONSE_PERIOD,
+        }));
+        assert_eq!(Balances::reserved_balance(3), DATA_CHALLENGE_DEPOSIT);
+        assert_noop!(
+            ReMLVerifier::challenge_batch_data(RuntimeOrigin::signed(3), 1, 10),
+            Error::<Test>::DataAlreadyChallenged
+        );
+        assert_noop!(
+            ReMLVerifier::challenge_batch_data(RuntimeOrigin::signed(3), 1, 12),
+            Error::<Test>::RequestNotInBatch
+        );
+        assert_noop!(
+            respond(AGGREGATOR, 1, 11, false),
+            Error::<Test>::DataChallengeNotFound
+        );
+
+        System::set_block_number(1 + CHALLENGE_PERIOD);
+        assert_noop!(
+            ReMLVerifier::challenge_batch_data(RuntimeOrigin::signed(3), 1, 11),
+            Error::<Test>::ChallengePeriodOver
+        );
+        assert_noop!(
+            ReMLVerifier::finalize_optimistic_batch(RuntimeOrigin::signed(3), 1),
+            Error::<Test>::DataChallengeOpen
+        );
+
+        assert_noop!(
+            respond(AGGREGATOR, 1, 10, true),
+            Error::<Test>::RequestCommitmentMismatch
+        );
+        assert_ok!(respond(AGGREGATOR, 1, 10, false));
+        System::assert_last_event(RuntimeEvent::ReMLVerifier(Event::DataChallengeAnswered {
+            batch_id: 1,
+            request_id: 10,
+            responder: AGGREGATOR,
+        }));
+        // The deposit compensates the responder
+        assert_eq!(Balances::total_balance(&3), 10 - DATA_CHALLENGE_DEPOSIT);
+        assert_eq!(
+            Balances::free_balance(AGGREGATOR),
+            1_000 - OPTIMISTIC_BOND + DATA_CHALLENGE_DEPOSIT
+        );
+
+        assert_ok!(ReMLVerifier::finalize_optimistic_batch(
+            RuntimeOrigin::signed(3),
+            1
+        ));
+        assert!(ReMLVerifier::is_request_verified(10));
+    });
+}
+
+#[test]
+fn withheld_data_voids_batch() {
+    new_test_ext().execute_with(|| {
+        Balances::make_free_balance_be(&3, 10);
+        assert_ok!(ReMLVerifier::submit_optimistic_batch(
+            RuntimeOrigin::signed(AGGREGATOR),
+            optimistic_submission(1, &[10, 11], &[])
+        ));
+        assert_ok!(ReMLVerifier::challenge_batch_data(
+            RuntimeOrigin::signed(3),
+            1,
+            11
+        ));
+
+        System::set_block_number(DATA_RESPONSE_PERIOD);
+        assert_noop!(
+            ReMLVerifier::claim_data_challenge(RuntimeOrigin::signed(BONDER), 1, 11),
+            Error::<Test>::DataResponsePeriodActive
+        );
+        assert_noop!(
+            ReMLVerifier::claim_data_challenge(RuntimeOrigin::signed(BONDER), 1, 10),
+            Error::<Test>::DataChallengeNotFound
+        );
+
+        System::set_block_number(1 + DATA_RESPONSE_PERIOD);
+        assert_ok!(ReMLVerifier::claim_data_challenge(
+            RuntimeOrigin::signed(BONDER),
+            1,
+            11
+        ));
+        System::assert_last_event(RuntimeEvent::ReMLVerifier(Event::BatchDataWithheld {
+            batch_id: 1,
+            aggregator: AGGREGATOR,
+            challenger: 3,
+            request_id: 11,
+            slashed: OPTIMISTIC_BOND,
+        }));
+
+        // The challenger gets the deposit back and the bond
+        assert_eq!(Balances::reserved_balance(3), 0);
+        assert_eq!(Balances::free_balance(3), 10 + OPTIMISTIC_BOND);
+        assert!(!Aggregators::<Test>::get(AGGREGATOR).unwrap().active);
+        assert_eq!(
+            FraudProvenAt::<Test>::get(AGGREGATOR),
+            Some(1 + DATA_RESPONSE_PERIOD)
+        );
+        assert!(PendingBatches::<Test>::get(1).is_none());
+        assert_eq!(PendingRequests::<Test>::get(11), None);
+        assert!(DataChallenges::<Test>::get(1, 11).is_none());
+    });
+}
+
+#[test]
+fn withheld_data_slashes_aggregator_bond() {
+    new_test_ext().execute_with(|| {
+        Balances::make_free_balance_be(&3, 10);
+        assert_ok!(ReMLVerifier::bond_aggregator(RuntimeOrigin::signed(BONDER)));
+        assert_ok!(ReMLVerifier::submit_optimistic_batch(
+            RuntimeOrigin::signed(BONDER),
+            optimistic_submission(1, &[10], &[])
+        ));
+        assert_ok!(ReMLVerifier::challenge_batch_data(
+            RuntimeOrigin::signed(3),
+            1,
+            10
+        ));
+
+        System::set_block_number(1 + DATA_RESPONSE_PERIOD);
+        assert_ok!(ReMLVerifier::claim_data_challenge(
+            RuntimeOrigin::signed(AGGREGATOR),
+            1,
+            10
+        ));
+        System::assert_has_event(RuntimeEvent::ReMLVerifier(Event::AggregatorSlashed {
+            aggregator: BONDER,
+            amount: BOND / 2,
+            reason: SlashReason::InvalidBatch,
+        }));
+        let info = Aggregators::<Test>::get(BONDER).unwrap();
+        assert_eq!(info.bond, BOND / 2);
+        assert!(!info.active);
+        assert_eq!(Balances::free_balance(REWARD_ACCOUNT), 1_000 + BOND / 2);
+    });
+}
+
+#[test]
+fn revealed_forged_data_proves_fraud() {
+    new_test_ext().execute_with(|| {
+        Balances::make_free_balance_be(&3, 10);
+        assert_ok!(ReMLVerifier::submit_optimistic_batch(
+            RuntimeOrigin::signed(AGGREGATOR),
+            optimistic_submission(1, &[10, 11], &[11])
+        ));
+        assert_ok!(ReMLVerifier::challenge_batch_data(
+            RuntimeOrigin::signed(3),
+            1,
+            11
+        ));
+
+        assert_ok!(respond(BONDER, 1, 11, true));
+        System::assert_last_event(RuntimeEvent::ReMLVerifier(Event::FraudProven {
+            batch_id: 1,
+            aggregator: AGGREGATOR,
+            challenger: 3,
+            request_id: 11,
+            slashed: OPTIMISTIC_BOND,
+        }));
+        assert_eq!(Balances::free_balance(3), 10 + OPTIMISTIC_BOND);
+        assert!(!Aggregators::<Test>::get(AGGREGATOR).unwrap().active);
+        assert!(PendingBatches::<Test>::get(1).is_none());
+        assert!(DataChallenges::<Test>::get(1, 11).is_none());
+    });
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// AGGREGATED PROOF TESTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn aggregated_proof_settles_every_batch() {
+    new_test_ext().execute_with(|| {
+        allow_aggregation_vkey();
+        let aggregated = aggregated_submission(&[
+            submission(1, &[10, 11]),
+            submission_with_rejections(2, &[12], &[(13, RequestRejection::InvalidSignature)]),
+        ]);
+        let batches_root = aggregated.public_values.batches_root;
+        assert_ok!(ReMLVerifier::submit_aggregated_proof(
+            RuntimeOrigin::signed(AGGREGATOR),
+            aggregated
+        ));
+
+        for request_id in [10, 11, 12] {
+            assert!(ReMLVerifier::is_request_verified(request_id));
+        }
+        assert!(ReMLVerifier::is_request_rejected(13));
+        assert_eq!(
+            BatchRequestIds::<Test>::get(1).unwrap().to_vec(),
+            vec![10, 11]
+        );
+        // Children record the vkey their proofs were verified under
+        let batch = VerifiedBatches::<Test>::get(2).unwrap();
+        assert_eq!(batch.vkey_hash, VKEY);
+        assert_eq!(batch.signature_count, 1);
+        assert_ne!(
+            VerifiedBatches::<Test>::get(1).unwrap().proof_commitment,
+            batch.proof_commitment
+        );
+
+        // Counted per batch, but not rewarded
+        let info = Aggregators::<Test>::get(AGGREGATOR).unwrap();
+        assert_eq!(info.proofs_submitted, 2);
+        assert_eq!(info.rewards_earned, 0);
+        assert_eq!(Balances::free_balance(REWARD_ACCOUNT), 1_000);
+        assert_eq!(TotalProofsVerified::<Test>::get(), 2);
+        assert_eq!(TotalSignaturesVerified::<Test>::get(), 3);
+
+        System::assert_has_event(RuntimeEvent::ReMLVerifier(Event::ProofVerified {
+            batch_id: 2,
+            aggregator: AGGREGATOR,
+            signature_count: 1,
+            block_number: 1,
+        }));
+        System::assert_last_event(RuntimeEvent::ReMLVerifier(Event::AggregatedProofVerified {
+            aggregator: AGGREGATOR,
+            batch_count: 2,
+            signature_count: 3,
+            batches_root,
+        }));
+    });
+}
+
+#[test]
+fn aggregated_proof_checks_every_batch() {
+    new_test_ext().execute_with(|| {
+        allow_aggregation_vkey();
+        let submit = |batches: &[ProofSubmission]| {
+            ReMLVerifier::submit_aggregated_proof(
+                RuntimeOrigin::signed(AGGREGATOR),
+                aggregated_submission(batches),
+            )
+        };
+
+        assert_noop!(submit(&[]), Error::<Test>::NoBatches);
+        assert_noop!(
+            submit(&[submission(1, &[10]), submission(1, &[11])]),
+            Error::<Test>::DuplicateBatchId
+        );
+        assert_noop!(
+            submit(&[submission(1, &[10]), submission(2, &[11, 10])]),
+            Error::<Test>::DuplicateRequestId
+        );
+        assert_noop!(
+            submit(&[submission(1, &[10]), submission(2, &[ALLOCATED_REQUESTS])]),
+            Error::<Test>::RequestNotAllocated
+        );
+        // Every child must settle a request, if only a rejected one
+        assert_noop!(
+            submit(&[submission(1, &[10]), submission(2, &[])]),
+            Error::<Test>::ProofVerificationFailed
+        );
+
+        let mut wrong_root = aggregated_submission(&[submission(1, &[10]), submission(2, &[11])]);
+        wrong_root.public_values.batches_root = [0xAA; 32];
+        assert_noop!(
+            ReMLVerifier::submit_aggregated_proof(RuntimeOrigin::signed(AGGREGATOR), wrong_root),
+            Error::<Test>::InvalidMerkleRoot
+        );
+
+        // Child proofs must come from an allowed batch guest
+        let mut unknown_guest = aggregated_submission(&[submission(1, &[10])]);
+        unknown_guest.public_values.batch_vkey_hash = [0xAA; 32];
+        assert_noop!(
+            ReMLVerifier::submit_aggregated_proof(RuntimeOrigin::signed(AGGREGATOR), unknown_guest),
+            Error::<Test>::InvalidVKeyHash
+        );
+
+        // A batch settled on its own cannot be aggregated again
+        assert_ok!(ReMLVerifier::submit_proof(
+            RuntimeOrigin::signed(AGGREGATOR),
+            submission(1, &[10])
+        ));
+        assert_noop!(
+            submit(&[submission(2, &[11]), submission(1, &[12])]),
+            Error::<Test>::BatchAlreadyVerified
+        );
+
+        assert_noop!(
+            ReMLVerifier::submit_aggregated_proof(
+                RuntimeOrigin::signed(BONDER),
+                aggregated_submission(&[submission(2, &[11])])
+            ),
+            Error::<Test>::NotAuthorized
+        );
+
+        assert_ok!(submit(&[
+            submission(2, &[11]),
+            submission_with_rejections(3, &[], &[(12, RequestRejection::InvalidSize)])
+        ]));
+        assert!(ReMLVerifier::is_request_rejected(12));
+    });
+}
+
+#[test]
+fn aggregated_proof_needs_exact_binding() {
+    new_test_ext().execute_with(|| {
+        allow_aggregation_vkey();
+        let submit = |submission: AggregatedProofSubmission| {
+            ReMLVerifier::submit_aggregated_proof(RuntimeOrigin::signed(AGGREGATOR), submission)
+        };
+        let aggregated = aggregated_submission(&[submission(1, &[10]), submission(2, &[11])]);
+        let public_hash = ReMLVerifier::compute_aggregated_public_values_hash(
+            &AGGREGATION_VKEY,
+            &aggregated.public_values,
+        );
+
+        // Size alone does not pass
+        let mut unbound = aggregated.clone();
+        unbound.proof = vec![0xCD; 2 * MIN_PROOF_SIZE].try_into().unwrap();
+        assert_noop!(submit(unbound), Error::<Test>::ProofVerificationFailed);
+
+        // Nor does a near miss
+        let mut near_miss = aggregated.clone();
+        let mut near_hash = public_hash;
+        near_hash[0] ^= 0xFF;
+        near_hash[31] ^= 0xFF;
+        near_miss.proof = groth16_proof(near_hash);
+        assert_noop!(submit(near_miss), Error::<Test>::ProofVerificationFailed);
+
+        // The aggregation vkey is bound
+        let mut other_guest = aggregated.clone();
+        other_guest.proof = groth16_proof(ReMLVerifier::compute_aggregated_public_values_hash(
+            &[0xAA; 32],
+            &aggregated.public_values,
+        ));
+        assert_noop!(submit(other_guest), Error::<Test>::ProofVerificationFailed);
+
+        // So is each child's content, not only its requests root
+        let mut added_rejection = aggregated_submission(&[
+            submission(1, &[10]),
+            submission_with_rejections(2, &[11], &[(12, RequestRejection::InvalidSize)]),
+        ]);
+        assert_eq!(
+            added_rejection.public_values.batches_root,
+            aggregated.public_values.batches_root
+        );
+        added_rejection.proof = aggregated.proof.clone();
+        assert_noop!(
+            submit(added_rejection),
+            Error::<Test>::ProofVerificationFailed
+        );
+
+        let mut bound = aggregated;
+        bound.proof = groth16_proof(public_hash);
+        assert_ok!(submit(bound));
+    });
+}
+
+#[test]
+fn aggregated_proof_needs_allowed_aggregation_vkey() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ReMLVerifier::submit_aggregated_proof(
+                RuntimeOrigin::signed(AGGREGATOR),
+                aggregated_submission(&[submission(1, &[10])])
+            ),
+            Error::<Test>::InvalidVKeyHash
+        );
+    });
+}
+
+#[test]
+fn aggregated_batch_can_be_invalidated_alone() {
+    new_test_ext().execute_with(|| {
+        allow_aggregation_vkey();
+        let aggregated = aggregated_submission(&[submission(1, &[10]), submission(2, &[11])]);
+        assert_ok!(ReMLVerifier::submit_aggregated_proof(
+            RuntimeOrigin::signed(AGGREGATOR),
+            aggregated.clone()
+        ));
+
+        assert_ok!(ReMLVerifier::report_invalid_batch(RuntimeOrigin::root(), 1));
+        assert!(!ReMLVerifier::is_request_verified(10));
+        assert!(ReMLVerifier::is_request_verified(11));
+
+        // The invalidated batch keeps its proof commitment, so the aggregated
+        // proof cannot be replayed
+        assert_noop!(
+            ReMLVerifier::submit_aggregated_proof(RuntimeOrigin::signed(AGGREGATOR), aggregated),
+            Error::<Test>::ProofAlreadyUsed
+        );
+    });
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// VERIFICATION KEY TESTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+const NEW_VKEY: [u8; 32] = [8u8; 32];
+
+/// Submission for `batch_id` proven under `vkey_hash`
+fn submission_with_vkey(batch_id: u64, vkey_hash: [u8; 32]) -> crate::ProofSubmission {
+    let mut submission = submission(batch_id, &[batch_id * 10]);
+    submission.vkey_hash = vkey_hash;
+    submission
+}
+
+#[test]
+fn vkey_admin_requires_admin_origin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ReMLVerifier::add_vkey(RuntimeOrigin::signed(AGGREGATOR), NEW_VKEY, 2, None),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            ReMLVerifier::retire_vkey(RuntimeOrigin::signed(AGGREGATOR), VKEY, None),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn submit_proof_rejects_unknown_vkey() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ReMLVerifier::submit_proof(
+                RuntimeOrigin::signed(AGGREGATOR),
+                submission_with_vkey(1, NEW_VKEY)
+            ),
+            Error::<Test>::InvalidVKeyHash
+        );
+    });
+}
+
+#[test]
+fn add_vkey_validates_input() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ReMLVerifier::add_vkey(RuntimeOrigin::root(), [0u8; 32], 2, None),
+            Error::<Test>::InvalidVKeyHash
+        );
+        assert_noop!(
+            ReMLVerifier::add_vkey(RuntimeOrigin::root(), VKEY, 2, None),
+            Error::<Test>::VKeyAlreadyAllowed
+        );
+
+        // MaxVKeys is 3, VKEY is allowed at genesis
+        assert_ok!(ReMLVerifier::add_vkey(
+            RuntimeOrigin::root(),
+            [1u8; 32],
+            2,
+            None
+        ));
+        assert_ok!(ReMLVerifier::add_vkey(
+            RuntimeOrigin::root(),
+            [2u8; 32],
+            3,
+            None
+        ));
+        assert_noop!(
+            ReMLVerifier::add_vkey(RuntimeOrigin::root(), NEW_VKEY, 4, None),
+            Error::<Test>::TooManyVKeys
+        );
+        assert_eq!(AllowedVKeys::<Test>::count(), 3);
+    });
+}
+
+#[test]
+fn vkey_activates_at_active_from() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ReMLVerifier::add_vkey(
+            RuntimeOrigin::root(),
+            NEW_VKEY,
+            2,
+            Some(10)
+        ));
+
+        assert_noop!(
+            ReMLVerifier::submit_proof(
+                RuntimeOrigin::signed(AGGREGATOR),
+                submission_with_vkey(1, NEW_VKEY)
+            ),
+            Error::<Test>::VKeyNotActive
+        );
+
+        System::set_block_number(10);
+        assert_ok!(ReMLVerifier::submit_proof(
+            RuntimeOrigin::signed(AGGREGATOR),
+            submission_with_vkey(1, NEW_VKEY)
+        ));
+        assert_eq!(VerifiedBatches::<Test>::get(1).unwrap().vkey_hash, NEW_VKEY);
+    });
+}
+
+#[test]
+fn guest_upgrade_overlaps_old_and_new_vkeys() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ReMLVerifier::add_vkey(
+            RuntimeOrigin::root(),
+            NEW_VKEY,
+            2,
+            None
+        ));
+        assert_ok!(ReMLVerifier::retire_vkey(
+            RuntimeOrigin::root(),
+            VKEY,
+            Some(5)
+        ));
+        assert_eq!(AllowedVKeys::<Test>::get(VKEY).unwrap().expires_at, Some(5));
+
+        // Both provers are accepted during the overlap
+        assert_ok!(ReMLVerifier::submit_proof(
+            RuntimeOrigin::signed(AGGREGATOR),
+            submission_with_vkey(1, VKEY)
+        ));
+        assert_ok!(ReMLVerifier::submit_proof(
+            RuntimeOrigin::signed(AGGREGATOR),
+            submission_with_vkey(2, NEW_VKEY)
+        ));
+
+        System::set_block_number(5);
+        assert_noop!(
+            ReMLVerifier::submit_proof(
+                RuntimeOrigin::signed(AGGREGATOR),
+                submission_with_vkey(3, VKEY)
+            ),
+            Error::<Test>::VKeyNotActive
+        );
+        assert_ok!(ReMLVerifier::submit_proof(
+            RuntimeOrigin::signed(AGGREGATOR),
+            submission_with_vkey(3, NEW_VKEY)
+        ));
+
+        // Batches keep the vkey they were proven under
+        assert_eq!(VerifiedBatches::<Test>::get(1).unwrap().vkey_hash, VKEY);
+        assert_eq!(VerifiedBatches::<Test>::get(3).unwrap().vkey_hash, NEW_VKEY);
+    });
+}
+
+#[test]
+fn retire_vkey_without_future_expiry_removes_it() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(5);
+        assert_ok!(ReMLVerifier::retire_vkey(
+            RuntimeOrigin::root(),
+            VKEY,
+            Some(5)
+        ));
+        assert!(!AllowedVKeys::<Test>::contains_key(VKEY));
+        assert_eq!(AllowedVKeys::<Test>::count(), 0);
+
+        assert_noop!(
+            ReMLVerifier::retire_vkey(RuntimeOrigin::root(), VKEY, None),
+            Error::<Test>::VKeyNotFound
+        );
+        assert_noop!(
+            ReMLVerifier::submit_proof(
+                RuntimeOrigin::signed(AGGREGATOR),
+                submission_with_vkey(1, VKEY)
+            ),
+            Error::<Test>::InvalidVKeyHash
+        );
+    });
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// REQUEST CONSUMPTION TESTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn consume_verified_request_is_single_use() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ReMLVerifier::submit_proof(
+            RuntimeOrigin::signed(AGGREGATOR),
+            submission(1, &[10])
+        ));
+
+        assert_ok!(ReMLVerifier::consume_verified_request(
+            10, &BONDER, [1u8; 32]
+        ));
+        assert!(ReMLVerifier::is_request_consumed(10));
+        let info = ConsumedRequests::<Test>::get(10).unwrap();
+        assert_eq!(info.consumer, BONDER);
+        assert_eq!(info.call, [1u8; 32]);
+        assert_eq!(info.consumed_at, 1);
+        System::assert_last_event(RuntimeEvent::ReMLVerifier(Event::RequestConsumed {
+            request_id: 10,
+            consumer: BONDER,
+            call: [1u8; 32],
+        }));
+
+        // Neither another account nor another call can reuse it
+        assert_noop!(
+            ReMLVerifier::consume_verified_request(10, &BONDER, [1u8; 32]),
+            Error::<Test>::RequestAlreadyConsumed
+        );
+        assert_noop!(
+            ReMLVerifier::consume_verified_request(10, &3, [2u8; 32]),
+            Error::<Test>::RequestAlreadyConsumed
+        );
+    });
+}
+
+#[test]
+fn consume_requires_verified_request() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ReMLVerifier::consume_verified_request(10, &BONDER, [1u8; 32]),
+            Error::<Test>::RequestNotVerified
+        );
+    });
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// RETENTION TESTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+fn on_idle(now: u64, limit: Weight) -> Weight {
+    <ReMLVerifier as Hooks<u64>>::on_idle(now, limit)
+}
+
+#[test]
+fn expired_batch_is_pruned_on_idle() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ReMLVerifier::submit_proof(
+            RuntimeOrigin::signed(AGGREGATOR),
+            submission(1, &[10, 11])
+        ));
+        let commitment = H256::from(VerifiedBatches::<Test>::get(1).unwrap().proof_commitment);
+
+        on_idle(RETENTION_PERIOD, Weight::MAX);
+        assert!(VerifiedBatches::<Test>::contains_key(1));
+
+        on_idle(1 + RETENTION_PERIOD, Weight::MAX);
+        assert!(!VerifiedBatches::<Test>::contains_key(1));
+        assert!(!BatchRequestIds::<Test>::contains_key(1));
+        assert!(!ReMLVerifier::is_request_verified(10));
+        assert!(!ReMLVerifier::is_request_verified(11));
+        assert!(!ProofCommitments::<Test>::contains_key(commitment));
+        assert_eq!(PruneCursor::<Test>::get().head, 1);
+        System::assert_has_event(RuntimeEvent::ReMLVerifier(Event::BatchPruned {
+            batch_id: 1,
+        }));
+
+        // The ID stays tombstoned for another retention period
+        assert_eq!(
+            PrunedBatches::<Test>::get(1),
+            Some(1 + 2 * RETENTION_PERIOD)
+        );
+        assert!(BatchQueue::<Test>::contains_key(0));
+        on_idle(2 * RETENTION_PERIOD, Weight::MAX);
+        assert!(PrunedBatches::<Test>::contains_key(1));
+
+        on_idle(1 + 2 * RETENTION_PERIOD, Weight::MAX);
+        assert!(!PrunedBatches::<Test>::contains_key(1));
+        assert!(!BatchQueue::<Test>::contains_key(0));
+        assert_eq!(PruneCursor::<Test>::get().tombstone_head, 1);
+    });
+}
+
+#[test]
+fn pruned_batch_id_is_tombstoned_for_a_retention_period() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ReMLVerifier::submit_proof(
+            RuntimeOrigin::signed(AGGREGATOR),
+            submission(3, &[10])
+        ));
+        System::set_block_number(1 + RETENTION_PERIOD);
+        on_idle(1 + RETENTION_PERIOD, Weight::MAX);
+
+        assert_noop!(
+            ReMLVerifier::submit_proof(RuntimeOrigin::signed(AGGREGATOR), submission(3, &[11])),
+            Error::<Test>::BatchExpired
+        );
+        // Only the pruned ID is blocked, lower and higher IDs are not
+        assert_ok!(ReMLVerifier::submit_proof(
+            RuntimeOrigin::signed(AGGREGATOR),
+            submission(1, &[11])
+        ));
+        assert_ok!(ReMLVerifier::submit_proof(
+            RuntimeOrigin::signed(AGGREGATOR),
+            submission(u64::MAX, &[12])
+        ));
+
+        // Batches 1 and `u64::MAX` are pruned as the tombstone of 3 expires
+        System::set_block_number(1 + 2 * RETENTION_PERIOD);
+        on_idle(1 + 2 * RETENTION_PERIOD, Weight::MAX);
+        assert!(PrunedBatches::<Test>::contains_key(1));
+        assert!(PrunedBatches::<Test>::contains_key(u64::MAX));
+        assert_ok!(ReMLVerifier::submit_proof(
+            RuntimeOrigin::signed(AGGREGATOR),
+            submission(3, &[13])
+        ));
+    });
+}
+
+#[test]
+fn pruned_request_cannot_be_verified_again() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ReMLVerifier::submit_proof(
+            RuntimeOrigin::signed(AGGREGATOR),
+            submission(1, &[10])
+        ));
+        on_idle(1 + RETENTION_PERIOD, Weight::MAX);
+
+        assert!(!RequestOwners::<Test>::contains_key(10));
+        assert_noop!(
+            ReMLVerifier::submit_proof(RuntimeOrigin::signed(AGGREGATOR), submission(2, &[10])),
+            Error::<Test>::RequestNotAllocated
+        );
+    });
+}
+
+#[test]
+fn consumed_requests_are_pruned_with_their_batch() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ReMLVerifier::submit_proof(
+            RuntimeOrigin::signed(AGGREGATOR),
+            submission(1, &[11, 12])
+        ));
+        assert_ok!(ReMLVerifier::consume_verified_request(
+            11, &BONDER, [1u8; 32]
+        ));
+
+        on_idle(1 + RETENTION_PERIOD, Weight::MAX);
+
+        assert!(!VerifiedBatches::<Test>::contains_key(1));
+        assert!(!ReMLVerifier::is_request_verified(11));
+        assert!(!ReMLVerifier::is_request_consumed(11));
+        assert!(!RequestOwners::<Test>::contains_key(11));
+        assert!(!ReMLVerifier::is_request_verified(12));
+
+        // Without an allocation the request can be neither consumed nor
+        // settled again
+        assert_noop!(
+            ReMLVerifier::consume_verified_request(11, &BONDER, [1u8; 32]),
+            Error::<Test>::RequestNotVerified
+        );
+        System::set_block_number(1 + RETENTION_PERIOD);
+        assert_noop!(
+            ReMLVerifier::submit_proof(RuntimeOrigin::signed(AGGREGATOR), submission(2, &[11])),
+            Error::<Test>::RequestNotAllocated
+        );
+    });
+}
+
+#[test]
+fn consumed_request_of_a_revoked_batch_is_dropped_with_its_allocation() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ReMLVerifier::submit_proof(
+            RuntimeOrigin::signed(AGGREGATOR),
+            submission(1, &[10])
+        ));
+        assert_ok!(ReMLVerifier::consume_verified_request(
+            10, &BONDER, [1u8; 32]
+        ));
+        assert_ok!(ReMLVerifier::report_invalid_batch(RuntimeOrigin::root(), 1));
+
+        // The allocation is still live, so the record keeps the request from
+        // being consumed twice
+        assert!(RequestOwners::<Test>::contains_key(10));
+        assert!(ReMLVerifier::is_request_consumed(10));
+
+        on_idle(1 + ALLOCATION_EXPIRY, Weight::MAX);
+        assert!(!RequestOwners::<Test>::contains_key(10));
+        assert!(!ReMLVerifier::is_request_consumed(10));
+    });
+}
+
+#[test]
+fn pruning_resumes_where_the_weight_ran_out() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ReMLVerifier::submit_proof(
+            RuntimeOrigin::signed(AGGREGATOR),
+            submission(1, &[10, 11, 12])
+        ));
+        let now = 1 + RETENTION_PERIOD;
+
+        // Room for the batch and two of its three requests
+        let limit = <() as WeightInfo>::prune_cursor()
+            .saturating_add(<() as WeightInfo>::prune_batch())
+            .saturating_add(<() as WeightInfo>::prune_request().saturating_mul(2));
+        assert_eq!(on_idle(now, limit), limit);
+        assert_eq!(PruneCursor::<Test>::get().request_index, 2);
+        assert!(VerifiedBatches::<Test>::contains_key(1));
+        assert!(!ReMLVerifier::is_request_verified(11));
+        assert!(ReMLVerifier::is_request_verified(12));
+
+        // Not even the cursor fits
+        assert_eq!(on_idle(now, Weight::zero()), Weight::zero());
+
+        on_idle(now, Weight::MAX);
+        assert!(!VerifiedBatches::<Test>::contains_key(1));
+        assert!(!ReMLVerifier::is_request_verified(12));
+        assert_eq!(PruneCursor::<Test>::get().request_index, 0);
+    });
+}
+
+#[test]
+fn pruning_keeps_a_batch_proven_again_after_invalidation() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ReMLVerifier::submit_proof(
+            RuntimeOrigin::signed(AGGREGATOR),
+            submission(1, &[10])
+        ));
+        assert_ok!(ReMLVerifier::report_invalid_batch(RuntimeOrigin::root(), 1));
+
+        System::set_block_number(5);
+        assert_ok!(ReMLVerifier::register_aggregator(RuntimeOrigin::root(), 10));
+        assert_ok!(ReMLVerifier::submit_proof(
+            RuntimeOrigin::signed(10),
+            submission(1, &[10, 11])
+        ));
+
+        // The invalidated proof expires, the new one is kept
+        on_idle(1 + RETENTION_PERIOD, Weight::MAX);
+        assert_eq!(VerifiedBatches::<Test>::get(1).unwrap().aggregator, 10);
+        assert!(ReMLVerifier::is_request_verified(10));
+
+        on_idle(5 + RETENTION_PERIOD, Weight::MAX);
+        assert!(!VerifiedBatches::<Test>::contains_key(1));
+        assert!(!ReMLVerifier::is_request_verified(10));
+        // Only the proof that was pruned leaves a tombstone
+        assert_eq!(
+            PrunedBatches::<Test>::get(1),
+            Some(5 + 2 * RETENTION_PERIOD)
+        );
+        assert!(!BatchQueue::<Test>::contains_key(0));
+    });
+}
+
+#[test]
+fn verification_state_is_bounded_by_retention() {
+    new_test_ext().execute_with(|| {
+        ProofReward::set(0);
+
+        // One three-request batch per block for five retention periods
+        for block in 1..=5 * RETENTION_PERIOD {
+            System::set_block_number(block);
+            assert_ok!(ReMLVerifier::submit_proof(
+                RuntimeOrigin::signed(AGGREGATOR),
+                submission(block, &[3 * block, 3 * block + 1, 3 * block + 2])
+            ));
+            on_idle(block, Weight::MAX);
+
+            let live = block.min(RETENTION_PERIOD) as usize;
+            let tombstones = block.saturating_sub(RETENTION_PERIOD).min(RETENTION_PERIOD) as usize;
+            assert_eq!(VerifiedBatches::<Test>::iter().count(), live);
+            assert_eq!(BatchRequestIds::<Test>::iter().count(), live);
+            assert_eq!(ProofCommitments::<Test>::iter().count(), live);
+            assert_eq!(PrunedBatches::<Test>::iter().count(), tombstones);
+            assert_eq!(BatchQueue::<Test>::iter().count(), live + tombstones);
+            assert_eq!(VerifiedRequests::<Test>::iter().count(), 3 * live);
+        }
+    });
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// MIGRATION TESTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn migrate_v0_to_v1_adds_zero_vkey_to_batches() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(0).put::<Pallet<Test>>();
+
+        // v0 layout: aggregator, verified_at, signature_count, requests_root, proof_commitment
+        let old = (AGGREGATOR, 3u64, 2u32, [1u8; 32], [2u8; 32]);
+        frame_support::storage::unhashed::put_raw(
+            &VerifiedBatches::<Test>::hashed_key_for(9),
+            &old.encode(),
+        );
+
+        MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+        let batch = VerifiedBatches::<Test>::get(9).unwrap();
+        assert_eq!(batch.aggregator, AGGREGATOR);
+        assert_eq!(batch.verified_at, 3);
+        assert_eq!(batch.signature_count, 2);
+        assert_eq!(batch.requests_root, [1u8; 32]);
+        assert_eq!(batch.proof_commitment, [2u8; 32]);
+        assert_eq!(batch.vkey_hash, [0u8; 32]);
+        assert_eq!(Pallet::<Test>::on_chain_storage_version(), 1);
+    });
+}
+
+#[test]
+fn migrate_v1_to_v2_converts_aggregators_and_counts_them() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(1).put::<Pallet<Test>>();
+        Aggregators::<Test>::remove(AGGREGATOR);
+
+        // v1 layout: registered_at, proofs_submitted, active
+        for (account, old) in [(5u64, (1u64, 3u64, true)), (6, (2, 0, false))] {
+            frame_support::storage::unhashed::put_raw(
+                &Aggregators::<Test>::hashed_key_for(account),
+                &old.encode(),
+            );
+        }
+        System::set_block_number(20);
+
+        MigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+        let info = Aggregators::<Test>::get(5).unwrap();
+        assert_eq!(info.registered_at, 1);
+        assert_eq!(info.proofs_submitted, 3);
+        assert!(info.active);
+        assert_eq!(info.bond, 0);
+        assert_eq!(info.last_active, 20);
+        assert!(!Aggregators::<Test>::get(6).unwrap().active);
+        assert_eq!(Aggregators::<Test>::count(), 2);
+        assert_eq!(Pallet::<Test>::on_chain_storage_version(), 2);
+    });
+}
+
+#[test]
+fn migrate_v2_to_v3_queues_batches_oldest_first() {
+    new_test_ext().execute_with(|| {
+        for (block, batch_id) in [(3, 4), (2, 5)] {
+            System::set_block_number(block);
+            assert_ok!(ReMLVerifier::submit_proof(
+                RuntimeOrigin::signed(AGGREGATOR),
+                submission(batch_id, &[batch_id])
+            ));
+        }
+        // v2 had no queue
+        let _ = BatchQueue::<Test>::clear(u32::MAX, None);
+        PruneCursor::<Test>::kill();
+        StorageVersion::new(2).put::<Pallet<Test>>();
+
+        MigrateV2ToV3::<Test>::on_runtime_upgrade();
+
+        assert_eq!(BatchQueue::<Test>::get(0).unwrap().batch_id, 5);
+        assert_eq!(BatchQueue::<Test>::get(1).unwrap().batch_id, 4);
+        assert_eq!(PruneCursor::<Test>::get().tail, 2);
+        assert_eq!(Pallet::<Test>::on_chain_storage_version(), 3);
+
+        <ReMLVerifier as Hooks<u64>>::on_idle(2 + RETENTION_PERIOD, Weight::MAX);
+        assert!(!VerifiedBatches::<Test>::contains_key(5));
+        assert!(VerifiedBatches::<Test>::contains_key(4));
+    });
+}
+
+#[test]
+fn migrate_v3_to_v4_starts_allocation_above_settled_requests() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ReMLVerifier::submit_proof(
+            RuntimeOrigin::signed(AGGREGATOR),
+            submission(1, &[10])
+        ));
+        // v3 requests were chosen by clients
+        VerifiedRequests::<Test>::insert(5_000, (1, 1));
+        ConsumedRequests::<Test>::insert(
+            7_000,
+            crate::ConsumptionInfo {
+                consumer: BONDER,
+                call: [1u8; 32],
+                consumed_at: 1,
+            },
+        );
+        NextRequestId::<Test>::kill();
+        StorageVersion::new(3).put::<Pallet<Test>>();
+
+        MigrateV3ToV4::<Test>::on_runtime_upgrade();
+
+        assert_eq!(NextRequestId::<Test>::get(), 7_001);
+        assert_eq!(Pallet::<Test>::on_chain_storage_version(), 4);
+    });
+}
+
+#[test]
+fn migrate_v4_to_v5_adds_allocation_expiry() {
+    new_test_ext().execute_with(|| {
+        // v4 allocations recorded the owner only
+        let _ = RequestOwners::<Test>::clear(u32::MAX, None);
+        for request_id in [3u64, 5] {
+            frame_support::storage::unhashed::put_raw(
+                &RequestOwners::<Test>::hashed_key_for(request_id),
+                &REQUESTER.encode(),
+            );
+        }
+        AllocationCursor::<Test>::kill();
+        StorageVersion::new(4).put::<Pallet<Test>>();
+
+        System::set_block_number(7);
+        MigrateV4ToV5::<Test>::on_runtime_upgrade();
+
+        let allocation = RequestOwners::<Test>::get(5).unwrap();
+        assert_eq!(allocation.owner, REQUESTER);
+        assert_eq!(allocation.deposit, 0);
+        assert_eq!(allocation.expires_at, 7 + ALLOCATION_EXPIRY);
+        assert_eq!(AllocationCursor::<Test>::get(), 3);
+        assert_eq!(Pallet::<Test>::on_chain_storage_version(), 5);
+    });
+}
+
+#[test]
+fn migrate_v5_to_v6_starts_tombstones_at_the_prune_head() {
+    new_test_ext().execute_with(|| {
+        // v5 layout: head, tail, request_index, highest_pruned
+        let old = (4u64, 6u64, 2u32, Some(9u64));
+        frame_support::storage::unhashed::put_raw(
+            &PruneCursor::<Test>::hashed_key(),
+            &old.encode(),
+        );
+        StorageVersion::new(5).put::<Pallet<Test>>();
+
+        MigrateV5ToV6::<Test>::on_runtime_upgrade();
+
+        let cursor = PruneCursor::<Test>::get();
+        assert_eq!((cursor.head, cursor.tail, cursor.request_index), (4, 6, 2));
+        assert_eq!(cursor.tombstone_head, 4);
+        assert_eq!(Pallet::<Test>::on_chain_storage_version(), 6);
+
+        // The old mark no longer blocks the IDs below it
+        assert_ok!(ReMLVerifier::submit_proof(
+            RuntimeOrigin::signed(AGGREGATOR),
+            submission(9, &[10])
+        ));
+    });
+}
+
+#[test]
+fn migrate_v6_to_v7_drops_consumed_requests_of_pruned_batches() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ReMLVerifier::submit_proof(
+            RuntimeOrigin::signed(AGGREGATOR),
+            submission(1, &[10])
+        ));
+        // v6 kept consumed 10 verified and allocated after batch 1 was pruned
+        on_idle(1 + RETENTION_PERIOD, Weight::MAX);
+        VerifiedRequests::<Test>::insert(10, (1, 1));
+        RequestOwners::<Test>::insert(
+            10,
+            crate::RequestAllocation {
+                owner: REQUESTER,
+                deposit: 0,
+                expires_at: 1 + ALLOCATION_EXPIRY,
+            },
+        );
+        ConsumedRequests::<Test>::insert(
+            10,
+            crate::ConsumptionInfo {
+                consumer: BONDER,
+                call: [1u8; 32],
+                consumed_at: 1,
+            },
+        );
+        // 12 is consumed and still in its batch
+        assert_ok!(ReMLVerifier::submit_proof(
+            RuntimeOrigin::signed(AGGREGATOR),
+            submission(2, &[12])
+        ));
+        assert_ok!(ReMLVerifier::consume_verified_request(
+            12, &BONDER, [1u8; 32]
+        ));
+        StorageVersion::new(6).put::<Pallet<Test>>();
+
+        MigrateV6ToV7::<Test>::on_runtime_upgrade();
+
+        assert!(!ReMLVerifier::is_request_consumed(10));
+        assert!(!ReMLVerifier::is_request_verified(10));
+        assert!(!RequestOwners::<Test>::contains_key(10));
+        assert!(ReMLVerifier::is_request_consumed(12));
+        assert!(ReMLVerifier::is_request_verified(12));
+        assert_eq!(Pallet::<Test>::on_chain_storage_version(), 7);
+    });
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// INCLUSION PROOF TESTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn inclusion_proof_verifies_for_every_leaf() {
+    new_test_ext().execute_with(|| {
+        // Odd sizes exercise promotion of the trailing node
+        let mut batch_id = 0;
+        for size in [1u64, 2, 3, 5, 8, 13] {
+            batch_id += 1;
+            let ids: Vec<u64> = (0..size).map(|i| batch_id * 100 + i).collect();
+            assert_ok!(ReMLVerifier::submit_proof(
+                RuntimeOrigin::signed(AGGREGATOR),
+                submission(batch_id, &ids)
+            ));
+
+            for (index, id) in ids.iter().enumerate() {
+                let proof = ReMLVerifier::request_inclusion_proof(*id).unwrap();
+                assert_eq!(proof.batch_id, batch_id);
+                assert_eq!(proof.leaf_index, index as u32);
+                assert_eq!(proof.leaf_count, size as u32);
+                assert_eq!(
+                    proof.requests_root,
+                    VerifiedBatches::<Test>::get(batch_id)
+                        .unwrap()
+                        .requests_root
+                );
+                assert!(ReMLVerifier::verify_inclusion_proof(
+                    &proof.requests_root,
+                    *id,
+                    &proof.path
+                ));
+            }
+        }
+    });
+}
+
+#[test]
+fn inclusion_proof_rejects_other_request_id() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ReMLVerifier::submit_proof(
+            RuntimeOrigin::signed(AGGREGATOR),
+            submission(1, &[10, 11, 12, 13])
+        ));
+
+        let proof = ReMLVerifier::request_inclusion_proof(11).unwrap();
+        assert!(!ReMLVerifier::verify_inclusion_proof(
+            &proof.requests_root,
+            12,
+            &proof.path
+        ));
+        assert!(!ReMLVerifier::verify_inclusion_proof(
+            &[0u8; 32],
+            11,
+            &proof.path
+        ));
+    });
+}
+
+#[test]
+fn inclusion_proof_is_none_for_unverified_request() {
+    new_test_ext().execute_with(|| {
+        assert!(ReMLVerifier::request_inclusion_proof(42).is_none());
+    });
+}
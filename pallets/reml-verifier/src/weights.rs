@@ -5,8 +5,13 @@
 //! - Cryptographic operations (hashing, merkle tree)
 //! - Proof parsing and validation
 //!
-//! NOTE: These weights should be regenerated using frame-benchmarking
-//! after deployment to get accurate values for the target hardware.
+//! NOTE: These weights should be regenerated on the target hardware with
+//! `benchmark pallet --pallet pallet_reml_verifier` (see `benchmarking.rs`).
+//!
+//! PROVISIONAL: `submit_proof` and `submit_aggregated_proof` are benchmarked
+//! against the pallet's structural SP1 check, which does not verify the
+//! STARK or Groth16 proof itself. Until they are benchmarked against a real
+//! verifier, each proof is charged `SP1_VERIFICATION_MARGIN` on top.
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -16,11 +21,39 @@
 use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
 use core::marker::PhantomData;
 
+/// Provisional worst-case cost of verifying one SP1 proof in Wasm: 250 ms
+///
+/// Covers a compressed proof of `MAX_PROOF_SIZE` bytes, well above a Groth16
+/// pairing check. Replace with benchmarked figures once verification is real.
+pub const SP1_VERIFICATION_MARGIN: u64 = 250_000_000_000;
+
 /// Weight functions for pallet-reml-verifier
 pub trait WeightInfo {
     fn register_aggregator() -> Weight;
     fn deactivate_aggregator() -> Weight;
-    fn submit_proof(n: u32) -> Weight;
+    fn submit_proof(p: u32, n: u32) -> Weight;
+    fn add_vkey() -> Weight;
+    fn retire_vkey() -> Weight;
+    fn bond_aggregator() -> Weight;
+    fn unbond_aggregator() -> Weight;
+    fn withdraw_bond() -> Weight;
+    fn report_invalid_batch(n: u32) -> Weight;
+    fn report_missed_liveness() -> Weight;
+    fn prune_cursor() -> Weight;
+    fn prune_batch() -> Weight;
+    fn prune_request() -> Weight;
+    fn consume_verified_request() -> Weight;
+    fn allocate_request_id() -> Weight;
+    fn submit_optimistic_batch(n: u32) -> Weight;
+    fn challenge_optimistic_batch(n: u32) -> Weight;
+    fn finalize_optimistic_batch(n: u32) -> Weight;
+    fn submit_aggregated_proof(p: u32, b: u32, n: u32) -> Weight;
+    fn heartbeat() -> Weight;
+    fn prune_allocation() -> Weight;
+    fn challenge_batch_data() -> Weight;
+    fn respond_batch_data(n: u32) -> Weight;
+    fn claim_data_challenge(n: u32) -> Weight;
+    fn prune_tombstone() -> Weight;
 }
 
 /// Weights for pallet-reml-verifier using Substrate node
@@ -29,13 +62,13 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     /// Register aggregator
     /// 
-    /// Storage: Aggregators (r:1 w:1)
+    /// Storage: Aggregators (r:1 w:1), CounterForAggregators (r:1 w:1)
     /// Complexity: O(1)
     fn register_aggregator() -> Weight {
         // Base: 25 µs
         Weight::from_parts(25_000_000, 0)
-            .saturating_add(T::DbWeight::get().reads(1_u64))
-            .saturating_add(T::DbWeight::get().writes(1_u64))
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
     }
 
     /// Deactivate aggregator
@@ -53,21 +86,38 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     /// 
     /// Storage:
     /// - Aggregators (r:1 w:1)
+    /// - AllowedVKeys (r:1 w:0)
     /// - VerifiedBatches (r:1 w:1)
+    /// - PendingBatches (r:1 w:0)
     /// - ProofCommitments (r:1 w:1)
-    /// - VerifiedRequests (r:0 w:n)
+    /// - BatchRequestIds (r:0 w:1)
+    /// - RequestOwners (r:n w:n)
+    /// - VerifiedRequests (r:n w:n)
+    /// - ConsumedRequests (r:n w:0)
+    /// - PendingRequests (r:n w:0)
+    /// - RejectedRequests (r:n w:0)
     /// - TotalProofsVerified (r:1 w:1)
     /// - TotalSignaturesVerified (r:1 w:1)
+    /// - PruneCursor (r:1 w:1), BatchQueue (r:0 w:1)
+    /// - System Account: reward account, aggregator (r:2 w:2), request owners
+    ///   for the allocation deposits (r:n w:n)
+    /// 
+    /// `n` counts verified and rejected requests; verified ones are the
+    /// costlier, so the worst case rejects none.
     /// 
     /// Computation:
-    /// - Proof parsing: O(proof_size)
+    /// - Proof parsing: O(p) where p = proof size in bytes
     /// - Merkle root: O(n log n) where n = request count
-    /// - Proof verification: O(proof_size)
-    /// - Commitment hash: O(1)
-    fn submit_proof(n: u32) -> Weight {
-        // Base cost: proof parsing and verification
-        // ~100 µs base + ~1 µs per 100 bytes of proof (avg proof ~10KB)
-        let base_cost = 100_000_000u64;
+    /// - Proof verification: O(p), two scans for the vkey and public values,
+    ///   plus the provisional `SP1_VERIFICATION_MARGIN`
+    /// - Commitment hash: O(p)
+    fn submit_proof(p: u32, n: u32) -> Weight {
+        // Base cost: checks and hashing of the public values, and the
+        // provisional SP1 verification
+        let base_cost = 100_000_000u64.saturating_add(SP1_VERIFICATION_MARGIN);
+        
+        // Proof hashing and scans: ~50 ns per byte
+        let proof_cost = (p as u64).saturating_mul(50_000);
         
         // Merkle tree computation: O(n log n)
         // ~5 µs per hash, ~2n hashes for tree
@@ -78,18 +128,357 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
                 (n as u64).checked_ilog2().unwrap_or(1) as u64 * 5_000_000
             );
         
-        // Storage writes for each verified request
-        let per_request_storage = (n as u64).saturating_mul(5_000_000); // 5 µs per write
+        // Storage checks and writes for each verified request
+        let per_request_storage = (n as u64).saturating_mul(8_000_000); // 8 µs per request
         
         let total_computation = base_cost
+            .saturating_add(proof_cost)
             .saturating_add(merkle_cost)
             .saturating_add(per_request_storage);
         
         Weight::from_parts(total_computation, 0)
-            // Reads: aggregator, batch, commitment, 2 counters
-            .saturating_add(T::DbWeight::get().reads(5_u64))
-            // Writes: aggregator, batch, commitment, 2 counters, n requests
-            .saturating_add(T::DbWeight::get().writes(5_u64.saturating_add(n as u64)))
+            // Reads: aggregator, vkey, batch, pending batch, commitment, 2 counters,
+            // prune cursor, 2 reward accounts, allocation, verification, rejection,
+            // consumption, pending claim and owner account of n requests
+            .saturating_add(T::DbWeight::get().reads(10_u64.saturating_add((n as u64).saturating_mul(6))))
+            // Writes: aggregator, batch, commitment, batch ids, 2 counters, prune cursor,
+            // queue entry, 2 reward accounts, n requests, allocations and owner accounts
+            .saturating_add(T::DbWeight::get().writes(10_u64.saturating_add((n as u64).saturating_mul(3))))
+    }
+
+    /// Allow a guest verification key
+    /// 
+    /// Storage: AllowedVKeys (r:1 w:1), CounterForAllowedVKeys (r:1 w:1)
+    /// Complexity: O(1)
+    fn add_vkey() -> Weight {
+        // Base: 20 µs
+        Weight::from_parts(20_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+
+    /// Retire a guest verification key (worst case: removal)
+    /// 
+    /// Storage: AllowedVKeys (r:1 w:1), CounterForAllowedVKeys (r:1 w:1)
+    /// Complexity: O(1)
+    fn retire_vkey() -> Weight {
+        // Base: 20 µs
+        Weight::from_parts(20_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+
+    /// Bond a new aggregator
+    /// 
+    /// Storage: Aggregators (r:1 w:1), CounterForAggregators (r:1 w:1),
+    /// System Account (r:1 w:1)
+    /// Complexity: O(1)
+    fn bond_aggregator() -> Weight {
+        // Base: 35 µs
+        Weight::from_parts(35_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+
+    /// Start unbonding
+    /// 
+    /// Storage: Aggregators (r:1 w:1)
+    /// Complexity: O(1)
+    fn unbond_aggregator() -> Weight {
+        // Base: 20 µs
+        Weight::from_parts(20_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    /// Withdraw the bond and leave
+    /// 
+    /// Storage: Aggregators (r:1 w:1), CounterForAggregators (r:1 w:1),
+    /// System Account (r:1 w:1)
+    /// Complexity: O(1)
+    fn withdraw_bond() -> Weight {
+        // Base: 35 µs
+        Weight::from_parts(35_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+
+    /// Invalidate a batch of `n` verified or rejected requests and slash its
+    /// aggregator
+    /// 
+    /// Storage:
+    /// - VerifiedBatches (r:1 w:1)
+    /// - BatchRequestIds (r:1 w:1)
+    /// - BatchRejectedIds (r:1 w:1)
+    /// - VerifiedRequests or RejectedRequests (r:n w:n)
+    /// - RequestOwners (r:n w:n), ConsumedRequests (r:0 w:n), expired
+    ///   allocations
+    /// - Aggregators (r:1 w:1)
+    /// - System Account: aggregator, reward account (r:2 w:2)
+    /// Complexity: O(n)
+    fn report_invalid_batch(n: u32) -> Weight {
+        // Base: 50 µs + 5 µs per revoked request
+        Weight::from_parts(50_000_000, 0)
+            .saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(n as u64))
+            .saturating_add(T::DbWeight::get().reads(6_u64.saturating_add((n as u64).saturating_mul(2))))
+            .saturating_add(T::DbWeight::get().writes(6_u64.saturating_add((n as u64).saturating_mul(3))))
+    }
+
+    /// Slash an aggregator for missed liveness
+    /// 
+    /// Storage: Aggregators (r:1 w:1), System Account: aggregator, reward
+    /// account (r:2 w:2)
+    /// Complexity: O(1)
+    fn report_missed_liveness() -> Weight {
+        // Base: 40 µs
+        Weight::from_parts(40_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+
+    /// Load and store the pruning cursor in `on_idle`
+    /// 
+    /// Storage: PruneCursor (r:1 w:1)
+    /// Complexity: O(1)
+    fn prune_cursor() -> Weight {
+        // Base: 5 µs
+        Weight::from_parts(5_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    /// Prune one expired batch, excluding its requests
+    /// 
+    /// Storage:
+    /// - BatchQueue (r:1 w:1) for an invalidated batch, PrunedBatches (r:0 w:1)
+    ///   otherwise
+    /// - VerifiedBatches (r:1 w:1)
+    /// - BatchRequestIds (r:1 w:1)
+    /// - BatchRejectedIds (r:1 w:1)
+    /// - ProofCommitments (r:0 w:1)
+    /// Complexity: O(1), plus decoding up to MAX_VERIFIED_REQUESTS leaves
+    fn prune_batch() -> Weight {
+        // Base: 30 µs
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(5_u64))
+    }
+
+    /// Prune one verified request of an expired batch (rejected requests,
+    /// pruned the same way, are cheaper)
+    /// 
+    /// Storage: VerifiedRequests (r:1 w:1), RequestOwners (r:0 w:1),
+    /// ConsumedRequests (r:0 w:1)
+    /// Complexity: O(1)
+    fn prune_request() -> Weight {
+        // Base: 8 µs
+        Weight::from_parts(8_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+
+    /// Consume a verified request
+    /// 
+    /// Storage: VerifiedRequests (r:1), ConsumedRequests (r:1 w:1)
+    /// Complexity: O(1)
+    fn consume_verified_request() -> Weight {
+        // Base: 15 µs
+        Weight::from_parts(15_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    /// Allocate a request ID and reserve its deposit
+    /// 
+    /// Storage: NextRequestId (r:1 w:1), RequestOwners (r:0 w:1),
+    /// System Account (r:1 w:1)
+    /// Complexity: O(1)
+    fn allocate_request_id() -> Weight {
+        // Base: 25 µs
+        Weight::from_parts(25_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+
+    /// Post an optimistic batch of `n` requests
+    /// 
+    /// Storage:
+    /// - Aggregators (r:1 w:1)
+    /// - VerifiedBatches (r:1 w:0)
+    /// - PendingBatches (r:1 w:1)
+    /// - PendingBatchRequests (r:0 w:1)
+    /// - PruneCursor (r:1 w:0)
+    /// - ProofCommitments (r:1 w:0)
+    /// - RequestOwners (r:n w:0)
+    /// - VerifiedRequests (r:n w:0)
+    /// - ConsumedRequests (r:n w:0)
+    /// - RejectedRequests (r:n w:0)
+    /// - PendingRequests (r:n w:n)
+    /// - System Account: aggregator (r:1 w:1)
+    /// Complexity: O(n log n) for the merkle root
+    fn submit_optimistic_batch(n: u32) -> Weight {
+        // Base: 60 µs + 12 µs per request (merkle root, commitment, checks)
+        Weight::from_parts(60_000_000, 0)
+            .saturating_add(Weight::from_parts(12_000_000, 0).saturating_mul(n as u64))
+            .saturating_add(T::DbWeight::get().reads(6_u64.saturating_add((n as u64).saturating_mul(5))))
+            .saturating_add(T::DbWeight::get().writes(4_u64.saturating_add(n as u64)))
+    }
+
+    /// Check a fraud proof against an optimistic batch of `n` requests
+    /// 
+    /// Storage:
+    /// - PendingBatches (r:1 w:1)
+    /// - PendingBatchRequests (r:1 w:1)
+    /// - PendingRequests (r:0 w:n)
+    /// - RequestOwners (r:n w:n), ConsumedRequests (r:0 w:n), expired
+    ///   allocations
+    /// - DataChallenges (r:n w:n), at most one per request
+    /// - Aggregators (r:1 w:1), FraudProvenAt (r:0 w:1)
+    /// - System Account: aggregator, challenger, reward account (r:3 w:3),
+    ///   owners of expired allocations, data challengers (r:2n w:2n)
+    /// 
+    /// Computation:
+    /// - Request commitment: keccak256 over ~3.8 KB of signature data
+    /// - ML-DSA (Dilithium2) verification: ~400 µs
+    fn challenge_optimistic_batch(n: u32) -> Weight {
+        // Base: 465 µs + 8 µs per released request
+        Weight::from_parts(465_000_000, 0)
+            .saturating_add(Weight::from_parts(8_000_000, 0).saturating_mul(n as u64))
+            .saturating_add(T::DbWeight::get().reads(6_u64.saturating_add((n as u64).saturating_mul(4))))
+            .saturating_add(T::DbWeight::get().writes(7_u64.saturating_add((n as u64).saturating_mul(6))))
+    }
+
+    /// Settle an optimistic batch of `n` requests (a void batch is discarded
+    /// like a challenged one, which costs less)
+    /// 
+    /// Storage:
+    /// - FraudProvenAt (r:1 w:0), DataChallenges (r:1 w:0)
+    /// - PendingBatches (r:1 w:1)
+    /// - PendingBatchRequests (r:1 w:1)
+    /// - PendingRequests (r:0 w:n)
+    /// - VerifiedRequests (r:0 w:n)
+    /// - RequestOwners (r:n w:n)
+    /// - VerifiedBatches (r:0 w:1), BatchRequestIds (r:0 w:1)
+    /// - ProofCommitments (r:0 w:1)
+    /// - PruneCursor (r:1 w:1), BatchQueue (r:0 w:1)
+    /// - TotalProofsVerified (r:1 w:1), TotalSignaturesVerified (r:1 w:1)
+    /// - Aggregators (r:1 w:1)
+    /// - System Account: aggregator, reward account (r:2 w:2), request owners
+    ///   (r:n w:n)
+    /// Complexity: O(n)
+    fn finalize_optimistic_batch(n: u32) -> Weight {
+        // Base: 60 µs + 10 µs per settled request
+        Weight::from_parts(60_000_000, 0)
+            .saturating_add(Weight::from_parts(10_000_000, 0).saturating_mul(n as u64))
+            .saturating_add(T::DbWeight::get().reads(10_u64.saturating_add((n as u64).saturating_mul(2))))
+            .saturating_add(T::DbWeight::get().writes(12_u64.saturating_add((n as u64).saturating_mul(4))))
+    }
+
+    /// Submit and verify an aggregated proof of `b` batches settling `n`
+    /// requests in total
+    /// 
+    /// Storage:
+    /// - Aggregators (r:1 w:1)
+    /// - AllowedVKeys (r:2 w:0)
+    /// - VerifiedBatches, PendingBatches, ProofCommitments (r:b each)
+    /// - VerifiedBatches, ProofCommitments, BatchRequestIds, BatchQueue (w:b each)
+    /// - RequestOwners, VerifiedRequests, ConsumedRequests, PendingRequests,
+    ///   RejectedRequests (r:n each), RequestOwners, VerifiedRequests (w:n each)
+    /// - TotalProofsVerified, TotalSignaturesVerified, PruneCursor (r:1 w:1)
+    /// - System Account: request owners (r:n w:n)
+    /// 
+    /// Computation: `submit_proof` of a `p`-byte proof, with its provisional
+    /// `SP1_VERIFICATION_MARGIN`, plus the root of roots and a commitment and
+    /// public values hash per batch
+    fn submit_aggregated_proof(p: u32, b: u32, n: u32) -> Weight {
+        // Base: 100 µs + 50 ns per proof byte + 20 µs per batch + 18 µs per request
+        Weight::from_parts(100_000_000 + SP1_VERIFICATION_MARGIN, 0)
+            .saturating_add(Weight::from_parts(50_000, 0).saturating_mul(p as u64))
+            .saturating_add(Weight::from_parts(20_000_000, 0).saturating_mul(b as u64))
+            .saturating_add(Weight::from_parts(18_000_000, 0).saturating_mul(n as u64))
+            .saturating_add(T::DbWeight::get().reads(6_u64
+                .saturating_add((b as u64).saturating_mul(3))
+                .saturating_add((n as u64).saturating_mul(6))))
+            .saturating_add(T::DbWeight::get().writes(4_u64
+                .saturating_add((b as u64).saturating_mul(4))
+                .saturating_add((n as u64).saturating_mul(3))))
+    }
+
+    /// Reset an aggregator's liveness
+    /// 
+    /// Storage: Aggregators (r:1 w:1)
+    /// Complexity: O(1)
+    fn heartbeat() -> Weight {
+        // Base: 15 µs
+        Weight::from_parts(15_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    /// Check one request ID for allocation expiry in `on_idle` (worst case:
+    /// the allocation expired unsettled and is dropped)
+    /// 
+    /// Storage: NextRequestId (r:1), RequestOwners (r:1 w:1),
+    /// VerifiedRequests, RejectedRequests, PendingRequests (r:1 each),
+    /// ConsumedRequests (r:0 w:1), System Account: owner (r:1 w:1)
+    /// Complexity: O(1)
+    fn prune_allocation() -> Weight {
+        // Base: 20 µs
+        Weight::from_parts(20_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+
+    /// Demand the signature data of a request of a pending batch
+    /// 
+    /// Storage: PendingBatches (r:1), PendingBatchRequests (r:1),
+    /// DataChallenges (r:1 w:1), System Account: challenger (r:1 w:1)
+    /// Complexity: O(n) scan of the batch requests
+    fn challenge_batch_data() -> Weight {
+        // Base: 30 µs
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+
+    /// Reveal challenged signature data in a pending batch of `n` requests
+    /// (worst case: the signature is invalid and the batch is discarded as
+    /// by `challenge_optimistic_batch`)
+    /// 
+    /// Storage:
+    /// - DataChallenges (r:1 w:1), plus as `challenge_optimistic_batch`
+    /// - System Account: challenger, responder (r:2 w:2) when answered
+    /// 
+    /// Computation: as `challenge_optimistic_batch`
+    fn respond_batch_data(n: u32) -> Weight {
+        // Base: 475 µs + 8 µs per released request
+        Weight::from_parts(475_000_000, 0)
+            .saturating_add(Weight::from_parts(8_000_000, 0).saturating_mul(n as u64))
+            .saturating_add(T::DbWeight::get().reads(7_u64.saturating_add((n as u64).saturating_mul(4))))
+            .saturating_add(T::DbWeight::get().writes(7_u64.saturating_add((n as u64).saturating_mul(6))))
+    }
+
+    /// Void a pending batch of `n` requests whose data was withheld
+    /// 
+    /// Storage: as `challenge_optimistic_batch`, plus DataChallenges (r:1)
+    /// Complexity: O(n)
+    fn claim_data_challenge(n: u32) -> Weight {
+        // Base: 55 µs + 8 µs per released request
+        Weight::from_parts(55_000_000, 0)
+            .saturating_add(Weight::from_parts(8_000_000, 0).saturating_mul(n as u64))
+            .saturating_add(T::DbWeight::get().reads(7_u64.saturating_add((n as u64).saturating_mul(4))))
+            .saturating_add(T::DbWeight::get().writes(7_u64.saturating_add((n as u64).saturating_mul(6))))
+    }
+
+    /// Lift the expired tombstone of one pruned batch ID in `on_idle`
+    /// 
+    /// Storage: BatchQueue (r:1 w:1), PrunedBatches (r:1 w:1)
+    /// Complexity: O(1)
+    fn prune_tombstone() -> Weight {
+        // Base: 8 µs
+        Weight::from_parts(8_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
     }
 }
 
@@ -103,9 +492,105 @@ impl WeightInfo for () {
         Weight::from_parts(20_000_000, 0)
     }
 
-    fn submit_proof(n: u32) -> Weight {
-        let base = 100_000_000u64;
-        let per_request = 15_000_000u64; // 15 µs per request
-        Weight::from_parts(base + (n as u64 * per_request), 0)
+    fn submit_proof(p: u32, n: u32) -> Weight {
+        let base = 100_000_000u64 + SP1_VERIFICATION_MARGIN;
+        let per_byte = 50_000u64; // 50 ns per proof byte
+        let per_request = 18_000_000u64; // 18 µs per request
+        Weight::from_parts(base + (p as u64 * per_byte) + (n as u64 * per_request), 0)
+    }
+
+    fn add_vkey() -> Weight {
+        Weight::from_parts(20_000_000, 0)
+    }
+
+    fn retire_vkey() -> Weight {
+        Weight::from_parts(20_000_000, 0)
+    }
+
+    fn bond_aggregator() -> Weight {
+        Weight::from_parts(35_000_000, 0)
+    }
+
+    fn unbond_aggregator() -> Weight {
+        Weight::from_parts(20_000_000, 0)
+    }
+
+    fn withdraw_bond() -> Weight {
+        Weight::from_parts(35_000_000, 0)
+    }
+
+    fn report_invalid_batch(n: u32) -> Weight {
+        Weight::from_parts(50_000_000 + n as u64 * 5_000_000, 0)
+    }
+
+    fn report_missed_liveness() -> Weight {
+        Weight::from_parts(40_000_000, 0)
+    }
+
+    fn prune_cursor() -> Weight {
+        Weight::from_parts(5_000_000, 0)
+    }
+
+    fn prune_batch() -> Weight {
+        Weight::from_parts(30_000_000, 0)
+    }
+
+    fn prune_request() -> Weight {
+        Weight::from_parts(8_000_000, 0)
+    }
+
+    fn consume_verified_request() -> Weight {
+        Weight::from_parts(15_000_000, 0)
+    }
+
+    fn allocate_request_id() -> Weight {
+        Weight::from_parts(25_000_000, 0)
+    }
+
+    fn submit_optimistic_batch(n: u32) -> Weight {
+        Weight::from_parts(60_000_000 + n as u64 * 12_000_000, 0)
+    }
+
+    fn challenge_optimistic_batch(n: u32) -> Weight {
+        Weight::from_parts(465_000_000 + n as u64 * 8_000_000, 0)
+    }
+
+    fn finalize_optimistic_batch(n: u32) -> Weight {
+        Weight::from_parts(60_000_000 + n as u64 * 10_000_000, 0)
+    }
+
+    fn submit_aggregated_proof(p: u32, b: u32, n: u32) -> Weight {
+        Weight::from_parts(
+            100_000_000
+                + SP1_VERIFICATION_MARGIN
+                + p as u64 * 50_000
+                + b as u64 * 20_000_000
+                + n as u64 * 18_000_000,
+            0,
+        )
+    }
+
+    fn heartbeat() -> Weight {
+        Weight::from_parts(15_000_000, 0)
+    }
+
+    fn prune_allocation() -> Weight {
+        Weight::from_parts(20_000_000, 0)
+    }
+
+    fn challenge_batch_data() -> Weight {
+        Weight::from_parts(30_000_000, 0)
+    }
+
+    fn respond_batch_data(n: u32) -> Weight {
+        Weight::from_parts(475_000_000 + n as u64 * 8_000_000, 0)
+    }
+
+    fn claim_data_challenge(n: u32) -> Weight {
+        Weight::from_parts(55_000_000 + n as u64 * 8_000_000, 0)
+    }
+
+    fn prune_tombstone() -> Weight {
+        Weight::from_parts(8_000_000, 0)
     }
 }
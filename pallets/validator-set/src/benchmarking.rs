@@ -0,0 +1,58 @@
+//! Benchmarking setup for pallet-validator-set
+//!
+//! These benchmarks measure the weight of the governance extrinsics on a
+//! full validator set (worst case for the membership scan):
+//! - `add_validator`: Add the last validator that fits
+//! - `remove_validator`: Remove the last validator of a full set
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as ValidatorSet;
+use frame_benchmarking::v2::*;
+use frame_support::{traits::Get, BoundedVec};
+
+/// Fill the set with `count` benchmark validators
+fn fill_validators<T: Config>(count: u32) {
+    let validators: alloc::vec::Vec<T::AccountId> =
+        (0..count).map(|i| account("validator", i, 0)).collect();
+    let validators: BoundedVec<_, T::MaxValidators> =
+        validators.try_into().expect("count fits MaxValidators");
+    Validators::<T>::put(validators);
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn add_validator() -> Result<(), BenchmarkError> {
+        let origin =
+            T::AddRemoveOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        fill_validators::<T>(T::MaxValidators::get() - 1);
+        let validator: T::AccountId = account("new", 0, 0);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, validator.clone());
+
+        assert!(Validators::<T>::get().contains(&validator));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn remove_validator() -> Result<(), BenchmarkError> {
+        let origin =
+            T::AddRemoveOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let max = T::MaxValidators::get();
+        fill_validators::<T>(max);
+        let validator: T::AccountId = account("validator", max - 1, 0);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, validator.clone());
+
+        assert!(!Validators::<T>::get().contains(&validator));
+        Ok(())
+    }
+
+    impl_benchmark_test_suite!(ValidatorSet, crate::mock::new_test_ext(), crate::mock::Test);
+}
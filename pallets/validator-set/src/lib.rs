@@ -0,0 +1,357 @@
+//! # Tesserax Validator Set Pallet
+//!
+//! Proof-of-authority validator set feeding `pallet_session`, which in turn
+//! rotates the Aura and GRANDPA authorities.
+//!
+//! ## Overview
+//!
+//! `AddRemoveOrigin` (governance) adds and removes validators. Changes are
+//! handed to `pallet_session` at the next session rotation as the queued set,
+//! so they take effect on authoring one session later, i.e. within two
+//! session periods.
+//!
+//! A validator must register session keys with `Session::set_keys` before it
+//! can author; `pallet_session` skips validators without keys.
+//!
+//! ## Offences
+//!
+//! The pallet is the `OnOffenceHandler` of `pallet_offences`. A reported
+//! offender (e.g. a GRANDPA equivocation) is removed from the set, unless that
+//! would leave fewer than `MinValidators`, and handed to
+//! `Config::PunishOffender` for the runtime's remaining penalties.
+//!
+//! ## Why PoA?
+//!
+//! Block rewards from pallet-emission go to the session validator of each
+//! block, so the set has to be accountable. A governance-managed set keeps
+//! that accountability without the weight of NPoS staking; a staking pallet
+//! can replace this one later as the `SessionManager`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub use pallet::*;
+
+use frame_support::weights::Weight;
+use sp_runtime::Perbill;
+
+/// Weight implementations
+pub mod weights;
+pub use weights::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+/// Penalty applied to a reported offender besides its removal from the set
+///
+/// The runtime uses this to disable the offender for the rest of the session
+/// and to forfeit its pending emission rewards.
+pub trait PunishOffender<AccountId> {
+    /// Punish `offender`, returning the weight consumed
+    fn punish(offender: &AccountId, slash_fraction: Perbill) -> Weight;
+}
+
+impl<AccountId> PunishOffender<AccountId> for () {
+    fn punish(_offender: &AccountId, _slash_fraction: Perbill) -> Weight {
+        Weight::zero()
+    }
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use alloc::vec::Vec;
+    use frame_support::pallet_prelude::*;
+    use frame_system::pallet_prelude::*;
+    use sp_staking::{
+        offence::{OffenceDetails, OnOffenceHandler},
+        SessionIndex,
+    };
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// Configuration trait for the validator set pallet.
+    ///
+    /// Note: `RuntimeEvent: From<Event<Self>>` is automatically appended by the pallet macro.
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// Origin allowed to add and remove validators
+        type AddRemoveOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Smallest validator set that `remove_validator` leaves in place
+        #[pallet::constant]
+        type MinValidators: Get<u32>;
+
+        /// Largest validator set (at most the Aura and GRANDPA `MaxAuthorities`)
+        #[pallet::constant]
+        type MaxValidators: Get<u32>;
+
+        /// Penalties for reported offenders besides removal from the set
+        type PunishOffender: PunishOffender<Self::AccountId>;
+
+        /// Weight information
+        type WeightInfo: WeightInfo;
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // STORAGE
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Current validator set, handed to `pallet_session` when it changes
+    #[pallet::storage]
+    #[pallet::getter(fn validators)]
+    pub type Validators<T: Config> =
+        StorageValue<_, BoundedVec<T::AccountId, T::MaxValidators>, ValueQuery>;
+
+    /// Whether `Validators` changed since it was last handed to `pallet_session`
+    #[pallet::storage]
+    pub type ValidatorsChanged<T> = StorageValue<_, bool, ValueQuery>;
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // GENESIS
+    // ═══════════════════════════════════════════════════════════════════════
+
+    #[pallet::genesis_config]
+    #[derive(frame_support::DefaultNoBound)]
+    pub struct GenesisConfig<T: Config> {
+        /// Validators of the genesis session
+        pub initial_validators: Vec<T::AccountId>,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            let mut validators = Vec::with_capacity(self.initial_validators.len());
+            for validator in &self.initial_validators {
+                assert!(
+                    !validators.contains(validator),
+                    "Duplicate initial validator"
+                );
+                validators.push(validator.clone());
+            }
+            let validators: BoundedVec<_, T::MaxValidators> = validators
+                .try_into()
+                .expect("Initial validators exceed MaxValidators");
+            Validators::<T>::put(validators);
+        }
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // EVENTS
+    // ═══════════════════════════════════════════════════════════════════════
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Validator joins the set from the next session rotation
+        ValidatorAdded { validator: T::AccountId },
+
+        /// Validator leaves the set from the next session rotation
+        ValidatorRemoved { validator: T::AccountId },
+
+        /// Validator was reported for an offence committed in `session`
+        OffenceReported {
+            validator: T::AccountId,
+            session: SessionIndex,
+            slash_fraction: Perbill,
+            removed: bool,
+        },
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // ERRORS
+    // ═══════════════════════════════════════════════════════════════════════
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Account is already a validator
+        AlreadyValidator,
+        /// Account is not a validator
+        NotValidator,
+        /// Validator set is full
+        TooManyValidators,
+        /// Removing the validator would leave fewer than `MinValidators`
+        TooFewValidators,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn integrity_test() {
+            assert!(
+                T::MinValidators::get() > 0,
+                "MinValidators must not be zero"
+            );
+            assert!(
+                T::MinValidators::get() <= T::MaxValidators::get(),
+                "MinValidators exceeds MaxValidators"
+            );
+        }
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // CALLS
+    // ═══════════════════════════════════════════════════════════════════════
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Add a validator to the set
+        ///
+        /// The validator needs session keys (`Session::set_keys`) to author.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::add_validator())]
+        pub fn add_validator(origin: OriginFor<T>, validator: T::AccountId) -> DispatchResult {
+            T::AddRemoveOrigin::ensure_origin(origin)?;
+
+            Validators::<T>::try_mutate(|validators| {
+                ensure!(
+                    !validators.contains(&validator),
+                    Error::<T>::AlreadyValidator
+                );
+                validators
+                    .try_push(validator.clone())
+                    .map_err(|_| Error::<T>::TooManyValidators)
+            })?;
+            ValidatorsChanged::<T>::put(true);
+
+            Self::deposit_event(Event::ValidatorAdded { validator });
+            Ok(())
+        }
+
+        /// Remove a validator from the set
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::remove_validator())]
+        pub fn remove_validator(origin: OriginFor<T>, validator: T::AccountId) -> DispatchResult {
+            T::AddRemoveOrigin::ensure_origin(origin)?;
+            Self::do_remove_validator(&validator)?;
+
+            Self::deposit_event(Event::ValidatorRemoved { validator });
+            Ok(())
+        }
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // HELPER FUNCTIONS
+    // ═══════════════════════════════════════════════════════════════════════
+
+    impl<T: Config> Pallet<T> {
+        /// Sessions' validators paired with their full identification
+        fn identified(validators: Vec<T::AccountId>) -> Vec<(T::AccountId, T::AccountId)> {
+            validators.into_iter().map(|v| (v.clone(), v)).collect()
+        }
+
+        /// Remove `validator`, keeping at least `MinValidators`
+        fn do_remove_validator(validator: &T::AccountId) -> DispatchResult {
+            Validators::<T>::try_mutate(|validators| {
+                let index = validators
+                    .iter()
+                    .position(|v| v == validator)
+                    .ok_or(Error::<T>::NotValidator)?;
+                ensure!(
+                    validators.len() as u32 > T::MinValidators::get(),
+                    Error::<T>::TooFewValidators
+                );
+                validators.remove(index);
+                Ok::<_, DispatchError>(())
+            })?;
+            ValidatorsChanged::<T>::put(true);
+            Ok(())
+        }
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // SESSION MANAGER
+    // ═══════════════════════════════════════════════════════════════════════
+
+    impl<T: Config> pallet_session::SessionManager<T::AccountId> for Pallet<T> {
+        fn new_session(new_index: SessionIndex) -> Option<Vec<T::AccountId>> {
+            if !ValidatorsChanged::<T>::take() {
+                return None;
+            }
+
+            let validators = Validators::<T>::get().into_inner();
+            log::info!(
+                target: "runtime::validator-set",
+                "Session {new_index}: queueing {} validators",
+                validators.len()
+            );
+            Some(validators)
+        }
+
+        fn new_session_genesis(_new_index: SessionIndex) -> Option<Vec<T::AccountId>> {
+            Some(Validators::<T>::get().into_inner())
+        }
+
+        fn end_session(_end_index: SessionIndex) {}
+
+        fn start_session(_start_index: SessionIndex) {}
+    }
+
+    /// Historical sessions identify validators by their account
+    impl<T: Config> pallet_session::historical::SessionManager<T::AccountId, T::AccountId>
+        for Pallet<T>
+    {
+        fn new_session(new_index: SessionIndex) -> Option<Vec<(T::AccountId, T::AccountId)>> {
+            <Self as pallet_session::SessionManager<_>>::new_session(new_index)
+                .map(Self::identified)
+        }
+
+        fn new_session_genesis(
+            new_index: SessionIndex,
+        ) -> Option<Vec<(T::AccountId, T::AccountId)>> {
+            <Self as pallet_session::SessionManager<_>>::new_session_genesis(new_index)
+                .map(Self::identified)
+        }
+
+        fn end_session(end_index: SessionIndex) {
+            <Self as pallet_session::SessionManager<_>>::end_session(end_index)
+        }
+
+        fn start_session(start_index: SessionIndex) {
+            <Self as pallet_session::SessionManager<_>>::start_session(start_index)
+        }
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // OFFENCES
+    // ═══════════════════════════════════════════════════════════════════════
+
+    impl<T: Config> OnOffenceHandler<T::AccountId, (T::AccountId, T::AccountId), Weight> for Pallet<T> {
+        fn on_offence(
+            offenders: &[OffenceDetails<T::AccountId, (T::AccountId, T::AccountId)>],
+            slash_fraction: &[Perbill],
+            session: SessionIndex,
+        ) -> Weight {
+            let mut weight = Weight::zero();
+            for (details, fraction) in offenders.iter().zip(slash_fraction) {
+                let validator = details.offender.0.clone();
+                let removed = Self::do_remove_validator(&validator).is_ok();
+                if !removed {
+                    log::warn!(
+                        target: "runtime::validator-set",
+                        "Offender kept in the validator set (unknown or MinValidators reached)"
+                    );
+                }
+
+                weight = weight
+                    .saturating_add(T::WeightInfo::remove_validator())
+                    .saturating_add(T::PunishOffender::punish(&validator, *fraction));
+
+                Self::deposit_event(Event::OffenceReported {
+                    validator,
+                    session,
+                    slash_fraction: *fraction,
+                    removed,
+                });
+            }
+            weight
+        }
+    }
+}
@@ -0,0 +1,66 @@
+//! Mock runtime for testing pallet-validator-set
+
+use frame_support::{derive_impl, parameter_types, traits::ConstU32, weights::Weight};
+use frame_system::EnsureRoot;
+use sp_runtime::{traits::IdentityLookup, BuildStorage, Perbill};
+
+use crate as pallet_validator_set;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime for testing
+frame_support::construct_runtime!(
+    pub enum Test {
+        System: frame_system,
+        ValidatorSet: pallet_validator_set,
+    }
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type AccountId = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+}
+
+parameter_types! {
+    /// Offenders passed to PunishOffender so far
+    pub static Punished: Vec<(u64, Perbill)> = Vec::new();
+}
+
+/// Records punished offenders in `Punished`
+pub struct RecordPunishment;
+impl crate::PunishOffender<u64> for RecordPunishment {
+    fn punish(offender: &u64, slash_fraction: Perbill) -> Weight {
+        Punished::mutate(|punished| punished.push((*offender, slash_fraction)));
+        Weight::zero()
+    }
+}
+
+impl pallet_validator_set::Config for Test {
+    type AddRemoveOrigin = EnsureRoot<u64>;
+    type MinValidators = ConstU32<1>;
+    type MaxValidators = ConstU32<4>;
+    type PunishOffender = RecordPunishment;
+    type WeightInfo = ();
+}
+
+/// Genesis validators (Alice, Bob)
+pub const GENESIS_VALIDATORS: [u64; 2] = [1, 2];
+
+// Build genesis storage for testing
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+
+    pallet_validator_set::GenesisConfig::<Test> {
+        initial_validators: GENESIS_VALIDATORS.to_vec(),
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
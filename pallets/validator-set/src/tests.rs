@@ -0,0 +1,196 @@
+//! Unit tests for pallet-validator-set
+
+use crate::{mock::*, Error, Event, Validators, ValidatorsChanged};
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
+use pallet_session::SessionManager;
+use sp_runtime::{DispatchError, Perbill};
+use sp_staking::offence::{OffenceDetails, OnOffenceHandler};
+
+/// Report `offenders` to the pallet as `pallet_offences` would
+fn report(offenders: &[u64], fraction: Perbill) {
+    let details: Vec<_> = offenders
+        .iter()
+        .map(|v| OffenceDetails {
+            offender: (*v, *v),
+            reporters: vec![],
+        })
+        .collect();
+    let fractions = vec![fraction; offenders.len()];
+    ValidatorSet::on_offence(&details, &fractions, 3);
+}
+
+#[test]
+fn test_genesis_validators() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            ValidatorSet::validators().into_inner(),
+            GENESIS_VALIDATORS.to_vec()
+        );
+        assert!(!ValidatorsChanged::<Test>::get());
+
+        // Genesis session takes the stored set; later sessions keep it until it changes
+        assert_eq!(
+            ValidatorSet::new_session_genesis(0),
+            Some(GENESIS_VALIDATORS.to_vec())
+        );
+        assert_eq!(ValidatorSet::new_session(1), None);
+    });
+}
+
+#[test]
+fn test_add_validator() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ValidatorSet::add_validator(RuntimeOrigin::root(), 3));
+
+        assert_eq!(Validators::<Test>::get().into_inner(), vec![1, 2, 3]);
+        System::assert_last_event(Event::ValidatorAdded { validator: 3 }.into());
+
+        // Handed to the session pallet once
+        assert_eq!(ValidatorSet::new_session(1), Some(vec![1, 2, 3]));
+        assert_eq!(ValidatorSet::new_session(2), None);
+    });
+}
+
+#[test]
+fn test_add_validator_rejects_non_root() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ValidatorSet::add_validator(RuntimeOrigin::signed(1), 3),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn test_add_validator_rejects_duplicate() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ValidatorSet::add_validator(RuntimeOrigin::root(), 1),
+            Error::<Test>::AlreadyValidator
+        );
+    });
+}
+
+#[test]
+fn test_add_validator_rejects_full_set() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ValidatorSet::add_validator(RuntimeOrigin::root(), 3));
+        assert_ok!(ValidatorSet::add_validator(RuntimeOrigin::root(), 4));
+
+        assert_noop!(
+            ValidatorSet::add_validator(RuntimeOrigin::root(), 5),
+            Error::<Test>::TooManyValidators
+        );
+    });
+}
+
+#[test]
+fn test_remove_validator() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ValidatorSet::remove_validator(RuntimeOrigin::root(), 1));
+
+        assert_eq!(Validators::<Test>::get().into_inner(), vec![2]);
+        System::assert_last_event(Event::ValidatorRemoved { validator: 1 }.into());
+        assert_eq!(ValidatorSet::new_session(1), Some(vec![2]));
+    });
+}
+
+#[test]
+fn test_remove_validator_rejects_unknown() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ValidatorSet::remove_validator(RuntimeOrigin::root(), 3),
+            Error::<Test>::NotValidator
+        );
+    });
+}
+
+#[test]
+fn test_remove_validator_keeps_min_validators() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ValidatorSet::remove_validator(RuntimeOrigin::root(), 1));
+
+        assert_noop!(
+            ValidatorSet::remove_validator(RuntimeOrigin::root(), 2),
+            Error::<Test>::TooFewValidators
+        );
+    });
+}
+
+#[test]
+fn test_changes_within_a_session_are_batched() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ValidatorSet::add_validator(RuntimeOrigin::root(), 3));
+        assert_ok!(ValidatorSet::remove_validator(RuntimeOrigin::root(), 1));
+
+        assert_eq!(ValidatorSet::new_session(1), Some(vec![2, 3]));
+        assert!(!ValidatorsChanged::<Test>::get());
+    });
+}
+
+#[test]
+fn test_historical_session_identifies_validators() {
+    use pallet_session::historical::SessionManager as HistoricalSessionManager;
+
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            <ValidatorSet as HistoricalSessionManager<u64, u64>>::new_session_genesis(0),
+            Some(vec![(1, 1), (2, 2)])
+        );
+
+        assert_ok!(ValidatorSet::add_validator(RuntimeOrigin::root(), 3));
+        assert_eq!(
+            <ValidatorSet as HistoricalSessionManager<u64, u64>>::new_session(1),
+            Some(vec![(1, 1), (2, 2), (3, 3)])
+        );
+    });
+}
+
+#[test]
+fn test_offender_is_removed_and_punished() {
+    new_test_ext().execute_with(|| {
+        let fraction = Perbill::from_percent(10);
+        report(&[2], fraction);
+
+        assert_eq!(Validators::<Test>::get().into_inner(), vec![1]);
+        assert_eq!(Punished::get(), vec![(2, fraction)]);
+        System::assert_last_event(
+            Event::OffenceReported {
+                validator: 2,
+                session: 3,
+                slash_fraction: fraction,
+                removed: true,
+            }
+            .into(),
+        );
+        assert_eq!(ValidatorSet::new_session(4), Some(vec![1]));
+    });
+}
+
+#[test]
+fn test_offender_is_kept_at_min_validators() {
+    new_test_ext().execute_with(|| {
+        let fraction = Perbill::from_percent(50);
+        report(&[1, 2], fraction);
+
+        // The second removal would go below MinValidators
+        assert_eq!(Validators::<Test>::get().into_inner(), vec![2]);
+        assert_eq!(Punished::get(), vec![(1, fraction), (2, fraction)]);
+        System::assert_last_event(
+            Event::OffenceReported {
+                validator: 2,
+                session: 3,
+                slash_fraction: fraction,
+                removed: false,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn test_integrity_test_accepts_mock_config() {
+    new_test_ext().execute_with(|| {
+        <crate::Pallet<Test> as Hooks<u64>>::integrity_test();
+    });
+}
@@ -0,0 +1,51 @@
+//! Weight information for pallet-validator-set
+//!
+//! These weights are used to calculate the transaction fee for each extrinsic.
+//! In production, these should be generated using frame-benchmarking.
+
+use frame_support::pallet_prelude::Get;
+use frame_support::weights::Weight;
+
+/// Weight functions needed for pallet-validator-set
+pub trait WeightInfo {
+    fn add_validator() -> Weight;
+    fn remove_validator() -> Weight;
+}
+
+/// Default weight implementations (for development)
+/// These should be replaced with benchmarked weights in production
+pub struct SubstrateWeight<T>(core::marker::PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    /// Weight for `add_validator`
+    ///
+    /// Includes:
+    /// - Validators read/write (linear scan for duplicates, up to MaxValidators)
+    /// - ValidatorsChanged write
+    fn add_validator() -> Weight {
+        Weight::from_parts(20_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+
+    /// Weight for `remove_validator`
+    ///
+    /// Includes:
+    /// - Validators read/write (linear scan, up to MaxValidators)
+    /// - ValidatorsChanged write
+    fn remove_validator() -> Weight {
+        Weight::from_parts(20_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+}
+
+/// Unit testing weight implementations
+impl WeightInfo for () {
+    fn add_validator() -> Weight {
+        Weight::from_parts(20_000_000, 0)
+    }
+
+    fn remove_validator() -> Weight {
+        Weight::from_parts(20_000_000, 0)
+    }
+}
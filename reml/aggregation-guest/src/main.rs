@@ -0,0 +1,68 @@
+//! # Re-ML Aggregation Guest Program
+//!
+//! Recursively verifies Re-ML batch proofs inside SP1 zkVM, so several
+//! batches settle on-chain with one proof.
+//!
+//! ## Algorithm
+//!
+//! 1. Read the batch guest vkey digest and the public values of each batch
+//! 2. Verify each batch proof with SP1 proof composition
+//!    (`verify_sp1_proof`) against the SHA-256 digest of its public values
+//! 3. Decode each batch output and check its protocol, chain and batch ID
+//! 4. Commit the outputs under a root of roots over (batch_id, requests_root)
+
+#![no_main]
+#![no_std]
+
+extern crate alloc;
+
+use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
+use reml_lib::{
+    vkey_hash_from_digest, RemlAggregateOutput, RemlAggregationInput, RemlProofOutput,
+    MAX_AGGREGATED_BATCHES, REML_VERSION, TESSERAX_CHAIN_ID,
+};
+use sha2::{Digest, Sha256};
+
+sp1_zkvm::entrypoint!(main);
+
+// ═══════════════════════════════════════════════════════════════════════════
+// MAIN ENTRY POINT
+// ═══════════════════════════════════════════════════════════════════════════
+
+pub fn main() {
+    // Read input from host
+    let input: RemlAggregationInput = sp1_zkvm::io::read();
+    
+    // Validate protocol
+    assert_eq!(input.version, REML_VERSION, "Invalid protocol version");
+    assert_eq!(input.chain_id, TESSERAX_CHAIN_ID, "Invalid chain ID");
+    assert!(
+        !input.batch_public_values.is_empty()
+            && input.batch_public_values.len() <= MAX_AGGREGATED_BATCHES,
+        "Invalid batch count"
+    );
+    
+    // Verify each batch proof, in the order the host wrote them
+    let mut batch_ids = BTreeSet::new();
+    let mut batches: Vec<RemlProofOutput> = Vec::with_capacity(input.batch_public_values.len());
+    
+    for public_values in input.batch_public_values.iter() {
+        let digest: [u8; 32] = Sha256::digest(public_values).into();
+        sp1_zkvm::lib::verify::verify_sp1_proof(&input.batch_vkey, &digest);
+        
+        // The batch guest commits its output with bincode
+        let output: RemlProofOutput =
+            bincode::deserialize(public_values).expect("Invalid batch public values");
+        assert_eq!(output.version, REML_VERSION, "Invalid batch protocol version");
+        assert_eq!(output.chain_id, TESSERAX_CHAIN_ID, "Invalid batch chain ID");
+        assert!(batch_ids.insert(output.batch_id), "Duplicate batch ID");
+        
+        batches.push(output);
+    }
+    
+    // Commit output
+    let output = RemlAggregateOutput::new(vkey_hash_from_digest(&input.batch_vkey), batches);
+    
+    sp1_zkvm::io::commit(&output);
+}
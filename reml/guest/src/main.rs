@@ -19,7 +19,7 @@ extern crate alloc;
 
 use alloc::vec::Vec;
 use reml_lib::{
-    RemlProofInput, RemlProofOutput, SignatureRequest,
+    RemlProofInput, RemlProofOutput, RequestRejection, SignatureRequest,
     MLDSA_PUBLIC_KEY_SIZE, MLDSA_SIGNATURE_SIZE, REML_VERSION, TESSERAX_CHAIN_ID,
 };
 
@@ -74,12 +74,14 @@ pub fn main() {
     assert_eq!(input.version, REML_VERSION, "Invalid protocol version");
     assert_eq!(input.chain_id, TESSERAX_CHAIN_ID, "Invalid chain ID");
     
-    // Verify each signature
+    // Verify each signature, recording why failed requests were rejected
     let mut verified_count: u32 = 0;
     let mut verified_request_ids: Vec<u64> = Vec::new();
+    let mut rejected_request_ids: Vec<(u64, RequestRejection)> = Vec::new();
     
     for request in input.requests.iter() {
         if !request.validate_sizes() {
+            rejected_request_ids.push((request.request_id, RequestRejection::InvalidSize));
             continue;
         }
         
@@ -90,6 +92,8 @@ pub fn main() {
         ) {
             verified_count += 1;
             verified_request_ids.push(request.request_id);
+        } else {
+            rejected_request_ids.push((request.request_id, RequestRejection::InvalidSignature));
         }
     }
     
@@ -102,6 +106,7 @@ pub fn main() {
         verified_count,
         requests_root,
         verified_request_ids,
+        rejected_request_ids,
     );
     
     sp1_zkvm::io::commit(&output);
@@ -1,7 +1,18 @@
 //! Build script for reml-host
 //!
-//! This compiles the guest program to an ELF binary that can be run in SP1
+//! This compiles the guest programs to ELF binaries that can be run in SP1:
+//! the batch guest and the aggregation guest, which verifies batch proofs.
+
+use sp1_build::{build_program_with_args, BuildArgs};
 
 fn main() {
     sp1_build::build_program("../guest");
+    build_program_with_args(
+        "../aggregation-guest",
+        BuildArgs {
+            output_directory: Some("../target/elf".to_string()),
+            elf_name: Some("reml-aggregation-guest-elf".to_string()),
+            ..Default::default()
+        },
+    );
 }
@@ -7,6 +7,7 @@
 //! - **Proof Generation**: Invokes SP1 prover on signature batches
 //! - **Test Data Generation**: Creates valid ML-DSA signatures for testing
 //! - **Local Verification**: Verifies proofs before on-chain submission
+//! - **Proof Aggregation**: Recursively combines batch proofs into one proof
 //! - **Aggregator Server**: HTTP server for receiving signature requests
 //!
 //! ## Usage
@@ -21,6 +22,9 @@
 //! # Verify proof locally
 //! reml-prover verify --proof proof.json
 //!
+//! # Aggregate batch proofs into one
+//! reml-prover aggregate --proofs proof_1.json,proof_2.json --output aggregate.json
+//!
 //! # Run aggregator server
 //! reml-prover serve --port 8080
 //! ```
@@ -30,10 +34,12 @@ use clap::{Parser, Subcommand};
 use pqcrypto_dilithium::dilithium2;
 use pqcrypto_traits::sign::{PublicKey, SecretKey};
 use reml_lib::{
-    RemlProofBundle, RemlProofInput, RemlProofOutput, SignatureRequest,
-    compute_requests_root, MLDSA_SIGNATURE_SIZE, MLDSA_PUBLIC_KEY_SIZE,
+    RemlAggregateBundle, RemlAggregateOutput, RemlAggregationInput, RemlProofBundle,
+    RemlProofInput, RemlProofOutput, RequestRejection, SignatureRequest,
+    compute_requests_root, MAX_AGGREGATED_BATCHES, MLDSA_SIGNATURE_SIZE, MLDSA_PUBLIC_KEY_SIZE,
 };
-use sp1_sdk::{ProverClient, SP1Stdin, HashableKey};
+use sp1_sdk::{ProverClient, SP1Proof, SP1ProofWithPublicValues, SP1Stdin, HashableKey};
+use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -43,6 +49,9 @@ use tracing::{info, warn, error};
 /// The ELF binary of the guest program
 const GUEST_ELF: &[u8] = include_bytes!("../../target/elf/riscv32im-succinct-zkvm-elf");
 
+/// The ELF binary of the aggregation guest program
+const AGGREGATION_ELF: &[u8] = include_bytes!("../../target/elf/reml-aggregation-guest-elf");
+
 // ═══════════════════════════════════════════════════════════════════════════
 // CLI INTERFACE
 // ═══════════════════════════════════════════════════════════════════════════
@@ -88,6 +97,21 @@ enum Commands {
         proof: PathBuf,
     },
     
+    /// Recursively aggregate batch proofs into one proof
+    Aggregate {
+        /// Batch proof bundles (JSON), comma-separated
+        #[arg(short, long, value_delimiter = ',', required = true)]
+        proofs: Vec<PathBuf>,
+        
+        /// Output file for the aggregated proof bundle (JSON)
+        #[arg(short, long)]
+        output: PathBuf,
+        
+        /// Use mock prover (faster, for testing)
+        #[arg(long)]
+        mock: bool,
+    },
+    
     /// Generate a test batch with real ML-DSA signatures
     GenTest {
         /// Number of signatures to generate
@@ -119,7 +143,11 @@ enum Commands {
     },
     
     /// Get verification key hash for the guest program
-    VKeyHash,
+    VKeyHash {
+        /// Print the aggregation guest's key instead
+        #[arg(long)]
+        aggregation: bool,
+    },
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -145,14 +173,17 @@ async fn main() -> Result<()> {
         Commands::Verify { proof } => {
             verify_proof(&proof).await?;
         }
+        Commands::Aggregate { proofs, output, mock } => {
+            aggregate_proofs(&proofs, &output, mock).await?;
+        }
         Commands::GenTest { count, output, include_invalid } => {
             generate_test_batch(count, &output, include_invalid)?;
         }
         Commands::Serve { port, batch_size, output_dir } => {
             run_server(port, batch_size, output_dir).await?;
         }
-        Commands::VKeyHash => {
-            print_vkey_hash()?;
+        Commands::VKeyHash { aggregation } => {
+            print_vkey_hash(aggregation)?;
         }
     }
     
@@ -179,6 +210,9 @@ async fn prove_batch(
     info!("Loaded {} signature requests", requests.len());
     
     let proof_input = RemlProofInput::new(requests, batch_id);
+    if let Some(request_id) = proof_input.duplicate_request_id() {
+        bail!("Duplicate request ID {} in input", request_id);
+    }
     let bundle = generate_proof(proof_input, use_mock).await?;
     
     // Save proof
@@ -189,6 +223,7 @@ async fn prove_batch(
     
     info!("✅ Proof saved to {:?}", output_path);
     info!("   Verified: {} signatures", bundle.output.verified_count);
+    info!("   Rejected: {} signatures", bundle.output.rejected_request_ids.len());
     info!("   Proof size: {} bytes", bundle.proof_size());
     info!("   Compression ratio: {:.1}x", bundle.compression_ratio());
     
@@ -218,8 +253,9 @@ async fn generate_proof(input: RemlProofInput, use_mock: bool) -> Result<RemlPro
     
     info!("Verification key hash: 0x{}", hex::encode(vk.hash_bytes()));
     
-    // Generate proof
+    // Generate proof (compressed, so it can be aggregated)
     let proof = client.prove(&pk, &stdin)
+        .compressed()
         .run()
         .context("Proof generation failed")?;
     
@@ -236,13 +272,109 @@ async fn generate_proof(input: RemlProofInput, use_mock: bool) -> Result<RemlPro
         .context("Failed to serialize proof")?;
     
     info!("✅ Proof generated successfully!");
-    info!("   Public output: {} verified, root: 0x{}",
+    info!("   Public output: {} verified, {} rejected, root: 0x{}",
           output.verified_count,
+          output.rejected_request_ids.len(),
           hex::encode(&output.requests_root[..8]));
     
     Ok(RemlProofBundle::new(proof_bytes, output, vkey_hash))
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// PROOF AGGREGATION
+// ═══════════════════════════════════════════════════════════════════════════
+
+async fn aggregate_proofs(
+    proof_paths: &[PathBuf],
+    output_path: &PathBuf,
+    use_mock: bool,
+) -> Result<()> {
+    if proof_paths.is_empty() || proof_paths.len() > MAX_AGGREGATED_BATCHES {
+        bail!("Expected 1 to {} batch proofs, got {}", MAX_AGGREGATED_BATCHES, proof_paths.len());
+    }
+    
+    info!("Initializing SP1 prover client...");
+    
+    let client = if use_mock {
+        info!("Using mock prover for faster testing");
+        ProverClient::builder().mock().build()
+    } else {
+        info!("Using real SP1 prover (this may take a while)");
+        ProverClient::from_env()
+    };
+    
+    // Batch proofs must come from the current batch guest
+    let (_, batch_vk) = client.setup(GUEST_ELF);
+    let batch_vkey_hash = batch_vk.hash_bytes();
+    
+    let mut stdin = SP1Stdin::new();
+    let mut batch_public_values = Vec::with_capacity(proof_paths.len());
+    let mut batch_ids = Vec::with_capacity(proof_paths.len());
+    
+    for path in proof_paths {
+        info!("Loading batch proof from {:?}", path);
+        
+        let proof_json = fs::read_to_string(path)
+            .context("Failed to read proof file")?;
+        let bundle: RemlProofBundle = serde_json::from_str(&proof_json)
+            .context("Failed to parse proof JSON")?;
+        let batch_id = bundle.output.batch_id;
+        
+        if batch_ids.contains(&batch_id) {
+            bail!("Duplicate batch {} in input", batch_id);
+        }
+        if bundle.vkey_hash[..] != batch_vkey_hash[..32] {
+            bail!("Batch {} was proven with a different program version", batch_id);
+        }
+        
+        let proof: SP1ProofWithPublicValues = bincode::deserialize(&bundle.proof)
+            .context("Failed to deserialize proof")?;
+        let SP1Proof::Compressed(compressed) = proof.proof else {
+            bail!("Batch {} has no compressed proof; prove it again to aggregate it", batch_id);
+        };
+        
+        // Verified in the guest, in this order
+        stdin.write_proof(*compressed, batch_vk.vk.clone());
+        batch_public_values.push(proof.public_values.to_vec());
+        batch_ids.push(batch_id);
+    }
+    
+    stdin.write(&RemlAggregationInput::new(batch_vk.hash_u32(), batch_public_values));
+    
+    info!("Aggregating batches {:?}...", batch_ids);
+    
+    let (pk, vk) = client.setup(AGGREGATION_ELF);
+    
+    info!("Aggregation key hash: 0x{}", hex::encode(vk.hash_bytes()));
+    
+    let proof = client.prove(&pk, &stdin)
+        .compressed()
+        .run()
+        .context("Proof aggregation failed")?;
+    
+    let output: RemlAggregateOutput = proof.public_values.read();
+    
+    let mut vkey_hash = [0u8; 32];
+    vkey_hash.copy_from_slice(&vk.hash_bytes()[..32]);
+    
+    let proof_bytes = bincode::serialize(&proof)
+        .context("Failed to serialize proof")?;
+    let bundle = RemlAggregateBundle::new(proof_bytes, output, vkey_hash);
+    
+    let output_json = serde_json::to_string_pretty(&bundle)
+        .context("Failed to serialize aggregated proof bundle")?;
+    fs::write(output_path, output_json)
+        .context("Failed to write output file")?;
+    
+    info!("✅ Aggregated proof saved to {:?}", output_path);
+    info!("   Batches: {}", bundle.output.batches.len());
+    info!("   Verified: {} signatures", bundle.output.verified_count());
+    info!("   Batches root: 0x{}", hex::encode(&bundle.output.batches_root[..8]));
+    info!("   Proof size: {} bytes", bundle.proof.len());
+    
+    Ok(())
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // PROOF VERIFICATION
 // ═══════════════════════════════════════════════════════════════════════════
@@ -258,6 +390,7 @@ async fn verify_proof(proof_path: &PathBuf) -> Result<()> {
     info!("Proof details:");
     info!("  Batch ID: {}", bundle.output.batch_id);
     info!("  Verified signatures: {}", bundle.output.verified_count);
+    info!("  Rejected signatures: {}", bundle.output.rejected_request_ids.len());
     info!("  Requests root: 0x{}", hex::encode(&bundle.output.requests_root[..8]));
     info!("  Proof size: {} bytes", bundle.proof_size());
     info!("  VKey hash: 0x{}", hex::encode(&bundle.vkey_hash[..8]));
@@ -369,6 +502,43 @@ struct AggregatorState {
     batch_size: usize,
     output_dir: PathBuf,
     batch_counter: u64,
+    /// Outcome of every request handed to the prover
+    outcomes: HashMap<u64, RequestOutcome>,
+}
+
+/// What became of a request once its batch was sent to the prover
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum RequestOutcome {
+    /// The batch is being proven
+    Proving { batch_id: u64 },
+    /// The proof verified the signature
+    Verified { batch_id: u64 },
+    /// The proof rejected the request
+    Rejected { batch_id: u64, reason: RequestRejection },
+    /// Proof generation failed; the request must be resubmitted
+    Failed { batch_id: u64 },
+}
+
+impl AggregatorState {
+    /// Record the outcome of every request in a proven batch
+    fn record_proof(&mut self, output: &RemlProofOutput) {
+        let batch_id = output.batch_id;
+        for &request_id in &output.verified_request_ids {
+            self.outcomes.insert(request_id, RequestOutcome::Verified { batch_id });
+        }
+        for &(request_id, reason) in &output.rejected_request_ids {
+            self.outcomes.insert(request_id, RequestOutcome::Rejected { batch_id, reason });
+        }
+    }
+    
+    /// Mark the requests of a batch that could not be proven
+    fn record_failure(&mut self, batch_id: u64) {
+        for outcome in self.outcomes.values_mut() {
+            if *outcome == (RequestOutcome::Proving { batch_id }) {
+                *outcome = RequestOutcome::Failed { batch_id };
+            }
+        }
+    }
 }
 
 async fn run_server(port: u16, batch_size: usize, output_dir: PathBuf) -> Result<()> {
@@ -387,6 +557,7 @@ async fn run_server(port: u16, batch_size: usize, output_dir: PathBuf) -> Result
         batch_size,
         output_dir,
         batch_counter: 0,
+        outcomes: HashMap::new(),
     }));
     
     let listener = TcpListener::bind(format!("0.0.0.0:{}", port)).await?;
@@ -396,6 +567,7 @@ async fn run_server(port: u16, batch_size: usize, output_dir: PathBuf) -> Result
     info!("  POST /submit - Submit a signature request");
     info!("  GET /status  - Get aggregator status");
     info!("  GET /batch   - Get current batch info");
+    info!("  GET /request/<id> - Get the outcome of a request");
     info!("");
     
     loop {
@@ -416,6 +588,8 @@ async fn run_server(port: u16, batch_size: usize, output_dir: PathBuf) -> Result
                         handle_status(&state).await
                     } else if request.starts_with("GET /batch") {
                         handle_batch_info(&state).await
+                    } else if request.starts_with("GET /request/") {
+                        handle_request_status(&request, &state).await
                     } else {
                         http_response(404, "Not Found", r#"{"error": "Not found"}"#)
                     };
@@ -433,7 +607,7 @@ async fn run_server(port: u16, batch_size: usize, output_dir: PathBuf) -> Result
     }
 }
 
-async fn handle_submit(request: &str, state: &Arc<RwLock<AggregatorState>>) -> String {
+async fn handle_submit(request: &str, state_handle: &Arc<RwLock<AggregatorState>>) -> String {
     // Extract body from HTTP request
     let body = request.split("\r\n\r\n").nth(1).unwrap_or("");
     
@@ -452,8 +626,23 @@ async fn handle_submit(request: &str, state: &Arc<RwLock<AggregatorState>>) -> S
             r#"{"error": "Invalid signature or public key size"}"#);
     }
     
-    let mut state = state.write().await;
+    let mut state = state_handle.write().await;
     let request_id = sig_request.request_id;
+    
+    // The pallet rejects batches with repeated request IDs, and settled
+    // request IDs can't be proven again
+    let settled = matches!(
+        state.outcomes.get(&request_id),
+        Some(
+            RequestOutcome::Proving { .. }
+                | RequestOutcome::Verified { .. }
+                | RequestOutcome::Rejected { .. }
+        )
+    );
+    if settled || state.pending_requests.iter().any(|r| r.request_id == request_id) {
+        return http_response(409, "Conflict", 
+            &format!(r#"{{"error": "Duplicate request_id: {}"}}"#, request_id));
+    }
     state.pending_requests.push(sig_request);
     
     let pending = state.pending_requests.len();
@@ -468,8 +657,12 @@ async fn handle_submit(request: &str, state: &Arc<RwLock<AggregatorState>>) -> S
         state.batch_counter += 1;
         let batch_id = state.batch_counter;
         let output_dir = state.output_dir.clone();
+        for request in &requests {
+            state.outcomes.insert(request.request_id, RequestOutcome::Proving { batch_id });
+        }
         drop(state); // Release lock before async work
         
+        let state = Arc::clone(state_handle);
         tokio::spawn(async move {
             info!("Batch {} complete, generating proof...", batch_id);
             
@@ -477,6 +670,12 @@ async fn handle_submit(request: &str, state: &Arc<RwLock<AggregatorState>>) -> S
             
             match generate_proof(input, false).await {
                 Ok(bundle) => {
+                    state.write().await.record_proof(&bundle.output);
+                    if !bundle.output.rejected_request_ids.is_empty() {
+                        warn!("Batch {} rejected {} requests",
+                              batch_id, bundle.output.rejected_request_ids.len());
+                    }
+                    
                     let output_path = output_dir.join(format!("proof_{}.json", batch_id));
                     match serde_json::to_string_pretty(&bundle) {
                         Ok(json) => {
@@ -491,6 +690,7 @@ async fn handle_submit(request: &str, state: &Arc<RwLock<AggregatorState>>) -> S
                 }
                 Err(e) => {
                     error!("Failed to generate proof for batch {}: {}", batch_id, e);
+                    state.write().await.record_failure(batch_id);
                 }
             }
         });
@@ -542,6 +742,48 @@ async fn handle_batch_info(state: &Arc<RwLock<AggregatorState>>) -> String {
     http_response(200, "OK", &json)
 }
 
+async fn handle_request_status(request: &str, state: &Arc<RwLock<AggregatorState>>) -> String {
+    // Request line: GET /request/<id> HTTP/1.1
+    let path = request.split_whitespace().nth(1).unwrap_or("");
+    let request_id: u64 = match path.trim_start_matches("/request/").parse() {
+        Ok(id) => id,
+        Err(_) => {
+            return http_response(400, "Bad Request", r#"{"error": "Invalid request_id"}"#);
+        }
+    };
+    
+    let state = state.read().await;
+    
+    let json = match state.outcomes.get(&request_id) {
+        Some(RequestOutcome::Proving { batch_id }) => format!(
+            r#"{{"request_id": {}, "status": "proving", "batch_id": {}}}"#,
+            request_id, batch_id
+        ),
+        Some(RequestOutcome::Verified { batch_id }) => format!(
+            r#"{{"request_id": {}, "status": "verified", "batch_id": {}}}"#,
+            request_id, batch_id
+        ),
+        Some(RequestOutcome::Rejected { batch_id, reason }) => format!(
+            r#"{{"request_id": {}, "status": "rejected", "batch_id": {}, "reason": "{:?}", "reason_code": {}}}"#,
+            request_id, batch_id, reason, reason.code()
+        ),
+        Some(RequestOutcome::Failed { batch_id }) => format!(
+            r#"{{"request_id": {}, "status": "failed", "batch_id": {}}}"#,
+            request_id, batch_id
+        ),
+        None if state.pending_requests.iter().any(|r| r.request_id == request_id) => format!(
+            r#"{{"request_id": {}, "status": "pending"}}"#,
+            request_id
+        ),
+        None => {
+            return http_response(404, "Not Found",
+                &format!(r#"{{"error": "Unknown request_id: {}"}}"#, request_id));
+        }
+    };
+    
+    http_response(200, "OK", &json)
+}
+
 fn http_response(status: u16, status_text: &str, body: &str) -> String {
     format!(
         "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
@@ -553,17 +795,23 @@ fn http_response(status: u16, status_text: &str, body: &str) -> String {
 // UTILITY
 // ═══════════════════════════════════════════════════════════════════════════
 
-fn print_vkey_hash() -> Result<()> {
-    info!("Computing verification key hash for guest program...");
+fn print_vkey_hash(aggregation: bool) -> Result<()> {
+    let (elf, program) = if aggregation {
+        (AGGREGATION_ELF, "Aggregation Program")
+    } else {
+        (GUEST_ELF, "Guest Program")
+    };
+    
+    info!("Computing verification key hash for {}...", program.to_lowercase());
     
     let client = ProverClient::from_env();
-    let (_, vk) = client.setup(GUEST_ELF);
+    let (_, vk) = client.setup(elf);
     
     let hash = vk.hash_bytes();
     
     println!();
     println!("═══════════════════════════════════════════════════════════════════");
-    println!("  Re-ML Guest Program Verification Key");
+    println!("  Re-ML {} Verification Key", program);
     println!("═══════════════════════════════════════════════════════════════════");
     println!();
     println!("  VKey Hash (hex): 0x{}", hex::encode(&hash));
@@ -581,20 +829,14 @@ fn print_vkey_hash() -> Result<()> {
     }
     println!("]");
     println!();
-    println!("  Use this hash in runtime/src/configs/mod.rs:");
+    println!("  Allow it on-chain with a council motion (AdminOrigin):");
     println!();
-    println!("    pub ExpectedVKeyHash: [u8; 32] = [");
-    for i in (0..32).step_by(8) {
-        print!("        ");
-        for j in i..(i + 8).min(32) {
-            print!("0x{:02x}", hash[j]);
-            if j < 31 {
-                print!(", ");
-            }
-        }
-        println!();
-    }
-    println!("    ];");
+    println!(
+        "    remlVerifier.addVkey(0x{}, <guest version>, <activation block or None>)",
+        hex::encode(&hash[..32])
+    );
+    println!();
+    println!("  Retire the previous guest with remlVerifier.retireVkey once provers upgraded.");
     println!();
     println!("═══════════════════════════════════════════════════════════════════");
     
@@ -635,6 +877,72 @@ mod tests {
         assert_eq!(parsed.public_key.len(), MLDSA_PUBLIC_KEY_SIZE);
     }
     
+    #[tokio::test]
+    async fn test_submit_rejects_duplicate_request_id() {
+        let state = Arc::new(RwLock::new(AggregatorState {
+            pending_requests: Vec::new(),
+            batch_size: 10,
+            output_dir: PathBuf::from("."),
+            batch_counter: 0,
+            outcomes: HashMap::new(),
+        }));
+        let request = SignatureRequest::new(
+            [0u8; 32],
+            vec![1u8; MLDSA_PUBLIC_KEY_SIZE],
+            vec![2u8; MLDSA_SIGNATURE_SIZE],
+            7,
+        );
+        let http = format!("POST /submit HTTP/1.1\r\n\r\n{}", serde_json::to_string(&request).unwrap());
+        
+        assert!(handle_submit(&http, &state).await.contains("HTTP/1.1 200 OK"));
+        let response = handle_submit(&http, &state).await;
+        assert!(response.contains("HTTP/1.1 409 Conflict"));
+        assert_eq!(state.read().await.pending_requests.len(), 1);
+    }
+    
+    #[tokio::test]
+    async fn test_request_status_reports_rejections() {
+        let state = Arc::new(RwLock::new(AggregatorState {
+            pending_requests: Vec::new(),
+            batch_size: 10,
+            output_dir: PathBuf::from("."),
+            batch_counter: 1,
+            outcomes: HashMap::new(),
+        }));
+        state.write().await.record_proof(&RemlProofOutput::new(
+            1,
+            1,
+            compute_requests_root(&[1, 2]),
+            vec![1],
+            vec![(2, RequestRejection::InvalidSignature)],
+        ));
+        
+        let status = |id: u64| {
+            let state = Arc::clone(&state);
+            async move {
+                handle_request_status(&format!("GET /request/{} HTTP/1.1\r\n\r\n", id), &state).await
+            }
+        };
+        
+        let verified = status(1).await;
+        assert!(verified.contains(r#""status": "verified""#));
+        let rejected = status(2).await;
+        assert!(rejected.contains(r#""status": "rejected""#));
+        assert!(rejected.contains(r#""reason": "InvalidSignature""#));
+        assert!(rejected.contains(r#""reason_code": 2"#));
+        assert!(status(3).await.contains("HTTP/1.1 404 Not Found"));
+        
+        // A rejected request ID can't be submitted again
+        let request = SignatureRequest::new(
+            [0u8; 32],
+            vec![1u8; MLDSA_PUBLIC_KEY_SIZE],
+            vec![2u8; MLDSA_SIGNATURE_SIZE],
+            2,
+        );
+        let http = format!("POST /submit HTTP/1.1\r\n\r\n{}", serde_json::to_string(&request).unwrap());
+        assert!(handle_submit(&http, &state).await.contains("HTTP/1.1 409 Conflict"));
+    }
+    
     #[test]
     fn test_http_response() {
         let response = http_response(200, "OK", r#"{"test": true}"#);
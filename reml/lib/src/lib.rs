@@ -8,6 +8,9 @@
 //! - **RemlProofInput**: Input to the zkVM guest program
 //! - **RemlProofOutput**: Public output committed in the proof
 //! - **RemlProofBundle**: Complete proof with metadata for on-chain submission
+//! - **RemlAggregationInput**: Input to the zkVM aggregation guest program
+//! - **RemlAggregateOutput**: Public output of an aggregated proof
+//! - **RemlAggregateBundle**: Complete aggregated proof for on-chain submission
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -29,6 +32,9 @@ pub const MLDSA_SIGNATURE_SIZE: usize = 2420;
 /// Maximum signatures per batch (limited by proof size and time)
 pub const MAX_BATCH_SIZE: usize = 256;
 
+/// Maximum batch proofs combined by one aggregated proof
+pub const MAX_AGGREGATED_BATCHES: usize = 16;
+
 /// Re-ML protocol version
 pub const REML_VERSION: u8 = 1;
 
@@ -89,6 +95,21 @@ impl SignatureRequest {
     pub fn raw_size(&self) -> usize {
         32 + self.public_key.len() + self.signature.len() + 8
     }
+    
+    /// Commitment posted for this request in an optimistic batch
+    ///
+    /// keccak256(request_id (LE) || message || public_key || signature), as
+    /// recomputed on-chain when the request is challenged.
+    pub fn commitment(&self) -> [u8; 32] {
+        use sha3::{Digest, Keccak256};
+        
+        let mut hasher = Keccak256::new();
+        hasher.update(self.request_id.to_le_bytes());
+        hasher.update(self.message);
+        hasher.update(&self.public_key);
+        hasher.update(&self.signature);
+        hasher.finalize().into()
+    }
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -131,12 +152,42 @@ impl RemlProofInput {
     pub fn raw_size(&self) -> usize {
         self.requests.iter().map(|r| r.raw_size()).sum()
     }
+    
+    /// First request ID that appears more than once, if any
+    ///
+    /// The pallet rejects batches with repeated request IDs.
+    pub fn duplicate_request_id(&self) -> Option<u64> {
+        let mut seen = alloc::collections::BTreeSet::new();
+        self.requests
+            .iter()
+            .map(|r| r.request_id)
+            .find(|id| !seen.insert(*id))
+    }
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
 // PROOF OUTPUT (public values committed in proof)
 // ═══════════════════════════════════════════════════════════════════════════
 
+/// Why the guest rejected a signature request
+///
+/// The discriminant is the reason code used on-chain and in the aggregator API.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[repr(u8)]
+pub enum RequestRejection {
+    /// Public key or signature is not sized for ML-DSA (Dilithium2)
+    InvalidSize = 1,
+    /// ML-DSA verification failed
+    InvalidSignature = 2,
+}
+
+impl RequestRejection {
+    /// Reason code of the rejection
+    pub fn code(self) -> u8 {
+        self as u8
+    }
+}
+
 /// Public output from the zkVM guest program
 ///
 /// These values are cryptographically committed in the proof
@@ -161,6 +212,10 @@ pub struct RemlProofOutput {
     
     /// List of verified request IDs
     pub verified_request_ids: Vec<u64>,
+    
+    /// Request IDs the guest rejected, with the reason
+    #[serde(default)]
+    pub rejected_request_ids: Vec<(u64, RequestRejection)>,
 }
 
 impl RemlProofOutput {
@@ -170,6 +225,7 @@ impl RemlProofOutput {
         verified_count: u32,
         requests_root: [u8; 32],
         verified_request_ids: Vec<u64>,
+        rejected_request_ids: Vec<(u64, RequestRejection)>,
     ) -> Self {
         Self {
             version: REML_VERSION,
@@ -178,6 +234,7 @@ impl RemlProofOutput {
             verified_count,
             requests_root,
             verified_request_ids,
+            rejected_request_ids,
         }
     }
 }
@@ -207,23 +264,11 @@ pub struct RemlProofBundle {
 impl RemlProofBundle {
     /// Create new proof bundle
     pub fn new(proof: Vec<u8>, output: RemlProofOutput, vkey_hash: [u8; 32]) -> Self {
-        use core::time::Duration;
-        
-        // Get timestamp (Unix epoch seconds)
-        #[cfg(feature = "std")]
-        let generated_at = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .map(|d| d.as_secs())
-            .unwrap_or(0);
-        
-        #[cfg(not(feature = "std"))]
-        let generated_at = 0u64;
-        
         Self {
             proof,
             output,
             vkey_hash,
-            generated_at,
+            generated_at: unix_timestamp(),
         }
     }
     
@@ -245,6 +290,143 @@ impl RemlProofBundle {
     }
 }
 
+/// Current Unix time in seconds (zero without `std`)
+fn unix_timestamp() -> u64 {
+    #[cfg(feature = "std")]
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    
+    #[cfg(not(feature = "std"))]
+    let now = 0u64;
+    
+    now
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// AGGREGATION (recursive verification of batch proofs)
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Input to the Re-ML aggregation guest program
+///
+/// The batch proofs themselves are passed to SP1 with `SP1Stdin::write_proof`,
+/// in the order of `batch_public_values`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RemlAggregationInput {
+    /// Protocol version
+    pub version: u8,
+    
+    /// Chain ID (must match on-chain config)
+    pub chain_id: u32,
+    
+    /// Verification key digest of the batch guest (`HashableKey::hash_u32`)
+    pub batch_vkey: [u32; 8],
+    
+    /// Public values of each batch proof, as committed by the batch guest
+    pub batch_public_values: Vec<Vec<u8>>,
+}
+
+impl RemlAggregationInput {
+    /// Create new aggregation input
+    pub fn new(batch_vkey: [u32; 8], batch_public_values: Vec<Vec<u8>>) -> Self {
+        Self {
+            version: REML_VERSION,
+            chain_id: TESSERAX_CHAIN_ID,
+            batch_vkey,
+            batch_public_values,
+        }
+    }
+}
+
+/// Public output of the aggregation guest program
+///
+/// Commits to the output of every batch proof it verified, under a root of
+/// roots that the pallet recomputes.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RemlAggregateOutput {
+    /// Protocol version
+    pub version: u8,
+    
+    /// Chain ID
+    pub chain_id: u32,
+    
+    /// Verification key hash of the batch guest the proofs were verified under
+    #[serde(with = "hex_serde_array")]
+    pub batch_vkey_hash: [u8; 32],
+    
+    /// Merkle root over (batch_id, requests_root) of the batches
+    #[serde(with = "hex_serde_array")]
+    pub batches_root: [u8; 32],
+    
+    /// Output of each batch proof, in verification order
+    pub batches: Vec<RemlProofOutput>,
+}
+
+impl RemlAggregateOutput {
+    /// Create new aggregate output, computing the root of roots
+    pub fn new(batch_vkey_hash: [u8; 32], batches: Vec<RemlProofOutput>) -> Self {
+        let roots: Vec<(u64, [u8; 32])> = batches
+            .iter()
+            .map(|batch| (batch.batch_id, batch.requests_root))
+            .collect();
+        
+        Self {
+            version: REML_VERSION,
+            chain_id: TESSERAX_CHAIN_ID,
+            batch_vkey_hash,
+            batches_root: compute_batches_root(&roots),
+            batches,
+        }
+    }
+    
+    /// Number of signatures verified across all batches
+    pub fn verified_count(&self) -> u32 {
+        self.batches.iter().map(|batch| batch.verified_count).sum()
+    }
+}
+
+/// Complete aggregated proof bundle for on-chain submission
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RemlAggregateBundle {
+    /// Serialized SP1 proof of the aggregation guest
+    #[serde(with = "hex_serde")]
+    pub proof: Vec<u8>,
+    
+    /// Public output values
+    pub output: RemlAggregateOutput,
+    
+    /// Verification key hash of the aggregation guest
+    #[serde(with = "hex_serde_array")]
+    pub vkey_hash: [u8; 32],
+    
+    /// Timestamp when proof was generated
+    pub generated_at: u64,
+}
+
+impl RemlAggregateBundle {
+    /// Create new aggregated proof bundle
+    pub fn new(proof: Vec<u8>, output: RemlAggregateOutput, vkey_hash: [u8; 32]) -> Self {
+        Self {
+            proof,
+            output,
+            vkey_hash,
+            generated_at: unix_timestamp(),
+        }
+    }
+}
+
+/// Verification key hash of a guest from its digest
+///
+/// The words in big-endian order, as `HashableKey::hash_bytes` returns them.
+pub fn vkey_hash_from_digest(digest: &[u32; 8]) -> [u8; 32] {
+    let mut hash = [0u8; 32];
+    for (chunk, word) in hash.chunks_exact_mut(4).zip(digest.iter()) {
+        chunk.copy_from_slice(&word.to_be_bytes());
+    }
+    hash
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // MERKLE ROOT COMPUTATION
 // ═══════════════════════════════════════════════════════════════════════════
@@ -260,7 +442,7 @@ pub fn compute_requests_root(ids: &[u64]) -> [u8; 32] {
     }
     
     // Hash each ID to create leaves
-    let mut leaves: Vec<[u8; 32]> = ids
+    let leaves: Vec<[u8; 32]> = ids
         .iter()
         .map(|id| {
             let mut hasher = Keccak256::new();
@@ -272,6 +454,36 @@ pub fn compute_requests_root(ids: &[u64]) -> [u8; 32] {
         })
         .collect();
     
+    merkle_root(leaves)
+}
+
+/// Compute the root of roots of aggregated batches
+///
+/// Leaves are keccak256(batch_id (LE) || requests_root), in batch order.
+pub fn compute_batches_root(batches: &[(u64, [u8; 32])]) -> [u8; 32] {
+    use sha3::{Digest, Keccak256};
+    
+    if batches.is_empty() {
+        return [0u8; 32];
+    }
+    
+    let leaves: Vec<[u8; 32]> = batches
+        .iter()
+        .map(|(batch_id, requests_root)| {
+            let mut hasher = Keccak256::new();
+            hasher.update(batch_id.to_le_bytes());
+            hasher.update(requests_root);
+            hasher.finalize().into()
+        })
+        .collect();
+    
+    merkle_root(leaves)
+}
+
+/// Build a keccak256 merkle tree over `leaves`, promoting odd nodes
+fn merkle_root(mut leaves: Vec<[u8; 32]>) -> [u8; 32] {
+    use sha3::{Digest, Keccak256};
+    
     // Build merkle tree
     while leaves.len() > 1 {
         let mut next_level = Vec::with_capacity((leaves.len() + 1) / 2);
@@ -380,6 +592,24 @@ mod tests {
         assert!(!invalid.validate_sizes());
     }
     
+    #[test]
+    fn test_request_commitment_binds_signature_data() {
+        let request = SignatureRequest::new(
+            [1u8; 32],
+            vec![2u8; MLDSA_PUBLIC_KEY_SIZE],
+            vec![3u8; MLDSA_SIGNATURE_SIZE],
+            7,
+        );
+        let mut forged = request.clone();
+        forged.signature[0] ^= 1;
+        let mut other_id = request.clone();
+        other_id.request_id = 8;
+        
+        assert_eq!(request.commitment(), request.clone().commitment());
+        assert_ne!(request.commitment(), forged.commitment());
+        assert_ne!(request.commitment(), other_id.commitment());
+    }
+    
     #[test]
     fn test_merkle_root_single() {
         let root = compute_requests_root(&[1]);
@@ -417,6 +647,68 @@ mod tests {
         assert_eq!(input.chain_id, TESSERAX_CHAIN_ID);
         assert_eq!(input.batch_id, 42);
         assert_eq!(input.batch_size(), 2);
+        assert_eq!(input.duplicate_request_id(), None);
+    }
+    
+    #[test]
+    fn test_proof_input_duplicate_request_id() {
+        let request = |id| SignatureRequest::new([0u8; 32], vec![0u8; MLDSA_PUBLIC_KEY_SIZE],
+                                                 vec![0u8; MLDSA_SIGNATURE_SIZE], id);
+        let input = RemlProofInput::new(vec![request(1), request(2), request(1)], 42);
+        
+        assert_eq!(input.duplicate_request_id(), Some(1));
+    }
+    
+    #[test]
+    fn test_rejection_reason_codes() {
+        assert_eq!(RequestRejection::InvalidSize.code(), 1);
+        assert_eq!(RequestRejection::InvalidSignature.code(), 2);
+        
+        let output = RemlProofOutput::new(
+            1,
+            1,
+            compute_requests_root(&[1]),
+            vec![1],
+            vec![(2, RequestRejection::InvalidSignature)],
+        );
+        assert_eq!(output.rejected_request_ids, vec![(2, RequestRejection::InvalidSignature)]);
+    }
+    
+    #[test]
+    fn test_batches_root() {
+        let first = (1, compute_requests_root(&[1, 2]));
+        let second = (2, compute_requests_root(&[3]));
+        
+        let root = compute_batches_root(&[first, second]);
+        assert_ne!(root, [0u8; 32]);
+        assert_ne!(root, compute_batches_root(&[second, first])); // Order matters
+        assert_ne!(root, compute_batches_root(&[(3, first.1), second])); // Binds batch IDs
+        assert_eq!(compute_batches_root(&[]), [0u8; 32]);
+    }
+    
+    #[test]
+    fn test_aggregate_output() {
+        let batches = vec![
+            RemlProofOutput::new(1, 2, compute_requests_root(&[1, 2]), vec![1, 2], Vec::new()),
+            RemlProofOutput::new(2, 1, compute_requests_root(&[3]), vec![3], Vec::new()),
+        ];
+        let output = RemlAggregateOutput::new([9u8; 32], batches);
+        
+        assert_eq!(output.verified_count(), 3);
+        assert_eq!(
+            output.batches_root,
+            compute_batches_root(&[
+                (1, compute_requests_root(&[1, 2])),
+                (2, compute_requests_root(&[3])),
+            ])
+        );
+    }
+    
+    #[test]
+    fn test_vkey_hash_from_digest() {
+        let hash = vkey_hash_from_digest(&[0x01020304, 0, 0, 0, 0, 0, 0, 0x05060708]);
+        assert_eq!(&hash[..4], &[1, 2, 3, 4]);
+        assert_eq!(&hash[28..], &[5, 6, 7, 8]);
     }
     
     #[test]
@@ -426,6 +718,7 @@ mod tests {
             100, // 100 signatures
             [0u8; 32],
             (0..100).collect(),
+            Vec::new(),
         );
         
         // Simulated 50KB proof
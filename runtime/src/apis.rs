@@ -27,7 +27,7 @@
 use alloc::vec::Vec;
 use frame_support::{
     genesis_builder_helper::{build_state, get_preset},
-    traits::Hooks,
+    traits::{Get, Hooks},
     weights::Weight,
 };
 use pallet_grandpa::AuthorityId as GrandpaId;
@@ -49,9 +49,9 @@ use pallet_evm::{Account as EVMAccount, Runner};
 
 // Local module imports
 use super::{
-    AccountId, Aura, Balance, Block, BlockNumber, Executive, Grandpa, InherentDataExt, Nonce,
-    Runtime, RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment,
-    UncheckedExtrinsic, VERSION,
+    AccountId, Aura, Balance, Block, BlockNumber, Emission, Executive, Grandpa, Historical,
+    InherentDataExt, Nonce, QuantumVault, RemlVerifier, Runtime, RuntimeCall, RuntimeGenesisConfig,
+    SessionKeys, System, TransactionPayment, UncheckedExtrinsic, VERSION,
 };
 
 impl_runtime_apis! {
@@ -158,23 +158,30 @@ impl_runtime_apis! {
         }
 
         fn submit_report_equivocation_unsigned_extrinsic(
-            _equivocation_proof: sp_consensus_grandpa::EquivocationProof<
+            equivocation_proof: sp_consensus_grandpa::EquivocationProof<
                 <Block as BlockT>::Hash,
                 NumberFor<Block>,
             >,
-            _key_owner_proof: sp_consensus_grandpa::OpaqueKeyOwnershipProof,
+            key_owner_proof: sp_consensus_grandpa::OpaqueKeyOwnershipProof,
         ) -> Option<()> {
-            None
+            let key_owner_proof = key_owner_proof.decode()?;
+
+            Grandpa::submit_unsigned_equivocation_report(
+                equivocation_proof,
+                key_owner_proof,
+            )
         }
 
         fn generate_key_ownership_proof(
             _set_id: sp_consensus_grandpa::SetId,
-            _authority_id: GrandpaId,
+            authority_id: GrandpaId,
         ) -> Option<sp_consensus_grandpa::OpaqueKeyOwnershipProof> {
-            // NOTE: this is the only implementation possible since we've
-            // defined our key owner proof type as a bottom type (i.e. a type
-            // with no values).
-            None
+            use codec::Encode;
+            use frame_support::traits::KeyOwnerProofSystem;
+
+            Historical::prove((sp_consensus_grandpa::KEY_TYPE, authority_id))
+                .map(|proof| proof.encode())
+                .map(sp_consensus_grandpa::OpaqueKeyOwnershipProof::new)
         }
     }
 
@@ -228,6 +235,125 @@ impl_runtime_apis! {
         }
     }
 
+    // ═══════════════════════════════════════════════════════════════════════════
+    // TESSERAX RUNTIME APIs
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Served by the node under the emission_*, vault_* and reml_* RPC namespaces
+    // (node/src/rpc).
+
+    impl pallet_emission::runtime_api::EmissionApi<Block, BlockNumber> for Runtime {
+        fn current_era() -> u32 {
+            Emission::current_era(System::block_number())
+        }
+
+        fn reward_at(block: BlockNumber) -> u128 {
+            Emission::reward_at(block)
+        }
+
+        fn total_emitted(block: BlockNumber) -> u128 {
+            Emission::total_emitted(block)
+        }
+
+        fn schedule(from_era: u32, to_era: u32) -> Vec<pallet_emission::runtime_api::EraSchedule> {
+            Emission::schedule(from_era, to_era)
+        }
+
+        fn time_to_end() -> pallet_emission::runtime_api::TimeToEnd<BlockNumber> {
+            let blocks_remaining = Emission::blocks_until_end(System::block_number());
+            pallet_emission::runtime_api::TimeToEnd {
+                end_block: Emission::emission_end_block(),
+                blocks_remaining,
+                ms_remaining: (blocks_remaining as u64).saturating_mul(super::MILLI_SECS_PER_BLOCK),
+            }
+        }
+
+        fn max_supply() -> u128 {
+            Emission::max_supply()
+        }
+
+        fn total_minted() -> u128 {
+            Emission::total_minted()
+        }
+
+        fn era_minted(era: u32) -> u128 {
+            Emission::era_minted(era)
+        }
+
+        fn emission_gap() -> pallet_emission::runtime_api::EmissionGap {
+            Emission::emission_gap(System::block_number())
+        }
+    }
+
+    impl pallet_quantum_vault::runtime_api::QuantumVaultApi<Block, AccountId, Balance> for Runtime {
+        fn vault_status(who: AccountId) -> Option<pallet_quantum_vault::runtime_api::VaultStatus> {
+            QuantumVault::vault_status(&who)
+        }
+
+        fn vault_nonce(who: AccountId) -> Option<u64> {
+            QuantumVault::vault_status(&who).map(|status| status.nonce)
+        }
+
+        fn vault_key_hash(who: AccountId) -> Option<[u8; 32]> {
+            QuantumVault::public_key_hash(&who)
+        }
+
+        fn total_vaults() -> u32 {
+            pallet_quantum_vault::TotalVaults::<Runtime>::get()
+        }
+
+        fn total_fees_collected() -> Balance {
+            pallet_quantum_vault::TotalFeesCollected::<Runtime>::get()
+        }
+
+        fn fee_quote() -> pallet_quantum_vault::runtime_api::VaultFeeQuote<Balance> {
+            pallet_quantum_vault::runtime_api::VaultFeeQuote {
+                creation_fee: <Runtime as pallet_quantum_vault::Config>::VaultCreationFee::get(),
+                transfer_premium: QuantumVault::transfer_premium(),
+            }
+        }
+
+        fn transfer_payload(from: AccountId, to: AccountId, amount: Balance) -> Option<Vec<u8>> {
+            let nonce = QuantumVault::vault_status(&from)?.nonce;
+            Some(QuantumVault::construct_transfer_message(&from, &to, amount, nonce))
+        }
+
+        fn destroy_payload(who: AccountId) -> Option<Vec<u8>> {
+            let nonce = QuantumVault::vault_status(&who)?.nonce;
+            Some(QuantumVault::construct_destroy_message(&who, nonce))
+        }
+    }
+
+    impl pallet_reml_verifier::runtime_api::RemlVerifierApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+        fn request_status(
+            request_id: u64,
+        ) -> Option<pallet_reml_verifier::runtime_api::RequestStatus<BlockNumber>> {
+            RemlVerifier::get_verification_info(request_id).map(|(batch_id, verified_at)| {
+                pallet_reml_verifier::runtime_api::RequestStatus { batch_id, verified_at }
+            })
+        }
+
+        fn batch(batch_id: u64) -> Option<pallet_reml_verifier::BatchInfo<AccountId, BlockNumber>> {
+            pallet_reml_verifier::VerifiedBatches::<Runtime>::get(batch_id)
+        }
+
+        fn aggregators() -> Vec<(AccountId, pallet_reml_verifier::AggregatorInfo<Balance, BlockNumber>)> {
+            pallet_reml_verifier::Aggregators::<Runtime>::iter().collect()
+        }
+
+        fn totals() -> pallet_reml_verifier::runtime_api::RemlTotals {
+            pallet_reml_verifier::runtime_api::RemlTotals {
+                proofs_verified: pallet_reml_verifier::TotalProofsVerified::<Runtime>::get(),
+                signatures_verified: pallet_reml_verifier::TotalSignaturesVerified::<Runtime>::get(),
+            }
+        }
+
+        fn request_proof(
+            request_id: u64,
+        ) -> Option<pallet_reml_verifier::runtime_api::RequestInclusionProof> {
+            RemlVerifier::request_inclusion_proof(request_id)
+        }
+    }
+
     // ═══════════════════════════════════════════════════════════════════════════
     // ETHEREUM RUNTIME APIs (Frontier Integration)
     // ═══════════════════════════════════════════════════════════════════════════
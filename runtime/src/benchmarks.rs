@@ -41,9 +41,14 @@ frame_benchmarking::define_benchmarks!(
     [pallet_balances, Balances]
     [pallet_timestamp, Timestamp]
     [pallet_sudo, Sudo]
+    [pallet_collective, Council]
+    [pallet_preimage, Preimage]
+    [pallet_whitelist, Whitelist]
     [pallet_template, Template]
 
     // ── Tesserax Custom Pallets ──
     [pallet_quantum_vault, QuantumVault]
+    [pallet_reml_verifier, RemlVerifier]
     [pallet_emission, Emission]
+    [pallet_validator_set, ValidatorSet]
 );
@@ -24,25 +24,38 @@
 // For more information, please refer to <http://unlicense.org>
 
 // Substrate and Polkadot dependencies
+use alloc::vec::Vec;
 use frame_support::{
     derive_impl, parameter_types,
-    traits::{ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, VariantCountOf},
+    traits::{
+        fungible::HoldConsideration, ConstBool, ConstU128, ConstU32, ConstU64, ConstU8,
+        EitherOfDiverse, Get, LinearStoragePrice, SortedMembers, VariantCountOf,
+    },
     weights::{
         constants::{RocksDbWeight, WEIGHT_REF_TIME_PER_SECOND},
         IdentityFee, Weight,
     },
 };
-use frame_system::limits::{BlockLength, BlockWeights};
+use frame_system::{
+    limits::{BlockLength, BlockWeights},
+    EnsureRoot,
+};
 use pallet_transaction_payment::{ConstFeeMultiplier, FungibleAdapter, Multiplier};
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
-use sp_runtime::{traits::One, Perbill};
+use sp_runtime::{
+    traits::{ConvertInto, One, OpaqueKeys},
+    Perbill,
+};
+use sp_staking::{offence::OffenceSeverity, SessionIndex};
 use sp_version::RuntimeVersion;
 
 // Local module imports
 use super::{
-    AccountId, Aura, Balance, Balances, Block, BlockNumber, Hash, Nonce, PalletInfo, Runtime,
-    RuntimeCall, RuntimeEvent, RuntimeFreezeReason, RuntimeHoldReason, RuntimeOrigin, RuntimeTask,
-    System, Timestamp, EXISTENTIAL_DEPOSIT, SLOT_DURATION, VERSION,
+    AccountId, Aura, Balance, Balances, Block, BlockNumber, Emission, Hash, Historical, Nonce,
+    Offences, PalletInfo, Preimage, QuantumVault, Runtime, RuntimeCall, RuntimeEvent,
+    RuntimeFreezeReason, RuntimeHoldReason, RuntimeOrigin, RuntimeTask, Session, SessionKeys,
+    System, Timestamp, UncheckedExtrinsic, ValidatorSet, DAYS, EXISTENTIAL_DEPOSIT, HOURS,
+    MICRO_TSRX, SLOT_DURATION, TSRX, VERSION,
 };
 
 const NORMAL_DISPATCH_RATIO: Perbill = Perbill::from_percent(75);
@@ -92,7 +105,8 @@ impl frame_system::Config for Runtime {
 
 impl pallet_aura::Config for Runtime {
     type AuthorityId = AuraId;
-    type DisabledValidators = ();
+    /// Validators disabled in the current session cannot author
+    type DisabledValidators = Session;
     type MaxAuthorities = ConstU32<32>;
     type AllowMultipleBlocksPerSlot = ConstBool<false>;
     type SlotDuration = pallet_aura::MinimumPeriodTimesTwo<Runtime>;
@@ -104,10 +118,11 @@ impl pallet_grandpa::Config for Runtime {
     type WeightInfo = ();
     type MaxAuthorities = ConstU32<32>;
     type MaxNominators = ConstU32<0>;
-    type MaxSetIdSessionEntries = ConstU64<0>;
+    type MaxSetIdSessionEntries = ConstU64<{ HISTORICAL_SESSIONS as u64 }>;
 
-    type KeyOwnerProof = sp_core::Void;
-    type EquivocationReportSystem = ();
+    type KeyOwnerProof = sp_session::MembershipProof;
+    type EquivocationReportSystem =
+        pallet_grandpa::EquivocationReportSystem<Self, Offences, Historical, ReportLongevity>;
 }
 
 impl pallet_timestamp::Config for Runtime {
@@ -143,7 +158,7 @@ parameter_types! {
 
 impl pallet_transaction_payment::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
-    type OnChargeTransaction = FungibleAdapter<Balances, ()>;
+    type OnChargeTransaction = FungibleAdapter<Balances, pallet_emission::PoolFees<Runtime>>;
     type OperationalFeeMultiplier = ConstU8<5>;
     type WeightToFee = IdentityFee<Balance>;
     type LengthToFee = IdentityFee<Balance>;
@@ -157,52 +172,307 @@ impl pallet_sudo::Config for Runtime {
     type WeightInfo = pallet_sudo::weights::SubstrateWeight<Runtime>;
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// GOVERNANCE
+// ═══════════════════════════════════════════════════════════════════════════
+//
+// A council (technical committee) administers the Tesserax pallets through
+// `AdminOrigin`: two thirds of the council, or root while sudo exists.
+//
+// Root-only calls (runtime upgrades, `force_*`) go through pallet-whitelist:
+// the council whitelists a call hash and then dispatches the call as root.
+// Once that path is in use, sudo can be removed (see docs/governance.md).
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// The council's pallet-collective instance
+pub type CouncilCollective = pallet_collective::Instance1;
+
+/// At least two thirds of the council
+pub type CouncilTwoThirds =
+    pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 2, 3>;
+
+/// Origin administering the Tesserax pallets: root or two thirds of the council
+pub type AdminOrigin = EitherOfDiverse<EnsureRoot<AccountId>, CouncilTwoThirds>;
+
+parameter_types! {
+    pub const CouncilMotionDuration: BlockNumber = 3 * DAYS;
+    pub const CouncilMaxProposals: u32 = 100;
+    pub const CouncilMaxMembers: u32 = 32;
+    pub MaxCouncilProposalWeight: Weight =
+        Perbill::from_percent(50) * RuntimeBlockWeights::get().max_block;
+}
+
+impl pallet_collective::Config<CouncilCollective> for Runtime {
+    type RuntimeOrigin = RuntimeOrigin;
+    type Proposal = RuntimeCall;
+    type RuntimeEvent = RuntimeEvent;
+    type MotionDuration = CouncilMotionDuration;
+    type MaxProposals = CouncilMaxProposals;
+    type MaxMembers = CouncilMaxMembers;
+    type DefaultVote = pallet_collective::PrimeDefaultVote;
+    type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
+    type SetMembersOrigin = AdminOrigin;
+    type MaxProposalWeight = MaxCouncilProposalWeight;
+    type DisapproveOrigin = AdminOrigin;
+    type KillOrigin = AdminOrigin;
+    type Consideration = ();
+}
+
+parameter_types! {
+    pub const PreimageBaseDeposit: Balance = TSRX;
+    pub const PreimageByteDeposit: Balance = MICRO_TSRX;
+    pub const PreimageHoldReason: RuntimeHoldReason =
+        RuntimeHoldReason::Preimage(pallet_preimage::HoldReason::Preimage);
+}
+
+impl pallet_preimage::Config for Runtime {
+    type WeightInfo = pallet_preimage::weights::SubstrateWeight<Runtime>;
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type ManagerOrigin = AdminOrigin;
+    type Consideration = HoldConsideration<
+        AccountId,
+        Balances,
+        PreimageHoldReason,
+        LinearStoragePrice<PreimageBaseDeposit, PreimageByteDeposit, Balance>,
+    >;
+}
+
+impl pallet_whitelist::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeCall = RuntimeCall;
+    type WhitelistOrigin = AdminOrigin;
+    type DispatchWhitelistedOrigin = AdminOrigin;
+    type Preimages = Preimage;
+    type WeightInfo = pallet_whitelist::weights::SubstrateWeight<Runtime>;
+}
+
 /// Configure the pallet-template in pallets/template.
 impl pallet_template::Config for Runtime {
     type WeightInfo = pallet_template::weights::SubstrateWeight<Runtime>;
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
-// AUTHORSHIP PALLET - Required for finding block author
+// VALIDATOR SET & SESSION
+// ═══════════════════════════════════════════════════════════════════════════
+//
+// Governance adds and removes validators in pallet-validator-set, which hands
+// the set to pallet-session. Sessions rotate hourly and push each validator's
+// registered session keys to Aura and GRANDPA, so a change takes effect on
+// authoring within two hours.
+//
+// GRANDPA equivocations are proven against historical session roots (kept
+// for a week) and reported to pallet-offences. The offender is removed from
+// the set, disabled for the rest of the session (at most a third of the set)
+// and forfeits its pending emission rewards.
 // ═══════════════════════════════════════════════════════════════════════════
 
-/// Converts Aura AuthorityId to AccountId for authorship tracking
-pub struct AuraAccountAdapter;
-impl frame_support::traits::FindAuthor<AccountId> for AuraAccountAdapter {
-    fn find_author<'a, I>(digests: I) -> Option<AccountId>
-    where
-        I: 'a + IntoIterator<Item = (frame_support::ConsensusEngineId, &'a [u8])>,
-    {
-        pallet_aura::AuraAuthorId::<Runtime>::find_author(digests).map(|aura_id| {
-            // AuraId is sr25519::Public which wraps CryptoBytes<32>
-            // We extract the 32-byte public key and convert to AccountId
-            use sp_core::crypto::ByteArray;
-            let raw: &[u8] = aura_id.as_slice();
-            let mut bytes = [0u8; 32];
-            bytes.copy_from_slice(&raw[..32]);
-            AccountId::from(bytes)
-        })
+/// Sessions whose roots are kept for key ownership proofs (one week)
+pub const HISTORICAL_SESSIONS: SessionIndex = 7 * 24;
+
+parameter_types! {
+    pub const SessionPeriod: BlockNumber = HOURS;
+    pub const SessionOffset: BlockNumber = 0;
+    /// Blocks an equivocation report stays valid in the transaction pool
+    pub const ReportLongevity: u64 = HISTORICAL_SESSIONS as u64 * HOURS as u64;
+}
+
+/// Disables a reported offender for the rest of the session and forfeits its
+/// pending emission rewards
+pub struct PunishValidator;
+impl pallet_validator_set::PunishOffender<AccountId> for PunishValidator {
+    fn punish(offender: &AccountId, slash_fraction: Perbill) -> Weight {
+        Session::report_offence(offender.clone(), OffenceSeverity(slash_fraction));
+        <Runtime as frame_system::Config>::DbWeight::get()
+            .reads_writes(2, 1)
+            .saturating_add(Emission::forfeit_rewards(offender))
     }
 }
 
+impl pallet_validator_set::Config for Runtime {
+    type AddRemoveOrigin = AdminOrigin;
+    type MinValidators = ConstU32<1>;
+    /// Aura and GRANDPA MaxAuthorities
+    type MaxValidators = ConstU32<32>;
+    type PunishOffender = PunishValidator;
+    type WeightInfo = pallet_validator_set::weights::SubstrateWeight<Runtime>;
+}
+
+/// Validator set that also prunes session roots older than `HISTORICAL_SESSIONS`
+pub struct HistoricalValidatorSet;
+impl pallet_session::historical::SessionManager<AccountId, AccountId> for HistoricalValidatorSet {
+    fn new_session(new_index: SessionIndex) -> Option<Vec<(AccountId, AccountId)>> {
+        <ValidatorSet as pallet_session::historical::SessionManager<_, _>>::new_session(new_index)
+    }
+
+    fn new_session_genesis(new_index: SessionIndex) -> Option<Vec<(AccountId, AccountId)>> {
+        <ValidatorSet as pallet_session::historical::SessionManager<_, _>>::new_session_genesis(
+            new_index,
+        )
+    }
+
+    fn end_session(end_index: SessionIndex) {
+        <ValidatorSet as pallet_session::historical::SessionManager<_, _>>::end_session(end_index)
+    }
+
+    fn start_session(start_index: SessionIndex) {
+        <ValidatorSet as pallet_session::historical::SessionManager<_, _>>::start_session(
+            start_index,
+        );
+        if let Some(up_to) = start_index.checked_sub(HISTORICAL_SESSIONS) {
+            Historical::prune_up_to(up_to);
+        }
+    }
+}
+
+impl pallet_session::historical::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type FullIdentification = AccountId;
+    type FullIdentificationOf = ConvertInto;
+}
+
+impl pallet_offences::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type IdentificationTuple = pallet_session::historical::IdentificationTuple<Self>;
+    type OnOffenceHandler = ValidatorSet;
+}
+
+/// Equivocation reports are submitted as unsigned (bare) extrinsics
+impl<LocalCall> frame_system::offchain::CreateTransactionBase<LocalCall> for Runtime
+where
+    RuntimeCall: From<LocalCall>,
+{
+    type Extrinsic = UncheckedExtrinsic;
+    type RuntimeCall = RuntimeCall;
+}
+
+impl<LocalCall> frame_system::offchain::CreateBare<LocalCall> for Runtime
+where
+    RuntimeCall: From<LocalCall>,
+{
+    fn create_bare(call: RuntimeCall) -> UncheckedExtrinsic {
+        UncheckedExtrinsic::new_bare(call)
+    }
+}
+
+impl pallet_session::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type ValidatorId = AccountId;
+    type ValidatorIdOf = ConvertInto;
+    type ShouldEndSession = pallet_session::PeriodicSessions<SessionPeriod, SessionOffset>;
+    type NextSessionRotation = pallet_session::PeriodicSessions<SessionPeriod, SessionOffset>;
+    type SessionManager =
+        pallet_session::historical::NoteHistoricalRoot<Self, HistoricalValidatorSet>;
+    type SessionHandler = <SessionKeys as OpaqueKeys>::KeyTypeIdProviders;
+    type Keys = SessionKeys;
+    type DisablingStrategy = pallet_session::disabling::UpToLimitDisablingStrategy;
+    type WeightInfo = pallet_session::weights::SubstrateWeight<Runtime>;
+    type Currency = Balances;
+    type KeyDeposit = ();
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// AUTHORSHIP PALLET - Required for finding block author
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Maps the Aura author index to the session validator's account
+pub type FindSessionAuthor = pallet_session::FindAccountFromAuthorIndex<Runtime, Aura>;
+
 impl pallet_authorship::Config for Runtime {
-    type FindAuthor = AuraAccountAdapter;
+    type FindAuthor = FindSessionAuthor;
     type EventHandler = ();
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
-// EMISSION PALLET CONFIGURATION (v2.0 - Stateless)
+// EMISSION PALLET CONFIGURATION
 // ═══════════════════════════════════════════════════════════════════════════
 //
 // Simple pre-computed sigmoid emission curve.
-// No storage, no complex ASM - just lookup and mint.
+// No complex ASM - just lookup and mint, with actual mints tracked in storage.
 //
-// Emission is distributed to block author (validator) on each block.
+// Emission is split between the block author (validator), the treasury and
+// the staker pool on each block (governance-updatable shares).
+// `REWARD_SCHEDULE` plus the terminal bonus adds up to MAX_SUPPLY on its own,
+// so the dev/testnet pre-mint (`GENESIS_SUPPLY`) is not counted against the
+// cap: counting it would stop minting ~10% early and leave the last eras
+// unpaid. Minting stops once `TotalMinted` reaches MAX_SUPPLY.
+//
+// Eras last one day. Testnets built with `fast-emission` use one-block eras,
+// which runs the whole 20-year schedule in about 12 hours.
+//
+// Transaction and EVM fees are burned during the schedule. Afterwards they are
+// pooled per era and paid to that era's block authors (up to the Aura
+// authority limit), pro rata by blocks authored.
 // ═══════════════════════════════════════════════════════════════════════════
 
+/// Blocks per emission era
+#[cfg(not(feature = "fast-emission"))]
+pub const EMISSION_BLOCKS_PER_ERA: BlockNumber = DAYS;
+/// Blocks per emission era (compressed testnet schedule)
+#[cfg(feature = "fast-emission")]
+pub const EMISSION_BLOCKS_PER_ERA: BlockNumber = 1;
+
+parameter_types! {
+    /// Validator/staker pool receiving its share of block rewards
+    /// Uses a deterministic address: "tesserax/staker_pool"
+    pub EmissionPoolAccountId: AccountId = {
+        // Format: "tesserax/staker_pool" padded to 32 bytes
+        let mut bytes = [0u8; 32];
+        let prefix = b"tesserax/staker_pool";
+        bytes[..prefix.len()].copy_from_slice(prefix);
+        AccountId::from(bytes)
+    };
+
+    /// Initial reward split: the whole block reward goes to the author
+    /// Governance can redirect up to MaxNonAuthorShare via set_reward_shares
+    pub DefaultRewardSplit: pallet_emission::RewardSplit = pallet_emission::RewardSplit {
+        treasury: Perbill::from_percent(0),
+        pool: Perbill::from_percent(0),
+    };
+    pub const MaxNonAuthorShare: Perbill = Perbill::from_percent(50);
+    /// Governance may scale remaining eras by at most ±20%
+    pub const MaxRewardScaleDeviation: Perbill = Perbill::from_percent(20);
+}
+
+/// Validators allowed to register an emission reward payee
+pub struct EmissionAuthorities;
+impl SortedMembers<AccountId> for EmissionAuthorities {
+    fn sorted_members() -> Vec<AccountId> {
+        let mut validators = ValidatorSet::validators().into_inner();
+        validators.sort();
+        validators
+    }
+
+    fn contains(who: &AccountId) -> bool {
+        ValidatorSet::validators().contains(who)
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn add(who: &AccountId) {
+        pallet_validator_set::Validators::<Runtime>::mutate(|validators| {
+            let _ = validators.try_push(who.clone());
+        });
+    }
+}
+
 impl pallet_emission::Config for Runtime {
     type Currency = Balances;
-    type FindAuthor = AuraAccountAdapter;
+    type FindAuthor = FindSessionAuthor;
+    type Authorities = EmissionAuthorities;
+    type BlocksPerEra = ConstU32<EMISSION_BLOCKS_PER_ERA>;
+    type MaxSupply = ConstU128<{ crate::tesserax_constants::MAX_SUPPLY }>;
+    type Schedule = pallet_emission::SigmoidSchedule<ConstU32<EMISSION_BLOCKS_PER_ERA>>;
+    type OnEraChange = ();
+    type PoolFeesAfterEmission = ConstBool<true>;
+    type MaxFeeRecipients = ConstU32<32>;
+    /// The schedule is sized for MAX_SUPPLY; the dev pre-mint sits outside it
+    type GenesisSupply = ConstU128<0>;
+    type TreasuryAccount = TreasuryAccountId;
+    type PoolAccount = EmissionPoolAccountId;
+    type RewardDistribution = DefaultRewardSplit;
+    type MaxNonAuthorShare = MaxNonAuthorShare;
+    type MaxRewardScaleDeviation = MaxRewardScaleDeviation;
+    type UpdateOrigin = AdminOrigin;
     type WeightInfo = ();
 }
 
@@ -319,6 +589,8 @@ where
 /// - 0x20: Verify STARK commitment (lightweight check)
 /// - 0x21: Check if request ID is verified via Re-ML
 /// - 0x22: Get batch information
+/// - 0x23: Consume a verified request ID (single use)
+/// - 0x24: Allocate a request ID
 pub struct TesseraxPrecompiles<R>(PhantomData<R>);
 
 impl<R> TesseraxPrecompiles<R>
@@ -340,16 +612,18 @@ where
         ]
     }
 
-    /// ZK-Coprocessor precompile addresses (0x20-0x22)
-    pub fn zk_addresses() -> [H160; 3] {
+    /// ZK-Coprocessor precompile addresses (0x20-0x24)
+    pub fn zk_addresses() -> [H160; 5] {
         [
             hash(0x20), // VerifyStarkCommitment
             hash(0x21), // IsRequestVerified
             hash(0x22), // GetBatchInfo
+            hash(0x23), // ConsumeVerifiedRequest
+            hash(0x24), // AllocateRequestId
         ]
     }
 
-    pub fn used_addresses() -> [H160; 8] {
+    pub fn used_addresses() -> [H160; 10] {
         [
             // Standard Ethereum precompiles
             hash(1), // ECRecover
@@ -361,6 +635,8 @@ where
             hash(0x20), // VerifyStarkCommitment
             hash(0x21), // IsRequestVerified
             hash(0x22), // GetBatchInfo
+            hash(0x23), // ConsumeVerifiedRequest
+            hash(0x24), // AllocateRequestId
         ]
     }
 }
@@ -384,7 +660,10 @@ where
     R: pallet_evm::Config + pallet_reml_verifier::Config,
 {
     fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
-        use crate::precompiles::{GetBatchInfo, IsRequestVerified, VerifyStarkCommitment};
+        use crate::precompiles::{
+            AllocateRequestId, ConsumeVerifiedRequest, GetBatchInfo, IsRequestVerified,
+            VerifyStarkCommitment,
+        };
 
         match handle.code_address() {
             // ═══════════════════════════════════════════════════════════════
@@ -403,7 +682,7 @@ where
             a if a == hash(5) => Some(pallet_evm_precompile_modexp::Modexp::execute(handle)),
 
             // ═══════════════════════════════════════════════════════════════
-            // ZK-COPROCESSOR PRECOMPILES (0x20 - 0x22)
+            // ZK-COPROCESSOR PRECOMPILES (0x20 - 0x24)
             // Re-ML STARK Verification for Quantum-Safe Smart Contracts
             // ═══════════════════════════════════════════════════════════════
 
@@ -413,6 +692,10 @@ where
             a if a == hash(0x21) => Some(IsRequestVerified::<R>::execute(handle)),
             // Get verified batch information
             a if a == hash(0x22) => Some(GetBatchInfo::<R>::execute(handle)),
+            // Use up a verified request ID on behalf of the calling contract
+            a if a == hash(0x23) => Some(ConsumeVerifiedRequest::<R>::execute(handle)),
+            // Allocate a request ID to the calling contract
+            a if a == hash(0x24) => Some(AllocateRequestId::<R>::execute(handle)),
 
             _ => None,
         }
@@ -455,9 +738,10 @@ impl pallet_evm::Config for Runtime {
     type ChainId = ChainId;
     type BlockGasLimit = BlockGasLimit;
     type Runner = pallet_evm::runner::stack::Runner<Self>;
-    type OnChargeTransaction = ();
+    type OnChargeTransaction =
+        pallet_evm::EVMFungibleAdapter<Balances, pallet_emission::PoolFees<Runtime>>;
     type OnCreate = ();
-    type FindAuthor = FindAuthorTruncated<AuraAccountAdapter>;
+    type FindAuthor = FindAuthorTruncated<FindSessionAuthor>;
     type GasLimitPovSizeRatio = GasLimitPovSizeRatio;
     type GasLimitStorageGrowthRatio = GasLimitStorageGrowthRatio;
     type Timestamp = Timestamp;
@@ -518,8 +802,6 @@ impl pallet_dynamic_fee::Config for Runtime {
 // - All fees preserved in treasury (not burned)
 // ═══════════════════════════════════════════════════════════════════════════
 
-use super::TSRX;
-
 parameter_types! {
     /// Fee to create a quantum vault: 2 TSRX (reduced from 10 TSRX)
     /// Rationale: With ~13.82M supply, lower fee improves retail adoption
@@ -581,29 +863,81 @@ impl pallet_quantum_vault::Config for Runtime {
 // ═══════════════════════════════════════════════════════════════════════════
 
 parameter_types! {
-    /// Maximum number of authorized aggregators
+    /// Maximum number of registered aggregators, bonded or admin-registered
     /// Smaller number = tighter control, larger = more decentralization
     pub const MaxAggregators: u32 = 16;
 
-    /// Expected verification key hash for the Re-ML guest program
-    /// This ensures only proofs from the correct SP1 program are accepted
-    ///
-    /// Development Mode: [0u8; 32] accepts any vkey for testing
-    /// Production Mode: Set to actual vkey hash from `reml-prover vkey-hash`
-    ///
-    /// To get the production vkey hash:
-    /// 1. Build guest: `cd reml && cargo prove build`
-    /// 2. Get hash: `cargo run --bin reml-prover -- vkey-hash`
-    /// 3. Replace the value below with the output
-    pub ExpectedVKeyHash: [u8; 32] = {
-        // Development/Testnet: Accept any vkey for testing
-        // For production, replace with actual vkey hash bytes
-        [0u8; 32]
-    };
+    /// Maximum number of allowed Re-ML guest verification keys
+    /// Two are needed to overlap old and new provers during a guest upgrade
+    pub const MaxVKeys: u32 = 8;
+
+    /// Bond reserved by permissionless aggregators: 1,000 TSRX
+    pub const AggregatorBond: Balance = 1_000 * TSRX;
+
+    /// Reward per accepted batch proof: 1 TSRX
+    /// Paid from the vault treasury, i.e. ten vault transfer premiums
+    pub const ProofReward: Balance = TSRX;
+
+    /// Active aggregators must land a proof or heartbeat at least once a day
+    pub const AggregatorLivenessPeriod: BlockNumber = DAYS;
+    pub const AggregatorLivenessSlash: Perbill = Perbill::from_percent(5);
+
+    /// A batch shown invalid costs the whole bond
+    pub const InvalidBatchSlash: Perbill = Perbill::from_percent(100);
+
+    /// Bonds stay slashable for a week after unbonding
+    pub const AggregatorUnbondingPeriod: BlockNumber = 7 * DAYS;
+
+    /// Verified batches are pruned after 30 days, with their requests whether
+    /// consumed (e.g. by vault transfers) or not
+    pub const RemlRetentionPeriod: BlockNumber = 30 * DAYS;
+
+    /// Optimistic batches stay off until a Dilithium host function lets the
+    /// Wasm runtime check fraud proofs; the vault's Wasm verifier is only
+    /// structural. Benchmarks still measure the optimistic calls.
+    pub const RemlOptimisticBatchesEnabled: bool = cfg!(feature = "runtime-benchmarks");
+
+    /// Optimistic batches can be challenged for a day
+    pub const RemlChallengePeriod: BlockNumber = DAYS;
+
+    /// Bond per optimistic batch, paid to a successful challenger: 100 TSRX
+    pub const OptimisticBatchBond: Balance = 100 * TSRX;
+
+    /// Deposit per demand for optimistic batch data, paid to whoever reveals
+    /// the data: 1 TSRX
+    pub const RemlDataChallengeDeposit: Balance = TSRX;
+
+    /// Aggregators have six hours to reveal demanded signature data
+    pub const RemlDataResponsePeriod: BlockNumber = 6 * HOURS;
+
+    /// Deposit per allocated request ID, returned on settlement or expiry:
+    /// 0.01 TSRX
+    pub const RequestAllocationDeposit: Balance = TSRX / 100;
+
+    /// Allocated request IDs left unsettled for a week are dropped
+    pub const RequestAllocationExpiry: BlockNumber = 7 * DAYS;
 }
 
 impl pallet_reml_verifier::Config for Runtime {
     type WeightInfo = pallet_reml_verifier::weights::SubstrateWeight<Self>;
     type MaxAggregators = MaxAggregators;
-    type ExpectedVKeyHash = ExpectedVKeyHash;
+    type MaxVKeys = MaxVKeys;
+    type AdminOrigin = AdminOrigin;
+    type Currency = Balances;
+    type AggregatorBond = AggregatorBond;
+    type ProofReward = ProofReward;
+    type RewardAccount = TreasuryAccountId;
+    type LivenessPeriod = AggregatorLivenessPeriod;
+    type LivenessSlash = AggregatorLivenessSlash;
+    type InvalidBatchSlash = InvalidBatchSlash;
+    type UnbondingPeriod = AggregatorUnbondingPeriod;
+    type RetentionPeriod = RemlRetentionPeriod;
+    type AllocationDeposit = RequestAllocationDeposit;
+    type AllocationExpiry = RequestAllocationExpiry;
+    type ChallengePeriod = RemlChallengePeriod;
+    type OptimisticBond = OptimisticBatchBond;
+    type DataChallengeDeposit = RemlDataChallengeDeposit;
+    type DataResponsePeriod = RemlDataResponsePeriod;
+    type SignatureVerifier = QuantumVault;
+    type OptimisticBatchesEnabled = RemlOptimisticBatchesEnabled;
 }
@@ -13,7 +13,8 @@
 //! - Remaining supply is emitted over time via Sigmoid curve
 
 use crate::{
-    tesserax_constants::DEV_ENDOWMENT, AccountId, BalancesConfig, RuntimeGenesisConfig, SudoConfig,
+    tesserax_constants::DEV_ENDOWMENT, AccountId, BalancesConfig, CouncilConfig,
+    RuntimeGenesisConfig, SessionConfig, SessionKeys, SudoConfig, ValidatorSetConfig,
 };
 use alloc::{vec, vec::Vec};
 use frame_support::build_struct_json_patch;
@@ -41,12 +42,15 @@ use sp_keyring::Sr25519Keyring;
 /// Build the genesis configuration for testnet/development networks.
 ///
 /// # Arguments
-/// * `initial_authorities` - BABE (Aura) and GRANDPA validator keypairs
+/// * `initial_authorities` - Validator accounts with their Aura and GRANDPA
+///   session keys; pallet-session hands the keys to Aura and GRANDPA
 /// * `endowed_accounts` - Accounts that receive initial token allocation
+/// * `council` - Council members administering the Tesserax pallets
 /// * `root` - The sudo (admin) account
 fn tesserax_genesis(
-    initial_authorities: Vec<(AuraId, GrandpaId)>,
+    initial_authorities: Vec<(AccountId, AuraId, GrandpaId)>,
     endowed_accounts: Vec<AccountId>,
+    council: Vec<AccountId>,
     root: AccountId,
 ) -> Value {
     // Calculate per-account endowment
@@ -64,20 +68,30 @@ fn tesserax_genesis(
                 .map(|account| (account, per_account))
                 .collect::<Vec<_>>(),
         },
-        aura: pallet_aura::GenesisConfig {
-            authorities: initial_authorities
+        validator_set: ValidatorSetConfig {
+            initial_validators: initial_authorities
                 .iter()
                 .map(|x| x.0.clone())
                 .collect::<Vec<_>>(),
         },
-        grandpa: pallet_grandpa::GenesisConfig {
-            authorities: initial_authorities
+        session: SessionConfig {
+            keys: initial_authorities
                 .iter()
-                .map(|x| (x.1.clone(), 1))
+                .map(|x| {
+                    (
+                        x.0.clone(),
+                        x.0.clone(),
+                        SessionKeys {
+                            aura: x.1.clone(),
+                            grandpa: x.2.clone(),
+                        },
+                    )
+                })
                 .collect::<Vec<_>>(),
         },
+        council: CouncilConfig { members: council },
         sudo: SudoConfig { key: Some(root) },
-        // Note: pallet-emission is stateless - no genesis config needed
+        // Note: pallet-emission needs no genesis config; its storage starts empty
     })
 }
 
@@ -86,7 +100,7 @@ fn tesserax_genesis(
 /// ═══════════════════════════════════════════════════════════════════════════
 ///
 /// Single validator node for local development.
-/// Alice is both the validator and the sudo account.
+/// Alice is the validator, the sole council member and the sudo account.
 ///
 /// Endowed accounts:
 /// - Alice: ~345,435 TSRX (Developer / Validator)
@@ -101,6 +115,7 @@ pub fn development_config_genesis() -> Value {
     tesserax_genesis(
         // Single validator: Alice
         vec![(
+            Sr25519Keyring::Alice.to_account_id(),
             sp_keyring::Sr25519Keyring::Alice.public().into(),
             sp_keyring::Ed25519Keyring::Alice.public().into(),
         )],
@@ -111,6 +126,8 @@ pub fn development_config_genesis() -> Value {
             Sr25519Keyring::AliceStash.to_account_id(),
             Sr25519Keyring::BobStash.to_account_id(),
         ],
+        // Council: Alice
+        vec![Sr25519Keyring::Alice.to_account_id()],
         // Sudo: Alice
         sp_keyring::Sr25519Keyring::Alice.to_account_id(),
     )
@@ -121,7 +138,7 @@ pub fn development_config_genesis() -> Value {
 /// ═══════════════════════════════════════════════════════════════════════════
 ///
 /// Multi-validator network for local testing.
-/// Alice and Bob are validators.
+/// Alice and Bob are validators; Alice, Bob and Charlie form the council.
 ///
 /// All well-known keyring accounts are endowed.
 /// ═══════════════════════════════════════════════════════════════════════════
@@ -131,10 +148,12 @@ pub fn local_config_genesis() -> Value {
         // Two validators: Alice and Bob
         vec![
             (
+                Sr25519Keyring::Alice.to_account_id(),
                 sp_keyring::Sr25519Keyring::Alice.public().into(),
                 sp_keyring::Ed25519Keyring::Alice.public().into(),
             ),
             (
+                Sr25519Keyring::Bob.to_account_id(),
                 sp_keyring::Sr25519Keyring::Bob.public().into(),
                 sp_keyring::Ed25519Keyring::Bob.public().into(),
             ),
@@ -144,6 +163,12 @@ pub fn local_config_genesis() -> Value {
             .filter(|v| v != &Sr25519Keyring::One && v != &Sr25519Keyring::Two)
             .map(|v| v.to_account_id())
             .collect::<Vec<_>>(),
+        // Council: Alice, Bob and Charlie (two of three carry a motion)
+        vec![
+            Sr25519Keyring::Alice.to_account_id(),
+            Sr25519Keyring::Bob.to_account_id(),
+            Sr25519Keyring::Charlie.to_account_id(),
+        ],
         // Sudo: Alice
         Sr25519Keyring::Alice.to_account_id(),
     )
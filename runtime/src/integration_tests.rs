@@ -2,10 +2,62 @@
 //!
 //! These tests verify the interaction between pallets and the overall system behavior.
 //! Note: Runtime integration tests in Substrate are limited; most testing is done
-//! in individual pallet tests. These tests focus on constant verification.
+//! in individual pallet tests. These tests focus on constant verification, plus
+//! a few checks of pallets wired to the real consensus digests.
 
 use crate::*;
+use codec::Encode;
+use frame_support::traits::Hooks;
 use pallet_emission::{BLOCKS_PER_ERA, TOTAL_ERAS};
+use sp_consensus_aura::{Slot, AURA_ENGINE_ID};
+use sp_keyring::{Ed25519Keyring, Sr25519Keyring};
+use sp_runtime::{Digest, DigestItem};
+
+/// Genesis with Alice and Bob as endowed validators
+fn two_validator_ext() -> sp_io::TestExternalities {
+    let validators = [Sr25519Keyring::Alice, Sr25519Keyring::Bob];
+    let accounts = || validators.iter().map(|v| v.to_account_id());
+
+    RuntimeGenesisConfig {
+        balances: BalancesConfig {
+            balances: accounts().map(|a| (a, DEV_ENDOWMENT)).collect(),
+            ..Default::default()
+        },
+        validator_set: ValidatorSetConfig {
+            initial_validators: accounts().collect(),
+        },
+        session: SessionConfig {
+            keys: validators
+                .iter()
+                .zip([Ed25519Keyring::Alice, Ed25519Keyring::Bob])
+                .map(|(aura, grandpa)| {
+                    let keys = SessionKeys {
+                        aura: aura.public().into(),
+                        grandpa: grandpa.public().into(),
+                    };
+                    (aura.to_account_id(), aura.to_account_id(), keys)
+                })
+                .collect(),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+    .build_storage()
+    .expect("genesis builds")
+    .into()
+}
+
+/// Start block `n` authored in Aura `slot` and run the emission hook
+fn initialize_block(n: BlockNumber, slot: u64) {
+    let digest = Digest {
+        logs: vec![DigestItem::PreRuntime(
+            AURA_ENGINE_ID,
+            Slot::from(slot).encode(),
+        )],
+    };
+    System::initialize(&n, &Default::default(), &digest);
+    <Emission as Hooks<BlockNumber>>::on_initialize(n);
+}
 
 // ═══════════════════════════════════════════════════════════════════════════
 // CONSTANTS INTEGRATION TESTS
@@ -127,6 +179,120 @@ fn integration_time_constants() {
     assert_eq!(DAYS, BLOCKS_PER_ERA);
 }
 
+#[test]
+#[cfg(not(feature = "fast-emission"))]
+fn integration_emission_era_is_one_day() {
+    use frame_support::traits::Get;
+
+    type BlocksPerEra = <Runtime as pallet_emission::Config>::BlocksPerEra;
+    assert_eq!(BlocksPerEra::get(), DAYS);
+    assert_eq!(
+        pallet_emission::Pallet::<Runtime>::total_eras(),
+        TOTAL_ERAS as u32
+    );
+}
+
+#[test]
+fn integration_validator_set_fits_authority_limits() {
+    use frame_support::traits::Get;
+
+    // Every validator must fit into the Aura and GRANDPA authority sets
+    type MaxValidators = <Runtime as pallet_validator_set::Config>::MaxValidators;
+    type MaxAura = <Runtime as pallet_aura::Config>::MaxAuthorities;
+    type MaxGrandpa = <Runtime as pallet_grandpa::Config>::MaxAuthorities;
+    assert!(MaxValidators::get() <= MaxAura::get());
+    assert!(MaxValidators::get() <= MaxGrandpa::get());
+
+    // Sessions rotate hourly
+    assert_eq!(configs::SessionPeriod::get(), HOURS);
+}
+
+#[test]
+fn integration_worst_case_proof_fits_a_block() {
+    use frame_support::{dispatch::DispatchClass, traits::Get};
+    use pallet_reml_verifier::{
+        weights::{WeightInfo, SP1_VERIFICATION_MARGIN},
+        MAX_PROOF_SIZE, MAX_VERIFIED_REQUESTS,
+    };
+
+    // Provisional SP1 verification included, a full batch stays submittable
+    type Weights = <Runtime as pallet_reml_verifier::Config>::WeightInfo;
+    let weight = Weights::submit_proof(MAX_PROOF_SIZE, MAX_VERIFIED_REQUESTS);
+    let max_extrinsic = configs::RuntimeBlockWeights::get()
+        .get(DispatchClass::Normal)
+        .max_extrinsic
+        .expect("normal extrinsics are limited");
+    assert!(weight.ref_time() > SP1_VERIFICATION_MARGIN);
+    assert!(weight.all_lte(max_extrinsic));
+}
+
+#[test]
+#[cfg(not(feature = "runtime-benchmarks"))]
+fn integration_optimistic_batches_stay_disabled() {
+    use frame_support::traits::Get;
+
+    // The vault's Wasm Dilithium check is only structural, so fraud proofs
+    // against optimistic batches cannot be relied on yet
+    type Enabled = <Runtime as pallet_reml_verifier::Config>::OptimisticBatchesEnabled;
+    assert!(!Enabled::get());
+}
+
+#[test]
+fn integration_equivocation_reports_cover_historical_sessions() {
+    use frame_support::traits::Get;
+
+    // GRANDPA keeps a set id for every session a key ownership proof can target
+    type MaxSetIdSessionEntries = <Runtime as pallet_grandpa::Config>::MaxSetIdSessionEntries;
+    assert_eq!(
+        MaxSetIdSessionEntries::get(),
+        configs::HISTORICAL_SESSIONS as u64
+    );
+    assert_eq!(
+        configs::ReportLongevity::get(),
+        configs::HISTORICAL_SESSIONS as u64 * HOURS as u64
+    );
+}
+
+#[test]
+fn integration_validators_are_seeded_from_authorities() {
+    use frame_support::traits::OnRuntimeUpgrade;
+    use sp_core::crypto::key_types::AURA;
+    use sp_runtime::traits::OpaqueKeys;
+
+    two_validator_ext().execute_with(|| {
+        let validators = ValidatorSet::validators().into_inner();
+        let queued = pallet_session::QueuedKeys::<Runtime>::get();
+
+        // A chain from before pallet-session only has Aura and GRANDPA authorities
+        pallet_validator_set::Validators::<Runtime>::kill();
+        pallet_session::Validators::<Runtime>::kill();
+        pallet_session::QueuedKeys::<Runtime>::kill();
+        let _ = pallet_session::NextKeys::<Runtime>::clear(u32::MAX, None);
+        let _ = pallet_session::KeyOwner::<Runtime>::clear(u32::MAX, None);
+
+        migrations::SeedValidatorsFromAuthorities::on_runtime_upgrade();
+
+        assert_eq!(ValidatorSet::validators().into_inner(), validators);
+        assert_eq!(pallet_session::Validators::<Runtime>::get(), validators);
+        assert_eq!(pallet_session::QueuedKeys::<Runtime>::get(), queued);
+        for (account, keys) in &queued {
+            assert_eq!(
+                pallet_session::NextKeys::<Runtime>::get(account).as_ref(),
+                Some(keys)
+            );
+            assert_eq!(
+                pallet_session::KeyOwner::<Runtime>::get((AURA, keys.get_raw(AURA).to_vec())),
+                Some(account.clone())
+            );
+        }
+
+        // Already seeded: running it again changes nothing
+        pallet_session::QueuedKeys::<Runtime>::kill();
+        migrations::SeedValidatorsFromAuthorities::on_runtime_upgrade();
+        assert!(pallet_session::QueuedKeys::<Runtime>::get().is_empty());
+    });
+}
+
 #[test]
 fn integration_emission_sigmoid_properties() {
     // Verify sigmoid curve properties
@@ -187,5 +353,67 @@ fn integration_version_info() {
         VERSION.impl_name,
         alloc::borrow::Cow::Borrowed("tesserax-runtime")
     );
-    assert_eq!(VERSION.spec_version, 100);
+    assert_eq!(VERSION.spec_version, 113);
+    assert_eq!(VERSION.transaction_version, 6);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// EMISSION WITH AURA AUTHORS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn integration_emission_rewards_aura_author() {
+    let bob = Sr25519Keyring::Bob.to_account_id();
+
+    two_validator_ext().execute_with(|| {
+        let before = Balances::free_balance(&bob);
+
+        // Slot 1 of two authorities is Bob's; the reward is paid, not deferred
+        initialize_block(1, 1);
+
+        assert_eq!(
+            Balances::free_balance(&bob),
+            before + Emission::reward_for_era(0)
+        );
+        assert_eq!(Emission::unminted_rewards(), 0);
+        assert_eq!(Emission::total_minted(), Emission::reward_for_era(0));
+    });
+}
+
+#[test]
+fn integration_fees_are_pooled_for_aura_author_after_emission() {
+    use frame_support::traits::{
+        fungible::Balanced,
+        tokens::{Fortitude, Precision, Preservation},
+        OnUnbalanced,
+    };
+
+    let alice = Sr25519Keyring::Alice.to_account_id();
+    let bob = Sr25519Keyring::Bob.to_account_id();
+    let fee = TSRX;
+
+    two_validator_ext().execute_with(|| {
+        pallet_emission::BonusMinted::<Runtime>::put(true);
+
+        // First block after the schedule, authored by Bob, collects a fee
+        let first = Emission::emission_end_block() + 1;
+        initialize_block(first, 1);
+        let credit = <Balances as Balanced<AccountId>>::withdraw(
+            &alice,
+            fee,
+            Precision::Exact,
+            Preservation::Preserve,
+            Fortitude::Polite,
+        )
+        .unwrap();
+        <pallet_emission::PoolFees<Runtime> as OnUnbalanced<_>>::on_unbalanced(credit);
+        assert_eq!(Emission::pooled_fees(), fee);
+
+        // The next era pays the pool to the author recorded from the digest
+        let before = Balances::free_balance(&bob);
+        initialize_block(first + configs::EMISSION_BLOCKS_PER_ERA, 1);
+
+        assert_eq!(Balances::free_balance(&bob), before + fee);
+        assert_eq!(Emission::pooled_fees(), 0);
+    });
 }
@@ -7,6 +7,7 @@ pub mod apis;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarks;
 pub mod configs;
+pub mod migrations;
 pub mod precompiles;
 pub mod vault_blocker;
 
@@ -85,6 +86,8 @@ pub mod tesserax_constants {
 
     /// Genesis supply for development (10% of max supply)
     /// 1,381,742.2 TSRX × 10^18 = 1,381,742_200_000_000_000_000_000
+    /// Not counted against MAX_SUPPLY by pallet-emission, whose schedule
+    /// emits the full cap
     pub const GENESIS_SUPPLY: Balance = 1_381_742_200_000_000_000_000_000;
 
     /// Endowment per development account
@@ -172,10 +175,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     //   `spec_version`, and `authoring_version` are the same between Wasm and native.
     // This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
     //   the compatible custom types.
-    spec_version: 100,
+    spec_version: 113,
     impl_version: 1,
     apis: apis::RUNTIME_API_VERSIONS,
-    transaction_version: 1,
+    transaction_version: 6,
     system_version: 1,
 };
 
@@ -324,7 +327,16 @@ pub type SignedPayload = generic::SignedPayload<RuntimeCall, TxExtension>;
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (
+    pallet_reml_verifier::migrations::MigrateV0ToV1<Runtime>,
+    pallet_reml_verifier::migrations::MigrateV1ToV2<Runtime>,
+    pallet_reml_verifier::migrations::MigrateV2ToV3<Runtime>,
+    pallet_reml_verifier::migrations::MigrateV3ToV4<Runtime>,
+    pallet_reml_verifier::migrations::MigrateV4ToV5<Runtime>,
+    pallet_reml_verifier::migrations::MigrateV5ToV6<Runtime>,
+    pallet_reml_verifier::migrations::MigrateV6ToV7<Runtime>,
+    migrations::SeedValidatorsFromAuthorities,
+);
 
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
@@ -360,6 +372,22 @@ mod runtime {
     #[runtime::pallet_index(1)]
     pub type Timestamp = pallet_timestamp;
 
+    // Governance-managed validators and session key rotation. Declared before
+    // Aura and Grandpa so genesis and session changes reach them in order.
+    #[runtime::pallet_index(17)]
+    pub type ValidatorSet = pallet_validator_set;
+
+    #[runtime::pallet_index(18)]
+    pub type Session = pallet_session;
+
+    // Session roots for key ownership proofs, and offence reports (GRANDPA
+    // equivocations) punished through the validator set
+    #[runtime::pallet_index(19)]
+    pub type Historical = pallet_session::historical;
+
+    #[runtime::pallet_index(20)]
+    pub type Offences = pallet_offences;
+
     #[runtime::pallet_index(2)]
     pub type Aura = pallet_aura;
 
@@ -424,4 +452,17 @@ mod runtime {
 
     #[runtime::pallet_index(16)]
     pub type RemlVerifier = pallet_reml_verifier;
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // GOVERNANCE (Council administering the Tesserax pallets)
+    // ═══════════════════════════════════════════════════════════════════════
+
+    #[runtime::pallet_index(21)]
+    pub type Preimage = pallet_preimage;
+
+    #[runtime::pallet_index(22)]
+    pub type Council = pallet_collective<Instance1>;
+
+    #[runtime::pallet_index(23)]
+    pub type Whitelist = pallet_whitelist;
 }
@@ -0,0 +1,159 @@
+//! Runtime Migrations
+//!
+//! Migrations that span several pallets and so cannot live in any one of them.
+
+use crate::{AccountId, Runtime, SessionKeys};
+use alloc::vec::Vec;
+use frame_support::{
+    traits::{Get, OnRuntimeUpgrade},
+    weights::Weight,
+    BoundedVec,
+};
+use sp_runtime::traits::OpaqueKeys;
+
+#[cfg(feature = "try-runtime")]
+use codec::{Decode, Encode};
+
+const LOG_TARGET: &str = "runtime::migrations";
+
+/// Seed the validator set and `pallet_session` from the Aura and GRANDPA authorities
+///
+/// Chains launched before pallet-session kept their authorities directly in
+/// `pallet_aura::Authorities` and `pallet_grandpa::Authorities`, paired by
+/// index in the genesis config. Without this migration the first session
+/// rotation would hand an empty set to Aura and GRANDPA and halt the chain.
+///
+/// Each Aura key is mapped to the account of the same sr25519 public key (the
+/// convention of every Tesserax genesis preset), and becomes a validator with
+/// that Aura key and the GRANDPA key at the same index as its session keys.
+/// The migration only runs while `pallet_validator_set::Validators` and
+/// `pallet_session::Validators` are both empty, so it is a no-op on chains
+/// that started with pallet-session. Historical session roots start at the
+/// next rotation, so GRANDPA equivocations of the current session cannot be
+/// proven yet.
+pub struct SeedValidatorsFromAuthorities;
+
+impl SeedValidatorsFromAuthorities {
+    /// Validators and their session keys derived from the current authorities
+    fn authority_keys() -> Option<Vec<(AccountId, SessionKeys)>> {
+        let aura = pallet_aura::Authorities::<Runtime>::get();
+        let grandpa = pallet_grandpa::Pallet::<Runtime>::grandpa_authorities();
+        if aura.is_empty() || aura.len() != grandpa.len() {
+            log::error!(
+                target: LOG_TARGET,
+                "Cannot pair {} Aura with {} GRANDPA authorities, validators not seeded",
+                aura.len(),
+                grandpa.len()
+            );
+            return None;
+        }
+
+        Some(
+            aura.into_iter()
+                .zip(grandpa)
+                .map(|(aura, (grandpa, _weight))| {
+                    let public: sp_core::sr25519::Public = aura.clone().into();
+                    (AccountId::from(public), SessionKeys { aura, grandpa })
+                })
+                .collect(),
+        )
+    }
+}
+
+impl OnRuntimeUpgrade for SeedValidatorsFromAuthorities {
+    fn on_runtime_upgrade() -> Weight {
+        let db = <Runtime as frame_system::Config>::DbWeight::get();
+        if !pallet_validator_set::Validators::<Runtime>::get().is_empty()
+            || !pallet_session::Validators::<Runtime>::get().is_empty()
+        {
+            return db.reads(2);
+        }
+
+        let Some(keys) = Self::authority_keys() else {
+            return db.reads(4);
+        };
+        let accounts: Vec<AccountId> = keys.iter().map(|(account, _)| account.clone()).collect();
+        let Ok(validators) = BoundedVec::try_from(accounts.clone()) else {
+            log::error!(
+                target: LOG_TARGET,
+                "{} authorities exceed MaxValidators, validators not seeded",
+                accounts.len()
+            );
+            return db.reads(4);
+        };
+
+        // As `pallet_session`'s genesis build: own the keys and hold a consumer
+        // reference for them, so `purge_keys` can release it later
+        for (account, session_keys) in &keys {
+            for id in <SessionKeys as OpaqueKeys>::key_ids() {
+                pallet_session::KeyOwner::<Runtime>::insert(
+                    (*id, session_keys.get_raw(*id).to_vec()),
+                    account,
+                );
+            }
+            pallet_session::NextKeys::<Runtime>::insert(account, session_keys);
+            if frame_system::Pallet::<Runtime>::inc_consumers_without_limit(account).is_err() {
+                frame_system::Pallet::<Runtime>::inc_providers(account);
+            }
+        }
+        pallet_session::QueuedKeys::<Runtime>::put(keys.clone());
+        pallet_session::Validators::<Runtime>::put(accounts);
+        pallet_validator_set::Validators::<Runtime>::put(validators);
+
+        log::info!(
+            target: LOG_TARGET,
+            "Seeded {} validators from the Aura and GRANDPA authorities",
+            keys.len()
+        );
+
+        let count = keys.len() as u64;
+        let key_types = <SessionKeys as OpaqueKeys>::key_ids().len() as u64;
+        db.reads_writes(4 + count, 3 + count * (2 + key_types))
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+        let seeded = pallet_validator_set::Validators::<Runtime>::get().is_empty()
+            && pallet_session::Validators::<Runtime>::get().is_empty();
+        let keys = if seeded {
+            Self::authority_keys().ok_or("Aura and GRANDPA authorities do not pair up")?
+        } else {
+            Vec::new()
+        };
+        Ok(keys.encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+        let keys = Vec::<(AccountId, SessionKeys)>::decode(&mut &state[..])
+            .map_err(|_| sp_runtime::TryRuntimeError::Other("Invalid pre-upgrade state"))?;
+        frame_support::ensure!(
+            !pallet_validator_set::Validators::<Runtime>::get().is_empty(),
+            "Validator set is empty after migration"
+        );
+        if keys.is_empty() {
+            return Ok(());
+        }
+
+        let accounts: Vec<AccountId> = keys.iter().map(|(account, _)| account.clone()).collect();
+        frame_support::ensure!(
+            pallet_validator_set::Validators::<Runtime>::get().into_inner() == accounts,
+            "Validator set does not match the authorities"
+        );
+        frame_support::ensure!(
+            pallet_session::Validators::<Runtime>::get() == accounts,
+            "Session validators do not match the authorities"
+        );
+        frame_support::ensure!(
+            pallet_session::QueuedKeys::<Runtime>::get() == keys,
+            "Queued session keys do not match the authorities"
+        );
+        for (account, session_keys) in &keys {
+            frame_support::ensure!(
+                pallet_session::NextKeys::<Runtime>::get(account).as_ref() == Some(session_keys),
+                "Session keys missing for a seeded validator"
+            );
+        }
+        Ok(())
+    }
+}
@@ -9,6 +9,8 @@
 //! | 0x20 | verify_stark_commitment | 50,000 base + 100/byte |
 //! | 0x21 | is_request_verified | 10,000 |
 //! | 0x22 | get_batch_info | 15,000 |
+//! | 0x23 | consume_verified_request | 30,000 |
+//! | 0x24 | allocate_request_id | 25,000 |
 //!
 //! ## Usage from Solidity
 //!
@@ -16,6 +18,8 @@
 //! interface IReMLVerifier {
 //!     function isRequestVerified(uint64 requestId) external view returns (bool);
 //!     function getBatchInfo(uint64 batchId) external view returns (bytes);
+//!     function consumeVerifiedRequest(uint64 requestId, bytes32 callTag) external returns (bool);
+//!     function allocateRequestId() external returns (uint64);
 //! }
 //!
 //! contract QuantumSafe {
@@ -34,9 +38,10 @@ use alloc::vec;
 use alloc::vec::Vec;
 use core::marker::PhantomData;
 use fp_evm::{
-    ExitError, ExitSucceed, Precompile, PrecompileFailure, PrecompileHandle, PrecompileOutput,
-    PrecompileResult,
+    ExitError, ExitRevert, ExitSucceed, Precompile, PrecompileFailure, PrecompileHandle,
+    PrecompileOutput, PrecompileResult,
 };
+use pallet_evm::AddressMapping;
 
 // ═══════════════════════════════════════════════════════════════════════════
 // CONSTANTS
@@ -54,6 +59,12 @@ const IS_REQUEST_VERIFIED_GAS: u64 = 10_000;
 /// Gas cost for getting batch info
 const GET_BATCH_INFO_GAS: u64 = 15_000;
 
+/// Gas cost for consuming a verified request
+const CONSUME_VERIFIED_REQUEST_GAS: u64 = 30_000;
+
+/// Gas cost for allocating a request ID
+const ALLOCATE_REQUEST_ID_GAS: u64 = 25_000;
+
 // ═══════════════════════════════════════════════════════════════════════════
 // PRECOMPILE: Verify STARK Commitment (0x20)
 // ═══════════════════════════════════════════════════════════════════════════
@@ -291,6 +302,132 @@ where
     }
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// PRECOMPILE: Consume Verified Request (0x23)
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Uses up a verified request on behalf of the calling contract.
+///
+/// Same semantics as `pallet_reml_verifier::Pallet::consume_verified_request`:
+/// the caller's mapped account is recorded as consumer and any reuse reverts.
+///
+/// ## Input Format
+/// - bytes[0..8]: Request ID (little-endian u64)
+/// - bytes[8..40]: Call tag identifying the consuming call
+///
+/// ## Output
+/// - bytes[0..32]: 0x01 (consumed)
+///
+/// Reverts with "ReML: request not verified" or "ReML: request already
+/// consumed", and cannot be used from a static call.
+pub struct ConsumeVerifiedRequest<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> Precompile for ConsumeVerifiedRequest<Runtime>
+where
+    Runtime: pallet_reml_verifier::Config + pallet_evm::Config,
+{
+    fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        let input: Vec<u8> = handle.input().to_vec();
+
+        handle.record_cost(CONSUME_VERIFIED_REQUEST_GAS)?;
+
+        if handle.is_static() {
+            return Err(PrecompileFailure::Error {
+                exit_status: ExitError::Other("Cannot consume in a static call".into()),
+            });
+        }
+
+        // Validate input length (8 bytes request ID + 32 bytes call tag)
+        if input.len() < 40 {
+            return Err(PrecompileFailure::Error {
+                exit_status: ExitError::Other("Invalid consume input".into()),
+            });
+        }
+
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&input[0..8]);
+        let request_id = u64::from_le_bytes(bytes);
+        let mut call = [0u8; 32];
+        call.copy_from_slice(&input[8..40]);
+
+        let consumer = Runtime::AddressMapping::into_account_id(handle.context().caller);
+
+        match pallet_reml_verifier::Pallet::<Runtime>::consume_verified_request(
+            request_id, &consumer, call,
+        ) {
+            Ok(()) => {
+                let mut output = [0u8; 32];
+                output[31] = 1;
+                Ok(PrecompileOutput {
+                    exit_status: ExitSucceed::Returned,
+                    output: output.to_vec(),
+                })
+            }
+            Err(error) => {
+                let already_consumed: sp_runtime::DispatchError =
+                    pallet_reml_verifier::Error::<Runtime>::RequestAlreadyConsumed.into();
+                let reason: &[u8] = if error == already_consumed {
+                    b"ReML: request already consumed"
+                } else {
+                    b"ReML: request not verified"
+                };
+                Err(PrecompileFailure::Revert {
+                    exit_status: ExitRevert::Reverted,
+                    output: reason.to_vec(),
+                })
+            }
+        }
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// PRECOMPILE: Allocate Request ID (0x24)
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Allocates the next Re-ML request ID to the calling contract.
+///
+/// Same semantics as `pallet_reml_verifier::Pallet::allocate_request_id`:
+/// IDs come from the pallet's monotonic counter, so contracts cannot collide
+/// with each other or with other users. The allocation deposit is reserved
+/// from the calling contract's account; the call reverts with
+/// "ReML: allocation deposit not covered" if it cannot pay it.
+///
+/// ## Input Format
+/// - (empty)
+///
+/// ## Output
+/// - bytes[0..8]: Request ID (little-endian u64)
+///
+/// Cannot be used from a static call.
+pub struct AllocateRequestId<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> Precompile for AllocateRequestId<Runtime>
+where
+    Runtime: pallet_reml_verifier::Config + pallet_evm::Config,
+{
+    fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        handle.record_cost(ALLOCATE_REQUEST_ID_GAS)?;
+
+        if handle.is_static() {
+            return Err(PrecompileFailure::Error {
+                exit_status: ExitError::Other("Cannot allocate in a static call".into()),
+            });
+        }
+
+        let owner = Runtime::AddressMapping::into_account_id(handle.context().caller);
+        let request_id = pallet_reml_verifier::Pallet::<Runtime>::do_allocate_request_id(&owner)
+            .map_err(|_| PrecompileFailure::Revert {
+                exit_status: ExitRevert::Reverted,
+                output: b"ReML: allocation deposit not covered".to_vec(),
+            })?;
+
+        Ok(PrecompileOutput {
+            exit_status: ExitSucceed::Returned,
+            output: request_id.to_le_bytes().to_vec(),
+        })
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // HELPER: Standalone Precompile (No Runtime Access)
 // ═══════════════════════════════════════════════════════════════════════════
@@ -0,0 +1,186 @@
+//! Sigmoid emission curve
+//!
+//! Supply follows S(t) = S_max / (1 + e^(-k(t - t_mid))) with k = steepness / t_mid.
+//! Era `n` (1-based) ends at block `t_n = n × blocks_per_era` and pays
+//! `floor((S(t_n) - S(t_{n-1})) / blocks_per_era)` per block, with `S(t_0) = 0`
+//! so the supply already on the curve at genesis is paid out in the first era.
+//!
+//! The committed table was produced by the original Python generator, so the
+//! curve is evaluated in IEEE-754 double precision with exactly the same
+//! operations in the same order. Changing any of them changes consensus values.
+//! The exponential comes from [`crate::exp`] rather than the platform's C
+//! library, so the result does not depend on the machine running the tool.
+
+/// One TSRX in planck
+pub const UNIT: u128 = 1_000_000_000_000_000_000;
+
+/// Parameters of the emission curve
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CurveParams {
+    /// Blocks per year at the target block time
+    pub blocks_per_year: u32,
+    /// Length of the schedule in years
+    pub years: u32,
+    /// Blocks per era
+    pub blocks_per_era: u32,
+    /// `k × t_mid`, the exponent magnitude at genesis and at the last block
+    pub steepness: u32,
+}
+
+impl CurveParams {
+    /// Parameters of the Tesserax v3.0 schedule
+    pub const TESSERAX: Self = Self {
+        blocks_per_year: 5_256_000,
+        years: 20,
+        blocks_per_era: 14_400,
+        steepness: 10,
+    };
+
+    /// Total blocks in the schedule
+    pub fn total_blocks(&self) -> u64 {
+        self.blocks_per_year as u64 * self.years as u64
+    }
+
+    /// Number of eras in the schedule
+    pub fn total_eras(&self) -> u32 {
+        (self.total_blocks() / self.blocks_per_era as u64) as u32
+    }
+
+    /// Supply on the curve at block `t`, in planck
+    pub fn supply(&self, max_supply: u128, t: u64) -> f64 {
+        let mid = self.total_blocks() as f64 / 2.0;
+        let k = self.steepness as f64 / mid;
+        let exponent = -k * (t as f64 - mid);
+
+        if exponent > 700.0 {
+            0.0
+        } else if exponent < -700.0 {
+            max_supply as f64
+        } else {
+            max_supply as f64 / (1.0 + crate::exp::exp(exponent))
+        }
+    }
+}
+
+/// Generated emission table
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EmissionTable {
+    /// Curve the table was generated from
+    pub params: CurveParams,
+    /// Maximum supply in planck
+    pub max_supply: u128,
+    /// Reward per block of each era
+    pub rewards: Vec<u128>,
+    /// `max_supply` minus the scheduled emission, minted once after the schedule
+    pub bonus: u128,
+}
+
+impl EmissionTable {
+    /// Generate the table for `params`
+    pub fn generate(params: CurveParams) -> Self {
+        let max_supply = max_supply();
+        let blocks_per_era = params.blocks_per_era as f64;
+
+        let mut previous = 0.0;
+        let rewards: Vec<u128> = (1..=params.total_eras() as u64)
+            .map(|era| {
+                let current = params.supply(max_supply, era * params.blocks_per_era as u64);
+                // `as` truncates towards zero, like Python's `int()`
+                let reward = ((current - previous) / blocks_per_era) as u128;
+                previous = current;
+                reward
+            })
+            .collect();
+
+        let scheduled: u128 = rewards
+            .iter()
+            .map(|r| r * params.blocks_per_era as u128)
+            .sum();
+
+        Self {
+            params,
+            max_supply,
+            bonus: max_supply - scheduled,
+            rewards,
+        }
+    }
+
+    /// Emission of every era and the cumulative emission at its end
+    pub fn curve(&self) -> impl Iterator<Item = (u32, u128, u128)> + '_ {
+        let blocks_per_era = self.params.blocks_per_era as u128;
+        self.rewards
+            .iter()
+            .enumerate()
+            .scan(0u128, move |cumulative, (era, reward)| {
+                let era_emission = reward * blocks_per_era;
+                *cumulative += era_emission;
+                Some((era as u32, era_emission, *cumulative))
+            })
+    }
+
+    /// Era index (0-based) and reward of the highest-paying era
+    pub fn peak(&self) -> (usize, u128) {
+        self.rewards
+            .iter()
+            .copied()
+            .enumerate()
+            .fold(
+                (0, 0),
+                |peak, (era, reward)| {
+                    if reward > peak.1 {
+                        (era, reward)
+                    } else {
+                        peak
+                    }
+                },
+            )
+    }
+}
+
+/// Maximum supply: floor(π × e × φ × 10^6) TSRX
+pub fn max_supply() -> u128 {
+    use core::f64::consts::{E, PI};
+    let phi = (1.0 + 5f64.sqrt()) / 2.0;
+    (PI * E * phi * 1_000_000.0).floor() as u128 * UNIT
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn max_supply_is_pi_e_phi_million() {
+        assert_eq!(max_supply(), 13_817_580 * UNIT);
+    }
+
+    #[test]
+    fn curve_is_half_supply_at_midpoint() {
+        let params = CurveParams::TESSERAX;
+        let mid = params.total_blocks() / 2;
+        assert_eq!(params.supply(max_supply(), mid), max_supply() as f64 / 2.0);
+    }
+
+    #[test]
+    fn table_matches_committed_values() {
+        let table = EmissionTable::generate(CurveParams::TESSERAX);
+        assert_eq!(table.rewards.len(), 7_300);
+        assert_eq!(table.rewards[0], 43_681_219_431_296_712);
+        assert_eq!(table.rewards[1], 119_833_191_063_315);
+        assert_eq!(table.rewards[7_299], 119_505_360_178_963);
+    }
+
+    #[test]
+    fn table_covers_max_supply() {
+        let table = EmissionTable::generate(CurveParams::TESSERAX);
+
+        let (_, _, scheduled) = table.curve().last().unwrap();
+        assert_eq!(scheduled + table.bonus, table.max_supply);
+        assert_eq!(table.bonus, 627_288_682_625_208_744_000);
+    }
+
+    #[test]
+    fn peak_is_at_year_ten() {
+        let (era, _) = EmissionTable::generate(CurveParams::TESSERAX).peak();
+        assert_eq!(era, 3_649);
+    }
+}
@@ -0,0 +1,103 @@
+//! # Tesserax Emission Table Generator
+//!
+//! Regenerates `pallets/emission/src/emission_table.rs` (`MAX_SUPPLY`,
+//! `BONUS_AMOUNT` and `REWARD_SCHEDULE`) from the sigmoid curve parameters.
+//! The curve is evaluated exactly as the original Python generator did, so
+//! `--check` reproduces the committed consensus values bit for bit.
+//!
+//! ```text
+//! cargo run -p emission-gen             # rewrite the committed table
+//! cargo run -p emission-gen -- --check  # fail if the committed table differs
+//! cargo run -p emission-gen -- --csv    # print the cumulative supply curve
+//! ```
+
+mod curve;
+mod exp;
+mod render;
+
+use std::{
+    fs,
+    io::{self, Write},
+    path::PathBuf,
+    process::ExitCode,
+};
+
+use clap::Parser;
+
+use curve::{CurveParams, EmissionTable};
+
+/// Default location of the table, relative to this crate
+const TABLE_PATH: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../../pallets/emission/src/emission_table.rs"
+);
+
+#[derive(Debug, Parser)]
+#[command(about = "Generate and verify the Tesserax emission table")]
+struct Cli {
+    /// Verify the committed table instead of rewriting it
+    #[arg(long, conflicts_with = "csv")]
+    check: bool,
+
+    /// Print the cumulative supply curve as CSV to stdout
+    #[arg(long)]
+    csv: bool,
+
+    /// Path of `emission_table.rs`
+    #[arg(long, default_value = TABLE_PATH)]
+    table: PathBuf,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let table = EmissionTable::generate(CurveParams::TESSERAX);
+
+    if cli.csv {
+        // Ignore errors such as a closed pipe (`--csv | head`)
+        let _ = io::stdout().write_all(render::csv(&table).as_bytes());
+        return ExitCode::SUCCESS;
+    }
+
+    let source = render::rust_source(&table);
+
+    if cli.check {
+        let committed = match fs::read_to_string(&cli.table) {
+            Ok(committed) => committed,
+            Err(e) => {
+                eprintln!("failed to read {}: {e}", cli.table.display());
+                return ExitCode::FAILURE;
+            }
+        };
+        if committed == source {
+            println!("{} is up to date", cli.table.display());
+            return ExitCode::SUCCESS;
+        }
+
+        eprintln!("{} differs from the generated table", cli.table.display());
+        if let Some((line, (expected, found))) = source
+            .lines()
+            .zip(committed.lines())
+            .enumerate()
+            .find(|(_, (expected, found))| expected != found)
+        {
+            eprintln!("first difference at line {}:", line + 1);
+            eprintln!("  expected: {expected}");
+            eprintln!("  found:    {found}");
+        }
+        eprintln!("run `cargo run -p emission-gen` to regenerate it");
+        return ExitCode::FAILURE;
+    }
+
+    if let Err(e) = fs::write(&cli.table, source) {
+        eprintln!("failed to write {}: {e}", cli.table.display());
+        return ExitCode::FAILURE;
+    }
+
+    let (_, _, scheduled) = table.curve().last().unwrap_or_default();
+    println!("wrote {}", cli.table.display());
+    println!("  eras:       {}", table.rewards.len());
+    println!("  max supply: {} planck", table.max_supply);
+    println!("  scheduled:  {} planck", scheduled);
+    println!("  bonus:      {} planck", table.bonus);
+    ExitCode::SUCCESS
+}
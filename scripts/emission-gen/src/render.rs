@@ -0,0 +1,181 @@
+//! Output formats: the `emission_table.rs` source file and CSV
+
+use std::fmt::Write;
+
+use crate::curve::{EmissionTable, UNIT};
+
+/// Render `pallets/emission/src/emission_table.rs`
+///
+/// The output is already rustfmt-clean, so `--check` can compare it byte for byte.
+pub fn rust_source(table: &EmissionTable) -> String {
+    let params = table.params;
+    let (peak_era, peak_reward) = table.peak();
+    let mut out = String::new();
+
+    let _ = writeln!(out, "// AUTO-GENERATED by `cargo run -p emission-gen`");
+    let _ = writeln!(
+        out,
+        "// DO NOT EDIT MANUALLY - CI runs `emission-gen --check`"
+    );
+    let _ = writeln!(out, "//");
+    let _ = writeln!(
+        out,
+        "// Tesserax Protocol v3.0 - Pre-computed Sigmoid Emission Table"
+    );
+    let _ = writeln!(
+        out,
+        "// Max Supply: {} TSRX (π × e × φ × 10^6)",
+        grouped(table.max_supply / UNIT, ',')
+    );
+    let _ = writeln!(
+        out,
+        "// Duration: {} years ({} eras)",
+        params.years,
+        table.rewards.len()
+    );
+    let _ = writeln!(
+        out,
+        "// Bonus: {} TSRX minted post-emission to reach 100% coverage",
+        table.bonus / UNIT
+    );
+    let _ = writeln!(out, "//");
+    let _ = writeln!(out);
+
+    let _ = writeln!(
+        out,
+        "/// Maximum supply of $TSRX in smallest units (planck)"
+    );
+    let _ = writeln!(
+        out,
+        "/// S_max = floor(π × e × φ × 10^6) = {} TSRX × 10^18",
+        grouped(table.max_supply / UNIT, ',')
+    );
+    let _ = writeln!(
+        out,
+        "pub const MAX_SUPPLY: u128 = {};",
+        grouped(table.max_supply, '_')
+    );
+    let _ = writeln!(out);
+
+    let _ = writeln!(out, "/// Total number of eras in the emission schedule");
+    let _ = writeln!(
+        out,
+        "pub const TOTAL_ERAS: usize = {};",
+        table.rewards.len()
+    );
+    let _ = writeln!(out);
+
+    let _ = writeln!(out, "/// Blocks per era (24 hours at 6s block time)");
+    let _ = writeln!(
+        out,
+        "pub const BLOCKS_PER_ERA: u32 = {};",
+        params.blocks_per_era
+    );
+    let _ = writeln!(out);
+
+    let _ = writeln!(
+        out,
+        "/// Bonus amount to mint after emission ends (to reach 100% of max supply)"
+    );
+    let _ = writeln!(
+        out,
+        "/// This compensates for cumulative flooring in the emission schedule"
+    );
+    let _ = writeln!(out, "/// (MAX_SUPPLY - sum of all scheduled emissions)");
+    let _ = writeln!(
+        out,
+        "/// The pallet mints the exact remainder (MAX_SUPPLY - GenesisSupply - TotalMinted);"
+    );
+    let _ = writeln!(
+        out,
+        "/// this constant is informational and rounds that remainder down to whole TSRX."
+    );
+    let _ = writeln!(
+        out,
+        "pub const BONUS_AMOUNT: u128 = {};",
+        grouped(table.bonus / UNIT * UNIT, '_')
+    );
+    let _ = writeln!(out);
+
+    let _ = writeln!(out, "/// Pre-computed block rewards per era");
+    let _ = writeln!(
+        out,
+        "/// Peak reward at era {} (~year {}): {} TSRX/block",
+        peak_era,
+        (peak_era as u64 * params.blocks_per_era as u64 + params.blocks_per_year as u64 / 2)
+            / params.blocks_per_year as u64,
+        tsrx(peak_reward)
+    );
+    let _ = writeln!(
+        out,
+        "pub const REWARD_SCHEDULE: [u128; {}] = [",
+        table.rewards.len()
+    );
+    for reward in &table.rewards {
+        let _ = writeln!(out, "    {reward}_u128,");
+    }
+    let _ = writeln!(out, "];");
+
+    out
+}
+
+/// Render the cumulative supply curve as CSV (amounts in planck)
+pub fn csv(table: &EmissionTable) -> String {
+    let blocks_per_era = table.params.blocks_per_era as u64;
+    let mut out = String::from(
+        "era,start_block,end_block,reward_per_block,era_emission,cumulative_emission\n",
+    );
+
+    for (era, era_emission, cumulative) in table.curve() {
+        let start_block = era as u64 * blocks_per_era + 1;
+        let _ = writeln!(
+            out,
+            "{},{},{},{},{},{}",
+            era,
+            start_block,
+            start_block + blocks_per_era - 1,
+            table.rewards[era as usize],
+            era_emission,
+            cumulative
+        );
+    }
+
+    out
+}
+
+/// `value` with thousands separated by `separator`
+fn grouped(value: u128, separator: char) -> String {
+    let digits = value.to_string();
+    let mut out = String::with_capacity(digits.len() * 4 / 3);
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            out.push(separator);
+        }
+        out.push(digit);
+    }
+    out
+}
+
+/// Planck amount as TSRX rounded to six decimals
+fn tsrx(planck: u128) -> String {
+    let micro = (planck + UNIT / 2_000_000) / (UNIT / 1_000_000);
+    format!("{}.{:06}", micro / 1_000_000, micro % 1_000_000)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers_are_grouped() {
+        assert_eq!(grouped(13_817_580, ','), "13,817,580");
+        assert_eq!(grouped(100, '_'), "100");
+        assert_eq!(grouped(1_000, '_'), "1_000");
+    }
+
+    #[test]
+    fn tsrx_rounds_to_six_decimals() {
+        assert_eq!(tsrx(657_228_470_176_773_632), "0.657228");
+        assert_eq!(tsrx(627_288_682_625_208_744_000), "627.288683");
+    }
+}
@@ -1,1482 +0,0 @@
-// AUTO-GENERATED by scripts/generate_curve.py
-// DO NOT EDIT MANUALLY
-//
-// Sanctuary Protocol v2.0 - Pre-computed Sigmoid Emission Table
-// Max Supply: 13,817,580 SANC
-// Duration: 20 years (7300 eras)
-//
-
-/// Maximum supply of $SANC in smallest units (planck)
-/// 13,817,580 SANC × 10^18 = 13817580000000000000000000
-pub const MAX_SUPPLY: u128 = 13817580000000000000000000;
-
-/// Total number of eras in the emission schedule
-pub const TOTAL_ERAS: usize = 7300;
-
-/// Blocks per era (24 hours at 6s block time)
-pub const BLOCKS_PER_ERA: u32 = 14400;
-
-/// Pre-computed block rewards per era
-/// Peak reward at era 3650 (~year 10): 0.657228 SANC/block
-pub const REWARD_SCHEDULE: [u128; 7300] = [
-    43681219431296712_u128, 119833191063315_u128, 120161921271498_u128, 120491553180212_u128, 120822089262111_u128, 
-    121153531997170_u128, 121485883871559_u128, 121819147378879_u128, 122153325018917_u128, 122488419298991_u128, 
-    122824432732624_u128, 123161367840758_u128, 123499227151118_u128, 123838013197962_u128, 124177728523186_u128, 
-    124518375674966_u128, 124859957209078_u128, 125202475687699_u128, 125545933680685_u128, 125890333764230_u128, 
-    126235678522217_u128, 126581970545349_u128, 126929212431182_u128, 127277406784971_u128, 127626556218454_u128, 
-    127976663351186_u128, 128327730809232_u128, 128679761226547_u128, 129032757243640_u128, 129386721508775_u128, 
-    129741656677335_u128, 130097565411628_u128, 130454450381937_u128, 130812314265108_u128, 131171159746050_u128, 
-    131530989516381_u128, 131891806275684_u128, 132253612730349_u128, 132616411594752_u128, 132980205590446_u128, 
-    133344997446205_u128, 133710789898849_u128, 134077585692062_u128, 134445387577717_u128, 134814198314489_u128, 
-    135184020669417_u128, 135554857416330_u128, 135926711337314_u128, 136299585221800_u128, 136673481866672_u128, 
-    137048404076962_u128, 137424354664821_u128, 137801336450735_u128, 138179352262255_u128, 138558404935343_u128, 
-    138938497313068_u128, 139319632246938_u128, 139701812595916_u128, 140085041226638_u128, 140469321014158_u128, 
-    140854654840713_u128, 141241045597161_u128, 141628496181593_u128, 142017009500678_u128, 142406588468447_u128, 
-    142797236007458_u128, 143188955048054_u128, 143581748528323_u128, 143975619395056_u128, 144370570602368_u128, 
-    144766605113262_u128, 145163725898001_u128, 145561935935897_u128, 145961238213554_u128, 146361635726486_u128, 
-    146763131478079_u128, 147165728479750_u128, 147569429751744_u128, 147974238321877_u128, 148380157227035_u128, 
-    148787189511627_u128, 149195338229095_u128, 149604606440962_u128, 150014997216870_u128, 150426513635510_u128, 
-    150839158783253_u128, 151252935755766_u128, 151667847656334_u128, 152083897597551_u128, 152501088699806_u128, 
-    152919424092687_u128, 153338906914069_u128, 153759540310134_u128, 154181327436394_u128, 154604271456183_u128, 
-    155028375542360_u128, 155453642875608_u128, 155880076646199_u128, 156307680052264_u128, 156736456301527_u128, 
-    157166408610046_u128, 157597540202523_u128, 158029854313167_u128, 158463354184180_u128, 158898043067560_u128, 
-    159333924223312_u128, 159771000921292_u128, 160209276439374_u128, 160648754065289_u128, 161089437095307_u128, 
-    161531328834555_u128, 161974432597852_u128, 162418751708355_u128, 162864289499063_u128, 163311049311373_u128, 
-    163759034496605_u128, 164208248414462_u128, 164658694434588_u128, 165110375935526_u128, 165563296304787_u128, 
-    166017458939894_u128, 166472867246826_u128, 166929524641764_u128, 167387434549393_u128, 167846600404627_u128, 
-    168307025650956_u128, 168768713742044_u128, 169231668140723_u128, 169695892318936_u128, 170161389758964_u128, 
-    170628163951720_u128, 171096218398583_u128, 171565556609624_u128, 172036182105447_u128, 172508098415393_u128, 
-    172981309079260_u128, 173455817646216_u128, 173931627674819_u128, 174408742734188_u128, 174887166402259_u128, 
-    175366902267758_u128, 175847953928255_u128, 176330324992200_u128, 176814019076887_u128, 177299039810446_u128, 
-    177785390830496_u128, 178273075784362_u128, 178762098330164_u128, 179252462135077_u128, 179744170877264_u128, 
-    180237228244109_u128, 180731637933907_u128, 181227403654275_u128, 181724529123746_u128, 182223018070680_u128, 
-    182722874233359_u128, 183224101361072_u128, 183726703212507_u128, 184230683557523_u128, 184736046175400_u128, 
-    185242794856748_u128, 185750933401563_u128, 186260465621128_u128, 186771395336774_u128, 187283726380000_u128, 
-    187797462593590_u128, 188312607829943_u128, 188829165952923_u128, 189347140836065_u128, 189866536364468_u128, 
-    190387356432921_u128, 190909604947704_u128, 191433285825476_u128, 191958402993238_u128, 192484960389593_u128, 
-    193012961963021_u128, 193542411673718_u128, 194073313491808_u128, 194605671399269_u128, 195139489387979_u128, 
-    195674771461661_u128, 196211521634586_u128, 196749743931710_u128, 197289442389824_u128, 197830621055849_u128, 
-    198373283988766_u128, 198917435257642_u128, 199463078943775_u128, 200010219138553_u128, 200558859945528_u128, 
-    201109005479089_u128, 201660659864507_u128, 202213827239262_u128, 202768511751154_u128, 203324717560381_u128, 
-    203882448837445_u128, 204441709765427_u128, 205002504537711_u128, 205564837360171_u128, 206128712449774_u128, 
-    206694134034650_u128, 207261106355432_u128, 207829633663349_u128, 208399720222274_u128, 208971370306783_u128, 
-    209544588204150_u128, 210119378212372_u128, 210695744642170_u128, 211273691815653_u128, 211853224066334_u128, 
-    212434345740615_u128, 213017061195703_u128, 213601374801774_u128, 214187290939965_u128, 214774814004437_u128, 
-    215363948400403_u128, 215954698545953_u128, 216547068871020_u128, 217141063817161_u128, 217736687839095_u128, 
-    218333945402700_u128, 218932840987070_u128, 219533379082604_u128, 220135564193000_u128, 220739400833233_u128, 
-    221344893531668_u128, 221952046828548_u128, 222560865276240_u128, 223171353440519_u128, 223783515898520_u128, 
-    224397357241102_u128, 225012882070532_u128, 225630095002847_u128, 226249000665543_u128, 226869603699862_u128, 
-    227491908759260_u128, 228115920509592_u128, 228741643630455_u128, 229369082813062_u128, 229998242762770_u128, 
-    230629128196496_u128, 231261743845448_u128, 231896094452394_u128, 232532184774337_u128, 233170019580732_u128, 
-    233809603653704_u128, 234450941789525_u128, 235094038796388_u128, 235738899496887_u128, 236385528725613_u128, 
-    237033931331593_u128, 237684112175941_u128, 238336076134136_u128, 238989828094598_u128, 239645372958656_u128, 
-    240302715642092_u128, 240961861073032_u128, 241622814194219_u128, 242285579960775_u128, 242950163342440_u128, 
-    243616569322136_u128, 244284802895963_u128, 244954869074802_u128, 245626772881935_u128, 246300519355787_u128, 
-    246976113547236_u128, 247653560522269_u128, 248332865359544_u128, 249014033152628_u128, 249697069008704_u128, 
-    250381978048375_u128, 251068765407386_u128, 251757436234283_u128, 252447995693083_u128, 253140448960530_u128, 
-    253834801228877_u128, 254531057703412_u128, 255229223604606_u128, 255929304167018_u128, 256631304638773_u128, 
-    257335230283703_u128, 258041086378730_u128, 258748878216433_u128, 259458611102697_u128, 260170290359209_u128, 
-    260883921320873_u128, 261599509338448_u128, 262317059776766_u128, 263036578014949_u128, 263758069447903_u128, 
-    264481539484057_u128, 265206993548028_u128, 265934437077811_u128, 266663875527802_u128, 267395314365854_u128, 
-    268128759076072_u128, 268864215157036_u128, 269601688121853_u128, 270341183499978_u128, 271082706834609_u128, 
-    271826263685511_u128, 272571859626247_u128, 273319500247167_u128, 274069191152362_u128, 274820937962637_u128, 
-    275574746313596_u128, 276330621855753_u128, 277088570256388_u128, 277848597196781_u128, 278610708375324_u128, 
-    279374909504480_u128, 280141206313861_u128, 280909604547297_u128, 281680109965603_u128, 282452728344867_u128, 
-    283227465476432_u128, 284004327168755_u128, 284783319244745_u128, 285564447544843_u128, 286347717923780_u128, 
-    287133136253983_u128, 287920708422442_u128, 288710440333530_u128, 289502337907316_u128, 290296407079321_u128, 
-    291092653802891_u128, 291891084046081_u128, 292691703794637_u128, 293494519049420_u128, 294299535829146_u128, 
-    295106760167405_u128, 295916198115810_u128, 296727855741865_u128, 297541739129132_u128, 298357854379158_u128, 
-    299176207608763_u128, 299996804952937_u128, 300819652561960_u128, 301644756604536_u128, 302472123264696_u128, 
-    303301758744730_u128, 304133669263387_u128, 304967861055833_u128, 305804340375679_u128, 306643113492061_u128, 
-    307484186692849_u128, 308327566281732_u128, 309173258580914_u128, 310021269928655_u128, 310871606681709_u128, 
-    311724275213853_u128, 312579281915503_u128, 313436633196189_u128, 314296335481168_u128, 315158395215143_u128, 
-    316022818858580_u128, 316889612891513_u128, 317758783809909_u128, 318630338129064_u128, 319504282381407_u128, 
-    320380623116715_u128, 321259366904081_u128, 322140520328979_u128, 323024089996583_u128, 323910082528415_u128, 
-    324798504565855_u128, 325689362766779_u128, 326582663808778_u128, 327478414387063_u128, 328376621214578_u128, 
-    329277291024092_u128, 330180430565139_u128, 331086046607441_u128, 331994145937471_u128, 332904735362161_u128, 
-    333817821705156_u128, 334733411810454_u128, 335651512540055_u128, 336572130774166_u128, 337495273413272_u128, 
-    338420947375008_u128, 339349159597816_u128, 340279917037267_u128, 341213226669743_u128, 342149095489026_u128, 
-    343087530509471_u128, 344028538764165_u128, 344972127304581_u128, 345918303203182_u128, 346867073549962_u128, 
-    347818445456124_u128, 348772426050582_u128, 349729022483460_u128, 350688241922739_u128, 351650091557628_u128, 
-    352614578596286_u128, 353581710265894_u128, 354551493815100_u128, 355523936510461_u128, 356499045640401_u128, 
-    357476828511309_u128, 358457292451548_u128, 359440444807559_u128, 360426292947626_u128, 361414844259496_u128, 
-    362406106150375_u128, 363400086049441_u128, 364396791404280_u128, 365396229684829_u128, 366398408379505_u128, 
-    367403334999135_u128, 368411017073258_u128, 369421462153643_u128, 370434677811973_u128, 371450671640066_u128, 
-    372469451252094_u128, 373491024281126_u128, 374515398383170_u128, 375542581233181_u128, 376572580529020_u128, 
-    377605403987822_u128, 378641059349317_u128, 379679554373968_u128, 380720896842483_u128, 381765094558779_u128, 
-    382812155346070_u128, 383862087050945_u128, 384914897539527_u128, 385970594701257_u128, 387029186446276_u128, 
-    388090680705788_u128, 389155085434370_u128, 390222408606333_u128, 391292658219927_u128, 392365842293191_u128, 
-    393441968868156_u128, 394521046006861_u128, 395603081795119_u128, 396688084340208_u128, 397776061770774_u128, 
-    398867022239480_u128, 399960973919245_u128, 401057925007514_u128, 402157883721991_u128, 403260858304994_u128, 
-    404366857019246_u128, 405475888152048_u128, 406587960012508_u128, 407703080931619_u128, 408821259265165_u128, 
-    409942503389611_u128, 411066821706419_u128, 412194222637984_u128, 413324714631859_u128, 414458306156589_u128, 
-    415595005705730_u128, 416734821795321_u128, 417877762963902_u128, 419023837775207_u128, 420173054814271_u128, 
-    421325422691833_u128, 422480950040002_u128, 423639645516613_u128, 424801517801089_u128, 425966575598505_u128, 
-    427134827636945_u128, 428306282667504_u128, 429480949467163_u128, 430658836834786_u128, 431839953595378_u128, 
-    433024308596080_u128, 434211910710231_u128, 435402768833376_u128, 436596891887388_u128, 437794288817511_u128, 
-    438994968592875_u128, 440198940208897_u128, 441406212683548_u128, 442616795061543_u128, 443830696410189_u128, 
-    445047925823952_u128, 446268492419690_u128, 447492405341370_u128, 448719673757095_u128, 449950306859089_u128, 
-    451184313866868_u128, 452421704022862_u128, 453662486596994_u128, 454906670882197_u128, 456154266199435_u128, 
-    457405281892506_u128, 458659727333021_u128, 459917611917275_u128, 461178945066434_u128, 462443736229369_u128, 
-    463711994878652_u128, 464983730515039_u128, 466258952662730_u128, 467537670874653_u128, 468819894727402_u128, 
-    470105633825641_u128, 471394897799705_u128, 472687696305229_u128, 473984039026323_u128, 475283935671309_u128, 
-    476587395977416_u128, 477894429706012_u128, 479205046647776_u128, 480519256617524_u128, 481837069459201_u128, 
-    483158495042783_u128, 484483543264133_u128, 485812224048569_u128, 487144547345986_u128, 488480523135913_u128, 
-    489820161422713_u128, 491163472240822_u128, 492510465649286_u128, 493861151737045_u128, 495215540619655_u128, 
-    496573642439466_u128, 497935467368721_u128, 499301025604894_u128, 500670327375935_u128, 502043382935278_u128, 
-    503420202566906_u128, 504800796580395_u128, 506185175315651_u128, 507573349140143_u128, 508965328448389_u128, 
-    510361123665929_u128, 511760745244007_u128, 513164203665144_u128, 514571509437817_u128, 515982673102147_u128, 
-    517397705223955_u128, 518816616400777_u128, 520239417257929_u128, 521666118448578_u128, 523096730657751_u128, 
-    524531264596723_u128, 525969731008921_u128, 527412140664313_u128, 528858504365233_u128, 530308832940487_u128, 
-    531763137251282_u128, 533221428187299_u128, 534683716666873_u128, 536150013640813_u128, 537620330087018_u128, 
-    539094677016120_u128, 540573065466165_u128, 542055506508112_u128, 543542011240448_u128, 545032590794497_u128, 
-    546527256330786_u128, 548026019039550_u128, 549528890143757_u128, 551035880894737_u128, 552547002576877_u128, 
-    554062266502931_u128, 555581684019327_u128, 557105266500603_u128, 558633025355047_u128, 560164972020840_u128, 
-    561701117966636_u128, 563241474694694_u128, 564786053736111_u128, 566334866656096_u128, 567887925048843_u128, 
-    569445240543022_u128, 571006824796178_u128, 572572689500333_u128, 574142846378348_u128, 575717307184032_u128, 
-    577296083705892_u128, 578879187761743_u128, 580466631204718_u128, 582058425917185_u128, 583654583817193_u128, 
-    585255116852356_u128, 586860037005348_u128, 588469356290739_u128, 590083086754738_u128, 591701240479088_u128, 
-    593323829575461_u128, 594950866191901_u128, 596582362506344_u128, 598218330733172_u128, 599858783117312_u128, 
-    601503731939446_u128, 603153189512952_u128, 604807168183646_u128, 606465680333755_u128, 608128738376271_u128, 
-    609796354761323_u128, 611468541969694_u128, 613145312519673_u128, 614826678960564_u128, 616512653878558_u128, 
-    618203249893339_u128, 619898479657688_u128, 621598355861813_u128, 623302891227309_u128, 625012098513997_u128, 
-    626725990513086_u128, 628444580053829_u128, 630167879999123_u128, 631895903245653_u128, 633628662728694_u128, 
-    635366171415274_u128, 637108442311011_u128, 638855488454073_u128, 640607322921406_u128, 642363958822429_u128, 
-    644125409305231_u128, 645891687552669_u128, 647662806782225_u128, 649438780250667_u128, 651219621247349_u128, 
-    653005343101565_u128, 654795959175523_u128, 656591482871298_u128, 658391927624244_u128, 660197306909436_u128, 
-    662007634237485_u128, 663822923154536_u128, 665643187246785_u128, 667468440134323_u128, 669298695477584_u128, 
-    671133966970971_u128, 672974268349954_u128, 674819613383793_u128, 676670015882599_u128, 678525489692854_u128, 
-    680386048697485_u128, 682251706820526_u128, 684122478020453_u128, 685998376297503_u128, 687879415686503_u128, 
-    689765610264221_u128, 691656974142486_u128, 693553521474382_u128, 695455266450818_u128, 697362223299734_u128, 
-    699274406291410_u128, 701191829731155_u128, 703114507967237_u128, 705042455383463_u128, 706975686406380_u128, 
-    708914215498547_u128, 710858057165082_u128, 712807225949370_u128, 714761736433172_u128, 716721603241283_u128, 
-    718686841034724_u128, 720657464518355_u128, 722633488433406_u128, 724614927565091_u128, 726601796735358_u128, 
-    728594110809848_u128, 730591884693376_u128, 732595133330081_u128, 734603871708301_u128, 736618114853364_u128, 
-    738637877835603_u128, 740663175762557_u128, 742694023786878_u128, 744730437098901_u128, 746772430933670_u128, 
-    748820020566316_u128, 750873221312493_u128, 752932048532894_u128, 754996517626147_u128, 757066644037304_u128, 
-    759142443249063_u128, 761223930790798_u128, 763311122230112_u128, 765404033180703_u128, 767502679297374_u128, 
-    769607076276105_u128, 771717239859300_u128, 773833185828427_u128, 775954930011996_u128, 778082488277547_u128, 
-    780215876540206_u128, 782355110754531_u128, 784500206921864_u128, 786651181086183_u128, 788808049333370_u128, 
-    790970827796935_u128, 793139532650323_u128, 795314180115369_u128, 797494786453777_u128, 799681367976145_u128, 
-    801873941033160_u128, 804072522023931_u128, 806277127390858_u128, 808487773619413_u128, 810704477243892_u128, 
-    812927254839587_u128, 815156123031087_u128, 817391098484308_u128, 819632197914751_u128, 821879438079426_u128, 
-    824132835784713_u128, 826392407881300_u128, 828658171264368_u128, 830930142878651_u128, 833208339711372_u128, 
-    835492778799814_u128, 837783477223970_u128, 840080452114441_u128, 842383720644389_u128, 844693300037695_u128, 
-    847009207563386_u128, 849331460536365_u128, 851660076322215_u128, 853995072329846_u128, 856336466019906_u128, 
-    858684274896295_u128, 861038516515198_u128, 863399208476162_u128, 865766368430799_u128, 868140014076996_u128, 
-    870520163159536_u128, 872906833475374_u128, 875300042865522_u128, 877699809224221_u128, 880106150490239_u128, 
-    882519084655101_u128, 884938629756573_u128, 887364803883208_u128, 889797625172241_u128, 892237111810858_u128, 
-    894683282035070_u128, 897136154132188_u128, 899595746437657_u128, 902062077337782_u128, 904535165269296_u128, 
-    907015028718596_u128, 909501686222975_u128, 911995156370190_u128, 914495457798608_u128, 917002609197347_u128, 
-    919516629307301_u128, 922037536919315_u128, 924565350876009_u128, 927100090071777_u128, 929641773452055_u128, 
-    932190420014057_u128, 934746048807130_u128, 937308678932034_u128, 939878329542028_u128, 942455019843165_u128, 
-    945038769092398_u128, 947629596600420_u128, 950227521730277_u128, 952832563897667_u128, 955444742571586_u128, 
-    958064077273752_u128, 960690587579260_u128, 963324293116504_u128, 965965213568350_u128, 968613368669580_u128, 
-    971268778210317_u128, 973931462033553_u128, 976601440037546_u128, 979278732173785_u128, 981963358448881_u128, 
-    984655338923476_u128, 987354693712973_u128, 990061442988696_u128, 992775606975346_u128, 995497205953549_u128, 
-    998226260259343_u128, 1000962790284110_u128, 1003706816474717_u128, 1006458359334029_u128, 1009217439420397_u128, 
-    1011984077348390_u128, 1014758293789882_u128, 1017540109470924_u128, 1020329545175750_u128, 1023126621744587_u128, 
-    1025931360074975_u128, 1028743781120737_u128, 1031563905893589_u128, 1034391755461754_u128, 1037227350951271_u128, 
-    1040070713546651_u128, 1042921864488186_u128, 1045780825075657_u128, 1048647616666300_u128, 1051522260675825_u128, 
-    1054404778577974_u128, 1057295191905402_u128, 1060193522249236_u128, 1063099791259293_u128, 1066014020645319_u128, 
-    1068936232174805_u128, 1071866447675392_u128, 1074804689034499_u128, 1077750978198241_u128, 1080705337173679_u128, 
-    1083667788026930_u128, 1086638352884913_u128, 1089617053933822_u128, 1092603913422402_u128, 1095598953657503_u128, 
-    1098602197008311_u128, 1101613665904521_u128, 1104633382836997_u128, 1107661370358206_u128, 1110697651081416_u128, 
-    1113742247681938_u128, 1116795182896687_u128, 1119856479525492_u128, 1122926160428546_u128, 1126004248529396_u128, 
-    1129090766813994_u128, 1132185738331058_u128, 1135289186191496_u128, 1138401133569784_u128, 1141521603702875_u128, 
-    1144650619892604_u128, 1147788205501972_u128, 1150934383959299_u128, 1154089178755964_u128, 1157252613447862_u128, 
-    1160424711654750_u128, 1163605497060684_u128, 1166794993414380_u128, 1169993224528855_u128, 1173200214283459_u128, 
-    1176415986620316_u128, 1179640565548100_u128, 1182873975140952_u128, 1186116239538258_u128, 1189367382945009_u128, 
-    1192627429632609_u128, 1195896403937999_u128, 1199174330264453_u128, 1202461233083041_u128, 1205757136929205_u128, 
-    1209062066406905_u128, 1212376046186295_u128, 1215699101004959_u128, 1219031255668271_u128, 1222372535048310_u128, 
-    1225722964085600_u128, 1229082567787588_u128, 1232451371231987_u128, 1235829399562194_u128, 1239216677991219_u128, 
-    1242613231801102_u128, 1246019086342189_u128, 1249434267033782_u128, 1252858799364724_u128, 1256292708893327_u128, 
-    1259736021246352_u128, 1263188762123086_u128, 1266650957289007_u128, 1270122632582703_u128, 1273603813911720_u128, 
-    1277094527254528_u128, 1280594798660230_u128, 1284104654248928_u128, 1287624120211647_u128, 1291153222810410_u128, 
-    1294691988380494_u128, 1298240443326063_u128, 1301798614125117_u128, 1305366527327018_u128, 1308944209553653_u128, 
-    1312531687499220_u128, 1316128987930514_u128, 1319736137687294_u128, 1323353163681992_u128, 1326980092901676_u128, 
-    1330616952404778_u128, 1334263769324439_u128, 1337920570867857_u128, 1341587384315776_u128, 1345264237023505_u128, 
-    1348951156420407_u128, 1352648170010924_u128, 1356355305373696_u128, 1360072590163968_u128, 1363800052109876_u128, 
-    1367537719016598_u128, 1371285618764604_u128, 1375043779310023_u128, 1378812228685441_u128, 1382590994999104_u128, 
-    1386380106436517_u128, 1390179591258330_u128, 1393989477805151_u128, 1397809794490914_u128, 1401640569809069_u128, 
-    1405481832330253_u128, 1409333610702511_u128, 1413195933652018_u128, 1417068829983084_u128, 1420952328578152_u128, 
-    1424846458397873_u128, 1428751248483724_u128, 1432666727952984_u128, 1436592926004274_u128, 1440529871915071_u128, 
-    1444477595042301_u128, 1448436124823133_u128, 1452405490774252_u128, 1456385722492445_u128, 1460376849657073_u128, 
-    1464378902024756_u128, 1468391909435565_u128, 1472415901809668_u128, 1476450909149011_u128, 1480496961536801_u128, 
-    1484554089138385_u128, 1488622322200667_u128, 1492701691052687_u128, 1496792226107592_u128, 1500893957858408_u128, 
-    1505006916883214_u128, 1509131133842227_u128, 1513266639479548_u128, 1517413464622366_u128, 1521571640181974_u128, 
-    1525741197153621_u128, 1529922166616227_u128, 1534114579735074_u128, 1538318467757652_u128, 1542533862017524_u128, 
-    1546760793933887_u128, 1550999295010479_u128, 1555249396836961_u128, 1559511131089287_u128, 1563784529528604_u128, 
-    1568069624002496_u128, 1572366446447019_u128, 1576675028881899_u128, 1580995403415624_u128, 1585327602243848_u128, 
-    1589671657649602_u128, 1594027602003303_u128, 1598395467763693_u128, 1602775287477553_u128, 1607167093779406_u128, 
-    1611570919394873_u128, 1615986797134989_u128, 1620414759902030_u128, 1624854840687547_u128, 1629307072572147_u128, 
-    1633771488726730_u128, 1638248122411836_u128, 1642737006978953_u128, 1647238175868919_u128, 1651751662616139_u128, 
-    1656277500842402_u128, 1660815724263506_u128, 1665366366685539_u128, 1669929462007143_u128, 1674505044218347_u128, 
-    1679093147402021_u128, 1683693805733078_u128, 1688307053479490_u128, 1692932925003239_u128, 1697571454757401_u128, 
-    1702222677290370_u128, 1706886627243749_u128, 1711563339353220_u128, 1716252848448762_u128, 1720955189454875_u128, 
-    1725670397391230_u128, 1730398507371360_u128, 1735139554606812_u128, 1739893574401247_u128, 1744660602156409_u128, 
-    1749440673369365_u128, 1754233823633881_u128, 1759040088640134_u128, 1763859504174931_u128, 1768692106122799_u128, 
-    1773537930464460_u128, 1778397013280904_u128, 1783269390747493_u128, 1788155099140223_u128, 1793054174832594_u128, 
-    1797966654297211_u128, 1802892574105126_u128, 1807831970927374_u128, 1812784881533219_u128, 1817751342794087_u128, 
-    1822731391678400_u128, 1827725065256960_u128, 1832732400700184_u128, 1837753435279851_u128, 1842788206368668_u128, 
-    1847836751440554_u128, 1852899108071378_u128, 1857975313938222_u128, 1863065406822300_u128, 1868169424604437_u128, 
-    1873287405270466_u128, 1878419386907871_u128, 1883565407708556_u128, 1888725505966808_u128, 1893899720082063_u128, 
-    1899088088556867_u128, 1904290649998040_u128, 1909507443118862_u128, 1914738506735434_u128, 1919983879769875_u128, 
-    1925243601249894_u128, 1930517710309221_u128, 1935806246187608_u128, 1941109248230686_u128, 1946426755891418_u128, 
-    1951758808728790_u128, 1957105446411596_u128, 1962466708711888_u128, 1967842635513273_u128, 1973233266805236_u128, 
-    1978638642687071_u128, 1984058803365696_u128, 1989493789157840_u128, 1994943640488582_u128, 2000408397893104_u128, 
-    2005888102017706_u128, 2011382793616024_u128, 2016892513554122_u128, 2022417302808166_u128, 2027957202465587_u128, 
-    2033512253725372_u128, 2039082497897194_u128, 2044667976403444_u128, 2050268730778364_u128, 2055884802670228_u128, 
-    2061516233837263_u128, 2067163066152605_u128, 2072825341603130_u128, 2078503102287999_u128, 2084196390422591_u128, 
-    2089905248333842_u128, 2095629718465777_u128, 2101369843375581_u128, 2107125665738114_u128, 2112897228340811_u128, 
-    2118684574088342_u128, 2124487746002179_u128, 2130306787219283_u128, 2136141740993995_u128, 2141992650697605_u128, 
-    2147859559819072_u128, 2153742511963577_u128, 2159641550858048_u128, 2165556720343740_u128, 2171488064381911_u128, 
-    2177435627054111_u128, 2183399452559860_u128, 2189379585218678_u128, 2195376069470235_u128, 2201388949873914_u128, 
-    2207418271109975_u128, 2213464077981450_u128, 2219526415409042_u128, 2225605328437393_u128, 2231700862232749_u128, 
-    2237813062083251_u128, 2243941973399524_u128, 2250087641715543_u128, 2256250112688201_u128, 2262429432096577_u128, 
-    2268625645848203_u128, 2274838799968283_u128, 2281068940610910_u128, 2287316114054258_u128, 2293580366700871_u128, 
-    2299861745079419_u128, 2306160295844541_u128, 2312476065775397_u128, 2318809101782075_u128, 2325159450895829_u128, 
-    2331527160278985_u128, 2337912277219701_u128, 2344314849134874_u128, 2350734923569270_u128, 2357172548196252_u128, 
-    2363627770818214_u128, 2370100639365566_u128, 2376591201901973_u128, 2383099506616201_u128, 2389625601829983_u128, 
-    2396169535995831_u128, 2402731357696018_u128, 2409311115645783_u128, 2415908858689690_u128, 2422524635807016_u128, 
-    2429158496106359_u128, 2435810488833802_u128, 2442480663362564_u128, 2449169069203347_u128, 2455875755999669_u128, 
-    2462600773528890_u128, 2469344171702791_u128, 2476106000568447_u128, 2482886310308086_u128, 2489685151238644_u128, 
-    2496502573815853_u128, 2503338628627242_u128, 2510193366400878_u128, 2517066837999543_u128, 2523959094424662_u128, 
-    2530870186814996_u128, 2537800166447368_u128, 2544749084737390_u128, 2551716993238011_u128, 2558703943645630_u128, 
-    2565709987790921_u128, 2572735177647281_u128, 2579779565328356_u128, 2586843203088475_u128, 2593926143322212_u128, 
-    2601028438567012_u128, 2608150141500712_u128, 2615291304943434_u128, 2622451981861082_u128, 2629632225357187_u128, 
-    2636832088682077_u128, 2644051625229680_u128, 2651290888536646_u128, 2658549932285697_u128, 2665828810303005_u128, 
-    2673127576560963_u128, 2680446285176431_u128, 2687784990415840_u128, 2695143746686448_u128, 2702522608546247_u128, 
-    2709921630699884_u128, 2717340867998224_u128, 2724780375441990_u128, 2732240208178417_u128, 2739720421505033_u128, 
-    2747221070866896_u128, 2754742211861836_u128, 2762283900233318_u128, 2769846191877725_u128, 2777429142841881_u128, 
-    2785032809323488_u128, 2792657247672001_u128, 2800302514388482_u128, 2807968666126909_u128, 2815655759692431_u128, 
-    2823363852047483_u128, 2831093000302464_u128, 2838843261725354_u128, 2846614693737485_u128, 2854407353915000_u128, 
-    2862221299989727_u128, 2870056589848448_u128, 2877913281534507_u128, 2885791433245618_u128, 2893691103342027_u128, 
-    2901612350333984_u128, 2909555232893392_u128, 2917519809850318_u128, 2925506140192112_u128, 2933514283065453_u128, 
-    2941544297777361_u128, 2949596243791562_u128, 2957670180738098_u128, 2965766168400218_u128, 2973884266726905_u128, 
-    2982024535827196_u128, 2990187035972512_u128, 2998371827596074_u128, 3006578971294215_u128, 3014808527826088_u128, 
-    3023060558114106_u128, 3031335123247723_u128, 3039632284475865_u128, 3047952103216105_u128, 3056294641049563_u128, 
-    3064659959724259_u128, 3073048121153945_u128, 3081459187419127_u128, 3089893220766624_u128, 3098350283611322_u128, 
-    3106830438538936_u128, 3115333748297855_u128, 3123860275809339_u128, 3132410084163438_u128, 3140983236619141_u128, 
-    3149579796605829_u128, 3158199827724442_u128, 3166843393746170_u128, 3175510558612302_u128, 3184201386441223_u128, 
-    3192915941517776_u128, 3201654288302298_u128, 3210416491429160_u128, 3219202615705454_u128, 3228012726113475_u128, 
-    3236846887809115_u128, 3245705166124919_u128, 3254587626566160_u128, 3263494334820297_u128, 3272425356744312_u128, 
-    3281380758376065_u128, 3290360605930509_u128, 3299364965800127_u128, 3308393904556241_u128, 3317447488949016_u128, 
-    3326525785907455_u128, 3335628862540422_u128, 3344756786140720_u128, 3353909624174892_u128, 3363087444297063_u128, 
-    3372290314339760_u128, 3381518302319156_u128, 3390771476433906_u128, 3400049905065583_u128, 3409353656779698_u128, 
-    3418682800324826_u128, 3428037404638285_u128, 3437417538836380_u128, 3446823272225469_u128, 3456254674295849_u128, 
-    3465711814726123_u128, 3475194763380727_u128, 3484703590312254_u128, 3494238365760735_u128, 3503799160154649_u128, 
-    3513386044115299_u128, 3522999088446905_u128, 3532638364148112_u128, 3542303942407618_u128, 3551995894604468_u128, 
-    3561714292309652_u128, 3571459207286256_u128, 3581230711489604_u128, 3591028877067114_u128, 3600853776364266_u128, 
-    3610705481913976_u128, 3620584066448243_u128, 3630489602893196_u128, 3640422164369826_u128, 3650381824196025_u128, 
-    3660368655886290_u128, 3670382733151437_u128, 3680424129899761_u128, 3690492920242285_u128, 3700589178480813_u128, 
-    3710712979122494_u128, 3720864396871671_u128, 3731043506633659_u128, 3741250383514173_u128, 3751485102820775_u128, 
-    3761747740062005_u128, 3772038370952042_u128, 3782357071401378_u128, 3792703917530517_u128, 3803078985660061_u128, 
-    3813482352316834_u128, 3823914094231260_u128, 3834374288341438_u128, 3844863011789355_u128, 3855380341923803_u128, 
-    3865926356305328_u128, 3876501132694578_u128, 3887104749065411_u128, 3897737283599364_u128, 3908398814687687_u128, 
-    3919089420931054_u128, 3929809181140437_u128, 3940558174338271_u128, 3951336479756706_u128, 3962144176846347_u128, 
-    3972981345261104_u128, 3983848064873922_u128, 3994744415770669_u128, 4005670478250712_u128, 4016626332829250_u128, 
-    4027612060235858_u128, 4038627741416816_u128, 4049673457533656_u128, 4060749289969564_u128, 4071855320318607_u128, 
-    4082991630397385_u128, 4094158302240659_u128, 4105355418102224_u128, 4116583060456079_u128, 4127841311996131_u128, 
-    4139130255638528_u128, 4150449974519325_u128, 4161800552000899_u128, 4173182071662037_u128, 4184594617310758_u128, 
-    4196038272974993_u128, 4207513122910444_u128, 4219019251596219_u128, 4230556743736870_u128, 4242125684264723_u128, 
-    4253726158336382_u128, 4265358251342343_u128, 4277022048892136_u128, 4288717636830058_u128, 4300445101228469_u128, 
-    4312204528388669_u128, 4323996004843228_u128, 4335819617356568_u128, 4347675452922338_u128, 4359563598768082_u128, 
-    4371484142357854_u128, 4383437171379987_u128, 4395422773765734_u128, 4407441037676744_u128, 4419492051511177_u128, 
-    4431575903902542_u128, 4443692683721441_u128, 4455842480075571_u128, 4468025382308268_u128, 4480241480008117_u128, 
-    4492490862992643_u128, 4504773621326370_u128, 4517089845311497_u128, 4529439625490232_u128, 4541823052649449_u128, 
-    4554240217813702_u128, 4566691212254540_u128, 4579176127482065_u128, 4591695055258619_u128, 4604248087580144_u128, 
-    4616835316696278_u128, 4629456835098997_u128, 4642112735526985_u128, 4654803110967378_u128, 4667528054653729_u128, 
-    4680287660068627_u128, 4693082020942243_u128, 4705911231261063_u128, 4718775385251003_u128, 4731674577396331_u128, 
-    4744608902432977_u128, 4757578455345325_u128, 4770583331374949_u128, 4783623626013336_u128, 4796699435007709_u128, 
-    4809810854363937_u128, 4822957980335764_u128, 4836140909438785_u128, 4849359738444623_u128, 4862614564380927_u128, 
-    4875905484535162_u128, 4889232596451983_u128, 4902595997937609_u128, 4915995787055741_u128, 4929432062136884_u128, 
-    4942904921764368_u128, 4956414464790364_u128, 4969960790326568_u128, 4983543997750896_u128, 4997164186703699_u128, 
-    5010821457090096_u128, 5024515909082299_u128, 5038247643115538_u128, 5052016759900297_u128, 5065823360403374_u128, 
-    5079667545866531_u128, 5093549417800663_u128, 5107469077984051_u128, 5121426628467025_u128, 5135422171570795_u128, 
-    5149455809888038_u128, 5163527646282311_u128, 5177637783897083_u128, 5191786326139135_u128, 5205973376698941_u128, 
-    5220199039537571_u128, 5234463418893089_u128, 5248766619281435_u128, 5263108745495256_u128, 5277489902604780_u128, 
-    5291910195957523_u128, 5306369731189073_u128, 5320868614202400_u128, 5335406951190583_u128, 5349984848625446_u128, 
-    5364602413261637_u128, 5379259752136922_u128, 5393956972573637_u128, 5408694182177523_u128, 5423471488838023_u128, 
-    5438289000739344_u128, 5453146826340361_u128, 5468045074394426_u128, 5482983853943248_u128, 5497963274314274_u128, 
-    5512983445128260_u128, 5528044476294904_u128, 5543146478014300_u128, 5558289560777814_u128, 5573473835376531_u128, 
-    5588699412884066_u128, 5603966404675793_u128, 5619274922419232_u128, 5634625078079288_u128, 5650016983915634_u128, 
-    5665450752486204_u128, 5680926496647195_u128, 5696444329549860_u128, 5712004364655074_u128, 5727606715710032_u128, 
-    5743251496773004_u128, 5758938822200525_u128, 5774668806651758_u128, 5790441565090247_u128, 5806257212781873_u128, 
-    5822115865300100_u128, 5838017638519276_u128, 5853962648629485_u128, 5869951012115579_u128, 5885982845779021_u128, 
-    5902058266728566_u128, 5918177392379968_u128, 5934340340462096_u128, 5950547229013734_u128, 5966798176383285_u128, 
-    5983093301240427_u128, 5999432722556009_u128, 6015816559623901_u128, 6032244932049633_u128, 6048717959756513_u128, 
-    6065235762982420_u128, 6081798462284176_u128, 6098406178536671_u128, 6115059032932279_u128, 6131757146989017_u128, 
-    6148500642537436_u128, 6165289641733725_u128, 6182124267057097_u128, 6199004641308909_u128, 6215930887614701_u128, 
-    6232903129424491_u128, 6249921490513647_u128, 6266986094982886_u128, 6284097067268469_u128, 6301254532120358_u128, 
-    6318458614628425_u128, 6335709440208186_u128, 6353007134606914_u128, 6370351823901892_u128, 6387743634505073_u128, 
-    6405182693158420_u128, 6422669126938861_u128, 6440203063262941_u128, 6457784629873723_u128, 6475413954857679_u128, 
-    6493091166634494_u128, 6510816393965805_u128, 6528589765950250_u128, 6546411412024343_u128, 6564281461969169_u128, 
-    6582200045903107_u128, 6600167294294935_u128, 6618183337945771_u128, 6636248308007422_u128, 6654362335975688_u128, 
-    6672525553692981_u128, 6690738093345706_u128, 6709000087471536_u128, 6727311668953598_u128, 6745672971023374_u128, 
-    6764084127272068_u128, 6782545271627594_u128, 6801056538378167_u128, 6819618062165898_u128, 6838229977981838_u128, 
-    6856892421175888_u128, 6875605527451257_u128, 6894369432867963_u128, 6913184273841084_u128, 6932050187153863_u128, 
-    6950967309932954_u128, 6969935779676670_u128, 6988955734239551_u128, 7008027311840224_u128, 7027150651057329_u128, 
-    7046325890835925_u128, 7065553170483118_u128, 7084832629671563_u128, 7104164408447322_u128, 7123548647210644_u128, 
-    7142985486739556_u128, 7162475068177922_u128, 7182017533041559_u128, 7201613023214742_u128, 7221261680954573_u128, 
-    7240963648891854_u128, 7260719070027885_u128, 7280528087747279_u128, 7300390845796989_u128, 7320307488311360_u128, 
-    7340278159795814_u128, 7360303005137925_u128, 7380382169603914_u128, 7400515798837494_u128, 7420704038866853_u128, 
-    7440947036099416_u128, 7461244937332909_u128, 7481597889737305_u128, 7502006040875208_u128, 7522469538696033_u128, 
-    7542988531531922_u128, 7563563168104739_u128, 7584193597525488_u128, 7604879969293726_u128, 7625622433305723_u128, 
-    7646421139839313_u128, 7667276239570207_u128, 7688187883569079_u128, 7709156223301568_u128, 7730181410623615_u128, 
-    7751263597794281_u128, 7772402937466425_u128, 7793599582689617_u128, 7814853686922372_u128, 7836165404011761_u128, 
-    7857534888211470_u128, 7878962294179467_u128, 7900447776974511_u128, 7921991492061976_u128, 7943593595311518_u128, 
-    7965254242997662_u128, 7986973591803877_u128, 8008751798827236_u128, 8030589021565019_u128, 8052485417929277_u128, 
-    8074441146243919_u128, 8096456365247620_u128, 8118531234086839_u128, 8140665912327464_u128, 8162860559948987_u128, 
-    8185115337343923_u128, 8207430405337033_u128, 8229805925149792_u128, 8252242058438829_u128, 8274738967276712_u128, 
-    8297296814157769_u128, 8319915761999836_u128, 8342595974143090_u128, 8365337614354714_u128, 8388140846822486_u128, 
-    8411005836175169_u128, 8433932747448138_u128, 8456921746123580_u128, 8479972998104278_u128, 8503086669727007_u128, 
-    8526262927763119_u128, 8549501939410971_u128, 8572803872311068_u128, 8596168894529172_u128, 8619597174581348_u128, 
-    8643088881408337_u128, 8666644184393027_u128, 8690263253359875_u128, 8713946258573740_u128, 8737693370740463_u128, 
-    8761504761007454_u128, 8785380600969516_u128, 8809321062658940_u128, 8833326318568225_u128, 8857396541620370_u128, 
-    8881531905195668_u128, 8905732583122389_u128, 8929998749680271_u128, 8954330579598195_u128, 8978728248057674_u128, 
-    9003191930696354_u128, 9027721803600436_u128, 9052318043326818_u128, 9076980826870470_u128, 9101710331694140_u128, 
-    9126506735720202_u128, 9151370217330078_u128, 9176300955364812_u128, 9201299129130316_u128, 9226364918395044_u128, 
-    9251498503389984_u128, 9276700064822650_u128, 9301969783850854_u128, 9327307842111260_u128, 9352714421707152_u128, 
-    9378189705214252_u128, 9403733875676646_u128, 9429347116612612_u128, 9455029612014032_u128, 9480781546345230_u128, 
-    9506603104558120_u128, 9532494472063076_u128, 9558455834761552_u128, 9584487379032110_u128, 9610589291733320_u128, 
-    9636761760204354_u128, 9663004972270224_u128, 9689319116234206_u128, 9715704380899400_u128, 9742160955534358_u128, 
-    9768689029909782_u128, 9795288794281046_u128, 9821960439393448_u128, 9848704156483366_u128, 9875520137281172_u128, 
-    9902408574007742_u128, 9929369659377364_u128, 9956403586611718_u128, 9983510549412504_u128, 10010690741989976_u128, 
-    10037944359051300_u128, 10065271595805210_u128, 10092672647960840_u128, 10120147711731230_u128, 10147696983832730_u128, 
-    10175320661485012_u128, 10203018942426790_u128, 10230792024882034_u128, 10258640107603086_u128, 10286563389843848_u128, 
-    10314562071370866_u128, 10342636352465068_u128, 10370786433918276_u128, 10399012517040766_u128, 10427314803651962_u128, 
-    10455693496104896_u128, 10484148797250674_u128, 10512680910472842_u128, 10541290039674580_u128, 10569976389277524_u128, 
-    10598740164232260_u128, 10627581570010172_u128, 10656500812610418_u128, 10685498098555872_u128, 10714573634911746_u128, 
-    10743727629250652_u128, 10772960289694534_u128, 10802271824888464_u128, 10831662444016362_u128, 10861132356792848_u128, 
-    10890681773471972_u128, 10920310904841394_u128, 10950019962227042_u128, 10979809157508846_u128, 11009678703084030_u128, 
-    11039628811908474_u128, 11069659697477496_u128, 11099771573831088_u128, 11129964655558000_u128, 11160239157789328_u128, 
-    11190595296210162_u128, 11221033287047942_u128, 11251553347101000_u128, 11282155693691954_u128, 11312840544716646_u128, 
-    11343608118623164_u128, 11374458634410102_u128, 11405392311641702_u128, 11436409370435620_u128, 11467510031472248_u128, 
-    11498694515989472_u128, 11529963045802476_u128, 11561315843270538_u128, 11592753131331402_u128, 11624275133486712_u128, 
-    11655882073806092_u128, 11687574176929474_u128, 11719351668066514_u128, 11751214773001256_u128, 11783163718085146_u128, 
-    11815198730259734_u128, 11847320037022906_u128, 11879527866462080_u128, 11911822447241388_u128, 11944204008605178_u128, 
-    11976672780376838_u128, 12009228992967548_u128, 12041872877368116_u128, 12074604665154218_u128, 12107424588500974_u128, 
-    12140332880152058_u128, 12173329773451746_u128, 12206415502337930_u128, 12239590301333950_u128, 12272854405560838_u128, 
-    12306208050733820_u128, 12339651473161148_u128, 12373184909762742_u128, 12406808598036990_u128, 12440522776096276_u128, 
-    12474327682650676_u128, 12508223557017854_u128, 12542210639111418_u128, 12576289169461886_u128, 12610459389198628_u128, 
-    12644721540060938_u128, 12679075864410266_u128, 12713522605199342_u128, 12748062006008290_u128, 12782694311025996_u128, 
-    12817419765061750_u128, 12852238613536514_u128, 12887151102494566_u128, 12922157478595352_u128, 12957257989119890_u128, 
-    12992452881988248_u128, 13027742405715268_u128, 13063126809463576_u128, 13098606343016212_u128, 13134181256783608_u128, 
-    13169851801809424_u128, 13205618229763550_u128, 13241480792954342_u128, 13277439744315810_u128, 13313495337437320_u128, 
-    13349647826515832_u128, 13385897466408906_u128, 13422244512607328_u128, 13458689221240932_u128, 13495231849086176_u128, 
-    13531872653561474_u128, 13568611892731286_u128, 13605449825303776_u128, 13642386710651208_u128, 13679422808771498_u128, 
-    13716558380330734_u128, 13753793686643962_u128, 13791128989678670_u128, 13828564552060618_u128, 13866100637072098_u128, 
-    13903737508654248_u128, 13941475431403574_u128, 13979314670594658_u128, 14017255492143458_u128, 14055298162641688_u128, 
-    14093442949348648_u128, 14131690120185992_u128, 14170039943751124_u128, 14208492689303798_u128, 14247048626782426_u128, 
-    14285708026791272_u128, 14324471160629566_u128, 14363338300243750_u128, 14402309718278144_u128, 14441385688051074_u128, 
-    14480566483561276_u128, 14519852379493718_u128, 14559243651210280_u128, 14598740574767240_u128, 14638343426895454_u128, 
-    14678052485037644_u128, 14717868027297136_u128, 14757790332484466_u128, 14797819680108052_u128, 14837956350356722_u128, 
-    14878200624128838_u128, 14918552783012496_u128, 14959013109296006_u128, 14999581885967892_u128, 15040259396732046_u128, 
-    15081045925969264_u128, 15121941758790860_u128, 15162947180999038_u128, 15204062479112528_u128, 15245287940360766_u128, 
-    15286623852675728_u128, 15328070504714076_u128, 15369628185831524_u128, 15411297186128272_u128, 15453077796384932_u128, 
-    15494970308126602_u128, 15536975013592578_u128, 15579092205743346_u128, 15621322178262904_u128, 15663665225564596_u128, 
-    15706121642777132_u128, 15748691725785352_u128, 15791375771166164_u128, 15834174076258440_u128, 15877086939114082_u128, 
-    15920114658537636_u128, 15963257534056002_u128, 16006515865940568_u128, 16049889955201388_u128, 16093380103584846_u128, 
-    16136986613598126_u128, 16180709788462612_u128, 16224549932170972_u128, 16268507349451044_u128, 16312582345782140_u128, 
-    16356775227398554_u128, 16401086301281394_u128, 16445515875165580_u128, 16490064257541006_u128, 16534731757671182_u128, 
-    16579518685550128_u128, 16624425351946640_u128, 16669452068396146_u128, 16714599147187878_u128, 16759866901384688_u128, 
-    16805255644805566_u128, 16850765692050108_u128, 16896397358471724_u128, 16942150960224228_u128, 16988026814198944_u128, 
-    17034025238082938_u128, 17080146550335734_u128, 17126391070193964_u128, 17172759117676034_u128, 17219251013579790_u128, 
-    17265867079482518_u128, 17312607637747940_u128, 17359473011544846_u128, 17406463524792348_u128, 17453579502226272_u128, 
-    17500821269366556_u128, 17548189152528884_u128, 17595683478813046_u128, 17643304576128560_u128, 17691052773173712_u128, 
-    17738928399440208_u128, 17786931785249296_u128, 17835063261686516_u128, 17883323160663454_u128, 17931711814895606_u128, 
-    17980229557900046_u128, 18028876724014068_u128, 18077653648369544_u128, 18126560666929072_u128, 18175598116448648_u128, 
-    18224766334532468_u128, 18274065659560664_u128, 18323496430762720_u128, 18373058988180184_u128, 18422753672672488_u128, 
-    18472580825933268_u128, 18522540790469392_u128, 18572633909630076_u128, 18622860527574276_u128, 18673220989320780_u128, 
-    18723715640692280_u128, 18774344828354996_u128, 18825108899817500_u128, 18876008203420232_u128, 18927043088347156_u128, 
-    18978213904614104_u128, 19029521003094416_u128, 19080964735482812_u128, 19132545454358308_u128, 19184263513105004_u128, 
-    19236119265978468_u128, 19288113068085956_u128, 19340245275382908_u128, 19392516244678756_u128, 19444926333640448_u128, 
-    19497475900791268_u128, 19550165305530632_u128, 19602994908087508_u128, 19655965069575164_u128, 19709076151973680_u128, 
-    19762328518115984_u128, 19815722531718140_u128, 19869258557350216_u128, 19922936960471240_u128, 19976758107391920_u128, 
-    20030722365332884_u128, 20084830102351308_u128, 20139081687401468_u128, 20193477490327772_u128, 20248017881833288_u128, 
-    20302703233525204_u128, 20357533917891496_u128, 20412510308292800_u128, 20467632778992680_u128, 20522901705158820_u128, 
-    20578317462812900_u128, 20633880428901680_u128, 20689590981256224_u128, 20745449498608200_u128, 20801456360585224_u128, 
-    20857611947719028_u128, 20913916641440780_u128, 20970370824084592_u128, 21026974878908488_u128, 21083729190046632_u128, 
-    21140634142568756_u128, 21197690122439376_u128, 21254897516550408_u128, 21312256712696720_u128, 21369768099598244_u128, 
-    21427432066884860_u128, 21485249005106844_u128, 21543219305759376_u128, 21601343361221920_u128, 21659621564823488_u128, 
-    21718054310818176_u128, 21776641994383980_u128, 21835385011634468_u128, 21894283759605952_u128, 21953338636279624_u128, 
-    22012550040559432_u128, 22071918372315172_u128, 22131444032313752_u128, 22191127422293772_u128, 22250968944923552_u128, 
-    22310969003823304_u128, 22371128003551124_u128, 22431446349621640_u128, 22491924448490872_u128, 22552562707564388_u128, 
-    22613361535229916_u128, 22674321340780452_u128, 22735442534503984_u128, 22796725527631044_u128, 22858170732355692_u128, 
-    22919778561837848_u128, 22981549430192792_u128, 23043483752507492_u128, 23105581944827776_u128, 23167844424194456_u128, 
-    23230271608578084_u128, 23292863916951180_u128, 23355621769248624_u128, 23418545586389792_u128, 23481635790261088_u128, 
-    23544892803735736_u128, 23608317050665624_u128, 23671908955880160_u128, 23735668945220040_u128, 23799597445466176_u128, 
-    23863694884425928_u128, 23927961690879500_u128, 23992398294604404_u128, 24057005126370808_u128, 24121782617940368_u128, 
-    24186731202076708_u128, 24251851312532612_u128, 24317143384089632_u128, 24382607852489352_u128, 24448245154505616_u128, 
-    24514055727910748_u128, 24580040011483708_u128, 24646198445017080_u128, 24712531469304244_u128, 24779039526155716_u128, 
-    24845723058422416_u128, 24912582509918800_u128, 24979618325520712_u128, 25046830951103640_u128, 25114220833573000_u128, 
-    25181788420854836_u128, 25249534161896952_u128, 25317458506679436_u128, 25385561906198320_u128, 25453844812514516_u128, 
-    25522307678672288_u128, 25590950958778432_u128, 25659775107975524_u128, 25728780582431404_u128, 25797967839362488_u128, 
-    25867337337022128_u128, 25936889534706412_u128, 26006624892746028_u128, 26076543872554036_u128, 26146646936539628_u128, 
-    26216934548188548_u128, 26287407172043260_u128, 26358065273683168_u128, 26428909319756040_u128, 26499939777954740_u128, 
-    26571157117037008_u128, 26642561806811500_u128, 26714154318179716_u128, 26785935123052116_u128, 26857904694450660_u128, 
-    26930063506438880_u128, 27002412034161524_u128, 27074950753828208_u128, 27147680142722772_u128, 27220600679196264_u128, 
-    27293712842675104_u128, 27367017113690220_u128, 27440513973803636_u128, 27514203905694688_u128, 27588087393106440_u128, 
-    27662164920872468_u128, 27736436974916876_u128, 27810904042240300_u128, 27885566610939720_u128, 27960425170188660_u128, 
-    28035480210298924_u128, 28110732222604100_u128, 28186181699593540_u128, 28261829134822640_u128, 28337675022961796_u128, 
-    28413719859771912_u128, 28489964142120724_u128, 28566408367985132_u128, 28643053036429056_u128, 28719898647667524_u128, 
-    28796945702966468_u128, 28874194704742920_u128, 28951646156511424_u128, 29029300562909668_u128, 29107158429679836_u128, 
-    29185220263696576_u128, 29263486572941368_u128, 29341957866507176_u128, 29420634654661380_u128, 29499517448719928_u128, 
-    29578606761177836_u128, 29657903105643936_u128, 29737406996845544_u128, 29817118950663420_u128, 29897039484087464_u128, 
-    29977169115258696_u128, 30057508363431956_u128, 30138057749048124_u128, 30218817793624636_u128, 30299789019864980_u128, 
-    30380971951586456_u128, 30462367113776116_u128, 30543975032544152_u128, 30625796235170500_u128, 30707831250058244_u128, 
-    30790080606775540_u128, 30872544836060300_u128, 30955224469766580_u128, 31038120040927504_u128, 31121232083729632_u128, 
-    31204561133512948_u128, 31288107726787196_u128, 31371872401217872_u128, 31455855695623908_u128, 31540058150021948_u128, 
-    31624480305556428_u128, 31709122704555524_u128, 31793985890524128_u128, 31879070408127576_u128, 31964376803210252_u128, 
-    32049905622793292_u128, 32135657415060576_u128, 32221632729386700_u128, 32307832116334648_u128, 32394256127623168_u128, 
-    32480905316157068_u128, 32567780236043516_u128, 32654881442561756_u128, 32742209492177096_u128, 32829764942550212_u128, 
-    32917548352525508_u128, 33005560282128784_u128, 33093801292623164_u128, 33182271946401892_u128, 33270972807095548_u128, 
-    33359904439525412_u128, 33449067409705824_u128, 33538462284848824_u128, 33628089633387460_u128, 33717950024926860_u128, 
-    33808044030297812_u128, 33898372221559112_u128, 33988935171918324_u128, 34079733455843636_u128, 34170767648984632_u128, 
-    34262038328225884_u128, 34353546071651996_u128, 34445291458554612_u128, 34537275069460352_u128, 34629497486098212_u128, 
-    34721959291443828_u128, 34814661069651896_u128, 34907603406126084_u128, 35000786887481752_u128, 35094212101576240_u128, 
-    35187879637478568_u128, 35281790085485764_u128, 35375944037125180_u128, 35470342085154496_u128, 35564984823592024_u128, 
-    35659872847632792_u128, 35755006753741792_u128, 35850387139616660_u128, 35946014604197016_u128, 36041889747641160_u128, 
-    36138013171368008_u128, 36234385478033808_u128, 36331007271513472_u128, 36427879156984488_u128, 36525001740794080_u128, 
-    36622375630587384_u128, 36720001435239856_u128, 36817879764882920_u128, 36916011230887648_u128, 37014396445888056_u128, 
-    37113036023767152_u128, 37211930579649896_u128, 37311080729959176_u128, 37410487092313240_u128, 37510150285625920_u128, 
-    37610070930064672_u128, 37710249647057576_u128, 37810687059295672_u128, 37911383790716624_u128, 38012340466551360_u128, 
-    38113557713251784_u128, 38215036158612024_u128, 38316776431602896_u128, 38418779162523440_u128, 38521044982919328_u128, 
-    38623574525622496_u128, 38726368424723160_u128, 38829427315588464_u128, 38932751834869488_u128, 39036342620463944_u128, 
-    39140200311609392_u128, 39244325548745760_u128, 39348718973624864_u128, 39453381229294096_u128, 39558312960059128_u128, 
-    39663514811516552_u128, 39768987430546888_u128, 39874731465298264_u128, 39980747565256320_u128, 40087036381125384_u128, 
-    40193598564930976_u128, 40300434769989544_u128, 40407545650894456_u128, 40514931863543992_u128, 40622594065113344_u128, 
-    40730532914084944_u128, 40838749070201832_u128, 40947243194574872_u128, 41056015949514960_u128, 41165067998691472_u128, 
-    41274400007057720_u128, 41384012640855584_u128, 41493906567634176_u128, 41604082456242856_u128, 41714540976828872_u128, 
-    41825282800825720_u128, 41936308601027736_u128, 42047619051450240_u128, 42159214827464728_u128, 42271096605738272_u128, 
-    42383265064233496_u128, 42495720882236584_u128, 42608464740326952_u128, 42721497320400560_u128, 42834819305639624_u128, 
-    42948431380603496_u128, 43062334231067872_u128, 43176528544183240_u128, 43291015008395664_u128, 43405794313458448_u128, 
-    43520867150448416_u128, 43636234211747288_u128, 43751896191060320_u128, 43867853783388360_u128, 43984107685107032_u128, 
-    44100658593817632_u128, 44217507208507920_u128, 44334654229465880_u128, 44452100358284392_u128, 44569846297893856_u128, 
-    44687892752541216_u128, 44806240427785296_u128, 44924890030496808_u128, 45043842268925936_u128, 45163097852560160_u128, 
-    45282657492264112_u128, 45402521900209640_u128, 45522691789906104_u128, 45643167876160768_u128, 45763950875137072_u128, 
-    45885041504296344_u128, 46006440482428096_u128, 46128148529696680_u128, 46250166367513040_u128, 46372494718669944_u128, 
-    46495134307267376_u128, 46618085858745168_u128, 46741350099850360_u128, 46864927758697832_u128, 46988819564684032_u128, 
-    47113026248561568_u128, 47237548542404272_u128, 47362387179611824_u128, 47487542894949392_u128, 47613016424452080_u128, 
-    47738808505525136_u128, 47864919876890352_u128, 47991351278628008_u128, 48118103452099976_u128, 48245177140035952_u128, 
-    48372573086496120_u128, 48500292036836280_u128, 48628334737817312_u128, 48756701937442048_u128, 48885394385109136_u128, 
-    49014412831517424_u128, 49143758028735920_u128, 49273430730103560_u128, 49403431690350392_u128, 49533761665509040_u128, 
-    49664421412935656_u128, 49795411691370504_u128, 49926733260816824_u128, 50058386882650296_u128, 50190373319563160_u128, 
-    50322693335610816_u128, 50455347696134896_u128, 50588337167835536_u128, 50721662518741056_u128, 50855324518200992_u128, 
-    50989323936925688_u128, 51123661546918744_u128, 51258338121535248_u128, 51393354435444520_u128, 51528711264692976_u128, 
-    51664409386594688_u128, 51800449579829168_u128, 51936832624397152_u128, 52073559301622912_u128, 52210630394200864_u128, 
-    52348046686081368_u128, 52485808962596592_u128, 52623918010381256_u128, 52762374617440216_u128, 52901179573036648_u128, 
-    53040333667808488_u128, 53179837693707912_u128, 53319692444024616_u128, 53459898713343848_u128, 53600457297600040_u128, 
-    53741368994044168_u128, 53882634601241424_u128, 54024254919117808_u128, 54166230748864624_u128, 54308562893026976_u128, 
-    54451252155464200_u128, 54594299341387128_u128, 54737705257267216_u128, 54881470710927416_u128, 55025596511518864_u128, 
-    55170083469476640_u128, 55314932396610608_u128, 55460144105974952_u128, 55605719411977672_u128, 55751659130338672_u128, 
-    55897964078106032_u128, 56044635073593128_u128, 56191672936471808_u128, 56339078487695544_u128, 56486852549531984_u128, 
-    56634995945597976_u128, 56783509500740680_u128, 56932394041177392_u128, 57081650394393040_u128, 57231279389219368_u128, 
-    57381281855741768_u128, 57531658625373816_u128, 57682410530824664_u128, 57833538406103704_u128, 57985043086543856_u128, 
-    58136925408717704_u128, 58289186210544648_u128, 58441826331211712_u128, 58594846611220144_u128, 58748247892348112_u128, 
-    58902031017664696_u128, 59056196831520576_u128, 59210746179594632_u128, 59365679908805384_u128, 59520998867366928_u128, 
-    59676703904798256_u128, 59832795871867336_u128, 59989275620670320_u128, 60146144004529048_u128, 60303401878079552_u128, 
-    60461050097197528_u128, 60619089519096192_u128, 60777521002186448_u128, 60936345406198104_u128, 61095563592123896_u128, 
-    61255176422182240_u128, 61415184759943040_u128, 61575589470155280_u128, 61736391418854184_u128, 61897591473347264_u128, 
-    62059190502228256_u128, 62221189375279320_u128, 62383588963578168_u128, 62546390139446816_u128, 62709593776456264_u128, 
-    62873200749449784_u128, 63037211934473000_u128, 63201628208848496_u128, 63366450451105856_u128, 63531679541074912_u128, 
-    63697316359764560_u128, 63863361789441984_u128, 64029816713600040_u128, 64196682016966576_u128, 64363958585527720_u128, 
-    64531647306439360_u128, 64699749068106336_u128, 64868264760163840_u128, 65037195273477376_u128, 65206541500082200_u128, 
-    65376304333276520_u128, 65546484667518960_u128, 65717083398559064_u128, 65888101423260192_u128, 66059539639734688_u128, 
-    66231398947292592_u128, 66403680246441648_u128, 66576384438901288_u128, 66749512427532744_u128, 66923065116450856_u128, 
-    67097043410898272_u128, 67271448217366616_u128, 67446280443461344_u128, 67621540998004224_u128, 67797230791005456_u128, 
-    67973350733584360_u128, 68149901738127888_u128, 68326884718104192_u128, 68504300588183792_u128, 68682150264164992_u128, 
-    68860434663071784_u128, 69039154703004688_u128, 69218311303252616_u128, 69397905384236936_u128, 69577937867548776_u128, 
-    69758409675907032_u128, 69939321733144464_u128, 70120674964249568_u128, 70302470295338640_u128, 70484708653674440_u128, 
-    70667390967591584_u128, 70850518166599104_u128, 71034091181282576_u128, 71218110943336712_u128, 71402578385644640_u128, 
-    71587494442072800_u128, 71772860047680688_u128, 71958676138562392_u128, 72144943651986400_u128, 72331663526213856_u128, 
-    72518836700661680_u128, 72706464115795344_u128, 72894546713156864_u128, 73083085435420752_u128, 73272081226235472_u128, 
-    73461535030368016_u128, 73651447793657232_u128, 73841820462971904_u128, 74032653986262032_u128, 74223949312484240_u128, 
-    74415707391667008_u128, 74607929174906048_u128, 74800615614266448_u128, 74993767662885120_u128, 75187386274942960_u128, 
-    75381472405580816_u128, 75576027011044112_u128, 75771051048510272_u128, 75966545476196032_u128, 76162511253329376_u128, 
-    76358949340149616_u128, 76555860697837440_u128, 76753246288617440_u128, 76951107075655600_u128, 77149444023129216_u128, 
-    77348258096189568_u128, 77547550260938672_u128, 77747321484433888_u128, 77947572734711264_u128, 78148304980776208_u128, 
-    78349519192547504_u128, 78551216340904032_u128, 78753397397656704_u128, 78956063335539168_u128, 79159215128282400_u128, 
-    79362853750423632_u128, 79566980177511344_u128, 79771595385932928_u128, 79976700353068400_u128, 80182296057117984_u128, 
-    80388383477200032_u128, 80594963593336944_u128, 80802037386404016_u128, 81009605838213248_u128, 81217669931378208_u128, 
-    81426230649397936_u128, 81635288976638320_u128, 81844845898350672_u128, 82054902400564592_u128, 82265459470204496_u128, 
-    82476518094987008_u128, 82688079263523584_u128, 82900143965171296_u128, 83112713190144736_u128, 83325787929464736_u128, 
-    83539369174935072_u128, 83753457919203008_u128, 83968055155656848_u128, 84183161878477104_u128, 84398779082627264_u128, 
-    84614907763886368_u128, 84831548918713840_u128, 85048703544366064_u128, 85266372638872992_u128, 85484557200945024_u128, 
-    85703258230136064_u128, 85922476726596544_u128, 86142213691292464_u128, 86362470125856208_u128, 86583247032684528_u128, 
-    86804545414798592_u128, 87026366275974592_u128, 87248710620631824_u128, 87471579453893296_u128, 87694973781585712_u128, 
-    87918894610113712_u128, 88143342946599568_u128, 88368319798794720_u128, 88593826175126368_u128, 88819863084599584_u128, 
-    89046431536871872_u128, 89273532542225248_u128, 89501167111524256_u128, 89729336256299888_u128, 89958040988581744_u128, 
-    90187282321061248_u128, 90417061266956400_u128, 90647378840123696_u128, 90878236054899632_u128, 91109633926212560_u128, 
-    91341573469545424_u128, 91574055700870528_u128, 91807081636793920_u128, 92040652294299168_u128, 92274768690971056_u128, 
-    92509431844869680_u128, 92744642774581760_u128, 92980402499113456_u128, 93216712038002192_u128, 93453572411204864_u128, 
-    93690984639218896_u128, 93928949742886656_u128, 94167468743553776_u128, 94406542662971344_u128, 94646172523319200_u128, 
-    94886359347229280_u128, 95127104157650336_u128, 95368407977987936_u128, 95610271831997088_u128, 95852696743870896_u128, 
-    96095683738082128_u128, 96339233839494960_u128, 96583348073337104_u128, 96828027465125216_u128, 97073273040790704_u128, 
-    97319085826456064_u128, 97565466848639936_u128, 97812417134126544_u128, 98059937710012432_u128, 98308029603608432_u128, 
-    98556693842547040_u128, 98805931454693664_u128, 99055743468142160_u128, 99306130911289232_u128, 99557094812648160_u128, 
-    99808636201025760_u128, 100060756105382672_u128, 100313455554945200_u128, 100566735579009520_u128, 100820597207132768_u128, 
-    101075041468988640_u128, 101330069394381280_u128, 101585682013352544_u128, 101841880355906960_u128, 102098665452300752_u128, 
-    102356038332808784_u128, 102614000027892304_u128, 102872551567965984_u128, 103131693983612272_u128, 103391428305399600_u128, 
-    103651755564054896_u128, 103912676790183888_u128, 104174193014527456_u128, 104436305267793888_u128, 104699014580691424_u128, 
-    104962321983946976_u128, 105226228508208224_u128, 105490735184104208_u128, 105755843042226704_u128, 106021553113116176_u128, 
-    106287866427201296_u128, 106554784014826784_u128, 106822306906272192_u128, 107090436131649248_u128, 107359172721051056_u128, 
-    107628517704272448_u128, 107898472111089616_u128, 108169036971031776_u128, 108440213313558208_u128, 108712002167792672_u128, 
-    108984404562751728_u128, 109257421527228240_u128, 109531054089735440_u128, 109805303278637472_u128, 110080170121925616_u128, 
-    110355655647386080_u128, 110631760882525504_u128, 110908486854552240_u128, 111185834590325088_u128, 111463805116404640_u128, 
-    111742399459015904_u128, 112021618644020400_u128, 112301463696934752_u128, 112581935642865536_u128, 112863035506518480_u128, 
-    113144764312207872_u128, 113427123083856512_u128, 113710112844865280_u128, 113993734618252864_u128, 114277989426516016_u128, 
-    114562878291760032_u128, 114848402235475008_u128, 115134562278703680_u128, 115421359441938864_u128, 115708794745151376_u128, 
-    115996869207771488_u128, 116285583848595632_u128, 116574939685861024_u128, 116864937737208320_u128, 117155579019681680_u128, 
-    117446864549626224_u128, 117738795342790512_u128, 118031372414233408_u128, 118324596778324016_u128, 118618469448779040_u128, 
-    118912991438541504_u128, 119208163759846128_u128, 119503987424172592_u128, 119800463442310880_u128, 120097592824137568_u128, 
-    120395376578830160_u128, 120693815714745920_u128, 120992911239347344_u128, 121292664159379280_u128, 121593075480579856_u128, 
-    121894146207876384_u128, 122195877345319984_u128, 122498269896029712_u128, 122801324862155296_u128, 123105043244942336_u128, 
-    123409426044685728_u128, 123714474260617840_u128, 124020188891094864_u128, 124326570933345168_u128, 124633621383590560_u128, 
-    124941341237018192_u128, 125249731487761984_u128, 125558793128828096_u128, 125868527152113472_u128, 126178934548405952_u128, 
-    126490016307346880_u128, 126801773417459152_u128, 127114206865979408_u128, 127427317639063072_u128, 127741106721532736_u128, 
-    128055575097111136_u128, 128370723748132224_u128, 128686553655736912_u128, 129003065799714624_u128, 129320261158661728_u128, 
-    129638140709683280_u128, 129956705428616720_u128, 130275956289948000_u128, 130595894266727696_u128, 130916520330654880_u128, 
-    131237835451918656_u128, 131559840599328688_u128, 131882536740166048_u128, 132205924840332336_u128, 132530005864088720_u128, 
-    132854780774223712_u128, 133180250532015856_u128, 133506416097084624_u128, 133833278427595488_u128, 134160838479961568_u128, 
-    134489097209039488_u128, 134818055567998832_u128, 135147714508433952_u128, 135478074980093712_u128, 135809137931133184_u128, 
-    136140904307917824_u128, 136473375055023520_u128, 136806551115413728_u128, 137140433430019952_u128, 137475022938114624_u128, 
-    137810320577040800_u128, 138146327282389264_u128, 138483043987737520_u128, 138820471624798912_u128, 139158611123404064_u128, 
-    139497463411351664_u128, 139837029414594880_u128, 140177310056915200_u128, 140518306260211328_u128, 140860018944256832_u128, 
-    141202449026849328_u128, 141545597423651968_u128, 141889465048156224_u128, 142234052811830944_u128, 142579361623935984_u128, 
-    142925392391550192_u128, 143272146019552672_u128, 143619623410604288_u128, 143967825465100928_u128, 144316753081248128_u128, 
-    144666407154809408_u128, 145016788579339328_u128, 145367898245996960_u128, 145719737043667168_u128, 146072305858690272_u128, 
-    146425605575085760_u128, 146779637074487040_u128, 147134401235861728_u128, 147489898936015104_u128, 147846131048881696_u128, 
-    148203098446084480_u128, 148560801996636608_u128, 148919242566969440_u128, 149278421020820704_u128, 149638338219430080_u128, 
-    149998995021203872_u128, 150360392282050400_u128, 150722530855025824_u128, 151085411590520608_u128, 151449035336073152_u128, 
-    151813402936500128_u128, 152178515233905920_u128, 152544373067291168_u128, 152910977272990784_u128, 153278328684422240_u128, 
-    153646428131992544_u128, 154015276443303072_u128, 154384874442842144_u128, 154755222952152768_u128, 155126322789758048_u128, 
-    155498174771179744_u128, 155870779708742784_u128, 156244138411761344_u128, 156618251686362048_u128, 156993120335502464_u128, 
-    157368745159073600_u128, 157745126953573856_u128, 158122266512360448_u128, 158500164625472480_u128, 158878822079761408_u128, 
-    159258239658583360_u128, 159638418142041632_u128, 160019358306837472_u128, 160401060926316576_u128, 160783526770273600_u128, 
-    161166756605082400_u128, 161550751193677600_u128, 161935511295368000_u128, 162321037666041792_u128, 162707331057877440_u128, 
-    163094392219483648_u128, 163482221895824768_u128, 163870820828295264_u128, 164260189754412224_u128, 164650329408104320_u128, 
-    165041240519469376_u128, 165432923814839648_u128, 165825380016800544_u128, 166218609843966816_u128, 166612614011141056_u128, 
-    167007393229201888_u128, 167402948205187776_u128, 167799279642008192_u128, 168196388238676448_u128, 168594274690160832_u128, 
-    168992939687347040_u128, 169392383917140928_u128, 169792608062160832_u128, 170193612800961184_u128, 170595398807930208_u128, 
-    170997966753271072_u128, 171401317302834144_u128, 171805451118294240_u128, 172210368856973344_u128, 172616071171859328_u128, 
-    173022558711633952_u128, 173429832120486400_u128, 173837892038215744_u128, 174246739100137920_u128, 174656373937160128_u128, 
-    175066797175529216_u128, 175478009437008832_u128, 175890011338730240_u128, 176302803493304096_u128, 176716386508531744_u128, 
-    177130760987600640_u128, 177545927529000672_u128, 177961886726384384_u128, 178378639168753152_u128, 178796185440131200_u128, 
-    179214526119761248_u128, 179633661781983296_u128, 180053592996309312_u128, 180474320327087520_u128, 180895844333894016_u128, 
-    181318165571141184_u128, 181741284588208352_u128, 182165201929497472_u128, 182589918134135104_u128, 183015433736140000_u128, 
-    183441749264358016_u128, 183868865242471232_u128, 184296782188718624_u128, 184725500616203296_u128, 185155021032678432_u128, 
-    185585343940416576_u128, 186016469836489312_u128, 186448399212320000_u128, 186881132554019136_u128, 187314670342048896_u128, 
-    187749013051568000_u128, 188184161151863136_u128, 188620115106815008_u128, 189056875374590752_u128, 189494442407634592_u128, 
-    189932816652807712_u128, 190371998551071200_u128, 190811988537635424_u128, 191252787041913184_u128, 191694394487510560_u128, 
-    192136811291956576_u128, 192580037867038592_u128, 193024074618439104_u128, 193468921945949760_u128, 193914580243192064_u128, 
-    194361049897812928_u128, 194808331291232960_u128, 195256424798805184_u128, 195705330789768096_u128, 196155049626882400_u128, 
-    196605581666878304_u128, 197056927260017504_u128, 197509086750419328_u128, 197962060475604096_u128, 198415848766819232_u128, 
-    198870451948787840_u128, 199325870339745664_u128, 199782104251543936_u128, 200239153989192384_u128, 200697019851362784_u128, 
-    201155702129866848_u128, 201615201110047680_u128, 202075517070369824_u128, 202536650282549600_u128, 202998601011592480_u128, 
-    203461369515569312_u128, 203924956045849408_u128, 204389360846625120_u128, 204854584155340640_u128, 205320626202384512_u128, 
-    205787487211173248_u128, 206255167397918592_u128, 206723666971841728_u128, 207192986134986976_u128, 207663125082165728_u128, 
-    208134084001115072_u128, 208605863072087552_u128, 209078462468177408_u128, 209551882355059648_u128, 210026122891139200_u128, 
-    210501184227289760_u128, 210977066506882048_u128, 211453769865923264_u128, 211931294432740576_u128, 212409640328223072_u128, 
-    212888807665486464_u128, 213368796550022208_u128, 213849607079660096_u128, 214331239344531040_u128, 214813693426787520_u128, 
-    215296969400938944_u128, 215781067333516352_u128, 216265987283277280_u128, 216751729300870272_u128, 217238293428984000_u128, 
-    217725679702403232_u128, 218213888147636000_u128, 218702918783342272_u128, 219192771619718880_u128, 219683446658965472_u128, 
-    220174943895004992_u128, 220667263313520896_u128, 221160404891733408_u128, 221654368598642016_u128, 222149154394782944_u128, 
-    222644762232192000_u128, 223141192054609600_u128, 223638443797033344_u128, 224136517385923104_u128, 224635412739219648_u128, 
-    225135129766195520_u128, 225635668367324544_u128, 226137028434468256_u128, 226639209850540320_u128, 227142212489786176_u128, 
-    227646036217596608_u128, 228150680890246880_u128, 228656146355306560_u128, 229162432451136576_u128, 229669539007280352_u128, 
-    230177465844035296_u128, 230686212772564544_u128, 231195779594990080_u128, 231706166104113376_u128, 232217372083638752_u128, 
-    232729397307744960_u128, 233242241541476320_u128, 233755904540332960_u128, 234270386050606048_u128, 234785685808893280_u128, 
-    235301803542434432_u128, 235818738968813024_u128, 236336491796105504_u128, 236855061722750720_u128, 237374448437363552_u128, 
-    237894651619033152_u128, 238415670936875520_u128, 238937506050462336_u128, 239460156609187008_u128, 239983622252824128_u128, 
-    240507902610932736_u128, 241032997303471616_u128, 241558905939923072_u128, 242085628120020064_u128, 242613163433186784_u128, 
-    243141511458799840_u128, 243670671766076192_u128, 244200643913775104_u128, 244731427450570816_u128, 245263021914679776_u128, 
-    245795426834028384_u128, 246328641726029344_u128, 246862666097656256_u128, 247397499445443456_u128, 247933141255187904_u128, 
-    248469591002396608_u128, 249006848151578080_u128, 249544912156783040_u128, 250083782461231680_u128, 250623458497481216_u128, 
-    251163939687090528_u128, 251705225440918400_u128, 252247315158806464_u128, 252790208229709952_u128, 253333904031566912_u128, 
-    253878401931298432_u128, 254423701284659456_u128, 254969801436294592_u128, 255516701719906080_u128, 256064401457601152_u128, 
-    256612899960470016_u128, 257162196528213088_u128, 257712290449196768_u128, 258263181000565344_u128, 258814867447644608_u128, 
-    259367349044612672_u128, 259920625033996896_u128, 260474694646767008_u128, 261029557102279104_u128, 261585211608201216_u128, 
-    262141657360513248_u128, 262698893543581504_u128, 263256919329785920_u128, 263815733879650528_u128, 264375336342104448_u128, 
-    264935725853829440_u128, 265496901539819104_u128, 266058862512782400_u128, 266621607873628384_u128, 267185136710906752_u128, 
-    267749448101385984_u128, 268314541109251648_u128, 268880414786702848_u128, 269447068173635424_u128, 270014500297530112_u128, 
-    270582710173694848_u128, 271151696804817280_u128, 271721459181207328_u128, 272291996280741056_u128, 272863307068860736_u128, 
-    273435390498108864_u128, 274008245508668704_u128, 274581871028028768_u128, 275156265970777760_u128, 275731429239107808_u128, 
-    276307359722069024_u128, 276884056295998016_u128, 277461517824424832_u128, 278039743157867776_u128, 278618731133926848_u128, 
-    279198480577059744_u128, 279778990298880352_u128, 280360259097785792_u128, 280942285758993824_u128, 281525069054617248_u128, 
-    282108607743514976_u128, 282692900571329024_u128, 283277946270261216_u128, 283863743559296480_u128, 284450291143904832_u128, 
-    285037587716283648_u128, 285625631954984864_u128, 286214422525026720_u128, 286803958078061632_u128, 287394237252003424_u128, 
-    287985258671064416_u128, 288577020945811520_u128, 289169522673184768_u128, 289762762436161920_u128, 290356738804093760_u128, 
-    290951450332275648_u128, 291546895562357376_u128, 292143073021765440_u128, 292739981224131648_u128, 293337618669088256_u128, 
-    293935983841988224_u128, 294535075214371200_u128, 295134891243404416_u128, 295735430372180928_u128, 296336691029532992_u128, 
-    296938671630032448_u128, 297541370573934336_u128, 298144786247140160_u128, 298748917021253184_u128, 299353761253280704_u128, 
-    299959317285932032_u128, 300565583447283008_u128, 301172558050981184_u128, 301780239395909952_u128, 302388625766580352_u128, 
-    302997715432571776_u128, 303607506648979072_u128, 304217997656002816_u128, 304829186679098304_u128, 305441071929031232_u128, 
-    306053651601468096_u128, 306666923877385728_u128, 307280886922754880_u128, 307895538888521216_u128, 308510877910661568_u128, 
-    309126902110164992_u128, 309743609592809152_u128, 310360998449347008_u128, 310979066755319936_u128, 311597812571058112_u128, 
-    312217233941754880_u128, 312837328897187200_u128, 313458095451920704_u128, 314079531605123264_u128, 314701635340658112_u128, 
-    315324404626804480_u128, 315947837416648768_u128, 316571931647525504_u128, 317196685241334144_u128, 317822096104539072_u128, 
-    318448162127759552_u128, 319074881186273088_u128, 319702251139418752_u128, 320330269831044672_u128, 320958935089041920_u128, 
-    321588244725847936_u128, 322218196537738240_u128, 322848788305403968_u128, 323480017793616576_u128, 324111882751078848_u128, 
-    324744380910871936_u128, 325377509989709952_u128, 326011267688685632_u128, 326645651692543040_u128, 327280659670199872_u128, 
-    327916289274281408_u128, 328552538141399744_u128, 329189403891967808_u128, 329826884130143232_u128, 330464976443996160_u128, 
-    331103678405136448_u128, 331742987569142336_u128, 332382901474963968_u128, 333023417645464128_u128, 333664533586989248_u128, 
-    334306246789537344_u128, 334948554726627392_u128, 335591454855169024_u128, 336234944615984384_u128, 336879021432875968_u128, 
-    337523682713447104_u128, 338168925848486400_u128, 338814748212378240_u128, 339461147162636608_u128, 340108120040333632_u128, 
-    340755664169633920_u128, 341403776858148480_u128, 342052455396655168_u128, 342701697059154688_u128, 343351499102926336_u128, 
-    344001858768230016_u128, 344652773278790592_u128, 345304239841052544_u128, 345956255644906624_u128, 346608817863112512_u128, 
-    347261923651615040_u128, 347915570149209152_u128, 348569754477819456_u128, 349224473742332224_u128, 349879725030539712_u128, 
-    350535505413214464_u128, 351191811943941888_u128, 351848641659362368_u128, 352505991578761024_u128, 353163858704478144_u128, 
-    353822240021480320_u128, 354481132497658496_u128, 355140533083679104_u128, 355800438712853504_u128, 356460846301380288_u128, 
-    357121752748046976_u128, 357783154934397888_u128, 358445049724678080_u128, 359107433965777664_u128, 359770304487100992_u128, 
-    360433658100977088_u128, 361097491601969664_u128, 361761801767473792_u128, 362426585357436288_u128, 363091839114262336_u128, 
-    363757559763058048_u128, 364423744011145664_u128, 365090388548734592_u128, 365757490048231872_u128, 366425045164708032_u128, 
-    367093050535580224_u128, 367761502780724032_u128, 368430398502548288_u128, 369099734285733632_u128, 369769506697568576_u128, 
-    370439712287483072_u128, 371110347587514688_u128, 371781409111935872_u128, 372452893357477440_u128, 373124796803105152_u128, 
-    373797115910261760_u128, 374469847122568960_u128, 375142986866088256_u128, 375816531549153280_u128, 376490477562332416_u128, 
-    377164821278633920_u128, 377839559053170304_u128, 378514687223512576_u128, 379190202109280192_u128, 379866100012644224_u128, 
-    380542377217712320_u128, 381219029991050560_u128, 381896054581497280_u128, 382573447219957504_u128, 383251204119664640_u128, 
-    383929321476105152_u128, 384607795466982016_u128, 385286622252139520_u128, 385965797973712768_u128, 386645318756090368_u128, 
-    387325180705839680_u128, 388005379911669632_u128, 388685912444542784_u128, 389366774357712192_u128, 390047961686535360_u128, 
-    390729470448697664_u128, 391411296643876992_u128, 392093436254303040_u128, 392775885244011328_u128, 393458639559701184_u128, 
-    394141695129840384_u128, 394825047865411328_u128, 395508693659649920_u128, 396192628387709632_u128, 396876847907407872_u128, 
-    397561348058293184_u128, 398246124662838912_u128, 398931173524951360_u128, 399616490431498880_u128, 400302071151155712_u128, 
-    400987911435110464_u128, 401674007016842624_u128, 402360353611786752_u128, 403046946918152832_u128, 403733782616143296_u128, 
-    404420856368288512_u128, 405108163819484224_u128, 405795700597215168_u128, 406483462310809216_u128, 407171444552407168_u128, 
-    407859642896253952_u128, 408548052899034432_u128, 409236670099985088_u128, 409925490020558656_u128, 410614508164722176_u128, 
-    411303720018845376_u128, 411993121051886784_u128, 412682706715095872_u128, 413372472442310848_u128, 414062413649847232_u128, 
-    414752525736572224_u128, 415442804083979136_u128, 416133244055889408_u128, 416823840998974464_u128, 417514590242345536_u128, 
-    418205487097702720_u128, 418896526859596224_u128, 419587704805090624_u128, 420279016193839424_u128, 420970456268532608_u128, 
-    421662020254299904_u128, 422353703359046400_u128, 423045500773676416_u128, 423737407671683136_u128, 424429419209558912_u128, 
-    425121530526459520_u128, 425813736744763584_u128, 426506032969215168_u128, 427198414288116352_u128, 427890875772507520_u128, 
-    428583412476018112_u128, 429276019436059392_u128, 429968691672594368_u128, 430661424188659840_u128, 431354211970813504_u128, 
-    432047049988313920_u128, 432739933193940800_u128, 433432856523472768_u128, 434125814896023168_u128, 434818803214226432_u128, 
-    435511816363492288_u128, 436204849213236224_u128, 436897896615835648_u128, 437590953407188800_u128, 438284014406715136_u128, 
-    438977074417504256_u128, 439670128225831104_u128, 440363170601864768_u128, 441056196299332416_u128, 441749200055445184_u128, 
-    442442176591494272_u128, 443135120612143040_u128, 443828026806060416_u128, 444520889845809152_u128, 445213704387585024_u128, 
-    445906465071850496_u128, 446599166522589056_u128, 447291803348386304_u128, 447984370141349056_u128, 448676861478074496_u128, 
-    449369271919053696_u128, 450061596009081536_u128, 450753828277443456_u128, 451445963237206976_u128, 452137995386414528_u128, 
-    452829919206890688_u128, 453521729165621376_u128, 454213419713374720_u128, 454904985285968384_u128, 455596420303673088_u128, 
-    456287719171585600_u128, 456978876279143936_u128, 457669886001059392_u128, 458360742696496384_u128, 459051440709706304_u128, 
-    459741974369952768_u128, 460432337991176448_u128, 461122525872852096_u128, 461812532299094080_u128, 462502351539737408_u128, 
-    463191977849368640_u128, 463881405468257664_u128, 464570628621686656_u128, 465259641520696000_u128, 465948438361822848_u128, 
-    466637013326691456_u128, 467325360583429760_u128, 468013474284767808_u128, 468701348570237632_u128, 469388977564346368_u128, 
-    470076355378067520_u128, 470763476108095296_u128, 471450333837142848_u128, 472136922634203392_u128, 472823236554102592_u128, 
-    473509269638244544_u128, 474195015914089344_u128, 474880469395675520_u128, 475565624083507968_u128, 476250473964147712_u128, 
-    476935013011666176_u128, 477619235185855488_u128, 478303134433794368_u128, 478986704689326272_u128, 479669939873208192_u128, 
-    480352833892924800_u128, 481035380643433472_u128, 481717574006456384_u128, 482399407851114176_u128, 483080876033888576_u128, 
-    483761972398436160_u128, 484442690776110080_u128, 485123024985550144_u128, 485802968833391168_u128, 486482516113405440_u128, 
-    487161660607844864_u128, 487840396086247872_u128, 488518716306446208_u128, 489196615014229376_u128, 489874085943605376_u128, 
-    490551122816614592_u128, 491227719343702464_u128, 491903869223943104_u128, 492579566144443008_u128, 493254803781384768_u128, 
-    493929575799132416_u128, 494603875851275264_u128, 495277697579844864_u128, 495951034616210176_u128, 496623880580480512_u128, 
-    497296229082027904_u128, 497968073719487040_u128, 498639408080680384_u128, 499310225743028800_u128, 499980520273290048_u128, 
-    500650285228118528_u128, 501319514153468288_u128, 501988200585525376_u128, 502656338050074112_u128, 503323920063093248_u128, 
-    503990940130681664_u128, 504657391749058496_u128, 505323268404711872_u128, 505988563574772032_u128, 506653270726750464_u128, 
-    507317383318613952_u128, 507980894799568128_u128, 508643798609013120_u128, 509306088177848640_u128, 509967756927691072_u128, 
-    510628798271395200_u128, 511289205613203712_u128, 511948972348523136_u128, 512608091864557888_u128, 513266557539564480_u128, 
-    513924362744231040_u128, 514581500840447104_u128, 515237965182384512_u128, 515893749116050240_u128, 516548845979696576_u128, 
-    517203249103895424_u128, 517856951811613056_u128, 518509947418061056_u128, 519162229231367104_u128, 519813790552277056_u128, 
-    520464624674378432_u128, 521114724884100480_u128, 521764084461310656_u128, 522412696678569088_u128, 523060554802246912_u128, 
-    523707652091817920_u128, 524353981800529856_u128, 524999537175068544_u128, 525644311456266496_u128, 526288297878506240_u128, 
-    526931489670540800_u128, 527573880055008704_u128, 528215462249179648_u128, 528856229464395712_u128, 529496174906816320_u128, 
-    530135291777083264_u128, 530773573270767872_u128, 531411012578333760_u128, 532047602885211200_u128, 532683337372133120_u128, 
-    533318209215097280_u128, 533952211585590464_u128, 534585337650550784_u128, 535217580572591616_u128, 535848933510486272_u128, 
-    536479389618608704_u128, 537108942047492608_u128, 537737583944055424_u128, 538365308451560832_u128, 538992108709469632_u128, 
-    539617977854334656_u128, 540242909019054912_u128, 540866895333733312_u128, 541489929925340992_u128, 542112005918090112_u128, 
-    542733116433396736_u128, 543353254590365312_u128, 543972413505416000_u128, 544590586292769216_u128, 545207766064706624_u128, 
-    545823945931198592_u128, 546439119000612096_u128, 547053278379412736_u128, 547666417172686784_u128, 548278528483879936_u128, 
-    548889605415319488_u128, 549499641068065024_u128, 550108628542206912_u128, 550716560937015168_u128, 551323431350977024_u128, 
-    551929232882094848_u128, 552533958627811968_u128, 553137601685385088_u128, 553740155151996544_u128, 554341612124567552_u128, 
-    554941965700541376_u128, 555541208977398528_u128, 556139335053104192_u128, 556736337026406464_u128, 557332207996538112_u128, 
-    557926941063887808_u128, 558520529329552384_u128, 559112965896232000_u128, 559704243867670592_u128, 560294356349289920_u128, 
-    560883296447928448_u128, 561471057272549760_u128, 562057631933757888_u128, 562643013544431104_u128, 563227195219647296_u128, 
-    563810170076982464_u128, 564391931236473024_u128, 564972471820802688_u128, 565551784955749504_u128, 566129863769924992_u128, 
-    566706701395147136_u128, 567282290966700992_u128, 567856625623227264_u128, 568429698506871104_u128, 569001502764065216_u128, 
-    569572031544672320_u128, 570141278002879872_u128, 570709235297312064_u128, 571275896590731392_u128, 571841255050598016_u128, 
-    572405303849293376_u128, 572968036163784704_u128, 573529445176333376_u128, 574089524074345792_u128, 574648266050634304_u128, 
-    575205664303529152_u128, 575761712037027584_u128, 576316402460980096_u128, 576869728791500800_u128, 577421684250407936_u128, 
-    577972262066379904_u128, 578521455474097664_u128, 579069257715474816_u128, 579615662038651136_u128, 580160661699186048_u128, 
-    580704249959610368_u128, 581246420089799936_u128, 581787165367049728_u128, 582326479076372224_u128, 582864354510646656_u128, 
-    583400784970395008_u128, 583935763764714496_u128, 584469284210606080_u128, 585001339633683200_u128, 585531923368208896_u128, 
-    586061028757207296_u128, 586588649152576384_u128, 587114777915310592_u128, 587639408415949056_u128, 588162534033866624_u128, 
-    588684148158877696_u128, 589204244189669632_u128, 589722815535592832_u128, 590239855615691008_u128, 590755357859260672_u128, 
-    591269315706335872_u128, 591781722607277952_u128, 592292572023111040_u128, 592801857426044032_u128, 593309572299284224_u128, 
-    593815710137223936_u128, 594320264445663616_u128, 594823228742110976_u128, 595324596555668096_u128, 595824361427517056_u128, 
-    596322516910695808_u128, 596819056570694400_u128, 597313973985343744_u128, 597807262745076352_u128, 598298916452888832_u128, 
-    598788928724976000_u128, 599277293190357632_u128, 599764003491438464_u128, 600249053284044672_u128, 600732436237349632_u128, 
-    601214146034545152_u128, 601694176372543104_u128, 602172520962422656_u128, 602649173529355904_u128, 603124127813204224_u128, 
-    603597377567996672_u128, 604068916562749696_u128, 604538738581243904_u128, 605006837422620032_u128, 605473206900708736_u128, 
-    605937840845147904_u128, 606400733101160192_u128, 606861877529179136_u128, 607321268006192128_u128, 607778898424770560_u128, 
-    608234762693815552_u128, 608688854738781952_u128, 609141168501156096_u128, 609591697939872640_u128, 610040437029972352_u128, 
-    610487379764167936_u128, 610932520151874688_u128, 611375852220329344_u128, 611817370013545600_u128, 612257067594178432_u128, 
-    612694939042033152_u128, 613130978454959744_u128, 613565179949523968_u128, 613997537659740032_u128, 614428045739009280_u128, 
-    614856698358777600_u128, 615283489709579904_u128, 615708414000890496_u128, 616131465460974208_u128, 616552638338079360_u128, 
-    616971926899170560_u128, 617389325431419136_u128, 617804828241309056_u128, 618218429655606144_u128, 618630124020835968_u128, 
-    619039905704029696_u128, 619447769092649216_u128, 619853708594513024_u128, 620257718638093952_u128, 620659793673116288_u128, 
-    621059928169958656_u128, 621458116620549120_u128, 621854353537619712_u128, 622248633456122752_u128, 622640950932038144_u128, 
-    623031300543342336_u128, 623419676890307072_u128, 623806074594604416_u128, 624190488301095936_u128, 624572912676267648_u128, 
-    624953342409496576_u128, 625331772213126528_u128, 625708196821870208_u128, 626082610993929216_u128, 626455009510321408_u128, 
-    626825387175553408_u128, 627193738817992320_u128, 627560059288971392_u128, 627924343463759744_u128, 628286586242158208_u128, 
-    628646782547008384_u128, 629004927326429440_u128, 629361015552327168_u128, 629715042221064576_u128, 630067002353760640_u128, 
-    630416890996737152_u128, 630764703220624256_u128, 631110434121553792_u128, 631454078820934784_u128, 631795632465230720_u128, 
-    632135090226629888_u128, 632472447303045632_u128, 632807698918116352_u128, 633140840321503872_u128, 633471866788595072_u128, 
-    633800773621545728_u128, 634127556148609536_u128, 634452209724585600_u128, 634774729730743424_u128, 635095111575271168_u128, 
-    635413350693498112_u128, 635729442547224960_u128, 636043382626139264_u128, 636355166446623232_u128, 636664789552946816_u128, 
-    636972247516670464_u128, 637277535937316736_u128, 637580650442072320_u128, 637881586686010880_u128, 638180340352839552_u128, 
-    638476907153779840_u128, 638771282829059456_u128, 639063463146718848_u128, 639353443904251648_u128, 639641220927188480_u128, 
-    639926790070363776_u128, 640210147217319936_u128, 640491288280903296_u128, 640770209203264768_u128, 641046905955709952_u128, 
-    641321374538998016_u128, 641593610984087040_u128, 641863611350791808_u128, 642131371729424640_u128, 642396888240049152_u128, 
-    642660157032853632_u128, 642921174287926912_u128, 643179936216526208_u128, 643436439059212928_u128, 643690679087940736_u128, 
-    643942652605010944_u128, 644192355943595392_u128, 644439785467661184_u128, 644684937572120320_u128, 644927808683425536_u128, 
-    645168395258601856_u128, 645406693786438912_u128, 645642700787043712_u128, 645876412812288384_u128, 646107826445213184_u128, 
-    646336938300847104_u128, 646563745026580352_u128, 646788243300822400_u128, 647010429834791680_u128, 647230301371694848_u128, 
-    647447854686578176_u128, 647663086587669632_u128, 647875993914887424_u128, 648086573541107328_u128, 648294822371939968_u128, 
-    648500737345505920_u128, 648704315432809472_u128, 648905553637886976_u128, 649104448998031616_u128, 649300998583270016_u128, 
-    649495199496811008_u128, 649687048875790336_u128, 649876543890078208_u128, 650063681743397248_u128, 650248459672875648_u128, 
-    650430874949419392_u128, 650610924877713152_u128, 650788606795995264_u128, 650963918076804992_u128, 651136856126533632_u128, 
-    651307418385425408_u128, 651475602328322944_u128, 651641405463697792_u128, 651804825335067392_u128, 651965859519950720_u128, 
-    652124505630092544_u128, 652280761312134016_u128, 652434624247538688_u128, 652586092151771392_u128, 652735162775790592_u128, 
-    652881833904705536_u128, 653026103359192960_u128, 653167968994603008_u128, 653307428700958080_u128, 653444480404221696_u128, 
-    653579122064508032_u128, 653711351678169984_u128, 653841167276233600_u128, 653968566925218048_u128, 654093548726911616_u128, 
-    654216110819117952_u128, 654336251374611840_u128, 654453968601959424_u128, 654569260745592960_u128, 654682126085288448_u128, 
-    654792562936538752_u128, 654900569651523072_u128, 655006144617168128_u128, 655109286256713728_u128, 655209993029862272_u128, 
-    655308263431362176_u128, 655404095993244160_u128, 655497489282733952_u128, 655588441903147008_u128, 655676952494932224_u128, 
-    655763019733659136_u128, 655846642332179456_u128, 655927819038838400_u128, 656006548638965632_u128, 656082829954129280_u128, 
-    656156661841912960_u128, 656228043197033728_u128, 656296972950521728_u128, 656363450069646336_u128, 656427473558586496_u128, 
-    656489042457908992_u128, 656548155845389056_u128, 656604812834593280_u128, 656659012576221440_u128, 656710754258107264_u128, 
-    656760037104099584_u128, 656806860375702400_u128, 656851223369838720_u128, 656893125421981568_u128, 656932565903171712_u128, 
-    656969544221881984_u128, 657004059823121792_u128, 657036112189631104_u128, 657065700839642880_u128, 657092825329641984_u128, 
-    657117485252576000_u128, 657139680238227456_u128, 657159409953363840_u128, 657176674102184064_u128, 657191472425200512_u128, 
-    657203804700432000_u128, 657213670742956288_u128, 657221070404239104_u128, 657226003573625472_u128, 657228470176773632_u128, 
-    657228470176773632_u128, 657226003573625472_u128, 657221070404239104_u128, 657213670742956288_u128, 657203804700506496_u128, 
-    657191472425200512_u128, 657176674102109568_u128, 657159409953438464_u128, 657139680238152960_u128, 657117485252501376_u128, 
-    657092825329791104_u128, 657065700839568384_u128, 657036112189556608_u128, 657004059823196416_u128, 656969544221881984_u128, 
-    656932565903171712_u128, 656893125421981568_u128, 656851223369913344_u128, 656806860375478784_u128, 656760037104248704_u128, 
-    656710754258107264_u128, 656659012576296064_u128, 656604812834444160_u128, 656548155845389056_u128, 656489042458058112_u128, 
-    656427473558511872_u128, 656363450069646336_u128, 656296972950521728_u128, 656228043197108224_u128, 656156661841912960_u128, 
-    656082829953980160_u128, 656006548639040128_u128, 655927819038838400_u128, 655846642332104832_u128, 655763019733733632_u128, 
-    655676952494932224_u128, 655588441903147008_u128, 655497489282659328_u128, 655404095993244160_u128, 655308263431511296_u128, 
-    655209993029787776_u128, 655109286256713728_u128, 655006144617093504_u128, 654900569651523072_u128, 654792562936687872_u128, 
-    654682126085288448_u128, 654569260745518336_u128, 654453968601959424_u128, 654336251374611840_u128, 654216110819117952_u128, 
-    654093548726986112_u128, 653968566925143424_u128, 653841167276233600_u128, 653711351678169984_u128, 653579122064582528_u128, 
-    653444480404072576_u128, 653307428701032704_u128, 653167968994603008_u128, 653026103359267584_u128, 652881833904705536_u128, 
-    652735162775716096_u128, 652586092151846016_u128, 652434624247389568_u128, 652280761312208640_u128, 652124505630092544_u128, 
-    651965859519876096_u128, 651804825335141888_u128, 651641405463697792_u128, 651475602328322944_u128, 651307418385425408_u128, 
-    651136856126459008_u128, 650963918076879488_u128, 650788606796069888_u128, 650610924877638528_u128, 650430874949419392_u128, 
-    650248459672801024_u128, 650063681743397248_u128, 649876543890078208_u128, 649687048875864960_u128, 649495199496811008_u128, 
-    649300998583270016_u128, 649104448998031616_u128, 648905553637886976_u128, 648704315432809472_u128, 648500737345505920_u128, 
-    648294822371939968_u128, 648086573541107328_u128, 647875993914961920_u128, 647663086587595136_u128, 647447854686578176_u128, 
-    647230301371694848_u128, 647010429834791680_u128, 646788243300822400_u128, 646563745026580352_u128, 646336938300921600_u128, 
-    646107826445138560_u128, 645876412812213760_u128, 645642700787192832_u128, 645406693786364288_u128, 645168395258601856_u128, 
-    644927808683351040_u128, 644684937572194816_u128, 644439785467661184_u128, 644192355943595392_u128, 643942652605085440_u128, 
-    643690679087940736_u128, 643436439059138432_u128, 643179936216451584_u128, 642921174288076032_u128, 642660157032779136_u128, 
-    642396888240049152_u128, 642131371729424640_u128, 641863611350866432_u128, 641593610984012416_u128, 641321374539147136_u128, 
-    641046905955560832_u128, 640770209203339392_u128, 640491288280828800_u128, 640210147217319936_u128, 639926790070289152_u128, 
-    639641220927262976_u128, 639353443904251648_u128, 639063463146793344_u128, 638771282828910336_u128, 638476907153779840_u128, 
-    638180340352914176_u128, 637881586686010880_u128, 637580650442072320_u128, 637277535937242240_u128, 636972247516744960_u128, 
-    636664789552946816_u128, 636355166446623232_u128, 636043382626213760_u128, 635729442547150336_u128, 635413350693498112_u128, 
-    635095111575345664_u128, 634774729730668928_u128, 634452209724585600_u128, 634127556148684160_u128, 633800773621545728_u128, 
-    633471866788595072_u128, 633140840321429248_u128, 632807698918116352_u128, 632472447303045632_u128, 632135090226629888_u128, 
-    631795632465230720_u128, 631454078820860288_u128, 631110434121702912_u128, 630764703220624256_u128, 630416890996662528_u128, 
-    630067002353835136_u128, 629715042220990080_u128, 629361015552252544_u128, 629004927326429440_u128, 628646782547157504_u128, 
-    628286586242009088_u128, 627924343463834368_u128, 627560059288971392_u128, 627193738817917696_u128, 626825387175702528_u128, 
-    626455009510321408_u128, 626082610993780096_u128, 625708196821944832_u128, 625331772213126528_u128, 624953342409496576_u128, 
-    624572912676267648_u128, 624190488301095936_u128, 623806074594678912_u128, 623419676890157952_u128, 623031300543416832_u128, 
-    622640950931963520_u128, 622248633456197376_u128, 621854353537694336_u128, 621458116620549120_u128, 621059928169884032_u128, 
-    620659793673190784_u128, 620257718638019456_u128, 619853708594438528_u128, 619447769092649216_u128, 619039905704104320_u128, 
-    618630124020835968_u128, 618218429655606144_u128, 617804828241309056_u128, 617389325431419136_u128, 616971926899170560_u128, 
-    616552638338079360_u128, 616131465460974208_u128, 615708414000890496_u128, 615283489709579904_u128, 614856698358703104_u128, 
-    614428045739083776_u128, 613997537659814656_u128, 613565179949449344_u128, 613130978454959744_u128, 612694939042033152_u128, 
-    612257067594103936_u128, 611817370013620096_u128, 611375852220254720_u128, 610932520151949312_u128, 610487379764167936_u128, 
-    610040437030046976_u128, 609591697939798144_u128, 609141168501156096_u128, 608688854738781952_u128, 608234762693815552_u128, 
-    607778898424695936_u128, 607321268006266752_u128, 606861877529179136_u128, 606400733101085568_u128, 605937840845222528_u128, 
-    605473206900708736_u128, 605006837422620032_u128, 604538738581243904_u128, 604068916562824320_u128, 603597377567922176_u128, 
-    603124127813129728_u128, 602649173529430400_u128, 602172520962497152_u128, 601694176372468480_u128, 601214146034545152_u128, 
-    600732436237349632_u128, 600249053284044672_u128, 599764003491438464_u128, 599277293190357632_u128, 598788928724976000_u128, 
-    598298916452963456_u128, 597807262744889984_u128, 597313973985418368_u128, 596819056570694400_u128, 596322516910807552_u128, 
-    595824361427405184_u128, 595324596555630848_u128, 594823228742185472_u128, 594320264445700864_u128, 593815710137112064_u128, 
-    593309572299358848_u128, 592801857426118528_u128, 592292572022999168_u128, 591781722607315200_u128, 591269315706298624_u128, 
-    590755357859335168_u128, 590239855615728256_u128, 589722815535518208_u128, 589204244189781504_u128, 588684148158765952_u128, 
-    588162534033829376_u128, 587639408415874432_u128, 587114777915347840_u128, 586588649152688256_u128, 586061028757132800_u128, 
-    585531923368283392_u128, 585001339633645952_u128, 584469284210643328_u128, 583935763764602624_u128, 583400784970469632_u128, 
-    582864354510572032_u128, 582326479076484096_u128, 581787165367087104_u128, 581246420089688064_u128, 580704249959647616_u128, 
-    580160661699111424_u128, 579615662038800256_u128, 579069257715325568_u128, 578521455474172288_u128, 577972262066342656_u128, 
-    577421684250445184_u128, 576869728791500800_u128, 576316402460942784_u128, 575761712037064896_u128, 575205664303529152_u128, 
-    574648266050559744_u128, 574089524074420352_u128, 573529445176296064_u128, 572968036163784704_u128, 572405303849330624_u128, 
-    571841255050598016_u128, 571275896590694144_u128, 570709235297349376_u128, 570141278002917184_u128, 569572031544672320_u128, 
-    569001502763990656_u128, 568429698506871104_u128, 567856625623264576_u128, 567282290966626432_u128, 566706701395258944_u128, 
-    566129863769924992_u128, 565551784955712192_u128, 564972471820839936_u128, 564391931236435712_u128, 563810170076982464_u128, 
-    563227195219647296_u128, 562643013544505664_u128, 562057631933646016_u128, 561471057272587072_u128, 560883296447965696_u128, 
-    560294356349252608_u128, 559704243867633280_u128, 559112965896306560_u128, 558520529329515136_u128, 557926941063813248_u128, 
-    557332207996575424_u128, 556736337026443712_u128, 556139335053104192_u128, 555541208977435840_u128, 554941965700541376_u128, 
-    554341612124567552_u128, 553740155151959232_u128, 553137601685385088_u128, 552533958627886528_u128, 551929232881983040_u128, 
-    551323431351014336_u128, 550716560937052480_u128, 550108628542169664_u128, 549499641067990464_u128, 548889605415394048_u128, 
-    548278528483768128_u128, 547666417172798656_u128, 547053278379338176_u128, 546439119000686656_u128, 545823945931161280_u128, 
-    545207766064632064_u128, 544590586292881024_u128, 543972413505453248_u128, 543353254590253504_u128, 542733116433545856_u128, 
-    542112005918015552_u128, 541489929925303680_u128, 540866895333770624_u128, 540242909019092224_u128, 539617977854260096_u128, 
-    538992108709432384_u128, 538365308451486272_u128, 537737583944167296_u128, 537108942047492608_u128, 536479389618645952_u128, 
-    535848933510486272_u128, 535217580572591616_u128, 534585337650513472_u128, 533952211585627712_u128, 533318209215134592_u128, 
-    532683337372058560_u128, 532047602885248512_u128, 531411012578333760_u128, 530773573270767872_u128, 530135291777083264_u128, 
-    529496174906741760_u128, 528856229464432960_u128, 528215462249179648_u128, 527573880055083264_u128, 526931489670428992_u128, 
-    526288297878580800_u128, 525644311456191872_u128, 524999537175143104_u128, 524353981800455296_u128, 523707652091929792_u128, 
-    523060554802209600_u128, 522412696678569088_u128, 521764084461422528_u128, 521114724884025920_u128, 520464624674266560_u128, 
-    519813790552426176_u128, 519162229231329856_u128, 518509947417986496_u128, 517856951811501248_u128, 517203249103969984_u128, 
-    516548845979733824_u128, 515893749116124800_u128, 515237965182421760_u128, 514581500840297984_u128, 513924362744268352_u128, 
-    513266557539601728_u128, 512608091864557888_u128, 511948972348597696_u128, 511289205613129152_u128, 510628798271357952_u128, 
-    509967756927691072_u128, 509306088177885952_u128, 508643798609050432_u128, 507980894799493568_u128, 507317383318576704_u128, 
-    506653270726862272_u128, 505988563574772032_u128, 505323268404674560_u128, 504657391748946624_u128, 503990940130718976_u128, 
-    503323920063130496_u128, 502656338050074112_u128, 501988200585600000_u128, 501319514153468288_u128, 500650285228043968_u128, 
-    499980520273252800_u128, 499310225743177920_u128, 498639408080568512_u128, 497968073719524288_u128, 497296229082065216_u128, 
-    496623880580517824_u128, 495951034616172864_u128, 495277697579733056_u128, 494603875851312512_u128, 493929575799095168_u128, 
-    493254803781422080_u128, 492579566144405696_u128, 491903869224017664_u128, 491227719343702464_u128, 490551122816614592_u128, 
-    489874085943530816_u128, 489196615014341248_u128, 488518716306408960_u128, 487840396086210560_u128, 487161660607844864_u128, 
-    486482516113480000_u128, 485802968833353920_u128, 485123024985624704_u128, 484442690775923648_u128, 483761972398547968_u128, 
-    483080876033925888_u128, 482399407851151488_u128, 481717574006344576_u128, 481035380643396160_u128, 480352833892924800_u128, 
-    479669939873170944_u128, 478986704689400832_u128, 478303134433906240_u128, 477619235185706368_u128, 476935013011591616_u128, 
-    476250473964334080_u128, 475565624083433408_u128, 474880469395861952_u128, 474195015913977472_u128, 473509269638207232_u128, 
-    472823236554214464_u128, 472136922634091520_u128, 471450333837105536_u128, 470763476108057984_u128, 470076355378179392_u128, 
-    469388977564383680_u128, 468701348570013952_u128, 468013474284842368_u128, 467325360583578880_u128, 466637013326766016_u128, 
-    465948438361673728_u128, 465259641520658688_u128, 464570628621761280_u128, 463881405468257664_u128, 463191977849256832_u128, 
-    462502351539849280_u128, 461812532299019520_u128, 461122525872926656_u128, 460432337991176448_u128, 459741974369952768_u128, 
-    459051440709780864_u128, 458360742696421824_u128, 457669886001022080_u128, 456978876279218496_u128, 456287719171436480_u128, 
-    455596420303784960_u128, 454904985285968384_u128, 454213419713374720_u128, 453521729165584128_u128, 452829919206965248_u128, 
-    452137995386377280_u128, 451445963237169728_u128, 450753828277480768_u128, 450061596009044224_u128, 449369271918979072_u128, 
-    448676861478149120_u128, 447984370141311808_u128, 447291803348460864_u128, 446599166522738176_u128, 445906465071626816_u128, 
-    445213704387547776_u128, 444520889845921024_u128, 443828026806060416_u128, 443135120612217600_u128, 442442176591345152_u128, 
-    441749200055482432_u128, 441056196299369728_u128, 440363170601939328_u128, 439670128225719296_u128, 438977074417429696_u128, 
-    438284014406789760_u128, 437590953407263424_u128, 436897896615761088_u128, 436204849213236224_u128, 435511816363492288_u128, 
-    434818803214226432_u128, 434125814895985856_u128, 433432856523510016_u128, 432739933193940800_u128, 432047049988313920_u128, 
-    431354211970813504_u128, 430661424188622528_u128, 429968691672668928_u128, 429276019435984768_u128, 428583412476092672_u128, 
-    427890875772470272_u128, 427198414288190912_u128, 426506032969177856_u128, 425813736744651776_u128, 425121530526534080_u128, 
-    424429419209447040_u128, 423737407671757696_u128, 423045500773639104_u128, 422353703359158272_u128, 421662020254188032_u128, 
-    420970456268644480_u128, 420279016193802176_u128, 419587704805127936_u128, 418896526859596224_u128, 418205487097628160_u128, 
-    417514590242345536_u128, 416823840998974464_u128, 416133244055889408_u128, 415442804084016384_u128, 414752525736534912_u128, 
-    414062413649921792_u128, 413372472442310848_u128, 412682706714984000_u128, 411993121052073216_u128, 411303720018770816_u128, 
-    410614508164759488_u128, 409925490020484096_u128, 409236670099985088_u128, 408548052898959872_u128, 407859642896253952_u128, 
-    407171444552518976_u128, 406483462310660096_u128, 405795700597326976_u128, 405108163819484224_u128, 404420856368437632_u128, 
-    403733782615956864_u128, 403046946918152832_u128, 402360353611749504_u128, 401674007016917184_u128, 400987911435185024_u128, 
-    400302071151081088_u128, 399616490431536192_u128, 398931173524988672_u128, 398246124662876160_u128, 397561348058293184_u128, 
-    396876847907333312_u128, 396192628387597824_u128, 395508693659687168_u128, 394825047865560512_u128, 394141695129728512_u128, 
-    393458639559701184_u128, 392775885243899520_u128, 392093436254340288_u128, 391411296643951552_u128, 390729470448660352_u128, 
-    390047961686498048_u128, 389366774357749504_u128, 388685912444654656_u128, 388005379911557824_u128, 387325180705802368_u128, 
-    386645318756239488_u128, 385965797973675520_u128, 385286622252064960_u128, 384607795467019264_u128, 383929321476105152_u128, 
-    383251204119739200_u128, 382573447219845696_u128, 381896054581646400_u128, 381219029990976000_u128, 380542377217712320_u128, 
-    379866100012644224_u128, 379190202109261568_u128, 378514687223456640_u128, 377839559053226240_u128, 377164821278671232_u128, 
-    376490477562295168_u128, 375816531549153280_u128, 375142986866106944_u128, 374469847122568960_u128, 373797115910205824_u128, 
-    373124796803086464_u128, 372452893357533376_u128, 371781409111824000_u128, 371110347587682432_u128, 370439712287445760_u128, 
-    369769506697494016_u128, 369099734285715008_u128, 368430398502548288_u128, 367761502780835904_u128, 367093050535524288_u128, 
-    366425045164708032_u128, 365757490048287808_u128, 365090388548715968_u128, 364423744011145664_u128, 363757559763132608_u128, 
-    363091839114038592_u128, 362426585357566784_u128, 361761801767585664_u128, 361097491601857792_u128, 360433658100995712_u128, 
-    359770304487119616_u128, 359107433965647168_u128, 358445049724696704_u128, 357783154934491072_u128, 357121752747953792_u128, 
-    356460846301305728_u128, 355800438713021312_u128, 355140533083529984_u128, 354481132497751680_u128, 353822240021517568_u128, 
-    353163858704403584_u128, 352505991578686464_u128, 351848641659492864_u128, 351191811943904640_u128, 350535505413195840_u128, 
-    349879725030595648_u128, 349224473742332224_u128, 348569754477782208_u128, 347915570149321024_u128, 347261923651428608_u128, 
-    346608817863224320_u128, 345956255645037120_u128, 345304239840940672_u128, 344652773278753344_u128, 344001858768248640_u128, 
-    343351499102944960_u128, 342701697059210624_u128, 342052455396561984_u128, 341403776858111168_u128, 340755664169820352_u128, 
-    340108120040203136_u128, 339461147162562048_u128, 338814748212452800_u128, 338168925848579584_u128, 337523682713391168_u128, 
-    336879021432931904_u128, 336234944615797952_u128, 335591454855374080_u128, 334948554726552832_u128, 334306246789611904_u128, 
-    333664533586933312_u128, 333023417645389568_u128, 332382901475001280_u128, 331742987569086400_u128, 331103678405155072_u128, 
-    330464976444163968_u128, 329826884129919552_u128, 329189403892060992_u128, 328552538141436992_u128, 327916289274132288_u128, 
-    327280659670274496_u128, 326645651692543040_u128, 326011267688704256_u128, 325377509989821824_u128, 324744380910704192_u128, 
-    324111882751246656_u128, 323480017793448832_u128, 322848788305441216_u128, 322218196537756864_u128, 321588244725866624_u128, 
-    320958935089135104_u128, 320330269830970112_u128, 319702251139269632_u128, 319074881186422208_u128, 318448162127666368_u128, 
-    317822096104520448_u128, 317196685241352768_u128, 316571931647618752_u128, 315947837416518336_u128, 315324404626785856_u128, 
-    314701635340751360_u128, 314079531605085952_u128, 313458095451995264_u128, 312837328897131328_u128, 312217233941829440_u128, 
-    311597812571020864_u128, 310979066755469056_u128, 310360998449235136_u128, 309743609592660032_u128, 309126902110276800_u128, 
-    308510877910661568_u128, 307895538888521216_u128, 307280886922754880_u128, 306666923877348416_u128, 306053651601523968_u128, 
-    305441071928993984_u128, 304829186679154176_u128, 304217997656040128_u128, 303607506648923136_u128, 302997715432609024_u128, 
-    302388625766543104_u128, 301780239396003136_u128, 301172558050906624_u128, 300565583447152512_u128, 299959317286025216_u128, 
-    299353761253299328_u128, 298748917021178624_u128, 298144786247102848_u128, 297541370574046208_u128, 296938671629920576_u128, 
-    296336691029663488_u128, 295735430372255488_u128, 295134891243255296_u128, 294535075214352576_u128, 293935983842174656_u128, 
-    293337618668883200_u128, 292739981224113024_u128, 292143073021840000_u128, 291546895562320128_u128, 290951450332387520_u128, 
-    290356738804112448_u128, 289762762436143296_u128, 289169522673259328_u128, 288577020945625088_u128, 287985258671027136_u128, 
-    287394237252189856_u128, 286803958078117568_u128, 286214422524840288_u128, 285625631955115328_u128, 285037587716190432_u128, 
-    284450291143830272_u128, 283863743559333760_u128, 283277946270279840_u128, 282692900571422240_u128, 282108607743496320_u128, 
-    281525069054710464_u128, 280942285758807424_u128, 280360259097748512_u128, 279778990299029472_u128, 279198480576873312_u128, 
-    278618731134020064_u128, 278039743157849152_u128, 277461517824406176_u128, 276884056296016672_u128, 276307359722031744_u128, 
-    275731429239275584_u128, 275156265970703168_u128, 274581871027935584_u128, 274008245508724640_u128, 273435390498146144_u128, 
-    272863307068748896_u128, 272291996280852896_u128, 271721459181207328_u128, 271151696804780000_u128, 270582710173713472_u128, 
-    270014500297474208_u128, 269447068173747264_u128, 268880414786646912_u128, 268314541109251648_u128, 267749448101367360_u128, 
-    267185136710869472_u128, 266621607873702944_u128, 266058862512689216_u128, 265496901539912320_u128, 264935725853885376_u128, 
-    264375336341936672_u128, 263815733879762368_u128, 263256919329785920_u128, 262698893543693344_u128, 262141657360345472_u128, 
-    261585211608313088_u128, 261029557102297728_u128, 260474694646711072_u128, 259920625034034176_u128, 259367349044668576_u128, 
-    258814867447532736_u128, 258263181000658560_u128, 257712290449103552_u128, 257162196528082592_u128, 256612899960581856_u128, 
-    256064401457507936_u128, 255516701719924704_u128, 254969801436369184_u128, 254423701284789952_u128, 253878401931205248_u128, 
-    253333904031641472_u128, 252790208229598080_u128, 252247315158731904_u128, 251705225441067520_u128, 251163939686997312_u128, 
-    250623458497518496_u128, 250083782461250304_u128, 249544912156671200_u128, 249006848151671264_u128, 248469591002359328_u128, 
-    247933141255299776_u128, 247397499445275680_u128, 246862666097674880_u128, 246328641726103904_u128, 245795426833879232_u128, 
-    245263021914772960_u128, 244731427450626752_u128, 244200643913886976_u128, 243670671765815232_u128, 243141511458874400_u128, 
-    242613163433298624_u128, 242085628119926848_u128, 241558905940053568_u128, 241032997303341120_u128, 240507902610951360_u128, 
-    239983622252712288_u128, 239460156609354784_u128, 238937506050425056_u128, 238415670936968736_u128, 237894651618995872_u128, 
-    237374448437270368_u128, 236855061722713440_u128, 236336491796105504_u128, 235818738968831648_u128, 235301803542434432_u128, 
-    234785685809061056_u128, 234270386050568768_u128, 233755904540314304_u128, 233242241541364480_u128, 232729397307838144_u128, 
-    232217372083564192_u128, 231706166104169280_u128, 231195779594990080_u128, 230686212772564544_u128, 230177465844035296_u128, 
-    229669539007149856_u128, 229162432451304352_u128, 228656146355157440_u128, 228150680890358720_u128, 227646036217671200_u128, 
-    227142212489804800_u128, 226639209850372544_u128, 226137028434486880_u128, 225635668367417760_u128, 225135129766232800_u128, 
-    224635412739200992_u128, 224136517385792608_u128, 223638443797126560_u128, 223141192054628256_u128, 222644762232117440_u128, 
-    222149154394913440_u128, 221654368598642016_u128, 221160404891770688_u128, 220667263313371744_u128, 220174943895060928_u128, 
-    219683446658909536_u128, 219192771619681600_u128, 218702918783342272_u128, 218213888147654656_u128, 217725679702477824_u128, 
-    217238293429021280_u128, 216751729300888896_u128, 216265987283184096_u128, 215781067333404512_u128, 215296969400994880_u128, 
-    214813693426750208_u128, 214331239344605600_u128, 213849607079697376_u128, 213368796550003584_u128, 212888807665449184_u128, 
-    212409640328204416_u128, 211931294432684640_u128, 211453769865997856_u128, 210977066506900672_u128, 210501184227289760_u128, 
-    210026122891157824_u128, 209551882355041024_u128, 209078462468168096_u128, 208605863072012992_u128, 208134084001189664_u128, 
-    207663125082109824_u128, 207192986135070848_u128, 206723666971869696_u128, 206255167397890624_u128, 205787487211210528_u128, 
-    205320626202300608_u128, 204854584155368608_u128, 204389360846569184_u128, 203924956045942592_u128, 203461369515476096_u128, 
-    202998601011639104_u128, 202536650282549600_u128, 202075517070509632_u128, 201615201109917184_u128, 201155702129801600_u128, 
-    200697019851437376_u128, 200239153989089856_u128, 199782104251655776_u128, 199325870339680416_u128, 198870451948787840_u128, 
-    198415848766847200_u128, 197962060475613408_u128, 197509086750577792_u128, 197056927259924320_u128, 196605581666766464_u128, 
-    196155049626910368_u128, 195705330789749440_u128, 195256424798861088_u128, 194808331291260928_u128, 194361049897850208_u128, 
-    193914580243117504_u128, 193468921946033664_u128, 193024074618411136_u128, 192580037866992000_u128, 192136811291956576_u128, 
-    191694394487519904_u128, 191252787041931840_u128, 190811988537476960_u128, 190371998551220320_u128, 189932816652919552_u128, 
-    189494442407560032_u128, 189056875374609408_u128, 188620115106824352_u128, 188184161151741984_u128, 187749013051530720_u128, 
-    187314670342095488_u128, 186881132553972544_u128, 186448399212329344_u128, 186016469836666400_u128, 185585343940369952_u128, 
-    185155021032650464_u128, 184725500616156704_u128, 184296782188616096_u128, 183868865242536480_u128, 183441749264311392_u128, 
-    183015433736158656_u128, 182589918134330848_u128, 182165201929413600_u128, 181741284588264256_u128, 181318165570880224_u128, 
-    180895844334127008_u128, 180474320326966336_u128, 180053592996421152_u128, 179633661781908736_u128, 179214526119714624_u128, 
-    178796185440159168_u128, 178378639168790432_u128, 177961886726384384_u128, 177545927529093888_u128, 177130760987554016_u128, 
-    176716386508522432_u128, 176302803493238880_u128, 175890011338767520_u128, 175478009437102048_u128, 175066797175463968_u128, 
-    174656373937048256_u128, 174246739100128608_u128, 173837892038355552_u128, 173429832120458432_u128, 173022558711736480_u128, 
-    172616071171672928_u128, 172210368856917440_u128, 171805451118452704_u128, 171401317302787552_u128, 170997966753299008_u128, 
-    170595398807846336_u128, 170193612800858656_u128, 169792608062291328_u128, 169392383917178240_u128, 168992939687272480_u128, 
-    168594274690151520_u128, 168196388238769664_u128, 167799279641905664_u128, 167402948205206432_u128, 167007393229248480_u128, 
-    166612614011178336_u128, 166218609843966816_u128, 165825380016707328_u128, 165432923814914208_u128, 165041240519478688_u128, 
-    164650329408160256_u128, 164260189754244480_u128, 163870820828332544_u128, 163482221895806112_u128, 163094392219660736_u128, 
-    162707331057672384_u128, 162321037666125664_u128, 161935511295489184_u128, 161550751193547104_u128, 161166756605250176_u128, 
-    160783526770031264_u128, 160401060926279296_u128, 160019358306865440_u128, 159638418142274656_u128, 159258239658368992_u128, 
-    158878822079817312_u128, 158500164625472480_u128, 158122266512397728_u128, 157745126953629792_u128, 157368745159073600_u128, 
-    156993120335353312_u128, 156618251686408640_u128, 156244138411854528_u128, 155870779708621600_u128, 155498174771403456_u128, 
-    155126322789674144_u128, 154755222952012960_u128, 154384874442972640_u128, 154015276443228512_u128, 153646428131964576_u128, 
-    153278328684487488_u128, 152910977272972128_u128, 152544373067356416_u128, 152178515233849984_u128, 151813402936425536_u128, 
-    151449035336222272_u128, 151085411590501984_u128, 150722530854886016_u128, 150360392282013120_u128, 149998995021241152_u128, 
-    149638338219392800_u128, 149278421020904576_u128, 148919242566932160_u128, 148560801996692512_u128, 148203098446121760_u128, 
-    147846131048918976_u128, 147489898935800736_u128, 147134401235992224_u128, 146779637074393824_u128, 146425605575160320_u128, 
-    146072305858718240_u128, 145719737043555296_u128, 145367898246071520_u128, 145016788579385920_u128, 144666407154828064_u128, 
-    144316753081192224_u128, 143967825465035696_u128, 143619623410828000_u128, 143272146019459472_u128, 142925392391373088_u128, 
-    142579361624029200_u128, 142234052811905504_u128, 141889465048137584_u128, 141545597423624016_u128, 141202449027026416_u128, 
-    140860018944172944_u128, 140518306260146080_u128, 140177310056896560_u128, 139837029414585552_u128, 139497463411435552_u128, 
-    139158611123432032_u128, 138820471624770960_u128, 138483043987709552_u128, 138146327282417232_u128, 137810320576975552_u128, 
-    137475022938123936_u128, 137140433429917424_u128, 136806551115516256_u128, 136473375055098096_u128, 136140904307796656_u128, 
-    135809137931105216_u128, 135478074980130992_u128, 135147714508489872_u128, 134818055568008144_u128, 134489097209020848_u128, 
-    134160838479924288_u128, 133833278427474304_u128, 133506416097233760_u128, 133180250532081104_u128, 132854780774149136_u128, 
-    132530005864079392_u128, 132205924840425536_u128, 131882536739951664_u128, 131559840599421888_u128, 131237835451872048_u128, 
-    130916520330636240_u128, 130595894266811584_u128, 130275956290003920_u128, 129956705428626032_u128, 129638140709599392_u128, 
-    129320261158652400_u128, 129003065799723952_u128, 128686553655858080_u128, 128370723748011056_u128, 128055575097139088_u128, 
-    127741106721514096_u128, 127427317639109680_u128, 127114206866109888_u128, 126801773417356624_u128, 126490016307393472_u128, 
-    126178934548228848_u128, 125868527152169392_u128, 125558793128837408_u128, 125249731487706064_u128, 124941341237204608_u128, 
-    124633621383376192_u128, 124326570933457024_u128, 124020188891104176_u128, 123714474260571248_u128, 123409426044620496_u128, 
-    123105043244970288_u128, 122801324862295104_u128, 122498269895927184_u128, 122195877345347936_u128, 121894146207950944_u128, 
-    121593075480533248_u128, 121292664159444512_u128, 120992911239244816_u128, 120693815714792512_u128, 120395376578708992_u128, 
-    120097592824212144_u128, 119800463442282928_u128, 119503987424200544_u128, 119208163759902048_u128, 118912991438578800_u128, 
-    118618469448825648_u128, 118324596778193536_u128, 118031372414233408_u128, 117738795342706624_u128, 117446864549821952_u128, 
-    117155579019551200_u128, 116864937737208320_u128, 116574939685870336_u128, 116285583848614272_u128, 115996869207920624_u128, 
-    115708794745076816_u128, 115421359441817696_u128, 115134562278834176_u128, 114848402235475008_u128, 114562878291685472_u128, 
-    114277989426516016_u128, 113993734618271504_u128, 113710112844809360_u128, 113427123083987008_u128, 113144764312170592_u128, 
-    112863035506471872_u128, 112581935642809616_u128, 112301463696953392_u128, 112021618644076320_u128, 111742399458904048_u128, 
-    111463805116460560_u128, 111185834590278480_u128, 110908486854486992_u128, 110631760882767840_u128, 110355655647311520_u128, 
-    110080170121948912_u128, 109805303278572224_u128, 109531054089819328_u128, 109257421527135024_u128, 108984404562858912_u128, 
-    108712002167690144_u128, 108440213313670064_u128, 108169036970901280_u128, 107898472111126896_u128, 107628517704300400_u128, 
-    107359172721120960_u128, 107090436131542064_u128, 106822306906262880_u128, 106554784014938640_u128, 106287866427075456_u128, 
-    106021553113074240_u128, 105755843042142816_u128, 105490735184383840_u128, 105226228508110352_u128, 104962321984021536_u128, 
-    104699014580579568_u128, 104436305267737952_u128, 104174193014555424_u128, 103912676790239808_u128, 103651755563998976_u128, 
-    103391428305488160_u128, 103131693983467808_u128, 102872551568189680_u128, 102614000027817744_u128, 102356038332603728_u128, 
-    102098665452501152_u128, 101841880355823072_u128, 101585682013268656_u128, 101330069394493136_u128, 101075041469002624_u128, 
-    100820597207048880_u128, 100566735579032816_u128, 100313455555057056_u128, 100060756105224224_u128, 99808636200979152_u128, 
-    99557094812722720_u128, 99306130911303216_u128, 99055743468314592_u128, 98805931454605120_u128, 98556693842514416_u128, 
-    98308029603487264_u128, 98059937710161552_u128, 97812417133982080_u128, 97565466848779744_u128, 97319085826446752_u128, 
-    97073273040814000_u128, 96828027465115888_u128, 96583348073332448_u128, 96339233839443696_u128, 96095683738175328_u128, 
-    95852696743805664_u128, 95610271831955136_u128, 95368407977945984_u128, 95127104157846080_u128, 94886359347126752_u128, 
-    94646172523347168_u128, 94406542662873472_u128, 94167468743563088_u128, 93928949742975200_u128, 93690984639265504_u128, 
-    93453572411186224_u128, 93216712037936944_u128, 92980402499164720_u128, 92744642774516512_u128, 92509431844981520_u128, 
-    92274768690952416_u128, 92040652294164032_u128, 91807081636947712_u128, 91574055700740032_u128, 91341573469512816_u128, 
-    91109633926343040_u128, 90878236054755152_u128, 90647378840212240_u128, 90417061266984352_u128, 90187282320982016_u128, 
-    89958040988563104_u128, 89729336256383760_u128, 89501167111398432_u128, 89273532542351072_u128, 89046431536941776_u128, 
-    88819863084511024_u128, 88593826174995872_u128, 88368319798929872_u128, 88143342946548304_u128, 87918894610025168_u128, 
-    87694973781832720_u128, 87471579453846688_u128, 87248710620627168_u128, 87026366275988576_u128, 86804545414640144_u128, 
-    86583247032782400_u128, 86362470125870192_u128, 86142213691297120_u128, 85922476726456736_u128, 85703258230233936_u128, 
-    85484557200917056_u128, 85266372638882304_u128, 85048703544505872_u128, 84831548918611312_u128, 84614907763960928_u128, 
-    84398779082571344_u128, 84183161878397872_u128, 83968055155694128_u128, 83753457919310192_u128, 83539369174841856_u128, 
-    83325787929376192_u128, 83112713190149392_u128, 82900143965292464_u128, 82688079263383776_u128, 82476518095089536_u128, 
-    82265459470032064_u128, 82054902400667120_u128, 81844845898406592_u128, 81635288976601040_u128, 81426230649495808_u128, 
-    81217669931336256_u128, 81009605838157328_u128, 80802037386590432_u128, 80594963593267040_u128, 80388383477055568_u128, 
-    80182296057271776_u128, 79976700352933248_u128, 79771595386040112_u128, 79566980177399504_u128, 79362853750502848_u128, 
-    79159215128245120_u128, 78956063335609072_u128, 78753397397726608_u128, 78551216340773552_u128, 78349519192566144_u128, 
-    78148304980771552_u128, 77947572734697296_u128, 77747321484545744_u128, 77547550260966640_u128, 77348258096101024_u128, 
-    77149444022984736_u128, 76951107075697536_u128, 76753246288617440_u128, 76555860697912000_u128, 76358949340196224_u128, 
-    76162511253427248_u128, 75966545476009616_u128, 75771051048584848_u128, 75576027011048768_u128, 75381472405534224_u128, 
-    75187386274919664_u128, 74993767662978336_u128, 74800615614229168_u128, 74607929174831488_u128, 74415707391839440_u128, 
-    74223949312456272_u128, 74032653986271360_u128, 73841820462874048_u128, 73651447793643248_u128, 73461535030405296_u128, 
-    73272081226328672_u128, 73083085435327536_u128, 72894546713254736_u128, 72706464115664848_u128, 72518836700647696_u128, 
-    72331663526293088_u128, 72144943651883872_u128, 71958676138641616_u128, 71772860047638744_u128, 71587494442184648_u128, 
-    71402578385588712_u128, 71218110943248168_u128, 71034091181156744_u128, 70850518166799504_u128, 70667390967512360_u128, 
-    70484708653762984_u128, 70302470295273400_u128, 70120674964300832_u128, 69939321733102520_u128, 69758409675874416_u128, 
-    69577937867707224_u128, 69397905384139072_u128, 69218311303243296_u128, 69039154703093232_u128, 68860434663104400_u128, 
-    68682150264034496_u128, 68504300588132528_u128, 68326884718094872_u128, 68149901738109248_u128, 67973350733705528_u128, 
-    67797230790860984_u128, 67621540998088112_u128, 67446280443452024_u128, 67271448217254768_u128, 67097043410991480_u128, 
-    66923065116455512_u128, 66749512427677216_u128, 66576384438686912_u128, 66403680246646704_u128, 66231398947227344_u128, 
-    66059539639678768_u128, 65888101423250872_u128, 65717083398684888_u128, 65546484667318560_u128, 65376304333472256_u128, 
-    65206541499975016_u128, 65037195273533304_u128, 64868264760107920_u128, 64699749068045752_u128, 64531647306588488_u128, 
-    64363958585574320_u128, 64196682016929288_u128, 64029816713623344_u128, 63863361789222952_u128, 63697316359829808_u128, 
-    63531679541098216_u128, 63366450451068576_u128, 63201628208825192_u128, 63037211934496304_u128, 62873200749552304_u128, 
-    62709593776358392_u128, 62546390139516720_u128, 62383588963480296_u128, 62221189375386512_u128, 62059190502074472_u128, 
-    61897591473515032_u128, 61736391418784280_u128, 61575589470090032_u128, 61415184759938384_u128, 61255176422326712_u128, 
-    61095563592147200_u128, 60936345406081600_u128, 60777521002153832_u128, 60619089519133472_u128, 60461050097281416_u128, 
-    60303401878051592_u128, 60146144004389240_u128, 59989275620730904_u128, 59832795871811408_u128, 59676703904900784_u128, 
-    59520998867269056_u128, 59365679908870624_u128, 59210746179659880_u128, 59056196831529896_u128, 58902031017566824_u128, 
-    58748247892497240_u128, 58594846611196840_u128, 58441826331225696_u128, 58289186210442120_u128, 58136925408792264_u128, 
-    57985043086520560_u128, 57833538406108368_u128, 57682410530782720_u128, 57531658625411096_u128, 57381281855755752_u128, 
-    57231279389070240_u128, 57081650394546832_u128, 56932394041079528_u128, 56783509500694072_u128, 56634995945565352_u128, 
-    56486852549657816_u128, 56339078487681560_u128, 56191672936583656_u128, 56044635073460304_u128, 55897964078241184_u128, 
-    55751659130110320_u128, 55605719412129136_u128, 55460144106016896_u128, 55314932396475464_u128, 55170083469548880_u128, 
-    55025596511430320_u128, 54881470710997320_u128, 54737705257276536_u128, 54594299341382472_u128, 54451252155622648_u128, 
-    54308562892901144_u128, 54166230748806368_u128, 54024254919225000_u128, 53882634601087632_u128, 53741368994158344_u128, 
-    53600457297604696_u128, 53459898713278600_u128, 53319692444075880_u128, 53179837693638008_u128, 53040333667843440_u128, 
-    52901179573018008_u128, 52762374617426240_u128, 52623918010525720_u128, 52485808962519696_u128, 52348046685997480_u128, 
-    52210630394294072_u128, 52073559301639224_u128, 51936832624350552_u128, 51800449579938688_u128, 51664409386659928_u128, 
-    51528711264560160_u128, 51393354435474808_u128, 51258338121537584_u128, 51123661546970008_u128, 50989323936739272_u128, 
-    50855324518198656_u128, 50721662518850576_u128, 50588337167688736_u128, 50455347696242088_u128, 50322693335592176_u128, 
-    50190373319504912_u128, 50058386882790104_u128, 49926733260704976_u128, 49795411691489344_u128, 49664421412786520_u128, 
-    49533761665669824_u128, 49403431690168640_u128, 49273430730189776_u128, 49143758028745240_u128, 49014412831531408_u128, 
-    48885394385139432_u128, 48756701937353504_u128, 48628334737896536_u128, 48500292036938808_u128, 48372573086440200_u128, 
-    48245177140000992_u128, 48118103451967160_u128, 47991351278772480_u128, 47864919876701608_u128, 47738808505767472_u128, 
-    47613016424342560_u128, 47487542894975024_u128, 47362387179616480_u128, 47237548542455536_u128, 47113026248426416_u128, 
-    46988819564700336_u128, 46864927758746768_u128, 46741350099824728_u128, 46618085858833712_u128, 46495134307120576_u128, 
-    46372494718716552_u128, 46250166367652848_u128, 46128148529750272_u128, 46006440482320912_u128, 45885041504168184_u128, 
-    45763950875288536_u128, 45643167875976688_u128, 45522691790106496_u128, 45402521900060512_u128, 45282657492247800_u128, 
-    45163097852630064_u128, 45043842268809424_u128, 44924890030774096_u128, 44806240427617520_u128, 44687892752459656_u128, 
-    44569846298122216_u128, 44452100358172544_u128, 44334654229309760_u128, 44217507208680352_u128, 44100658593878216_u128, 
-    43984107685032464_u128, 43867853783316128_u128, 43751896191244408_u128, 43636234211630776_u128, 43520867150420448_u128, 
-    43405794313558648_u128, 43291015008332752_u128, 43176528544267120_u128, 43062334231035248_u128, 42948431380398448_u128, 
-    42834819305907592_u128, 42721497320367936_u128, 42608464740163840_u128, 42495720882276200_u128, 42383265064133296_u128, 
-    42271096605847792_u128, 42159214827532304_u128, 42047619051387328_u128, 41936308601104632_u128, 41825282800674264_u128, 
-    41714540976770616_u128, 41604082456366360_u128, 41493906567627192_u128, 41384012640955776_u128, 41274400006903928_u128, 
-    41165067998856912_u128, 41056015949454376_u128, 40947243194616816_u128, 40838749070115616_u128, 40730532914108240_u128, 
-    40622594065199560_u128, 40514931863336608_u128, 40407545651001648_u128, 40300434770080416_u128, 40193598564844760_u128, 
-    40087036381057808_u128, 39980747565377488_u128, 39874731465207392_u128, 39768987430486304_u128, 39663514811600440_u128, 
-    39558312960129032_u128, 39453381229142632_u128, 39348718973799632_u128, 39244325548661872_u128, 39140200311721240_u128, 
-    39036342620373064_u128, 38932751834846184_u128, 38829427315518560_u128, 38726368424855976_u128, 38623574525622496_u128, 
-    38521044982968264_u128, 38418779162341688_u128, 38316776431726392_u128, 38215036158658624_u128, 38113557713209848_u128, 
-    38012340466644568_u128, 37911383790525552_u128, 37810687059398200_u128, 37710249646913112_u128, 37610070930150888_u128, 
-    37510150285744760_u128, 37410487092117504_u128, 37311080730078016_u128, 37211930579540376_u128, 37113036023848704_u128, 
-    37014396445899704_u128, 36916011230976192_u128, 36817879764808352_u128, 36720001435065096_u128, 36622375630608360_u128, 
-    36525001740896608_u128, 36427879157028760_u128, 36331007271445896_u128, 36234385478080408_u128, 36138013171312088_u128, 
-    36041889747757664_u128, 35946014604183032_u128, 35850387139441896_u128, 35755006753879272_u128, 35659872847541916_u128, 
-    35564984823608332_u128, 35470342085107896_u128, 35375944037157800_u128, 35281790085620912_u128, 35187879637553132_u128, 
-    35094212101501676_u128, 35000786887505056_u128, 34907603406198320_u128, 34814661069558688_u128, 34721959291352952_u128, 
-    34629497486242684_u128, 34537275069336852_u128, 34445291458577912_u128, 34353546071610052_u128, 34262038328165300_u128, 
-    34170767649168716_u128, 34079733455694508_u128, 33988935172097748_u128, 33898372221540472_u128, 33808044030316452_u128, 
-    33717950024868604_u128, 33628089633280272_u128, 33538462284827852_u128, 33449067409682524_u128, 33359904439655900_u128, 
-    33270972807156132_u128, 33182271946380920_u128, 33093801292571900_u128, 33005560282014608_u128, 32917548352485896_u128, 
-    32829764942657400_u128, 32742209492095540_u128, 32654881442752832_u128, 32567780235985260_u128, 32480905316131436_u128, 
-    32394256127529960_u128, 32307832116309016_u128, 32221632729491556_u128, 32135657415144460_u128, 32049905622825916_u128, 
-    31964376802988888_u128, 31879070408174176_u128, 31793985890624324_u128, 31709122704371440_u128, 31624480305684588_u128, 
-    31540058150087192_u128, 31455855695637892_u128, 31371872401140976_u128, 31288107726742924_u128, 31204561133484988_u128, 
-    31121232083750604_u128, 31038120040967116_u128, 30955224469904060_u128, 30872544835927480_u128, 30790080606789524_u128, 
-    30707831250093196_u128, 30625796235081956_u128, 30543975032639688_u128, 30462367113650284_u128, 30380971951532860_u128, 
-    30299789019855660_u128, 30218817793827364_u128, 30138057749104048_u128, 30057508363280492_u128, 29977169115293652_u128, 
-    29897039484080472_u128, 29817118950665748_u128, 29737406996819912_u128, 29657903105655584_u128, 29578606761180164_u128, 
-    29499517448743232_u128, 29420634654589148_u128, 29341957866602716_u128, 29263486572967000_u128, 29185220263505500_u128, 
-    29107158429831296_u128, 29029300562960932_u128, 28951646156297048_u128, 28874194704882728_u128, 28796945703015400_u128, 
-    28719898647527716_u128, 28643053036594500_u128, 28566408367794056_u128, 28489964142283836_u128, 28413719859729968_u128, 
-    28337675023079468_u128, 28261829134682832_u128, 28186181699574896_u128, 28110732222790516_u128, 28035480210259312_u128, 
-    27960425169998752_u128, 27885566611070212_u128, 27810904042236804_u128, 27736436974796872_u128, 27662164920943540_u128, 
-    27588087393168188_u128, 27514203905751776_u128, 27440513973571784_u128, 27367017113891780_u128, 27293712842633160_u128, 
-    27220600679296460_u128, 27147680142487424_u128, 27074950753794420_u128, 27002412034209292_u128, 26930063506513448_u128, 
-    26857904694383084_u128, 26785935123134836_u128, 26714154318234472_u128, 26642561806788200_u128, 26571157117095264_u128, 
-    26499939777753180_u128, 26428909319745556_u128, 26358065273906864_u128, 26287407171966368_u128, 26216934548188548_u128, 
-    26146646936390496_u128, 26076543872626268_u128, 26006624892800788_u128, 25936889534757676_u128, 25867337336937076_u128, 
-    25797967839270448_u128, 25728780582434896_u128, 25659775108002320_u128, 25590950958737652_u128, 25522307678748016_u128, 
-    25453844812587920_u128, 25385561906004916_u128, 25317458506834392_u128, 25249534161867828_u128, 25181788420879300_u128, 
-    25114220833642904_u128, 25046830950976644_u128, 24979618325488088_u128, 24912582510083076_u128, 24845723058413096_u128, 
-    24779039526068332_u128, 24712531469384636_u128, 24646198444996108_u128, 24580040011326424_u128, 24514055727992304_u128, 
-    24448245154610472_u128, 24382607852438088_u128, 24317143384074484_u128, 24251851312566400_u128, 24186731202004472_u128, 
-    24121782617970660_u128, 24057005126345176_u128, 23992398294648676_u128, 23927961690849208_u128, 23863694884406120_u128, 
-    23799597445524428_u128, 23735668945303924_u128, 23671908955888316_u128, 23608317050614360_u128, 23544892803713596_u128, 
-    23481635790163220_u128, 23418545586431736_u128, 23355621769435036_u128, 23292863916834672_u128, 23230271608529152_u128, 
-    23167844424267856_u128, 23105581944844088_u128, 23043483752393312_u128, 22981549430244056_u128, 22919778561724836_u128, 
-    22858170732550264_u128, 22796725527540168_u128, 22735442534347864_u128, 22674321340924924_u128, 22613361535222924_u128, 
-    22552562707579536_u128, 22491924448630684_u128, 22431446349459692_u128, 22371128003535976_u128, 22310969003881560_u128, 
-    22250968944860636_u128, 22191127422179592_u128, 22131444032439584_u128, 22071918372390904_u128, 22012550040573416_u128, 
-    21953338636272636_u128, 21894283759519736_u128, 21835385011688060_u128, 21776641994449224_u128, 21718054310817008_u128, 
-    21659621564849120_u128, 21601343361199784_u128, 21543219305567136_u128, 21485249005140632_u128, 21427432066960588_u128, 
-    21369768099707764_u128, 21312256712659436_u128, 21254897516584196_u128, 21197690122250632_u128, 21140634142664296_u128, 
-    21083729189935952_u128, 21026974879009852_u128, 20970370823935464_u128, 20913916641446608_u128, 20857611947829712_u128, 
-    20801456360713384_u128, 20745449498471884_u128, 20689590981269040_u128, 20633880428970420_u128, 20578317462783772_u128, 
-    20522901705109888_u128, 20467632778946076_u128, 20412510308333576_u128, 20357533918059268_u128, 20302703233357432_u128, 
-    20248017881997568_u128, 20193477490257864_u128, 20139081687250008_u128, 20084830102533060_u128, 20030722365367836_u128, 
-    19976758107401240_u128, 19922936960429296_u128, 19869258557291960_u128, 19815722531723964_u128, 19762328518056564_u128, 
-    19709076151963192_u128, 19655965069564676_u128, 19602994908174892_u128, 19550165305405968_u128, 19497475900808744_u128, 
-    19444926333784920_u128, 19392516244481856_u128, 19340245275582136_u128, 19288113067978772_u128, 19236119265994780_u128, 
-    19184263513207532_u128, 19132545454238304_u128, 19080964735497956_u128, 19029521003099076_u128, 18978213904645560_u128, 
-    18927043088188704_u128, 18876008203569360_u128, 18825108899882744_u128, 18774344828311888_u128, 18723715640636356_u128, 
-    18673220989381364_u128, 18622860527370384_u128, 18572633909812992_u128, 18522540790427448_u128, 18472580825914628_u128, 
-    18422753672677148_u128, 18373058988310672_u128, 18323496430559996_u128, 18274065659705136_u128, 18224766334534796_u128, 
-    18175598116372916_u128, 18126560666990820_u128, 18077653648458092_u128, 18028876723888236_u128, 17980229557886066_u128, 
-    17931711814907258_u128, 17883323160749670_u128, 17835063261509422_u128, 17786931785221334_u128, 17738928399472832_u128, 
-    17691052773342650_u128, 17643304576058654_u128, 17595683478936544_u128, 17548189152397230_u128, 17500821269545980_u128, 
-    17453579502145882_u128, 17406463524793512_u128, 17359473011488922_u128, 17312607637574342_u128, 17265867079734176_u128, 
-    17219251013459786_u128, 17172759117821670_u128, 17126391070100758_u128, 17080146550262334_u128, 17034025238122550_u128, 
-    16988026814243216_u128, 16942150960230054_u128, 16896397358434440_u128, 16850765691953406_u128, 16805255644778770_u128, 
-    16759866901498866_u128, 16714599147149430_u128, 16669452068406632_u128, 16624425351946640_u128, 16579518685638674_u128, 
-    16534731757501080_u128, 16490064257490908_u128, 16445515875416074_u128, 16401086301233626_u128, 16356775227435836_u128, 
-    16312582345769324_u128, 16268507349322884_u128, 16224549932378358_u128, 16180709788322802_u128, 16136986613675022_u128, 
-    16093380103611642_u128, 16049889955098860_u128, 16006515865848528_u128, 15963257534169016_u128, 15920114658517830_u128, 
-    15877086939142044_u128, 15834174076288732_u128, 15791375771248886_u128, 15748691725462624_u128, 15706121642905290_u128, 
-    15663665225762662_u128, 15621322178159210_u128, 15579092205710722_u128, 15536975013585588_u128, 15494970308145244_u128, 
-    15453077796347650_u128, 15411297186045552_u128, 15369628185986480_u128, 15328070504619704_u128, 15286623852780584_u128, 
-    15245287940260568_u128, 15204062479088062_u128, 15162947180993212_u128, 15121941758899214_u128, 15081045925878388_u128, 
-    15040259396643498_u128, 14999581886056438_u128, 14959013109426494_u128, 14918552782957736_u128, 14878200624047282_u128, 
-    14837956350241378_u128, 14797819680279320_u128, 14757790332453010_u128, 14717868027291312_u128, 14678052485024828_u128, 
-    14638343426928076_u128, 14598740574722968_u128, 14559243651324460_u128, 14519852379349246_u128, 14480566483650988_u128, 
-    14441385687890294_u128, 14402309718412128_u128, 14363338300219282_u128, 14324471160700636_u128, 14285708026648548_u128, 
-    14247048626943208_u128, 14208492689271758_u128, 14170039943707434_u128, 14131690120323472_u128, 14093442949193110_u128, 
-    14055298162775672_u128, 14017255492039184_u128, 13979314670636018_u128, 13941475431323766_u128, 13903737508649588_u128, 
-    13866100637160644_u128, 13828564552149748_u128, 13791128989506236_u128, 13753793686610758_u128, 13716558380396562_u128, 
-    13679422808840820_u128, 13642386710666354_u128, 13605449825192510_u128, 13568611892782548_u128, 13531872653650602_u128, 
-    13495231848905586_u128, 13458689221147726_u128, 13422244512678980_u128, 13385897466546968_u128, 13349647826544958_u128, 
-    13313495337361008_u128, 13277439744279694_u128, 13241480793032986_u128, 13205618229800250_u128, 13169851801655632_u128, 
-    13134181256866330_u128, 13098606342953880_u128, 13063126809527656_u128, 13027742405749638_u128, 12992452881974850_u128, 
-    12957257989154844_u128, 12922157478539428_u128, 12887151102422330_u128, 12852238613693800_u128, 12817419764945824_u128, 
-    12782694311007354_u128, 12748062005961688_u128, 12713522605234294_u128, 12679075864548912_u128, 12644721539927536_u128, 
-    12610459389330864_u128, 12576289169228286_u128, 12542210639220936_u128, 12508223556971252_u128, 12474327682826022_u128, 
-    12440522775938990_u128, 12406808597999124_u128, 12373184909800608_u128, 12339651473181536_u128, 12306208050874796_u128, 
-    12272854405464136_u128, 12239590301322882_u128, 12206415502526090_u128, 12173329773297956_u128, 12140332880099628_u128, 
-    12107424588497478_u128, 12074604665250920_u128, 12041872877268500_u128, 12009228993099202_u128, 11976672780248096_u128, 
-    11944204008606342_u128, 11911822447170318_u128, 11879527866576842_u128, 11847320037015334_u128, 11815198730315658_u128, 
-    11783163718009414_u128, 11751214773119514_u128, 11719351668072338_u128, 11687574176785586_u128, 11655882073922600_u128, 
-    11624275133401078_u128, 11592753131226544_u128, 11561315843404522_u128, 11529963045791408_u128, 11498694516033162_u128, 
-    11467510031477492_u128, 11436409370366880_u128, 11405392311540340_u128, 11374458634433404_u128, 11343608118779868_u128, 
-    11312840544760918_u128, 11282155693601660_u128, 11251553346974588_u128, 11221033287148722_u128, 11190595296243948_u128, 
-    11160239157871466_u128, 11129964655493338_u128, 11099771573764678_u128, 11069659697489728_u128, 11039628811920124_u128, 
-    11009678703202286_u128, 10979809157482632_u128, 10950019962100632_u128, 10920310904992272_u128, 10890681773347890_u128, 
-    10861132356743914_u128, 10831662444160250_u128, 10802271824725934_u128, 10772960289806964_u128, 10743727629278030_u128, 
-    10714573634803394_u128, 10685498098643836_u128, 10656500812612750_u128, 10627581570014832_u128, 10598740164154782_u128, 
-    10569976389232084_u128, 10541290039595354_u128, 10512680910487988_u128, 10484148797451650_u128, 10455693496028000_u128, 
-    10427314803697400_u128, 10399012517045426_u128, 10370786433850700_u128, 10342636352488370_u128, 10314562071482714_u128, 
-    10286563389656268_u128, 10258640107621144_u128, 10230792024945532_u128, 10203018942539804_u128, 10175320661463458_u128, 
-    10147696983819914_u128, 10120147711712588_u128, 10092672647990550_u128, 10065271595801132_u128, 10037944359037320_u128, 
-    10010690741890362_u128, 9983510549446292_u128, 9956403586642012_u128, 9929369659458336_u128, 9902408573876088_u128, 
-    9875520137218258_u128, 9848704156509580_u128, 9821960439371312_u128, 9795288794468626_u128, 9768689029721038_u128, 
-    9742160955583292_u128, 9715704381018822_u128, 9689319116034976_u128, 9663004972428674_u128, 9636761760058136_u128, 
-    9610589291913326_u128, 9584487379045508_u128, 9558455834593780_u128, 9532494472144632_u128, 9506603104688028_u128, 
-    9480781546108718_u128, 9455029612230156_u128, 9429347116489696_u128, 9403733875754708_u128, 9378189705102986_u128, 
-    9352714421849292_u128, 9327307841966208_u128, 9301969783812406_u128, 9276700064851776_u128, 9251498503442996_u128, 
-    9226364918392132_u128, 9201299129101772_u128, 9176300955421900_u128, 9151370217351632_u128, 9126506735635734_u128, 
-    9101710331764626_u128, 9076980826930472_u128, 9052318043071082_u128, 9027721803764714_u128, 9003191930650915_u128, 
-    8978728248053596_u128, 8954330579700140_u128, 8929998749616192_u128, 8905732583169575_u128, 8881531905131588_u128, 
-    8857396541764840_u128, 8833326318437153_u128, 8809321062604181_u128, 8785380601125055_u128, 8761504760858906_u128, 
-    8737693370752696_u128, 8713946258560341_u128, 8690263253527065_u128, 8666644184450699_u128, 8643088881322121_u128, 
-    8619597174579600_u128, 8596168894363147_u128, 8572803872453209_u128, 8549501939288055_u128, 8526262927841180_u128, 
-    8503086669594770_u128, 8479972998267972_u128, 8456921746088628_u128, 8433932747372407_u128, 8411005836285852_u128, 
-    8388140846846371_u128, 8365337614413551_u128, 8342595973899587_u128, 8319915762155374_u128, 8297296814242238_u128, 
-    8274738967011073_u128, 8252242058505821_u128, 8229805925129985_u128, 8207430405374899_u128, 8185115337284503_u128, 
-    8162860560095787_u128, 8140665912449215_u128, 8118531233880037_u128, 8096456365265679_u128, 8074441146290522_u128, 
-    8052485417981124_u128, 8030589021513173_u128, 8008751798808012_u128, 7986973591786983_u128, 7965254242967952_u128, 
-    7943593595316178_u128, 7921991492244312_u128, 7900447776866741_u128, 7878962294236556_u128, 7857534888213800_u128, 
-    7836165404000693_u128, 7814853686948586_u128, 7793599582557962_u128, 7772402937522349_u128, 7751263597789621_u128, 
-    7730181410649830_u128, 7709156223243897_u128, 7688187883607527_u128, 7667276239478165_u128, 7646421139786301_u128, 
-    7625622433462427_u128, 7604879969287901_u128, 7584193597535391_u128, 7563563168030174_u128, 7542988531492309_u128, 
-    7522469538641856_u128, 7502006040944531_u128, 7481597889866047_u128, 7461244937319510_u128, 7440947035963683_u128, 
-    7420704039053849_u128, 7400515798801376_u128, 7380382169505464_u128, 7360303005167052_u128, 7340278159637945_u128, 
-    7320307488410392_u128, 7300390845783591_u128, 7280528087846311_u128, 7260719069941669_u128, 7240963648904087_u128, 
-    7221261680971467_u128, 7201613023127361_u128, 7182017533100979_u128, 7162475068174136_u128, 7142985486821695_u128, 
-    7123548647220256_u128, 7104164408292074_u128, 7084832629705059_u128, 7065553170679726_u128, 7046325890585723_u128, 
-    7027150651178790_u128, 7008027311977704_u128, 6988955733992553_u128, 6969935779873860_u128, 6950967309736928_u128, 
-    6932050187127066_u128, 6913184273949145_u128, 6894369433002821_u128, 6875605527386012_u128, 6856892421240550_u128, 
-    6838229977962614_u128, 6819618061992300_u128, 6801056538515356_u128, 6782545271524484_u128, 6764084127249349_u128, 
-    6745672971173961_u128, 6727311668931461_u128, 6709000087348037_u128, 6690738093399008_u128, 6672525553761430_u128, 
-    6654362336007145_u128, 6636248307857126_u128, 6618183337927129_u128, 6600167294385521_u128, 6582200045848057_u128, 
-    6564281461974412_u128, 6546411412125996_u128, 6528589765962483_u128, 6510816394038331_u128, 6493091166609736_u128, 
-    6475413954678547_u128, 6457784629843140_u128, 6440203063254494_u128, 6422669127107507_u128, 6405182693149682_u128, 
-    6387743634321572_u128, 6370351824011118_u128, 6353007134562350_u128, 6335709440407124_u128, 6318458614485993_u128, 
-    6301254531976470_u128, 6284097067310412_u128, 6266986095217942_u128, 6249921490429178_u128, 6232903129463813_u128, 
-    6215930887648488_u128, 6199004641204634_u128, 6182124267099332_u128, 6165289641703142_u128, 6148500642579670_u128, 
-    6131757146994260_u128, 6115059032957911_u128, 6098406178481621_u128, 6081798462172911_u128, 6065235763086696_u128, 
-    6048717959681365_u128, 6032244932055750_u128, 6015816559712156_u128, 5999432722451151_u128, 5983093301266350_u128, 
-    5966798176554844_u128, 5950547228862855_u128, 5934340340377045_u128, 5918177392389288_u128, 5902058266787986_u128, 
-    5885982845759797_u128, 5869951012237039_u128, 5853962648406371_u128, 5838017638691417_u128, 5822115865278837_u128, 
-    5806257212890517_u128, 5790441564906164_u128, 5774668806644185_u128, 5758938822229943_u128, 5743251496832715_u128, 
-    5727606715621776_u128, 5712004364661191_u128, 5696444329567628_u128, 5680926496703411_u128, 5665450752579994_u128, 
-    5650016983857962_u128, 5634625078092686_u128, 5619274922392144_u128, 5603966404609966_u128, 5588699413047178_u128, 
-    5573473835110018_u128, 5558289560889080_u128, 5543146477939735_u128, 5528044476501707_u128, 5512983445025150_u128, 
-    5497963274197183_u128, 5482983854108399_u128, 5468045074401999_u128, 5453146826212493_u128, 5438289000674390_u128, 
-    5423471489071332_u128, 5408694182090433_u128, 5393956972506644_u128, 5379259752349259_u128, 5364602413200179_u128, 
-    5349984848580007_u128, 5335406951114561_u128, 5320868614175312_u128, 5306369731282862_u128, 5291910195957815_u128, 
-    5277489902615557_u128, 5263108745522344_u128, 5248766619242696_u128, 5234463418937653_u128, 5220199039469996_u128, 
-    5205973376746419_u128, 5191786326226225_u128, 5177637783816110_u128, 5163527646317554_u128, 5149455809935515_u128, 
-    5135422171471472_u128, 5121426628323428_u128, 5107469078038519_u128, 5093549417865616_u128, 5079667545799248_u128, 
-    5065823360430462_u128, 5052016759902918_u128, 5038247643255057_u128, 5024515908928799_u128, 5010821457155632_u128, 
-    4997164186526606_u128, 4983543997869734_u128, 4969960790521719_u128, 4956414464564920_u128, 4942904921871264_u128, 
-    4929432062075717_u128, 4915995787050207_u128, 4902595998070138_u128, 4889232596410914_u128, 4875905484391856_u128, 
-    4862614564332285_u128, 4849359738551519_u128, 4836140909368880_u128, 4822957980594995_u128, 4809810854250924_u128, 
-    4796699435042079_u128, 4783623625884303_u128, 4770583331333880_u128, 4757578455350568_u128, 4744608902490649_u128, 
-    4731674577459537_u128, 4718775385111775_u128, 4705911231345823_u128, 4693082020867095_u128, 4680287660170572_u128, 
-    4667528054707323_u128, 4654803110972330_u128, 4642112735311444_u128, 4629456835114435_u128, 4616835316727153_u128, 
-    4604248087688497_u128, 4591695055239104_u128, 4579176127365266_u128, 4566691212351534_u128, 4554240217885937_u128, 
-    4541823052551291_u128, 4529439625526932_u128, 4517089845395674_u128, 4504773621336856_u128, 4492490862977206_u128, 
-    4480241479943454_u128, 4468025382309724_u128, 4455842480150136_u128, 4443692683687945_u128, 4431575903742926_u128, 
-    4419492051731378_u128, 4407441037578294_u128, 4395422773743889_u128, 4383437171346200_u128, 4371484142398050_u128, 
-    4359563598763131_u128, 4347675452901658_u128, 4335819617273847_u128, 4323996004936435_u128, 4312204528498770_u128, 
-    4300445101166719_u128, 4288717636742676_u128, 4277022048879902_u128, 4265358251380790_u128, 4253726158345994_u128, 
-    4242125684174429_u128, 4230556743712404_u128, 4219019251806226_u128, 4207513122705681_u128, 4196038273046646_u128, 
-    4184594617377168_u128, 4173182071587471_u128, 4161800552015171_u128, 4150449974550491_u128, 4139130255680180_u128, 
-    4127841312040113_u128, 4116583060415301_u128, 4105355418038144_u128, 4094158302290175_u128, 4082991630552924_u128, 
-    4071855320207924_u128, 4060749289829753_u128, 4049673457545598_u128, 4038627741482644_u128, 4027612060215469_u128, 
-    4016626332915175_u128, 4005670478156340_u128, 3994744415706590_u128, 3983848065035287_u128, 3972981345164402_u128, 
-    3962144176905475_u128, 3951336479578740_u128, 3940558174592259_u128, 3929809180968004_u128, 3919089421008823_u128, 
-    3908398814780602_u128, 3897737283542275_u128, 3887104749000167_u128, 3876501132711472_u128, 3865926356382515_u128, 
-    3855380341868753_u128, 3844863011771296_u128, 3834374288243862_u128, 3823914094334953_u128, 3813482352347418_u128, 
-    3803078985628021_u128, 3792703917523526_u128, 3782357071380698_u128, 3772038370993693_u128, 3761747740007538_u128, 
-    3751485102962041_u128, 3741250383353100_u128, 3731043506764440_u128, 3720864396841087_u128, 3710712979017637_u128, 
-    3700589178430423_u128, 3690492920215779_u128, 3680424130106563_u128, 3670382733089978_u128, 3660368655942797_u128, 
-    3650381824248745_u128, 3640422164337204_u128, 3630489602686684_u128, 3620584066670483_u128, 3610705481723194_u128, 
-    3600853776411161_u128, 3591028877212895_u128, 3581230711352561_u128, 3571459207396502_u128, 3561714292270621_u128, 
-    3551995894541262_u128, 3542303942476504_u128, 3532638364195298_u128, 3522999088263987_u128, 3513386044143698_u128, 
-    3503799160102511_u128, 3494238365899817_u128, 3484703590251087_u128, 3475194763363105_u128, 3465711814846127_u128, 
-    3456254674310412_u128, 3446823272261004_u128, 3437417538755552_u128, 3428037404597362_u128, 3418682800291476_u128, 
-    3409353656790330_u128, 3400049905046359_u128, 3390771476608523_u128, 3381518302280126_u128, 3372290314206649_u128, 
-    3363087444384445_u128, 3353909624213340_u128, 3344756785987948_u128, 3335628862599404_u128, 3326525785894930_u128, 
-    3317447489063922_u128, 3308393904550124_u128, 3299364965692065_u128, 3290360606126535_u128, 3281380758297277_u128, 
-    3272425356586735_u128, 3263494334929961_u128, 3254587626516352_u128, 3245705166175746_u128, 3236846887843193_u128, 
-    3228012726050270_u128, 3219202615626811_u128, 3210416491402654_u128, 3201654288356766_u128, 3192915941617245_u128, 
-    3184201386461320_u128, 3175510558613613_u128, 3166843393649614_u128, 3158199827592205_u128, 3149579796911664_u128, 
-    3140983236437825_u128, 3132410084132272_u128, 3123860276017889_u128, 3115333748117558_u128, 3106830438541994_u128, 
-    3098350283612342_u128, 3089893220842792_u128, 3081459187449273_u128, 3073048121095109_u128, 3064659959741881_u128, 
-    3056294641052913_u128, 3047952103138918_u128, 3039632284558004_u128, 3031335123122622_u128, 3023060558136534_u128, 
-    3014808528008715_u128, 3006578971148142_u128, 2998371827455099_u128, 2990187036233345_u128, 2982024535742728_u128, 
-    2973884266629183_u128, 2965766168345604_u128, 2957670180941405_u128, 2949596243720346_u128, 2941544297626628_u128, 
-    2933514283157058_u128, 2925506140361050_u128, 2917519809735411_u128, 2909555232820865_u128, 2901612350412481_u128, 
-    2893691103305327_u128, 2885791433338388_u128, 2877913281455864_u128, 2870056589795000_u128, 2862221300045651_u128, 
-    2854407354046801_u128, 2846614693637434_u128, 2838843261700451_u128, 2831093000223967_u128, 2823363852090882_u128, 
-    2815655759885835_u128, 2807968666044334_u128, 2800302514344063_u128, 2792657247667923_u128, 2785032809197076_u128, 
-    2777429143007468_u128, 2769846191832869_u128, 2762283900196618_u128, 2754742211876400_u128, 2747221070799030_u128, 
-    2739720421636979_u128, 2732240208167931_u128, 2724780375362619_u128, 2717340868042643_u128, 2709921630582210_u128, 
-    2702522608548577_u128, 2695143746912474_u128, 2687784990197241_u128, 2680446285163178_u128, 2673127576631887_u128, 
-    2665828810319753_u128, 2658549932390554_u128, 2651290888411545_u128, 2644051625292158_u128, 2636832088599647_u128, 
-    2629632225392576_u128, 2622451981834722_u128, 2615291304984649_u128, 2608150141602656_u128, 2601028438598178_u128, 
-    2593926143178906_u128, 2586843202999928_u128, 2579779565567199_u128, 2572735177491888_u128, 2565709987920391_u128, 
-    2558703943613007_u128, 2551716993268740_u128, 2544749084542675_u128, 2537800166432076_u128, 2530870186890290_u128, 
-    2523959094318057_u128, 2517066838160033_u128, 2510193366369566_u128, 2503338628540443_u128, 2496502573968188_u128, 
-    2489685151053542_u128, 2482886310285075_u128, 2476106000660052_u128, 2469344171623128_u128, 2462600773662874_u128, 
-    2455875755925686_u128, 2449169069347526_u128, 2442480663223919_u128, 2435810488938223_u128, 2429158496084223_u128, 
-    2422524635747014_u128, 2415908858713429_u128, 2409311115621171_u128, 2402731357853596_u128, 2396169535899275_u128, 
-    2389625601738087_u128, 2383099506604259_u128, 2376591202030278_u128, 2370100639399499_u128, 2363627770840933_u128, 
-    2357172548185329_u128, 2350734923561697_u128, 2344314849099048_u128, 2337912277224653_u128, 2331527160216653_u128, 
-    2325159450800583_u128, 2318809102000237_u128, 2312476065795495_u128, 2306160295806675_u128, 2299861745057573_u128, 
-    2293580366571984_u128, 2287316114268487_u128, 2281068940425225_u128, 2274838800004692_u128, 2268625645881553_u128, 
-    2262429432123519_u128, 2256250112649170_u128, 2250087641675348_u128, 2243941973269763_u128, 2237813062245781_u128, 
-    2231700862223719_u128, 2225605328464336_u128, 2219526415333603_u128, 2213464078092279_u128, 2207418270957204_u128, 
-    2201388949934790_u128, 2195376069540140_u128, 2189379585034012_u128, 2183399452721079_u128, 2177435627116444_u128, 
-    2171488064375648_u128, 2165556720355974_u128, 2159641550765570_u128, 2153742512058240_u128, 2147859559793004_u128, 
-    2141992650572795_u128, 2136141741149680_u128, 2130306787082677_u128, 2124487746018636_u128, 2118684574113100_u128, 
-    2112897228267265_u128, 2107125665829719_u128, 2101369843254267_u128, 2095629718635151_u128, 2089905248127913_u128, 
-    2084196390423320_u128, 2078503102273436_u128, 2072825341772504_u128, 2067163066119982_u128, 2061516233857506_u128, 
-    2055884802781056_u128, 2050268730686614_u128, 2044667976414076_u128, 2039082497759423_u128, 2033512253860814_u128, 
-    2027957202365098_u128, 2022417303006959_u128, 2016892513433245_u128, 2011382793676900_u128, 2005888101981297_u128, 
-    2000408397782858_u128, 1994943640667131_u128, 1989493789175753_u128, 1984058803192536_u128, 1978638642601292_u128, 
-    1973233266987572_u128, 1967842635340404_u128, 1962466708885777_u128, 1957105446463588_u128, 1951758808703303_u128, 
-    1946426755786997_u128, 1941109248195006_u128, 1935806246258533_u128, 1930517710308784_u128, 1925243601273487_u128, 
-    1919983879782108_u128, 1914738506762376_u128, 1909507442992888_u128, 1904290649997894_u128, 1899088088705124_u128, 
-    1893899720042304_u128, 1888725505831949_u128, 1883565407747440_u128, 1878419386865636_u128, 1873287405307312_u128, 
-    1868169424745849_u128, 1863065406705500_u128, 1857975314052692_u128, 1852899108013415_u128, 1847836751304968_u128, 
-    1842788206495516_u128, 1837753435258443_u128, 1832732400758438_u128, 1827725065116276_u128, 1822731391794908_u128, 
-    1817751342616849_u128, 1812784881641572_u128, 1807831970840721_u128, 1802892574273772_u128, 1797966654210630_u128, 
-    1793054175009032_u128, 1788155099088013_u128, 1783269390805310_u128, 1778397013027353_u128, 1773537930708400_u128, 
-    1768692105969227_u128, 1763859504211485_u128, 1759040088748996_u128, 1754233823492105_u128, 1749440673395070_u128, 
-    1744660602069975_u128, 1739893574471079_u128, 1735139554657858_u128, 1730398507286309_u128, 1725670397459824_u128, 
-    1720955189536140_u128, 1716252848469515_u128, 1711563339363341_u128, 1706886627171878_u128, 1702222677296778_u128, 
-    1697571454692302_u128, 1692932924909231_u128, 1688307053647480_u128, 1683693805712179_u128, 1679093147250633_u128, 
-    1674505044410149_u128, 1669929461846726_u128, 1665366366751584_u128, 1660815724377247_u128, 1656277500721889_u128, 
-    1651751662678471_u128, 1647238175946906_u128, 1642737006972764_u128, 1638248122201611_u128, 1633771488824670_u128, 
-    1629307072541855_u128, 1624854840693518_u128, 1620414759874359_u128, 1615986797126469_u128, 1611570919342808_u128, 
-    1607167094012859_u128, 1602775287283930_u128, 1598395467689419_u128, 1594027602271418_u128, 1589671657624626_u128, 
-    1585327602133310_u128, 1580995403436086_u128, 1576675028873307_u128, 1572366446381848_u128, 1568069624047716_u128, 
-    1563784529509526_u128, 1559511131151546_u128, 1555249396910653_u128, 1550999294872853_u128, 1546760794018939_u128, 
-    1542533861987523_u128, 1538318467759399_u128, 1534114579718835_u128, 1529922166548361_u128, 1525741197228769_u128, 
-    1521571640144327_u128, 1517413464574088_u128, 1513266639498845_u128, 1509131133899389_u128, 1505006916756511_u128, 
-    1500893957945790_u128, 1496792226149753_u128, 1492701691094848_u128, 1488622322060128_u128, 1484554089070300_u128, 
-    1480496961702680_u128, 1476450909087188_u128, 1472415901845058_u128, 1468391909404471_u128, 1464378902088399_u128, 
-    1460376849772416_u128, 1456385722481231_u128, 1452405490686944_u128, 1448436124861654_u128, 1444477594880937_u128, 
-    1440529872111679_u128, 1436592925982064_u128, 1432666728008107_u128, 1428751248363647_u128, 1424846458415568_u128, 
-    1420952328635970_u128, 1417068829795214_u128, 1413195933707578_u128, 1409333610696030_u128, 1405481832276587_u128, 
-    1401640569965263_u128, 1397809794532420_u128, 1393989477792335_u128, 1390179591261024_u128, 1386380106305372_u128, 
-    1382590995037916_u128, 1378812228825543_u128, 1375043779184266_u128, 1371285618823149_u128, 1367537718810815_u128, 
-    1363800052303717_u128, 1360072590072217_u128, 1356355305272770_u128, 1352648170167045_u128, 1348951156420189_u128, 
-    1345264237039525_u128, 1341587384435853_u128, 1337920570721712_u128, 1334263769351818_u128, 1330616952438711_u128, 
-    1326980092840582_u128, 1323353163713886_u128, 1319736137767685_u128, 1316128987860172_u128, 1312531687446063_u128, 
-    1308944209681813_u128, 1305366527276482_u128, 1301798613983050_u128, 1298240443405362_u128, 1294691988252480_u128, 
-    1291153222873907_u128, 1287624120276964_u128, 1284104654214631_u128, 1280594798589014_u128, 1277094527302223_u128, 
-    1273603813958105_u128, 1270122632458768_u128, 1266650957302843_u128, 1263188762243308_u128, 1259736021182272_u128, 
-    1256292708916629_u128, 1252858799497616_u128, 1249434266976474_u128, 1246019086299227_u128, 1242613231815375_u128, 
-    1239216677874419_u128, 1235829399720646_u128, 1232451371107032_u128, 1229082567725256_u128, 1225722964223080_u128, 
-    1222372534950006_u128, 1219031255746842_u128, 1215699101112220_u128, 1212376046141294_u128, 1209062066376613_u128, 
-    1205757136913330_u128, 1202461232995733_u128, 1199174330315498_u128, 1195896403967781_u128, 1192627429644260_u128, 
-    1189367382887483_u128, 1186116239687388_u128, 1182873974990001_u128, 1179640565680046_u128, 1176415986554415_u128, 
-    1173200214199573_u128, 1169993224605459_u128, 1166794993314620_u128, 1163605497211781_u128, 1160424711690358_u128, 
-    1157252613336815_u128, 1154089178737614_u128, 1150934384031826_u128, 1147788205507652_u128, 1144650619900686_u128, 
-    1141521603648261_u128, 1138401133485970_u128, 1135289186298538_u128, 1132185738374166_u128, 1129090766746710_u128, 
-    1126004248599156_u128, 1122926160368835_u128, 1119856479536997_u128, 1116795182839234_u128, 1113742247756795_u128, 
-    1110697651025273_u128, 1107661370424178_u128, 1104633382838235_u128, 1101613665748691_u128, 1098602197233318_u128, 
-    1095598953580316_u128, 1092603913314850_u128, 1089617054067297_u128, 1086638352871515_u128, 1083667788103534_u128, 
-    1080705337095472_u128, 1077750978223363_u128, 1074804688968453_u128, 1071866447557645_u128, 1068936232366972_u128, 
-    1066014020579419_u128, 1063099791316673_u128, 1060193522059983_u128, 1057295191931034_u128, 1054404778560207_u128, 
-    1051522260621794_u128, 1048647616790091_u128, 1045780824993737_u128, 1042921864503550_u128, 1040070713695564_u128, 
-    1037227350796679_u128, 1034391755525106_u128, 1031563905809485_u128, 1028743781218895_u128, 1025931360129369_u128, 
-    1023126621662594_u128, 1020329545238518_u128, 1017540109382305_u128, 1014758293961295_u128, 1011984077351521_u128, 
-    1009217439420325_u128, 1006458359289391_u128, 1003706816527801_u128, 1000962790108110_u128, 998226260345050_u128, 
-    995497205912917_u128, 992775606977313_u128, 990061442958185_u128, 987354693722876_u128, 984655338989595_u128, 
-    981963358476552_u128, 979278732051087_u128, 976601440027934_u128, 973931462125304_u128, 971268778061405_u128, 
-    968613368896625_u128, 965965213454390_u128, 963324293242479_u128, 960690587531710_u128, 958064077233338_u128, 
-    955444742512967_u128, 952832563834461_u128, 950227521810814_u128, 947629596607629_u128, 945038769285293_u128, 
-    942455019711146_u128, 939878329392970_u128, 937308679092888_u128, 934746048827373_u128, 932190420104205_u128, 
-    929641773387248_u128, 927100090035150_u128, 924565350810036_u128, 922037536921427_u128, 919516629429707_u128, 
-    917002609097004_u128, 914495457729358_u128, 911995156387157_u128, 909501686279918_u128, 907015028766292_u128, 
-    904535165204926_u128, 902062077401861_u128, 899595746268355_u128, 897136154206972_u128, 894683282128968_u128, 
-    892237111840385_u128, 889797624998133_u128, 887364803855646_u128, 884938629920704_u128, 882519084701086_u128, 
-    880106150450226_u128, 877699809272427_u128, 875300042675468_u128, 872906833509307_u128, 870520163281724_u128, 
-    868140013947890_u128, 865766368506893_u128, 863399208466513_u128, 861038516378446_u128, 858684274943517_u128, 
-    856336466116899_u128, 853995072301156_u128, 851660076495375_u128, 849331460356469_u128, 847009207480046_u128, 
-    844693300119542_u128, 842383720528390_u128, 840080452302201_u128, 837783477247017_u128, 835492778809316_u128, 
-    833208339689927_u128, 830930142887936_u128, 828658171104169_u128, 826392407934238_u128, 824132835780708_u128, 
-    821879438090058_u128, 819632198010506_u128, 817391098392011_u128, 815156123128445_u128, 812927254771502_u128, 
-    810704477215056_u128, 808487773607326_u128, 806277127394791_u128, 804072522023931_u128, 801873941090358_u128, 
-    799681367891421_u128, 797494786470124_u128, 795314180123816_u128, 793139532746369_u128, 790970827784264_u128, 
-    788808049429636_u128, 786651180979832_u128, 784500206925250_u128, 782355110861500_u128, 780215876384194_u128, 
-    778082488132858_u128, 775954930299626_u128, 773833185585325_u128, 771717240120788_u128, 769607076159451_u128, 
-    767502679384755_u128, 765404033094050_u128, 763311122225124_u128, 761223930671850_u128, 759142443372016_u128, 
-    757066643921232_u128, 754996517853812_u128, 752932048467103_u128, 750873221295418_u128, 748820020381759_u128, 
-    746772431111309_u128, 744730437079677_u128, 742694023821175_u128, 740663175677069_u128, 738637877883407_u128, 
-    736618114781457_u128, 734603871756397_u128, 732595133298624_u128, 730591884793319_u128, 728594110730877_u128, 
-    726601796645610_u128, 724614927773568_u128, 722633488157755_u128, 720657464674659_u128, 718686841069021_u128, 
-    716721603322547_u128, 714761736373025_u128, 712807226053026_u128, 710858057002079_u128, 708914215351018_u128, 
-    706975686634154_u128, 705042455341884_u128, 703114508052434_u128, 701191829703593_u128, 699274406277065_u128, 
-    697362223307161_u128, 695455266477324_u128, 693553521470996_u128, 691656973971619_u128, 689765610259160_u128, 
-    687879415867929_u128, 685998376332238_u128, 684122477932052_u128, 682251706649076_u128, 680386048763276_u128, 
-    678525489808962_u128, 676670015916969_u128, 674819613367264_u128, 672974268290680_u128, 671133966967184_u128, 
-    669298695527610_u128, 667468439953662_u128, 665643187420091_u128, 663822923162947_u128, 662007634207848_u128, 
-    660197306834762_u128, 658391927621914_u128, 656591482849270_u128, 654795959244190_u128, 653005343086637_u128, 
-    651219621253101_u128, 649438780172679_u128, 647662806870990_u128, 645891687478868_u128, 644125409469326_u128, 
-    642363958674937_u128, 640607323016105_u128, 638855488325404_u128, 637108442374107_u128, 635366171442180_u128, 
-    633628662555243_u128, 631895903335437_u128, 630167880062730_u128, 628444580061002_u128, 626725990505003_u128, 
-    625012098420353_u128, 623302891429196_u128, 621598355811496_u128, 619898479636789_u128, 618203249930695_u128, 
-    616512653867963_u128, 614826678921607_u128, 613145312564638_u128, 611468541971806_u128, 609796354765255_u128, 
-    608128738417996_u128, 606465680253911_u128, 604807168193404_u128, 603153189411225_u128, 601503731976911_u128, 
-    599858783214341_u128, 598218330596529_u128, 596582362640402_u128, 594950866073317_u128, 593323829561334_u128, 
-    591701240428334_u128, 590083086892983_u128, 588469356279161_u128, 586860036954667_u128, 585255116989035_u128, 
-    583654583706146_u128, 582058426070320_u128, 580466630958048_u128, 578879187930171_u128, 577296083714048_u128, 
-    575717307124868_u128, 574142846381297_u128, 572572689552871_u128, 571006824858255_u128, 569445240516116_u128, 
-    567887925043382_u128, 566334866658718_u128, 564786053729921_u128, 563241474624789_u128, 561701117860249_u128, 
-    560164972102360_u128, 558633025271525_u128, 557105266630328_u128, 555581683950041_u128, 554062266642377_u128, 
-    552547002478610_u128, 551035880870452_u128, 549528890185700_u128, 548026018941283_u128, 546527256399781_u128, 
-    545032590928991_u128, 543542011045842_u128, 542055506460307_u128, 540573065540184_u128, 539094677100662_u128, 
-    537620330106060_u128, 536150013520700_u128, 534683716756293_u128, 533221428180637_u128, 531763137205443_u128, 
-    530308832944164_u128, 528858504361119_u128, 527412140718890_u128, 525969730981797_u128, 524531264561552_u128, 
-    523096730720738_u128, 521666118423674_u128, 520239417380336_u128, 518816616256780_u128, 517397705210374_u128, 
-    515982673205439_u128, 514571509355424_u128, 513164203668566_u128, 511760745258316_u128, 510361123536386_u128, 
-    508965328660143_u128, 507573348997383_u128, 506185175301998_u128, 504800796538306_u128, 503420202565413_u128, 
-    502043382944162_u128, 500670327384528_u128, 499301025745615_u128, 497935467140874_u128, 496573642622457_u128, 
-    495215540558160_u128, 493861151701874_u128, 492510465763573_u128, 491163472304100_u128, 489820161480822_u128, 
-    488480523003712_u128, 487144547328400_u128, 485812224015728_u128, 484483543223063_u128, 483158495107772_u128, 
-    481837069528960_u128, 480519256494862_u128, 479205046759369_u128, 477894429585062_u128, 476587396024092_u128, 
-    475283935786434_u128, 473984039029455_u128, 472687696357913_u128, 471394897630913_u128, 470105633900607_u128, 
-    468819894727839_u128, 467537670866498_u128, 466258952473950_u128, 464983730602348_u128, 463711994812534_u128, 
-    462443736454922_u128, 461178944941224_u128, 459917611920115_u128, 458659727250700_u128, 457405281836000_u128, 
-    456154266280773_u128, 454906670891517_u128, 453662486720384_u128, 452421704073871_u128, 451184313854998_u128, 
-    449950306817656_u128, 448719673566603_u128, 447492405601384_u128, 446268492333711_u128, 445047925859651_u128, 
-    443830696336570_u128, 442616795114882_u128, 441406212650216_u128, 440198940143852_u128, 438994968498813_u128, 
-    437794288916379_u128, 436596891852181_u128, 435402768954891_u128, 434211910680139_u128, 433024308527468_u128, 
-    431839953698160_u128, 430658836796975_u128, 429480949323457_u128, 428306282777149_u128, 427134827613680_u128, 
-    425966575630854_u128, 424801517731694_u128, 423639645564873_u128, 422480950033412_u128, 421325422636855_u128, 
-    420173055023877_u128, 419023837650106_u128, 417877763059002_u128, 416734821557061_u128, 415595005836875_u128, 
-    414458306204940_u128, 413324714608194_u128, 412194222546179_u128, 411066821816702_u128, 409942503322783_u128, 
-    408821259160489_u128, 407703081127626_u128, 406587959828953_u128, 405475888106191_u128, 404366857309752_u128, 
-    403260858193528_u128, 402157883748370_u128, 401057924877298_u128, 399960973974641_u128, 398867022241682_u128, 
-    397776061774488_u128, 396688084519995_u128, 395603081530354_u128, 394521046094679_u128, 393441968966860_u128, 
-    392365842392095_u128, 391292658019059_u128, 390222408689472_u128, 389155085306356_u128, 388090680711431_u128, 
-    387029186404242_u128, 385970594779117_u128, 384914897633862_u128, 383862087064544_u128, 382812155316360_u128, 
-    381765094634509_u128, 380720896667666_u128, 379679554555813_u128, 378641059351101_u128, 377605403746121_u128, 
-    376572580582595_u128, 375542581210935_u128, 374515398472863_u128, 373491024315314_u128, 372469451281749_u128, 
-    371450671617365_u128, 370434677865622_u128, 369421462122587_u128, 368411016931719_u128, 367403334985609_u128, 
-    366398408529455_u128, 365396229510194_u128, 364396791366068_u128, 363400086193147_u128, 362406106236627_u128, 
-    361414844338230_u128, 360426292892285_u128, 359440444740512_u128, 358457292426372_u128, 357476828344192_u128, 
-    356499045932218_u128, 355523936391732_u128, 354551493757501_u128, 353581710274723_u128, 352614578635989_u128, 
-    351650091533890_u128, 350688241810145_u128, 349729022604738_u128, 348772426013736_u128, 347818445475384_u128, 
-    346867073682272_u128, 345918303028729_u128, 344972127251260_u128, 344028538893325_u128, 343087530498384_u128, 
-    342149095653812_u128, 341213226455677_u128, 340279917087876_u128, 339349159646476_u128, 338420947420592_u128, 
-    337495273251944_u128, 336572130877039_u128, 335651512541074_u128, 334733411831425_u128, 333817821738944_u128, 
-    332904735403613_u128, 331994145965415_u128, 331086046564333_u128, 330180430340350_u128, 329277291179103_u128, 
-    328376621176659_u128, 327478414367784_u128, 326582663892464_u128, 325689362741548_u128, 324798504502413_u128, 
-    323910082613302_u128, 323024089766807_u128, 322140520445087_u128, 321259367042471_u128, 320380622997203_u128, 
-    319504282344052_u128, 318630338222999_u128, 317758783774028_u128, 316889612882775_u128, 316022818838355_u128, 
-    315158395228141_u128, 314296335490380_u128, 313436633212445_u128, 312579281981713_u128, 311724275087296_u128, 
-    310871606862224_u128, 310021269700826_u128, 309173258681785_u128, 308327566199430_u128, 307484186885051_u128, 
-    306643113282109_u128, 305804340469286_u128, 304967861139173_u128, 304133669177407_u128, 303301758767886_u128, 
-    302472123199724_u128, 301644756507688_u128, 300819652577416_u128, 299996804996282_u128, 299176207649924_u128, 
-    298357854573108_u128, 297541738905818_u128, 296727855726737_u128, 295916198175848_u128, 295106760287919_u128, 
-    294299535799455_u128, 293494519043486_u128, 292691703756517_u128, 291891083973318_u128, 291092653877785_u128, 
-    290296407057294_u128, 289502337844874_u128, 288710440275294_u128, 287920708532451_u128, 287133136352852_u128, 
-    286347717920394_u128, 285564447568108_u128, 284783319181630_u128, 284004327093989_u128, 283227465489084_u128, 
-    282452728401683_u128, 281680110015683_u128, 280909604365853_u128, 280141206381745_u128, 279374909650734_u128, 
-    278610708356719_u128, 277848596981860_u128, 277088570455709_u128, 276330621769118_u128, 275574746299032_u128, 
-    274820937931088_u128, 274069191296578_u128, 273319500132006_u128, 272571859516056_u128, 271826263781758_u128, 
-    271082706963879_u128, 270341183246317_u128, 269601688304280_u128, 268864215128619_u128, 268128759096279_u128, 
-    267395314242027_u128, 266663875644547_u128, 265934437189477_u128, 265206993358975_u128, 264481539529987_u128, 
-    263758069439019_u128, 263036578015623_u128, 262317059891090_u128, 261599509249319_u128, 260883921318124_u128, 
-    260170290281403_u128, 259458611068709_u128, 258748878460463_u128, 258041086193172_u128, 257335230389434_u128, 
-    256631304636625_u128, 255929304162558_u128, 255229223598526_u128, 254531057724948_u128, 253834801173117_u128, 
-    253140449021715_u128, 252447995603772_u128, 251757436296232_u128, 251068765282995_u128, 250381978090136_u128, 
-    249697069050684_u128, 249014033094191_u128, 248332865448473_u128, 247653560595688_u128, 246976113465389_u128, 
-    246300519434522_u128, 245626772836115_u128, 244954869047113_u128, 244284802847939_u128, 243616569317275_u128, 
-    242950163384675_u128, 242285579979689_u128, 241622814031872_u128, 240961861216428_u128, 240302715568128_u128, 
-    239645372911306_u128, 238989828175517_u128, 238336076290311_u128, 237684112036113_u128, 237033931386388_u128, 
-    236385528674167_u128, 235738899574656_u128, 235094038719146_u128, 234450941782844_u128, 233809603695301_u128, 
-    233170019535203_u128, 232532184828623_u128, 231896094355983_u128, 231261743792492_u128, 230629128365961_u128, 
-    229998242707683_u128, 229369082791125_u128, 228741643694972_u128, 228115920348774_u128, 227491908875132_u128, 
-    226869603756204_u128, 226249000518068_u128, 225630095134192_u128, 225012881937603_u128, 224397357200033_u128, 
-    223783516000164_u128, 223171353416681_u128, 222560865423050_u128, 221952046799694_u128, 221344893520081_u128, 
-    220739400812025_u128, 220135564201601_u128, 219533378916625_u128, 218932841079694_u128, 218333945471230_u128, 
-    217736687915572_u128, 217141063640532_u128, 216547068917841_u128, 215954698527921_u128, 215363948444239_u128, 
-    214774814043741_u128, 214187290852502_u128, 213601374843990_u128, 213017061246020_u128, 212434345733798_u128, 
-    211853223982530_u128, 211273691816554_u128, 210695744612816_u128, 210119378195651_u128, 209544588389398_u128, 
-    208971370272740_u128, 208399720117407_u128, 207829633598605_u128, 207261106540671_u128, 206694134022289_u128, 
-    206128712315189_u128, 205564837392839_u128, 205002504632183_u128, 204441709708429_u128, 203882448893305_u128, 
-    203324717414627_u128, 202768511693255_u128, 202213827404394_u128, 201660659924992_u128, 201109005526776_u128, 
-    200558859735822_u128, 200010219271254_u128, 199463078913492_u128, 198917435232529_u128, 198373284052700_u128, 
-    197830621049214_u128, 197289442344668_u128, 196749743912532_u128, 196211521726272_u128, 195674771461097_u128, 
-    195139489239604_u128, 194605671482654_u128, 194073313567193_u128, 193542411615819_u128, 193012962049392_u128, 
-    192484960244858_u128, 191958403070470_u128, 191433285754042_u128, 190909605014696_u128, 190387356378507_u128, 
-    189866536415468_u128, 189347140800785_u128, 188829165955317_u128, 188312607852534_u128, 187797462615035_u128, 
-    187283726365418_u128, 186771395375413_u128, 186260465469358_u128, 185750933366374_u128, 185242795039930_u128, 
-    184736046165233_u128, 184230683461404_u128, 183726703349304_u128, 183224101205879_u128, 182722874346773_u128, 
-    182223017999801_u128, 181724529033216_u128, 181227403718747_u128, 180731637880731_u128, 180237228387423_u128, 
-    179744170764897_u128, 179252462179669_u128, 178762098306944_u128, 178273075865845_u128, 177785390829841_u128, 
-    177299039918052_u128, 176814018954818_u128, 176330324957520_u128, 175847954048760_u128, 175366902052873_u128, 
-    174887166434636_u128, 174408742869255_u128, 173931627628462_u128, 173455817580507_u128, 172981309146253_u128, 
-    172508098448297_u128, 172036182056632_u128, 171565556690379_u128, 171096218323007_u128, 170628163822769_u128, 
-    170161389908787_u128, 169695892405398_u128, 169231668031724_u128, 168768713656019_u128, 168307025848274_u128, 
-    167846600283695_u128, 167387434576190_u128, 166929524550096_u128, 166472867371927_u128, 166017458866021_u128, 
-    165563296348023_u128, 165110375940532_u128, 164658694362671_u128, 164208248631823_u128, 163759034274065_u128, 
-    163311049350694_u128, 162864289536919_u128, 162418751551861_u128, 161974432711165_u128, 161531328839170_u128, 
-    161089437102389_u128, 160648754070814_u128, 160209276463568_u128, 159771000850641_u128, 159333924249417_u128, 
-    158898043080758_u128, 158463354063785_u128, 158029854514144_u128, 157597540107041_u128, 157166408605513_u128, 
-    156736456281292_u128, 156307680002630_u128, 155880076637780_u128, 155453642905863_u128, 155028375526004_u128, 
-    154604271515584_u128, 154181327444596_u128, 153759540330423_u128, 153338906892187_u128, 152919423998143_u128, 
-    152501088963934_u128, 152083897464768_u128, 151667847711075_u128, 151252935676322_u128, 150839158825287_u128, 
-    150426513578830_u128, 150014997252596_u128, 149604606565708_u128, 149195338088156_u128, 148787189582979_u128, 
-    148380157172776_u128, 147974238322323_u128, 147569429750743_u128, 147165728475418_u128, 146763131513733_u128, 
-    146361635733940_u128, 145961238153425_u128, 145561935938701_u128, 145163725958021_u128, 144766605079638_u128, 
-    144370570470067_u128, 143975619444954_u128, 143581748574289_u128, 143188955173719_u128, 142797235962365_u128, 
-    142406588405004_u128, 142017009519019_u128, 141628496172664_u128, 141241045681584_u128, 140854654764901_u128, 
-    140469320887391_u128, 140085041364700_u128, 139701812617689_u128, 139319632260264_u128, 138938497160678_u128, 
-    138558404932840_u128, 138179352445000_u128, 137801336416283_u128, 137424354460594_u128, 137048404191841_u128, 
-    136673481881754_u128, 136299585293371_u128, 135926711294944_u128, 135554857351249_u128, 135184020628803_u128, 
-    134814198443249_u128, 134445387513710_u128, 134077585752355_u128, 133710789878306_u128, 133344997356339_u128, 
-    132980205651230_u128, 132616411631233_u128, 132253612761124_u128, 131891806356548_u128, 131530989434889_u128, 
-    131171159759184_u128, 130812314197688_u128, 130454450513437_u128, 130097565276423_u128, 129741656697077_u128, 
-    129386721494521_u128, 129032757282661_u128, 128679761228012_u128, 128327730646220_u128, 127976663449454_u128, 
-    127626556356835_u128, 127277406684009_u128, 126929212492276_u128, 126581970351627_u128, 126235678621623_u128, 
-    125890333872255_u128, 125545933568300_u128, 125202475771057_u128, 124859957199649_u128, 124518375467981_u128, 
-    124177728637355_u128, 123838013277761_u128, 123499227152238_u128, 123161367725561_u128, 122824432909899_u128, 
-    122488419275243_u128, 122153325032024_u128, 121819147346757_u128, 121485883833348_u128, 121153532105705_u128, 
-    120822089181211_u128, 120491553121166_u128, 120161921241215_u128, 119833191006135_u128, 119505360178963_u128, 
-];